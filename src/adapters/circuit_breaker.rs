@@ -0,0 +1,229 @@
+//! Circuit breaker for adapters whose backend can go down.
+//!
+//! When Fabric or the LLM provider behind it is unavailable, every step
+//! would otherwise retry its whole `RetryPolicy` before failing. The
+//! orchestrator keeps a [`CircuitBreaker`] per adapter: after
+//! `failure_threshold` consecutive failed calls the circuit opens and calls
+//! fail fast, without retries, for `cooldown_seconds`. After that one trial
+//! call is let through (half-open); it closes the circuit if it succeeds
+//! and reopens it if it fails.
+//!
+//! The Fabric breaker is configured in the `fabric:` section of
+//! `.arkai/config.yaml` (`failure_threshold: 0` turns it off):
+//!
+//! ```yaml
+//! fabric:
+//!   circuit_breaker:
+//!     failure_threshold: 5
+//!     cooldown_seconds: 30
+//! ```
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{info, warn};
+
+/// Configured breaker thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit (0 disables the breaker)
+    #[serde(default = "default_failure_threshold")]
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a trial call
+    #[serde(default = "default_cooldown_seconds")]
+    pub cooldown_seconds: u64,
+}
+
+fn default_failure_threshold() -> u32 {
+    5
+}
+
+fn default_cooldown_seconds() -> u64 {
+    30
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_failure_threshold(),
+            cooldown_seconds: default_cooldown_seconds(),
+        }
+    }
+}
+
+/// State of a circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Calls go through
+    Closed,
+    /// Calls fail fast until the cooldown ends
+    Open,
+    /// One trial call is testing whether the adapter has recovered
+    HalfOpen,
+}
+
+impl fmt::Display for CircuitState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitState::Closed => write!(f, "closed"),
+            CircuitState::Open => write!(f, "open"),
+            CircuitState::HalfOpen => write!(f, "half-open"),
+        }
+    }
+}
+
+/// A call refused because the circuit is open
+#[derive(Debug, Clone, Error)]
+#[error("Circuit open for adapter '{adapter}' after {failures} consecutive failures; retrying in {retry_in:?}")]
+pub struct CircuitOpen {
+    pub adapter: String,
+    pub failures: u32,
+    pub retry_in: Duration,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    consecutive_failures: u32,
+    /// When the circuit last opened, or when the half-open trial started
+    since: Instant,
+}
+
+/// Consecutive-failure breaker for one adapter, shared by concurrent steps
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    adapter: String,
+    failure_threshold: u32,
+    cooldown: Duration,
+    circuit: Mutex<Circuit>,
+}
+
+impl CircuitBreaker {
+    /// A closed breaker for `adapter`
+    pub fn new(adapter: impl Into<String>, failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            adapter: adapter.into(),
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            circuit: Mutex::new(Circuit {
+                state: CircuitState::Closed,
+                consecutive_failures: 0,
+                since: Instant::now(),
+            }),
+        }
+    }
+
+    /// The breaker `config` describes, or `None` if it disables the breaker
+    pub fn from_config(adapter: impl Into<String>, config: &CircuitBreakerConfig) -> Option<Self> {
+        (config.failure_threshold > 0).then(|| {
+            Self::new(
+                adapter,
+                config.failure_threshold,
+                Duration::from_secs(config.cooldown_seconds),
+            )
+        })
+    }
+
+    /// Whether a call may go out now. While open, calls are refused until
+    /// the cooldown ends; then the first caller becomes the half-open trial
+    /// and the rest are refused until it reports back. A trial that never
+    /// reports back (e.g. its step was cancelled) is replaced after another
+    /// cooldown.
+    pub fn check(&self) -> Result<(), CircuitOpen> {
+        let mut circuit = self.circuit.lock().unwrap();
+        if circuit.state == CircuitState::Closed {
+            return Ok(());
+        }
+
+        let waited = circuit.since.elapsed();
+        if waited >= self.cooldown {
+            if circuit.state == CircuitState::Open {
+                info!(adapter = %self.adapter, "Circuit half-open, trying adapter again");
+            }
+            circuit.state = CircuitState::HalfOpen;
+            circuit.since = Instant::now();
+            return Ok(());
+        }
+
+        Err(CircuitOpen {
+            adapter: self.adapter.clone(),
+            failures: circuit.consecutive_failures,
+            retry_in: self.cooldown - waited,
+        })
+    }
+
+    /// Record a successful call, closing the circuit
+    pub fn record_success(&self) {
+        let mut circuit = self.circuit.lock().unwrap();
+        if circuit.state != CircuitState::Closed {
+            info!(adapter = %self.adapter, "Circuit closed, adapter recovered");
+        }
+        circuit.state = CircuitState::Closed;
+        circuit.consecutive_failures = 0;
+    }
+
+    /// Record a failed call, opening the circuit at the threshold or when
+    /// the half-open trial fails
+    pub fn record_failure(&self) {
+        let mut circuit = self.circuit.lock().unwrap();
+        circuit.consecutive_failures += 1;
+        let trips = match circuit.state {
+            CircuitState::Closed => circuit.consecutive_failures >= self.failure_threshold,
+            CircuitState::HalfOpen => true,
+            // A call let through before the circuit opened
+            CircuitState::Open => false,
+        };
+        if trips {
+            warn!(
+                adapter = %self.adapter,
+                failures = circuit.consecutive_failures,
+                cooldown_secs = self.cooldown.as_secs_f64(),
+                "Circuit opened, failing fast"
+            );
+            circuit.state = CircuitState::Open;
+            circuit.since = Instant::now();
+        }
+    }
+
+    /// Current state of the circuit
+    pub fn state(&self) -> CircuitState {
+        self.circuit.lock().unwrap().state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_at_threshold_and_failed_trial_reopens() {
+        let breaker = CircuitBreaker::new("fabric", 2, Duration::from_millis(50));
+
+        breaker.record_failure();
+        assert!(breaker.check().is_ok());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        let open = breaker.check().unwrap_err();
+        assert_eq!(open.failures, 2);
+
+        // After the cooldown only one trial goes through
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(breaker.check().is_ok());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.check().is_err());
+
+        // A failed trial reopens straight away
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(breaker.check().is_ok());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.check().is_ok());
+    }
+}
@@ -9,6 +9,10 @@
 //! - `__youtube__`: Fetch YouTube transcript with timestamps (uses `fabric -y <url> --transcript-with-timestamps`)
 //! - `__web__`: Fetch web page content (uses `fabric -u <url>`)
 //! - All other actions are treated as pattern names (uses `fabric -p <pattern>`)
+//!
+//! The CLI prints only the model's output, so results carry no token or cost
+//! figures and Fabric steps don't count towards `max_total_tokens` or
+//! `max_total_cost_usd`.
 
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
@@ -16,12 +20,16 @@ use std::time::Duration;
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use tokio::io::AsyncWriteExt;
-use tokio::process::Command;
-use tokio::time::timeout;
+use futures_util::stream::{self, BoxStream};
+use futures_util::TryStreamExt;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdout, Command};
+use tokio::task::JoinHandle;
+use tokio::time::{timeout, timeout_at, Instant};
 
 use super::{Adapter, AdapterOutput};
 use crate::config::{self, FabricBinaryOverrideSource};
+use crate::core::SafetyViolation;
 
 /// Special action for fetching YouTube transcripts
 pub const ACTION_YOUTUBE: &str = "__youtube__";
@@ -29,6 +37,24 @@ pub const ACTION_YOUTUBE: &str = "__youtube__";
 /// Special action for fetching web page content
 pub const ACTION_WEB: &str = "__web__";
 
+/// A spawned pattern process
+struct PatternProcess {
+    child: Child,
+    stdout: BufReader<ChildStdout>,
+    /// Collects stderr, for the error if the pattern fails
+    stderr: JoinHandle<String>,
+}
+
+/// Progress of a streamed pattern execution
+enum PatternStream {
+    /// Not spawned yet
+    Start,
+    /// Reading output
+    Reading(Box<PatternProcess>),
+    /// Finished or failed
+    Done,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FabricBinarySelectionSource {
     EnvOverride,
@@ -322,6 +348,8 @@ impl FabricAdapter {
 
     fn command(&self) -> Command {
         let mut command = Command::new(&self.binary_path);
+        // A timed-out or cancelled step drops the child; don't leave it running
+        command.kill_on_drop(true);
 
         #[cfg(unix)]
         if Self::should_alias_argv0(&self.binary_path) {
@@ -331,16 +359,8 @@ impl FabricAdapter {
         command
     }
 
-    /// Execute a pattern via subprocess
-    ///
-    /// This is the MVP implementation. It spawns `fabric -p <pattern>`
-    /// and pipes the input to stdin, collecting output from stdout.
-    async fn execute_subprocess(
-        &self,
-        pattern: &str,
-        input: &str,
-        step_timeout: Duration,
-    ) -> Result<String> {
+    /// Spawn `fabric -p <pattern>` with `input` on stdin
+    async fn spawn_pattern(&self, pattern: &str, input: &str) -> Result<PatternProcess> {
         self.ensure_compatible()?;
 
         let mut child = self
@@ -361,37 +381,92 @@ impl FabricAdapter {
             // Drop stdin to signal EOF
         }
 
-        // Wait for completion with timeout
-        let output = timeout(step_timeout, child.wait_with_output())
-            .await
-            .with_context(|| {
-                format!(
-                    "Fabric pattern '{}' timed out after {:?}",
-                    pattern, step_timeout
-                )
-            })?
-            .with_context(|| {
-                format!(
-                    "Failed to wait for fabric process for pattern '{}'",
-                    pattern
-                )
-            })?;
+        let stdout = child.stdout.take().context("Fabric stdout not captured")?;
+        let mut stderr = child.stderr.take().context("Fabric stderr not captured")?;
+        let stderr = tokio::spawn(async move {
+            let mut buf = String::new();
+            let _ = stderr.read_to_string(&mut buf).await;
+            buf
+        });
+
+        Ok(PatternProcess {
+            child,
+            stdout: BufReader::new(stdout),
+            stderr,
+        })
+    }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let exit_code = output.status.code().unwrap_or(-1);
-            anyhow::bail!(
-                "Fabric pattern '{}' failed with exit code {}: {}",
-                pattern,
-                exit_code,
-                stderr.trim()
-            );
-        }
+    /// Execute a pattern via subprocess, yielding stdout line by line as the
+    /// pattern writes it.
+    ///
+    /// This is the MVP implementation. It spawns `fabric -p <pattern>`
+    /// and pipes the input to stdin. `step_timeout` covers the whole
+    /// stream; the process is killed on timeout or if the stream is dropped.
+    fn stream_subprocess<'a>(
+        &'a self,
+        pattern: &'a str,
+        input: &'a str,
+        step_timeout: Duration,
+    ) -> BoxStream<'a, Result<String>> {
+        let deadline = Instant::now() + step_timeout;
+        let timed_out = move || {
+            anyhow::Error::new(SafetyViolation::step_timeout(step_timeout)).context(format!(
+                "Fabric pattern '{}' timed out after {:?}",
+                pattern, step_timeout
+            ))
+        };
 
-        let stdout =
-            String::from_utf8(output.stdout).context("Fabric output is not valid UTF-8")?;
+        Box::pin(stream::unfold(
+            PatternStream::Start,
+            move |state| async move {
+                let mut process = match state {
+                    PatternStream::Done => return None,
+                    PatternStream::Start => match self.spawn_pattern(pattern, input).await {
+                        Ok(process) => Box::new(process),
+                        Err(e) => return Some((Err(e), PatternStream::Done)),
+                    },
+                    PatternStream::Reading(process) => process,
+                };
 
-        Ok(stdout)
+                let mut line = String::new();
+                let read = match timeout_at(deadline, process.stdout.read_line(&mut line)).await {
+                    Ok(read) => read,
+                    Err(_) => return Some((Err(timed_out()), PatternStream::Done)),
+                };
+                match read {
+                    Ok(0) => {}
+                    Ok(_) => return Some((Ok(line), PatternStream::Reading(process))),
+                    Err(e) => {
+                        let e = anyhow::Error::new(e).context("Failed to read fabric output");
+                        return Some((Err(e), PatternStream::Done));
+                    }
+                }
+
+                // End of output: the stream ends cleanly only if the pattern succeeded
+                let status = match timeout_at(deadline, process.child.wait()).await {
+                    Ok(Ok(status)) => status,
+                    Ok(Err(e)) => {
+                        let e = anyhow::Error::new(e).context(format!(
+                            "Failed to wait for fabric process for pattern '{}'",
+                            pattern
+                        ));
+                        return Some((Err(e), PatternStream::Done));
+                    }
+                    Err(_) => return Some((Err(timed_out()), PatternStream::Done)),
+                };
+                if status.success() {
+                    return None;
+                }
+                let stderr = process.stderr.await.unwrap_or_default();
+                let e = anyhow::anyhow!(
+                    "Fabric pattern '{}' failed with exit code {}: {}",
+                    pattern,
+                    status.code().unwrap_or(-1),
+                    stderr.trim()
+                );
+                Some((Err(e), PatternStream::Done))
+            },
+        ))
     }
 
     /// Fetch YouTube transcript via fabric -y <url> --transcript-with-timestamps
@@ -465,25 +540,31 @@ impl Adapter for FabricAdapter {
     }
 
     async fn execute(&self, action: &str, input: &str, timeout: Duration) -> Result<AdapterOutput> {
-        // Handle special actions for content fetching
-        let content = match action {
-            ACTION_YOUTUBE => {
-                // Input is the YouTube URL
-                self.fetch_youtube(input, timeout).await?
-            }
-            ACTION_WEB => {
-                // Input is the web URL
-                self.fetch_web(input, timeout).await?
-            }
-            _ => {
-                // Standard pattern execution
-                self.execute_subprocess(action, input, timeout).await?
-            }
-        };
+        let content = self
+            .execute_streaming(action, input, timeout)
+            .try_collect::<String>()
+            .await?;
 
         Ok(AdapterOutput::new(content))
     }
 
+    fn execute_streaming<'a>(
+        &'a self,
+        action: &'a str,
+        input: &'a str,
+        timeout: Duration,
+    ) -> BoxStream<'a, Result<String>> {
+        // Handle special actions for content fetching (one chunk each)
+        match action {
+            // Input is the YouTube URL
+            ACTION_YOUTUBE => Box::pin(stream::once(self.fetch_youtube(input, timeout))),
+            // Input is the web URL
+            ACTION_WEB => Box::pin(stream::once(self.fetch_web(input, timeout))),
+            // Standard pattern execution
+            _ => self.stream_subprocess(action, input, timeout),
+        }
+    }
+
     async fn health_check(&self) -> Result<()> {
         self.ensure_compatible()?;
 
@@ -502,6 +583,26 @@ impl Adapter for FabricAdapter {
 
         Ok(())
     }
+
+    fn binary_path(&self) -> Option<String> {
+        Some(self.binary_path.clone())
+    }
+
+    async fn version(&self) -> Option<String> {
+        // Best-effort: a missing or hung binary just leaves the version out
+        let output = timeout(
+            Duration::from_secs(5),
+            self.command().arg("--version").output(),
+        )
+        .await
+        .ok()?
+        .ok()?;
+        if !output.status.success() {
+            return None;
+        }
+        let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        (!version.is_empty()).then_some(version)
+    }
 }
 
 #[cfg(test)]
@@ -605,5 +706,87 @@ exit 0
             .contains("incompatible"));
     }
 
+    #[tokio::test]
+    async fn test_timed_out_pattern_kills_subprocess() {
+        let dir = TempDir::new().unwrap();
+        let binary = write_executable(
+            &dir,
+            "fabric-ai",
+            r#"#!/bin/sh
+if [ "$1" = "--help" ]; then
+  printf '%s\n' '--pattern --youtube --scrape_url'
+  exit 0
+fi
+echo $$ > "$0.pid"
+exec sleep 30
+"#,
+        );
+
+        let adapter = FabricAdapter::with_binary_path(binary.to_string_lossy());
+        let result = adapter
+            .execute("summarize", "hello", Duration::from_millis(300))
+            .await;
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("timed out"));
+        assert!(matches!(
+            error.downcast_ref::<SafetyViolation>(),
+            Some(SafetyViolation::StepTimeout { .. })
+        ));
+
+        // The child is killed, not left sleeping (a zombie awaiting reaping
+        // counts as gone)
+        let pid = fs::read_to_string(binary.with_extension("pid")).unwrap();
+        let running = || {
+            let ps = std::process::Command::new("ps")
+                .args(["-o", "stat=", "-p", pid.trim()])
+                .output()
+                .unwrap();
+            let stat = String::from_utf8_lossy(&ps.stdout);
+            !stat.trim().is_empty() && !stat.trim().starts_with('Z')
+        };
+        for _ in 0..50 {
+            if !running() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("fabric subprocess {} still running", pid.trim());
+    }
+
+    #[tokio::test]
+    async fn test_pattern_output_streams_before_process_exits() {
+        let dir = TempDir::new().unwrap();
+        let binary = write_executable(
+            &dir,
+            "fabric-ai",
+            r#"#!/bin/sh
+if [ "$1" = "--help" ]; then
+  printf '%s\n' '--pattern --youtube --scrape_url'
+  exit 0
+fi
+cat
+echo
+sleep 1
+echo done
+"#,
+        );
+
+        let adapter = FabricAdapter::with_binary_path(binary.to_string_lossy());
+        let mut chunks = adapter.execute_streaming("summarize", "hello", Duration::from_secs(10));
+        let first = timeout(Duration::from_millis(800), chunks.try_next())
+            .await
+            .expect("first chunk arrives while the pattern is still running")
+            .unwrap();
+        assert_eq!(first.as_deref(), Some("hello\n"));
+        drop(chunks);
+
+        // The collected output is the chunks concatenated
+        let output = adapter
+            .execute("summarize", "hello", Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert_eq!(output.content, "hello\ndone\n");
+    }
+
     // Note: Integration tests with actual Fabric would go in tests/
 }
@@ -3,6 +3,7 @@
 //! Adapters provide a unified interface for interacting with external
 //! AI services like Fabric and Telegram.
 
+pub mod circuit_breaker;
 pub mod clawdbot;
 pub mod fabric;
 pub mod telegram;
@@ -11,6 +12,10 @@ use std::time::Duration;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream};
+
+// Re-export the circuit breaker
+pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitOpen, CircuitState};
 
 // Re-export the Fabric adapter and special actions
 pub use fabric::FabricAdapter;
@@ -28,10 +33,10 @@ pub struct AdapterOutput {
     /// The content returned by the adapter
     pub content: String,
 
-    /// Tokens used (if available)
+    /// Tokens used (if available; the Fabric CLI doesn't report them)
     pub tokens_used: Option<u64>,
 
-    /// Cost in USD (if available)
+    /// Cost in USD (if available; the Fabric CLI doesn't report it)
     pub cost_usd: Option<f64>,
 }
 
@@ -55,6 +60,33 @@ pub trait Adapter: Send + Sync {
     /// Execute an action with input
     async fn execute(&self, action: &str, input: &str, timeout: Duration) -> Result<AdapterOutput>;
 
+    /// Execute an action, yielding its output in chunks as it is produced.
+    /// The chunks concatenate to the output of [`Adapter::execute`], and
+    /// `timeout` covers the whole stream. By default the whole output is
+    /// yielded as one chunk once `execute` finishes.
+    fn execute_streaming<'a>(
+        &'a self,
+        action: &'a str,
+        input: &'a str,
+        timeout: Duration,
+    ) -> BoxStream<'a, Result<String>> {
+        Box::pin(stream::once(async move {
+            self.execute(action, input, timeout)
+                .await
+                .map(|output| output.content)
+        }))
+    }
+
     /// Health check (for HTTP adapters)
     async fn health_check(&self) -> Result<()>;
+
+    /// Path of the binary the adapter runs, if it runs one
+    fn binary_path(&self) -> Option<String> {
+        None
+    }
+
+    /// Version of the adapter's backend, if it reports one
+    async fn version(&self) -> Option<String> {
+        None
+    }
 }
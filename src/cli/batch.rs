@@ -0,0 +1,499 @@
+//! Batch runs for `arkai run <pipeline> --input-dir <dir>`.
+//!
+//! Runs the same pipeline once per matching file, each as its own run with its
+//! own event log, with at most `concurrency` runs in flight at a time. Progress
+//! is tracked in a batch manifest so `--resume-batch` can pick up where an
+//! interrupted batch left off.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, Semaphore};
+use tokio::task::JoinSet;
+use uuid::Uuid;
+
+use crate::config;
+use crate::core::{Orchestrator, Pipeline, TimeoutOverrides};
+use crate::domain::RunState;
+
+/// Progress of one input file within a batch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum EntryState {
+    /// Not started yet
+    Pending,
+
+    /// Run started but the batch hasn't recorded its outcome (e.g. interrupted)
+    Running,
+
+    /// Run completed successfully
+    Completed,
+
+    /// Run finished, but `continue_on_error` steps failed
+    PartiallyCompleted { failed_steps: Vec<String> },
+
+    /// Run failed, hit a safety limit, or couldn't start
+    Failed { error: String },
+}
+
+/// One input file and its run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchEntry {
+    /// Input file
+    pub path: PathBuf,
+
+    /// Run ID, once the run has been assigned one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<Uuid>,
+
+    /// Where this file's run got to
+    #[serde(flatten)]
+    pub state: EntryState,
+}
+
+impl BatchEntry {
+    /// Whether the run completed successfully
+    pub fn is_completed(&self) -> bool {
+        self.state == EntryState::Completed
+    }
+
+    /// Whether the run finished with usable output (not rerun on resume)
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.state,
+            EntryState::Completed | EntryState::PartiallyCompleted { .. }
+        )
+    }
+}
+
+/// Lightweight index of a batch's runs, saved as `batch-<id>.json` after every
+/// state change so an interrupted batch can be resumed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchManifest {
+    /// Batch ID
+    pub id: Uuid,
+
+    /// Name of the pipeline every file is run through
+    pub pipeline: String,
+
+    /// When the batch was created
+    pub created_at: DateTime<Utc>,
+
+    /// Input files in run order
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchManifest {
+    /// Create a manifest with every input pending
+    pub fn new(pipeline: &str, inputs: Vec<PathBuf>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            pipeline: pipeline.to_string(),
+            created_at: Utc::now(),
+            entries: inputs
+                .into_iter()
+                .map(|path| BatchEntry {
+                    path,
+                    run_id: None,
+                    state: EntryState::Pending,
+                })
+                .collect(),
+        }
+    }
+
+    /// Manifest path for a batch ID ($ARKAI_HOME/batches/batch-<id>.json)
+    pub fn path_for(id: Uuid) -> Result<PathBuf> {
+        Ok(config::arkai_home()?
+            .join("batches")
+            .join(format!("batch-{}.json", id)))
+    }
+
+    /// Load a saved manifest
+    pub fn load(id: Uuid) -> Result<Self> {
+        let path = Self::path_for(id)?;
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Batch {} not found ({})", id, path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse batch manifest: {}", path.display()))
+    }
+
+    /// Save the manifest, replacing any previous copy atomically
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path_for(self.id)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, &path).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// Files directly under `dir` whose names match `glob`, sorted by path
+pub fn collect_inputs(dir: &Path, glob: &str) -> Result<Vec<PathBuf>> {
+    let pattern = Pattern::new(glob).with_context(|| format!("Invalid glob: {}", glob))?;
+
+    let mut inputs = Vec::new();
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read input directory: {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        let matches = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| pattern.matches(n));
+        if matches && path.is_file() {
+            inputs.push(path);
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Run `pipeline` over every entry in `manifest` that hasn't completed,
+/// saving the manifest as each run starts and finishes.
+///
+/// Entries with a recorded run ID are resumed from their event log; the rest
+/// get a fresh run.
+pub async fn run_batch(
+    pipeline: &Pipeline,
+    manifest: BatchManifest,
+    concurrency: usize,
+) -> Result<BatchManifest> {
+    manifest.save()?;
+
+    let pipeline = Arc::new(pipeline.clone());
+    let manifest = Arc::new(Mutex::new(manifest));
+    let permits = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+
+    let pending: Vec<usize> = {
+        let manifest = manifest.lock().await;
+        (0..manifest.entries.len())
+            .filter(|&i| !manifest.entries[i].is_finished())
+            .collect()
+    };
+
+    for index in pending {
+        let pipeline = Arc::clone(&pipeline);
+        let manifest = Arc::clone(&manifest);
+        let permits = Arc::clone(&permits);
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            run_entry(&pipeline, &manifest, index).await
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(result) => result?,
+            Err(e) => tracing::error!(error = %e, "Batch run task panicked"),
+        }
+    }
+
+    let manifest = Arc::try_unwrap(manifest)
+        .map_err(|_| anyhow::anyhow!("Batch manifest still in use"))?
+        .into_inner();
+    Ok(manifest)
+}
+
+/// Run (or resume) one manifest entry, recording its progress
+async fn run_entry(
+    pipeline: &Pipeline,
+    manifest: &Mutex<BatchManifest>,
+    index: usize,
+) -> Result<()> {
+    let orchestrator = Orchestrator::new();
+
+    let (path, previous_run) = {
+        let manifest = manifest.lock().await;
+        let entry = &manifest.entries[index];
+        (entry.path.clone(), entry.run_id)
+    };
+
+    // A previous attempt that left events behind is resumed rather than rerun
+    let previous = match previous_run {
+        Some(run_id) => orchestrator.get_run_status(run_id).await.ok(),
+        None => None,
+    };
+    let run_id = previous_run.unwrap_or_else(Uuid::new_v4);
+
+    update_entry(manifest, index, run_id, EntryState::Running).await?;
+
+    let input = match tokio::fs::read_to_string(&path).await {
+        Ok(input) if input.trim().is_empty() => Err("Input is empty".to_string()),
+        Ok(input) => Ok(input),
+        Err(e) => Err(format!("Failed to read input file: {}", e)),
+    };
+
+    let state = match (input, previous) {
+        (_, Some(run))
+            if matches!(
+                run.state,
+                RunState::Completed | RunState::PartiallyCompleted { .. }
+            ) =>
+        {
+            Ok(run.state)
+        }
+        (Ok(input), Some(_)) => orchestrator
+            .resume_run(run_id, pipeline, input)
+            .await
+            .map(|run| run.state),
+        (Ok(input), None) => orchestrator
+            .run_pipeline_with_id(run_id, pipeline, input)
+            .await
+            .map(|run| run.state),
+        (Err(e), _) => Err(anyhow::anyhow!(e).into()),
+    };
+
+    let state = match state {
+        Ok(RunState::Completed) => EntryState::Completed,
+        Ok(RunState::PartiallyCompleted { failed_steps }) => {
+            EntryState::PartiallyCompleted { failed_steps }
+        }
+        Ok(RunState::Failed { error }) => EntryState::Failed { error },
+        Ok(RunState::SafetyLimitReached { limit }) => EntryState::Failed {
+            error: format!("safety limit reached - {}", limit),
+        },
+        Ok(other) => EntryState::Failed {
+            error: format!("run ended in state {:?}", other),
+        },
+        Err(e) => EntryState::Failed {
+            error: e.to_string(),
+        },
+    };
+
+    update_entry(manifest, index, run_id, state).await
+}
+
+async fn update_entry(
+    manifest: &Mutex<BatchManifest>,
+    index: usize,
+    run_id: Uuid,
+    state: EntryState,
+) -> Result<()> {
+    let mut manifest = manifest.lock().await;
+    let entry = &mut manifest.entries[index];
+    entry.run_id = Some(run_id);
+    entry.state = state;
+    manifest.save()
+}
+
+/// Render a summary table of batch entries
+pub fn render_summary(entries: &[BatchEntry]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{:<38} {:<12} INPUT", "RUN ID", "OUTCOME");
+
+    for entry in entries {
+        let run_id = entry
+            .run_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| "-".to_string());
+        let (label, detail) = match &entry.state {
+            EntryState::Pending => ("pending", None),
+            EntryState::Running => ("interrupted", None),
+            EntryState::Completed => ("completed", None),
+            EntryState::PartiallyCompleted { failed_steps } => {
+                ("partial", Some(failed_steps.join(", ")))
+            }
+            EntryState::Failed { error } => ("failed", Some(error.clone())),
+        };
+
+        let _ = write!(out, "{:<38} {:<12} {}", run_id, label, entry.path.display());
+        if let Some(detail) = detail {
+            let _ = write!(out, " ({})", detail);
+        }
+        out.push('\n');
+    }
+
+    let completed = entries.iter().filter(|e| e.is_completed()).count();
+    let partial = entries.iter().filter(|e| e.is_finished()).count() - completed;
+    let _ = write!(out, "\n{} of {} runs completed", completed, entries.len());
+    if partial > 0 {
+        let _ = write!(out, ", {} partially", partial);
+    }
+    out.push('\n');
+    out
+}
+
+/// Execute `arkai run <pipeline> --input-dir <dir>`
+pub(crate) async fn execute_batch(
+    pipeline_name: &str,
+    dir: &Path,
+    glob: &str,
+    concurrency: usize,
+    timeouts: &TimeoutOverrides,
+    strict: bool,
+) -> Result<()> {
+    let pipeline = super::load_run_pipeline(pipeline_name, timeouts, strict)?;
+    let inputs = collect_inputs(dir, glob)?;
+    if inputs.is_empty() {
+        anyhow::bail!("No files matching '{}' in {}", glob, dir.display());
+    }
+
+    // Record the name the pipeline was loaded by, so resume can load it again
+    let manifest = BatchManifest::new(pipeline_name, inputs);
+    eprintln!(
+        "Batch {}: running '{}' over {} files (concurrency {})",
+        manifest.id,
+        pipeline.name,
+        manifest.entries.len(),
+        concurrency.max(1)
+    );
+
+    finish_batch(run_batch(&pipeline, manifest, concurrency).await?)
+}
+
+/// Execute `arkai run --resume-batch <batch-id>`
+pub(crate) async fn execute_resume_batch(
+    batch_id: &str,
+    concurrency: usize,
+    timeouts: &TimeoutOverrides,
+    strict: bool,
+) -> Result<()> {
+    let id =
+        Uuid::parse_str(batch_id).with_context(|| format!("Invalid batch ID: {}", batch_id))?;
+    let manifest = BatchManifest::load(id)?;
+    let pipeline = super::load_run_pipeline(&manifest.pipeline, timeouts, strict)?;
+
+    let remaining = manifest.entries.iter().filter(|e| !e.is_finished()).count();
+    eprintln!(
+        "Batch {}: resuming '{}' ({} of {} files remaining)",
+        manifest.id,
+        manifest.pipeline,
+        remaining,
+        manifest.entries.len()
+    );
+
+    finish_batch(run_batch(&pipeline, manifest, concurrency).await?)
+}
+
+fn finish_batch(manifest: BatchManifest) -> Result<()> {
+    print!("{}", render_summary(&manifest.entries));
+
+    let failed = manifest.entries.iter().filter(|e| !e.is_finished()).count();
+    if failed > 0 {
+        anyhow::bail!(
+            "{} of {} runs did not complete (resume with: arkai run --resume-batch {})",
+            failed,
+            manifest.entries.len(),
+            manifest.id
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_input_dir_flags_parse() {
+        let cli = Cli::try_parse_from([
+            "arkai",
+            "run",
+            "hello",
+            "--input-dir",
+            "in",
+            "--glob",
+            "*.md",
+            "--concurrency",
+            "4",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Run {
+                input_dir,
+                glob,
+                concurrency,
+                ..
+            } => {
+                assert_eq!(input_dir, Some(PathBuf::from("in")));
+                assert_eq!(glob, "*.md");
+                assert_eq!(concurrency, 4);
+            }
+            other => panic!("Expected Run, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["arkai", "run", "hello", "--glob", "*.md"]).is_err());
+        assert!(Cli::try_parse_from(["arkai", "run", "--resume-batch", "id"]).is_ok());
+        assert!(Cli::try_parse_from(["arkai", "run"]).is_err());
+        assert!(
+            Cli::try_parse_from(["arkai", "run", "hello", "--input-dir", "in", "--stdin"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_collect_inputs_filters_and_sorts() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("b.md"), "b").unwrap();
+        std::fs::write(dir.path().join("a.md"), "a").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "n").unwrap();
+        std::fs::create_dir(dir.path().join("sub.md")).unwrap();
+
+        let names: Vec<_> = collect_inputs(dir.path(), "*.md")
+            .unwrap()
+            .iter()
+            .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["a.md", "b.md"]);
+
+        assert_eq!(collect_inputs(dir.path(), "*").unwrap().len(), 3);
+        assert!(collect_inputs(dir.path(), "[").is_err());
+    }
+
+    #[test]
+    fn test_render_summary_counts_outcomes() {
+        let entries = vec![
+            BatchEntry {
+                path: PathBuf::from("a.md"),
+                run_id: Some(Uuid::nil()),
+                state: EntryState::Completed,
+            },
+            BatchEntry {
+                path: PathBuf::from("b.md"),
+                run_id: None,
+                state: EntryState::Failed {
+                    error: "Input is empty".to_string(),
+                },
+            },
+        ];
+
+        let summary = render_summary(&entries);
+        assert!(summary.contains("completed"));
+        assert!(summary.contains("b.md (Input is empty)"));
+        assert!(summary.contains("1 of 2 runs completed"));
+    }
+
+    #[test]
+    fn test_manifest_entry_serialization() {
+        let entry = BatchEntry {
+            path: PathBuf::from("a.md"),
+            run_id: None,
+            state: EntryState::Failed {
+                error: "boom".to_string(),
+            },
+        };
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["state"], "failed");
+        assert_eq!(json["error"], "boom");
+        assert!(json.get("run_id").is_none());
+
+        let back: BatchEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(back.state, entry.state);
+    }
+}
@@ -0,0 +1,299 @@
+//! System clipboard access for `arkai run --clipboard` / `--to-clipboard`.
+//!
+//! Shells out to the platform clipboard tools rather than linking a GUI crate:
+//! - macOS: `pbpaste` / `pbcopy`
+//! - Linux: `wl-paste`/`wl-copy`, `xclip`, or `xsel` (first one found)
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
+
+/// A source of clipboard text (mockable for tests)
+pub trait Clipboard {
+    /// Read the clipboard contents as text
+    fn read_text(&self) -> Result<String>;
+
+    /// Replace the clipboard contents with text
+    fn write_text(&self, text: &str) -> Result<()>;
+}
+
+/// Clipboard backed by the platform's command-line tools
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClipboard;
+
+impl SystemClipboard {
+    /// Candidate read commands for this platform, in preference order
+    fn read_commands() -> &'static [(&'static str, &'static [&'static str])] {
+        if cfg!(target_os = "macos") {
+            &[("pbpaste", &[])]
+        } else {
+            &[
+                ("wl-paste", &["--no-newline"]),
+                ("xclip", &["-selection", "clipboard", "-o"]),
+                ("xsel", &["--clipboard", "--output"]),
+            ]
+        }
+    }
+
+    /// Candidate write commands for this platform, in preference order
+    fn write_commands() -> &'static [(&'static str, &'static [&'static str])] {
+        if cfg!(target_os = "macos") {
+            &[("pbcopy", &[])]
+        } else {
+            &[
+                ("wl-copy", &[]),
+                ("xclip", &["-selection", "clipboard"]),
+                ("xsel", &["--clipboard", "--input"]),
+            ]
+        }
+    }
+
+    fn no_tool_error(commands: &[(&str, &[&str])]) -> anyhow::Error {
+        let tools: Vec<&str> = commands.iter().map(|(p, _)| *p).collect();
+        anyhow::anyhow!("No clipboard tool found (tried: {})", tools.join(", "))
+    }
+}
+
+impl Clipboard for SystemClipboard {
+    fn read_text(&self) -> Result<String> {
+        let mut last_error = None;
+
+        for (program, args) in Self::read_commands() {
+            let output = match Command::new(program).args(*args).output() {
+                Ok(output) => output,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    last_error = Some(anyhow::anyhow!("Failed to run {}: {}", program, e));
+                    continue;
+                }
+            };
+
+            if !output.status.success() {
+                last_error = Some(anyhow::anyhow!(
+                    "{} failed: {}",
+                    program,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
+                continue;
+            }
+
+            return String::from_utf8(output.stdout)
+                .context("Clipboard does not contain text (not valid UTF-8)");
+        }
+
+        Err(last_error.unwrap_or_else(|| Self::no_tool_error(Self::read_commands())))
+    }
+
+    fn write_text(&self, text: &str) -> Result<()> {
+        let mut last_error = None;
+
+        for (program, args) in Self::write_commands() {
+            // xclip and xsel fork to keep serving the selection, and the fork
+            // inherits any pipe we give it, so reading its output until EOF
+            // would hang: only stdin is piped, and we wait for the exit alone
+            let mut child = match Command::new(program)
+                .args(*args)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    last_error = Some(anyhow::anyhow!("Failed to run {}: {}", program, e));
+                    continue;
+                }
+            };
+
+            // Dropping stdin closes it, so the tool sees the end of the text
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin
+                    .write_all(text.as_bytes())
+                    .with_context(|| format!("Failed to write to {}", program))?;
+            }
+
+            let status = child
+                .wait()
+                .with_context(|| format!("Failed to wait for {}", program))?;
+            if status.success() {
+                return Ok(());
+            }
+
+            last_error = Some(anyhow::anyhow!("{} failed ({})", program, status));
+        }
+
+        Err(last_error.unwrap_or_else(|| Self::no_tool_error(Self::write_commands())))
+    }
+}
+
+/// Read pipeline input from the clipboard, rejecting empty contents
+pub fn read_clipboard_input(clipboard: &dyn Clipboard) -> Result<String> {
+    let text = clipboard.read_text()?;
+
+    if text.trim().is_empty() {
+        anyhow::bail!("Clipboard is empty");
+    }
+
+    Ok(text)
+}
+
+/// Emit a run's final output to the clipboard and/or `out`.
+///
+/// With `to_clipboard`, the output is copied instead of printed (or in addition,
+/// with `also_print`). If the clipboard is unavailable (e.g. headless), falls
+/// back to printing with a warning so the output is never lost.
+pub fn emit_output(
+    content: &str,
+    to_clipboard: bool,
+    also_print: bool,
+    clipboard: &dyn Clipboard,
+    out: &mut dyn Write,
+) -> Result<()> {
+    if to_clipboard {
+        match clipboard.write_text(content) {
+            Ok(()) => {
+                eprintln!("[Final output copied to clipboard]");
+                if !also_print {
+                    return Ok(());
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "Warning: could not copy to clipboard ({}); printing to stdout instead",
+                    e
+                );
+            }
+        }
+    }
+
+    writeln!(out, "{}", content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    use std::cell::RefCell;
+
+    struct MockClipboard(Result<String, String>);
+
+    impl Clipboard for MockClipboard {
+        fn read_text(&self) -> Result<String> {
+            self.0.clone().map_err(|e| anyhow::anyhow!(e))
+        }
+
+        fn write_text(&self, _text: &str) -> Result<()> {
+            anyhow::bail!("no clipboard in mock")
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingClipboard {
+        written: RefCell<Option<String>>,
+    }
+
+    impl Clipboard for RecordingClipboard {
+        fn read_text(&self) -> Result<String> {
+            Ok(self.written.borrow().clone().unwrap_or_default())
+        }
+
+        fn write_text(&self, text: &str) -> Result<()> {
+            *self.written.borrow_mut() = Some(text.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_read_clipboard_input_returns_text() {
+        let clipboard = MockClipboard(Ok("copied article".to_string()));
+        assert_eq!(read_clipboard_input(&clipboard).unwrap(), "copied article");
+    }
+
+    #[test]
+    fn test_read_clipboard_input_rejects_empty() {
+        let clipboard = MockClipboard(Ok("  \n".to_string()));
+        let err = read_clipboard_input(&clipboard).unwrap_err();
+        assert!(err.to_string().contains("Clipboard is empty"));
+    }
+
+    #[test]
+    fn test_read_clipboard_input_propagates_errors() {
+        let clipboard = MockClipboard(Err("Clipboard does not contain text".to_string()));
+        let err = read_clipboard_input(&clipboard).unwrap_err();
+        assert!(err.to_string().contains("does not contain text"));
+    }
+
+    #[test]
+    fn test_clipboard_flag_parses() {
+        let cli = Cli::try_parse_from(["arkai", "run", "hello", "--clipboard"]).unwrap();
+        match cli.command {
+            Commands::Run { clipboard, .. } => assert!(clipboard),
+            other => panic!("Expected Run, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_clipboard_conflicts_with_other_inputs() {
+        assert!(Cli::try_parse_from(["arkai", "run", "hello", "--clipboard", "--stdin"]).is_err());
+        assert!(
+            Cli::try_parse_from(["arkai", "run", "hello", "--clipboard", "-i", "in.txt"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_emit_output_to_clipboard_only() {
+        let clipboard = RecordingClipboard::default();
+        let mut out = Vec::new();
+
+        emit_output("final", true, false, &clipboard, &mut out).unwrap();
+
+        assert_eq!(clipboard.written.borrow().as_deref(), Some("final"));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_emit_output_to_clipboard_and_stdout() {
+        let clipboard = RecordingClipboard::default();
+        let mut out = Vec::new();
+
+        emit_output("final", true, true, &clipboard, &mut out).unwrap();
+
+        assert_eq!(clipboard.written.borrow().as_deref(), Some("final"));
+        assert_eq!(String::from_utf8(out).unwrap(), "final\n");
+    }
+
+    #[test]
+    fn test_emit_output_falls_back_to_stdout_without_clipboard() {
+        let clipboard = MockClipboard(Err("headless".to_string()));
+        let mut out = Vec::new();
+
+        emit_output("final", true, false, &clipboard, &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "final\n");
+    }
+
+    #[test]
+    fn test_emit_output_defaults_to_stdout() {
+        let clipboard = RecordingClipboard::default();
+        let mut out = Vec::new();
+
+        emit_output("final", false, false, &clipboard, &mut out).unwrap();
+
+        assert!(clipboard.written.borrow().is_none());
+        assert_eq!(String::from_utf8(out).unwrap(), "final\n");
+    }
+
+    #[test]
+    fn test_also_print_requires_to_clipboard() {
+        assert!(Cli::try_parse_from(["arkai", "run", "hello", "--also-print"]).is_err());
+        assert!(
+            Cli::try_parse_from(["arkai", "run", "hello", "--to-clipboard", "--also-print"])
+                .is_ok()
+        );
+    }
+}
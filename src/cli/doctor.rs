@@ -0,0 +1,349 @@
+//! Installation checks for `arkai doctor`.
+//!
+//! A first run can fail in ways that are hard to read from the error alone:
+//! Fabric not installed, ffmpeg missing, an unparseable config, an
+//! unwritable `~/.arkai`. Each check here looks at one of those and reports
+//! ✓ or ✗ with a hint for fixing it. Checks for optional features (voice
+//! capture) report a failure as a warning (!) that doesn't fail the
+//! installation. Checks take what they inspect as arguments (an adapter, a
+//! PATH lookup, a directory) so tests can run them without the real tools
+//! installed.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::adapters::Adapter;
+use crate::config::ResolvedConfig;
+use crate::ingest::{WatcherConfig, WhisperBinary};
+
+/// Outcome of one check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub passed: bool,
+    /// Only needed for an optional feature, so failing is a warning
+    pub optional: bool,
+    pub detail: String,
+    /// How to fix a failed check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: true,
+            optional: false,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            passed: false,
+            optional: false,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    /// Mark the check as only needed for an optional feature
+    fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = match (self.passed, self.optional) {
+            (true, _) => "✓",
+            (false, true) => "!",
+            (false, false) => "✗",
+        };
+        write!(f, "{} {}: {}", mark, self.name, self.detail)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "\n    → {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fabric answers its health check
+pub async fn check_fabric(adapter: &dyn Adapter) -> Check {
+    let binary = adapter
+        .binary_path()
+        .unwrap_or_else(|| adapter.name().to_string());
+    match adapter.health_check().await {
+        Ok(()) => Check::pass("fabric", binary),
+        Err(e) => Check::fail(
+            "fabric",
+            format!("{}: {:#}", binary, e),
+            "Install Fabric (https://github.com/danielmiessler/fabric) or point \
+             ARKAI_FABRIC_BIN / fabric.binary in config at it",
+        ),
+    }
+}
+
+/// ffmpeg and ffprobe are on PATH (needed for `.qta` voice memos)
+pub fn check_media_tools(available: impl Fn(&str) -> bool) -> Check {
+    let missing: Vec<_> = ["ffmpeg", "ffprobe"]
+        .into_iter()
+        .filter(|tool| !available(tool))
+        .collect();
+    if missing.is_empty() {
+        Check::pass("ffmpeg", "ffmpeg and ffprobe found")
+    } else {
+        Check::fail(
+            "ffmpeg",
+            format!("{} not found on PATH", missing.join(" and ")),
+            "Install ffmpeg (macOS: brew install ffmpeg, Debian/Ubuntu: apt install ffmpeg)",
+        )
+    }
+}
+
+/// A Whisper binary resolves (needed for voice transcription)
+pub fn check_whisper(whisper: Result<WhisperBinary>) -> Check {
+    match whisper {
+        Ok(whisper) => Check::pass(
+            "whisper",
+            format!("{} ({:?})", whisper.path.display(), whisper.flavor),
+        ),
+        Err(e) => Check::fail(
+            "whisper",
+            format!("{:#}", e),
+            "Install whisper (pip install openai-whisper) or set transcriber.binary in config",
+        ),
+    }
+}
+
+/// The config file parses
+pub fn check_config(config: Result<&ResolvedConfig>) -> Check {
+    match config {
+        Ok(config) => Check::pass(
+            "config",
+            match &config.config_file {
+                Some(path) => format!("loaded {}", path.display()),
+                None => "no config file, using defaults".to_string(),
+            },
+        ),
+        Err(e) => Check::fail(
+            "config",
+            format!("{:#}", e),
+            "Fix or remove the config file named above",
+        ),
+    }
+}
+
+/// `dir` accepts new files, or can be created: the nearest existing
+/// directory above it accepts new files. Nothing is created but a probe
+/// file, which is removed again.
+pub fn check_writable(name: &'static str, dir: &Path) -> Check {
+    let hint = format!(
+        "Check the permissions of {}, or move it with paths in config",
+        dir.display()
+    );
+    let Some(existing) = dir.ancestors().find(|path| path.exists()) else {
+        return Check::fail(
+            name,
+            format!("{} has no existing parent", dir.display()),
+            hint,
+        );
+    };
+    if !existing.is_dir() {
+        return Check::fail(
+            name,
+            format!(
+                "{} is not writable: {} is not a directory",
+                dir.display(),
+                existing.display()
+            ),
+            hint,
+        );
+    }
+
+    let probe = existing.join(format!(".arkai-doctor-{}", uuid::Uuid::new_v4()));
+    let result = std::fs::write(&probe, b"");
+    let _ = std::fs::remove_file(&probe);
+    match result {
+        Ok(()) if existing == dir => Check::pass(name, format!("{} is writable", dir.display())),
+        Ok(()) => Check::pass(
+            name,
+            format!(
+                "{} will be created under {}, which is writable",
+                dir.display(),
+                existing.display()
+            ),
+        ),
+        Err(e) => Check::fail(
+            name,
+            format!("{} is not writable: {}", existing.display(), e),
+            hint,
+        ),
+    }
+}
+
+/// The Voice Memos directory the watcher reads exists
+pub fn check_watch_path(path: &Path) -> Check {
+    if path.is_dir() {
+        Check::pass("voice watch path", path.display().to_string())
+    } else {
+        Check::fail(
+            "voice watch path",
+            format!("{} does not exist", path.display()),
+            "Only needed for voice capture: enable iCloud sync in Settings → Voice Memos \
+             on your iPhone so memos sync to this Mac",
+        )
+    }
+}
+
+/// `ok` when every check passed, `warn` when only optional checks failed,
+/// `fail` otherwise
+pub fn overall_status(checks: &[Check]) -> &'static str {
+    if checks.iter().any(|check| !check.passed && !check.optional) {
+        "fail"
+    } else if checks.iter().any(|check| !check.passed) {
+        "warn"
+    } else {
+        "ok"
+    }
+}
+
+/// Every check, against the real installation
+pub async fn run_checks(fabric: &dyn Adapter) -> Vec<Check> {
+    let config = crate::config::config();
+    let mut checks = vec![check_fabric(fabric).await];
+    // ffmpeg, Whisper and Voice Memos are only needed for voice capture
+    checks.push(check_media_tools(crate::ingest::tool_on_path).optional());
+    checks.push(check_whisper(WhisperBinary::from_config()).optional());
+    if let Ok(config) = config {
+        checks.push(check_writable("home", &config.home));
+        checks.push(check_writable("runs", &config.home.join("runs")));
+        checks.push(check_writable("library", &config.library));
+    }
+    checks.push(check_config(config));
+    checks.push(check_watch_path(&WatcherConfig::default().watch_path).optional());
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::AdapterOutput;
+    use crate::ingest::WhisperFlavor;
+    use async_trait::async_trait;
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    struct StubFabric {
+        healthy: bool,
+    }
+
+    #[async_trait]
+    impl Adapter for StubFabric {
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        async fn execute(
+            &self,
+            _action: &str,
+            input: &str,
+            _timeout: Duration,
+        ) -> Result<AdapterOutput> {
+            Ok(AdapterOutput::new(input.to_string()))
+        }
+
+        async fn health_check(&self) -> Result<()> {
+            if self.healthy {
+                Ok(())
+            } else {
+                anyhow::bail!("not installed")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fabric_check_follows_health_check() {
+        let healthy = check_fabric(&StubFabric { healthy: true }).await;
+        assert!(healthy.passed);
+        assert_eq!(healthy.to_string(), "✓ fabric: stub");
+
+        let broken = check_fabric(&StubFabric { healthy: false }).await;
+        assert!(!broken.passed);
+        assert_eq!(broken.detail, "stub: not installed");
+        assert!(broken.to_string().contains("\n    → Install Fabric"));
+    }
+
+    #[test]
+    fn test_tool_checks_with_mocked_availability() {
+        assert!(check_media_tools(|_| true).passed);
+        let missing = check_media_tools(|tool| tool == "ffmpeg");
+        assert!(!missing.passed);
+        assert_eq!(missing.detail, "ffprobe not found on PATH");
+        assert_eq!(
+            check_media_tools(|_| false).detail,
+            "ffmpeg and ffprobe not found on PATH"
+        );
+
+        let whisper = check_whisper(Ok(WhisperBinary {
+            path: PathBuf::from("/usr/local/bin/whisper-cli"),
+            flavor: WhisperFlavor::Cpp,
+            extra_args: Vec::new(),
+        }));
+        assert_eq!(whisper.detail, "/usr/local/bin/whisper-cli (Cpp)");
+        assert!(!check_whisper(Err(anyhow::anyhow!("no whisper on PATH"))).passed);
+
+        let config = check_config(Err(anyhow::anyhow!("invalid YAML at line 3")));
+        assert!(!config.passed);
+        assert_eq!(config.detail, "invalid YAML at line 3");
+    }
+
+    #[test]
+    fn test_path_checks() {
+        let temp = TempDir::new().unwrap();
+
+        // Checked, not created
+        let runs = temp.path().join("home").join("runs");
+        let check = check_writable("runs", &runs);
+        assert!(check.passed);
+        assert!(check.detail.contains("will be created under"));
+        assert!(!temp.path().join("home").exists());
+
+        std::fs::create_dir_all(&runs).unwrap();
+        assert!(check_writable("runs", &runs).passed);
+        // The probe file is cleaned up
+        assert_eq!(std::fs::read_dir(&runs).unwrap().count(), 0);
+        assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 1);
+
+        // A file where the directory should be
+        let blocked = temp.path().join("blocked");
+        std::fs::write(&blocked, "").unwrap();
+        let check = check_writable("library", &blocked.join("library"));
+        assert!(!check.passed);
+        assert!(check.hint.unwrap().contains("permissions"));
+
+        assert!(check_watch_path(temp.path()).passed);
+        assert!(!check_watch_path(&temp.path().join("Recordings")).passed);
+    }
+
+    #[test]
+    fn test_optional_failures_only_warn() {
+        let missing = check_watch_path(Path::new("/nonexistent/Recordings")).optional();
+        assert!(missing.to_string().starts_with("! voice watch path: "));
+
+        let passed = check_media_tools(|_| true);
+        assert_eq!(overall_status(std::slice::from_ref(&passed)), "ok");
+        assert_eq!(overall_status(&[passed.clone(), missing.clone()]), "warn");
+        let required = check_config(Err(anyhow::anyhow!("bad config")));
+        assert!(required.to_string().starts_with("✗ config: "));
+        assert_eq!(overall_status(&[passed, missing, required]), "fail");
+    }
+}
@@ -0,0 +1,129 @@
+//! Opening files at a position in the user's editor.
+//!
+//! The command comes from `ARKAI_EDITOR` (or `editor:` in config), a template
+//! with `{file}`, `{line}` and `{col}` placeholders:
+//!
+//! ```text
+//! ARKAI_EDITOR='nvim +{line} {file}'
+//! ARKAI_EDITOR='idea --line {line} --column {col} {file}'
+//! ARKAI_EDITOR='code -g {file}:{line}:{col}'
+//! ```
+//!
+//! Without one, `code -g` is tried, then `$EDITOR`, then the system opener.
+
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Result;
+
+/// Template used when no editor is configured
+const VSCODE_TEMPLATE: &str = "code -g {file}:{line}:{col}";
+
+/// Build the argv for an editor template. Placeholders are substituted after
+/// splitting on whitespace, so paths with spaces stay one argument. A template
+/// without `{file}` gets the file appended.
+pub fn interpolate(template: &str, file: &Path, line: usize, col: usize) -> Vec<String> {
+    let file = file.display().to_string();
+    let (line, col) = (line.to_string(), col.to_string());
+
+    let mut args: Vec<String> = template
+        .split_whitespace()
+        .map(|part| {
+            part.replace("{file}", &file)
+                .replace("{line}", &line)
+                .replace("{col}", &col)
+        })
+        .collect();
+    if !template.contains("{file}") {
+        args.push(file);
+    }
+    args
+}
+
+/// Editor templates to try, in order
+fn candidates(configured: Option<&str>, env_editor: Option<&str>) -> Vec<String> {
+    let mut templates = Vec::new();
+    if let Some(template) = configured {
+        templates.push(template.to_string());
+    }
+    templates.push(VSCODE_TEMPLATE.to_string());
+    if let Some(editor) = env_editor.filter(|e| !e.trim().is_empty()) {
+        templates.push(editor.to_string());
+    }
+    templates.push(
+        if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        }
+        .to_string(),
+    );
+    templates
+}
+
+/// Open `file` at `line`:`col` with the first editor that runs successfully.
+/// Returns the command line used, or `None` if no editor could be started.
+pub fn open_at(file: &Path, line: usize, col: usize) -> Result<Option<String>> {
+    let configured = crate::config::config()?.editor.clone();
+    let env_editor = std::env::var("EDITOR").ok();
+
+    for template in candidates(configured.as_deref(), env_editor.as_deref()) {
+        let args = interpolate(&template, file, line, col);
+        let Some((program, rest)) = args.split_first() else {
+            continue;
+        };
+        match Command::new(program).args(rest).status() {
+            Ok(status) if status.success() => return Ok(Some(args.join(" "))),
+            _ => continue,
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interpolate_editor_templates() {
+        let file = Path::new("/lib/my notes/transcript.md");
+
+        assert_eq!(
+            interpolate(VSCODE_TEMPLATE, file, 12, 5),
+            vec!["code", "-g", "/lib/my notes/transcript.md:12:5"]
+        );
+        assert_eq!(
+            interpolate("nvim +{line} {file}", file, 12, 5),
+            vec!["nvim", "+12", "/lib/my notes/transcript.md"]
+        );
+        assert_eq!(
+            interpolate("idea --line {line} --column {col} {file}", file, 3, 1),
+            vec![
+                "idea",
+                "--line",
+                "3",
+                "--column",
+                "1",
+                "/lib/my notes/transcript.md"
+            ]
+        );
+
+        // No {file}: appended, as with a bare $EDITOR
+        assert_eq!(
+            interpolate("vim", file, 1, 1),
+            vec!["vim", "/lib/my notes/transcript.md"]
+        );
+    }
+
+    #[test]
+    fn test_candidates_order() {
+        let templates = candidates(Some("hx {file}:{line}"), Some("vim"));
+        assert_eq!(templates[0], "hx {file}:{line}");
+        assert_eq!(templates[1], VSCODE_TEMPLATE);
+        assert_eq!(templates[2], "vim");
+        assert_eq!(templates.len(), 4);
+
+        assert_eq!(candidates(None, Some(" ")).len(), 2);
+    }
+}
@@ -0,0 +1,67 @@
+//! Decoding of `arkai run` input in legacy encodings (`--input-encoding`).
+//!
+//! Input defaults to UTF-8. Any WHATWG encoding label `encoding_rs` knows
+//! (`latin1`, `windows-1252`, `shift_jis`, ...) is accepted; the input is
+//! decoded to UTF-8 before the pipeline sees it.
+
+use encoding_rs::Encoding;
+
+use super::exit_code::ExitError;
+
+/// Encoding used when `--input-encoding` isn't given
+pub const DEFAULT_INPUT_ENCODING: &str = "utf-8";
+
+/// Look up an encoding by label, failing clearly on one we don't know
+pub fn parse_label(label: &str) -> Result<&'static Encoding, ExitError> {
+    Encoding::for_label(label.trim().as_bytes()).ok_or_else(|| {
+        ExitError::invalid_input(format!(
+            "Unknown input encoding '{}' (try utf-8, latin1, windows-1252, utf-16le)",
+            label
+        ))
+    })
+}
+
+/// Decode `bytes` from `encoding`, dropping a matching byte-order mark.
+/// Bytes that aren't valid in the encoding are an error, not replaced.
+pub fn decode(bytes: &[u8], encoding: &'static Encoding) -> Result<String, ExitError> {
+    let (text, had_errors) = encoding.decode_with_bom_removal(bytes);
+    if had_errors {
+        let hint = if encoding == encoding_rs::UTF_8 {
+            " (use --input-encoding for other encodings)"
+        } else {
+            ""
+        };
+        return Err(ExitError::invalid_input(format!(
+            "Input is not valid {}{}",
+            encoding.name(),
+            hint
+        )));
+    }
+    Ok(text.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_latin1_input() {
+        // "Café für Jürgen" in Latin-1: é = 0xE9, ü = 0xFC
+        let latin1 = b"Caf\xe9 f\xfcr J\xfcrgen\n";
+
+        let encoding = parse_label("latin1").unwrap();
+        assert_eq!(decode(latin1, encoding).unwrap(), "Café für Jürgen\n");
+
+        // The same bytes aren't UTF-8
+        let utf8 = parse_label(DEFAULT_INPUT_ENCODING).unwrap();
+        let error = decode(latin1, utf8).unwrap_err();
+        assert!(error.to_string().contains("--input-encoding"));
+        assert_eq!(decode("Café\n".as_bytes(), utf8).unwrap(), "Café\n");
+        assert_eq!(decode(b"\xef\xbb\xbfhi", utf8).unwrap(), "hi");
+
+        let unknown = parse_label("klingon").unwrap_err();
+        assert!(unknown
+            .to_string()
+            .contains("Unknown input encoding 'klingon'"));
+    }
+}
@@ -2,29 +2,35 @@
 //!
 //! Provides commands to:
 //! - `ground`: Ground claims.json against transcript → evidence.jsonl
+//! - `extract`: Run a configured extractor and ground its claims → evidence.jsonl
 //! - `show`: Display evidence details with source snippet
-//! - `open`: Open the evidence location in VS Code
+//! - `open`: Open the evidence location in an editor (see `editor`)
+//! - `delete`: Tombstone an evidence entry (evidence.jsonl stays append-only)
+//! - `compact`: Rewrite evidence.jsonl without tombstoned entries
+//! - `reindex`: Record artifact digests so `validate` can skip per-span checks
 //! - `validate`: Verify evidence integrity against transcripts
+//! - `stats`: Summarize resolution quality for one item or the whole library
 
-use std::collections::HashMap;
-use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
-use std::process::Command;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use chrono::Utc;
 use clap::Subcommand;
-use fs2::FileExt;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 use crate::evidence::{
-    compute_evidence_id, compute_hash, compute_slice_hash, extract_anchor_text,
-    find_nearest_timestamp, find_quote, offset_to_line_col, Evidence, EvidenceEvent, MatchStatus,
-    Span, Status,
+    extract_anchor_text, ground_claim, offset_to_line_col, run_extractor, DigestCache, Evidence,
+    EvidenceEvent, EvidenceRange, EvidenceStore, ExtractedClaim, Span, Status, VerifyResult,
 };
 use crate::library::{ContentId, ContentType, LibraryContent};
 
+use super::editor;
+
 /// Evidence-related subcommands
 #[derive(Subcommand, Debug)]
 pub enum EvidenceCommands {
@@ -32,44 +38,128 @@ pub enum EvidenceCommands {
     Ground {
         /// Path to the content directory (containing claims.json and Whisper JSON)
         content_dir: PathBuf,
+
+        /// Drop claims with confidence below this threshold
+        #[arg(long)]
+        min_confidence: Option<f64>,
+    },
+
+    /// Run a configured extractor over a content item's transcript and ground its claims
+    Extract {
+        /// Content ID to extract evidence for
+        content_id: String,
+
+        /// Extractor name (from `evidence.extractors` in config.yaml)
+        #[arg(long)]
+        extractor: String,
+
+        /// Drop claims below this confidence (overrides the extractor's `min_confidence`)
+        #[arg(long)]
+        min_confidence: Option<f64>,
     },
 
     /// Show details of an evidence entry
     Show {
         /// Evidence ID to display
         evidence_id: String,
+
+        /// Hide the entry if its confidence is below this threshold
+        #[arg(long)]
+        min_confidence: Option<f64>,
+
+        /// Lines of transcript to show before and after the span
+        #[arg(long, default_value_t = 2)]
+        context: usize,
+
+        /// Characters of anchor text around the span (recomputed from the transcript)
+        #[arg(long)]
+        bytes: Option<usize>,
     },
 
-    /// Open evidence location in VS Code
+    /// Open evidence location in an editor (ARKAI_EDITOR, else VS Code)
     Open {
-        /// Evidence ID to open
+        /// Evidence ID to open (a content ID with --all)
+        evidence_id: String,
+
+        /// Open every span of a content item instead of one evidence entry
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Delete an evidence entry (recorded as a tombstone event)
+    Delete {
+        /// Evidence ID to delete
         evidence_id: String,
+
+        /// Why the entry was deleted, kept on the tombstone
+        #[arg(long)]
+        reason: Option<String>,
+    },
+
+    /// Rewrite evidence.jsonl for a content item, dropping deleted entries
+    Compact {
+        /// Content ID to compact
+        content_id: String,
+    },
+
+    /// Record artifact digests in metadata.json for fast-path validation
+    Reindex {
+        /// Content ID to reindex
+        #[arg(required_unless_present = "all")]
+        content_id: Option<String>,
+
+        /// Reindex every content item in the library
+        #[arg(long, conflicts_with = "content_id")]
+        all: bool,
     },
 
     /// Validate all evidence for a content item
     Validate {
         /// Content ID to validate
-        content_id: String,
+        #[arg(required_unless_present = "all")]
+        content_id: Option<String>,
+
+        /// Validate every content item in the library
+        #[arg(long, conflicts_with = "content_id")]
+        all: bool,
+
+        /// Validate up to this many content items at once (with --all)
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        parallel: u32,
+
+        /// Only validate evidence grounded in this artifact (e.g. `transcript.md`)
+        #[arg(long)]
+        artifact: Option<String>,
+
+        /// Skip this many entries (in sorted order) before validating
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+
+        /// Validate at most this many entries
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+
+    /// Summarize resolution quality (resolved/ambiguous/unresolved, by extractor)
+    Stats {
+        /// Content ID to summarize
+        #[arg(required_unless_present = "all")]
+        content_id: Option<String>,
+
+        /// Summarize evidence across the whole library
+        #[arg(long, conflicts_with = "content_id")]
+        all: bool,
+
+        /// Only count evidence at or above this confidence
+        #[arg(long)]
+        min_confidence: Option<f64>,
     },
 }
 
 /// Claims file format from fabric extract_claims
 #[derive(Debug, Deserialize)]
 struct ClaimsFile {
-    claims: Vec<Claim>,
-}
-
-/// A single claim from extract_claims
-#[derive(Debug, Deserialize)]
-struct Claim {
-    claim: String,
-    quote: String,
-    #[serde(default = "default_confidence")]
-    confidence: f64,
-}
-
-fn default_confidence() -> f64 {
-    0.5
+    claims: Vec<ExtractedClaim>,
 }
 
 /// Whisper JSON output format
@@ -126,95 +216,143 @@ async fn find_content_directory(content_id: &str) -> Result<PathBuf> {
     anyhow::bail!("Content not found: {}", content_id)
 }
 
-/// Find evidence by ID in evidence.jsonl
-fn find_evidence(evidence_path: &PathBuf, evidence_id: &str) -> Result<Option<Evidence>> {
-    if !evidence_path.exists() {
-        return Ok(None);
-    }
-
-    let file = File::open(evidence_path)
-        .with_context(|| format!("Failed to open evidence file: {}", evidence_path.display()))?;
-
-    let reader = BufReader::new(file);
+/// Find evidence by ID prefix across every content directory in the library,
+/// returning it with the directory it lives in
+async fn locate_evidence(evidence_id: &str) -> Result<(Evidence, PathBuf)> {
+    for content_type in [ContentType::YouTube, ContentType::Web, ContentType::Other] {
+        let type_dir = crate::config::content_type_dir(content_type)?;
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.trim().is_empty() {
+        if !type_dir.exists() {
             continue;
         }
 
-        let evidence: Evidence = serde_json::from_str(&line)
-            .with_context(|| format!("Failed to parse evidence line: {}", line))?;
+        let mut entries = tokio::fs::read_dir(&type_dir).await?;
+
+        while let Some(entry) = entries.next_entry().await? {
+            let content_dir = entry.path();
 
-        // Match by ID prefix
-        if evidence.id.starts_with(evidence_id) || evidence_id.starts_with(&evidence.id) {
-            return Ok(Some(evidence));
+            if let Some(evidence) = EvidenceStore::open(&content_dir).find(evidence_id)? {
+                return Ok((evidence, content_dir));
+            }
         }
     }
 
-    Ok(None)
+    anyhow::bail!("Evidence not found: {}", evidence_id)
 }
 
-/// Load all evidence for a content ID
-fn load_all_evidence(evidence_path: &PathBuf) -> Result<Vec<Evidence>> {
-    if !evidence_path.exists() {
-        return Ok(Vec::new());
-    }
-
-    let file = File::open(evidence_path)
-        .with_context(|| format!("Failed to open evidence file: {}", evidence_path.display()))?;
-
-    let reader = BufReader::new(file);
-    let mut evidence_list = Vec::new();
-
-    for line in reader.lines() {
-        let line = line?;
-        if line.trim().is_empty() {
+/// Mark evidence stale for artifacts that were regenerated.
+///
+/// Evidence is append-only, so invalidation is recorded as an
+/// `EvidenceInvalidated` event per affected artifact. Returns the number of
+/// evidence records invalidated.
+pub(crate) fn invalidate_evidence(
+    content_dir: &Path,
+    content_id: &str,
+    artifacts: &[String],
+    reason: &str,
+) -> Result<usize> {
+    let store = EvidenceStore::open(content_dir);
+    let evidence_list = store.load_all()?;
+    let mut invalidated = 0;
+
+    for artifact in artifacts {
+        let file_name = format!("{}.md", artifact);
+        let evidence_ids: Vec<String> = evidence_list
+            .iter()
+            .filter(|e| {
+                e.span
+                    .as_ref()
+                    .is_some_and(|s| s.artifact == file_name || s.artifact == *artifact)
+            })
+            .map(|e| e.id.clone())
+            .collect();
+
+        if evidence_ids.is_empty() {
             continue;
         }
 
-        let evidence: Evidence = serde_json::from_str(&line)
-            .with_context(|| format!("Failed to parse evidence line: {}", line))?;
-
-        evidence_list.push(evidence);
+        invalidated += evidence_ids.len();
+        let event = EvidenceEvent::EvidenceInvalidated {
+            content_id: content_id.to_string(),
+            artifact: file_name,
+            evidence_ids,
+            reason: reason.to_string(),
+        };
+        store.append_event(&event)?;
     }
 
-    Ok(evidence_list)
+    Ok(invalidated)
 }
 
-/// Append an event to events.jsonl with file locking
-fn append_event(events_path: &PathBuf, event: &EvidenceEvent) -> Result<()> {
-    let file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(events_path)
-        .with_context(|| format!("Failed to open events file: {}", events_path.display()))?;
+/// Per-status counts from grounding a batch of claims
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct GroundingCounts {
+    resolved: usize,
+    ambiguous: usize,
+    unresolved: usize,
+    /// Claims dropped for falling below the confidence threshold
+    below_threshold: usize,
+}
+
+/// Ground claims against a transcript artifact, appending an evidence line and
+/// an `EvidenceAppended` event per claim to the content directory. Claims below
+/// `min_confidence` are dropped rather than stored.
+fn ground_claims(
+    content_dir: &Path,
+    content_id: &str,
+    transcript: &str,
+    transcript_artifact: &str,
+    claims: &[ExtractedClaim],
+    extractor: &str,
+    min_confidence: Option<f64>,
+) -> Result<GroundingCounts> {
+    let store = EvidenceStore::open(content_dir);
+    let ts = Utc::now().to_rfc3339();
+    let matching = crate::config::config()?.evidence_matching;
+
+    let mut counts = GroundingCounts::default();
+    let mut grounded = Vec::with_capacity(claims.len());
+
+    for claim in claims {
+        if !meets_confidence(claim.confidence, min_confidence) {
+            counts.below_threshold += 1;
+            continue;
+        }
 
-    // Acquire exclusive lock
-    file.lock_exclusive()
-        .context("Failed to acquire file lock on events.jsonl")?;
+        let evidence = ground_claim(
+            content_id,
+            transcript,
+            transcript_artifact,
+            claim,
+            extractor,
+            &ts,
+            &matching,
+        );
+        match evidence.status {
+            Status::Resolved => counts.resolved += 1,
+            Status::Ambiguous => counts.ambiguous += 1,
+            Status::Unresolved => counts.unresolved += 1,
+        }
 
-    // Create a wrapper struct for serialization with timestamp
-    #[derive(Serialize)]
-    struct EventWrapper<'a> {
-        ts: String,
-        #[serde(flatten)]
-        event: &'a EvidenceEvent,
+        grounded.push(evidence);
     }
 
-    let wrapper = EventWrapper {
-        ts: Utc::now().to_rfc3339(),
-        event,
-    };
+    // One locked write for the batch, with its `EvidenceAppended` events
+    store.append(&grounded)?;
 
-    let json = serde_json::to_string(&wrapper).context("Failed to serialize event")?;
+    Ok(counts)
+}
 
-    let mut file = file;
-    writeln!(file, "{}", json).context("Failed to write event")?;
-    file.flush().context("Failed to flush event")?;
+/// Whether a confidence passes an optional minimum threshold
+fn meets_confidence(confidence: f64, min_confidence: Option<f64>) -> bool {
+    min_confidence.is_none_or(|min| confidence >= min)
+}
 
-    // Lock is released when file is dropped
-    Ok(())
+/// Print the count of claims dropped by a confidence threshold, if any
+fn print_below_threshold(counts: &GroundingCounts, min_confidence: Option<f64>) {
+    if let (Some(min), n @ 1..) = (min_confidence, counts.below_threshold) {
+        println!("  Dropped:      {} (confidence below {:.2})", n, min);
+    }
 }
 
 /// Execute the `evidence ground` command
@@ -222,7 +360,7 @@ fn append_event(events_path: &PathBuf, event: &EvidenceEvent) -> Result<()> {
 /// Reads claims.json and a Whisper JSON transcript from content_dir,
 /// finds each quote in the transcript text, computes SHA256 spans,
 /// and writes evidence.jsonl.
-pub async fn execute_ground(content_dir: &PathBuf) -> Result<()> {
+pub async fn execute_ground(content_dir: &PathBuf, min_confidence: Option<f64>) -> Result<()> {
     println!("Grounding claims for: {}", content_dir.display());
 
     // Load metadata.json to get content_id
@@ -295,115 +433,22 @@ pub async fn execute_ground(content_dir: &PathBuf) -> Result<()> {
     }
 
     // Ground each claim against the transcript
-    let evidence_path = content_dir.join("evidence.jsonl");
-    let events_path = content_dir.join("events.jsonl");
-    let extractor = "extract_claims";
-    let ts = Utc::now().to_rfc3339();
-
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&evidence_path)
-        .with_context(|| format!("Failed to open evidence.jsonl for writing"))?;
-
-    let mut resolved_count = 0;
-    let mut ambiguous_count = 0;
-    let mut unresolved_count = 0;
-
-    for claim in &claims_file.claims {
-        let quote_sha256 = compute_hash(claim.quote.as_bytes());
-        let match_result = find_quote(&transcript, &claim.quote);
-
-        let evidence = match match_result.status() {
-            MatchStatus::Resolved => {
-                let (start, end) = match_result.selected_match().unwrap();
-                let slice_sha256 = compute_slice_hash(transcript.as_bytes(), start, end);
-                let anchor = extract_anchor_text(&transcript, start, end, 80);
-                let video_ts = find_nearest_timestamp(&transcript, start);
-                let id =
-                    compute_evidence_id(content_id, extractor, &quote_sha256, Some((start, end)));
-
-                resolved_count += 1;
-                Evidence::new_resolved(
-                    id,
-                    content_id.clone(),
-                    claim.claim.clone(),
-                    claim.quote.clone(),
-                    quote_sha256,
-                    Span {
-                        artifact: transcript_artifact.to_string(),
-                        utf8_byte_offset: [start, end],
-                        slice_sha256,
-                        anchor_text: Some(anchor),
-                        video_timestamp: video_ts,
-                    },
-                    claim.confidence,
-                    extractor.to_string(),
-                    ts.clone(),
-                )
-            }
-            MatchStatus::Ambiguous => {
-                let (start, end) = match_result.selected_match().unwrap();
-                let (match_count, _) = match_result.match_info();
-                let slice_sha256 = compute_slice_hash(transcript.as_bytes(), start, end);
-                let anchor = extract_anchor_text(&transcript, start, end, 80);
-                let video_ts = find_nearest_timestamp(&transcript, start);
-                let id =
-                    compute_evidence_id(content_id, extractor, &quote_sha256, Some((start, end)));
-
-                ambiguous_count += 1;
-                Evidence::new_ambiguous(
-                    id,
-                    content_id.clone(),
-                    claim.claim.clone(),
-                    claim.quote.clone(),
-                    quote_sha256,
-                    Span {
-                        artifact: transcript_artifact.to_string(),
-                        utf8_byte_offset: [start, end],
-                        slice_sha256,
-                        anchor_text: Some(anchor),
-                        video_timestamp: video_ts,
-                    },
-                    match_count,
-                    claim.confidence,
-                    extractor.to_string(),
-                    ts.clone(),
-                )
-            }
-            MatchStatus::Unresolved => {
-                let id = compute_evidence_id(content_id, extractor, &quote_sha256, None);
-
-                unresolved_count += 1;
-                Evidence::new_unresolved(
-                    id,
-                    content_id.clone(),
-                    claim.claim.clone(),
-                    claim.quote.clone(),
-                    quote_sha256,
-                    match_result.normalized_hint,
-                    claim.confidence,
-                    extractor.to_string(),
-                    ts.clone(),
-                )
-            }
-        };
-
-        // Write evidence line
-        let json = serde_json::to_string(&evidence).context("Failed to serialize evidence")?;
-        writeln!(file, "{}", json)?;
-
-        // Emit append event
-        let event = EvidenceEvent::EvidenceAppended {
-            content_id: content_id.clone(),
-            evidence_id: evidence.id.clone(),
-            status: evidence.status,
-            extractor: extractor.to_string(),
-        };
-        append_event(&events_path, &event)?;
-    }
-
-    file.flush()?;
+    let store = EvidenceStore::open(content_dir);
+    let counts = ground_claims(
+        content_dir,
+        content_id,
+        &transcript,
+        transcript_artifact,
+        &claims_file.claims,
+        "extract_claims",
+        min_confidence,
+    )?;
+    let GroundingCounts {
+        resolved: resolved_count,
+        ambiguous: ambiguous_count,
+        unresolved: unresolved_count,
+        ..
+    } = counts;
 
     // Print summary
     println!();
@@ -415,14 +460,15 @@ pub async fn execute_ground(content_dir: &PathBuf) -> Result<()> {
         ambiguous_count
     );
     println!("  Unresolved:   {} (no exact match)", unresolved_count);
+    print_below_threshold(&counts, min_confidence);
     println!();
-    println!("Evidence written to: {}", evidence_path.display());
+    println!("Evidence written to: {}", store.evidence_path().display());
 
     if unresolved_count > 0 {
         println!();
         println!("Unresolved claims (quote not found verbatim in transcript):");
         // Re-read to list unresolved
-        let evidence_list = load_all_evidence(&evidence_path)?;
+        let evidence_list = store.load_all()?;
         for ev in &evidence_list {
             if ev.status == Status::Unresolved {
                 let hint = if ev.resolution.reason
@@ -448,39 +494,184 @@ pub async fn execute_ground(content_dir: &PathBuf) -> Result<()> {
     Ok(())
 }
 
-/// Execute the `evidence show` command
-pub async fn execute_show(evidence_id: &str) -> Result<()> {
-    // Search through all content directories for evidence.jsonl files
-    for content_type in [ContentType::YouTube, ContentType::Web, ContentType::Other] {
-        let type_dir = crate::config::content_type_dir(content_type)?;
+/// Transcript artifacts to ground extracted claims against, in preference order
+const TRANSCRIPT_ARTIFACTS: &[&str] = &["transcript.txt", "transcript.md", "source.md"];
 
-        if !type_dir.exists() {
-            continue;
-        }
+/// Execute the `evidence extract` command
+///
+/// Runs the named extractor from config over the content item's transcript,
+/// then grounds each returned claim like `evidence ground` does.
+pub async fn execute_extract(
+    content_id: &str,
+    extractor_name: &str,
+    min_confidence: Option<f64>,
+) -> Result<()> {
+    let config = crate::config::config()?;
+    let extractor = config.extractors.get(extractor_name).ok_or_else(|| {
+        let mut known: Vec<&str> = config.extractors.keys().map(String::as_str).collect();
+        known.sort();
+        anyhow::anyhow!(
+            "Unknown extractor '{}' (configured: {})",
+            extractor_name,
+            if known.is_empty() {
+                "none".to_string()
+            } else {
+                known.join(", ")
+            }
+        )
+    })?;
 
-        let mut entries = tokio::fs::read_dir(&type_dir).await?;
+    let content_dir = find_content_directory(content_id).await?;
+    println!("Extracting evidence for: {}", content_dir.display());
 
-        while let Some(entry) = entries.next_entry().await? {
-            let content_dir = entry.path();
-            let evidence_path = content_dir.join("evidence.jsonl");
+    let min_confidence = min_confidence.or(extractor.min_confidence);
+    let counts = extract_into(&content_dir, extractor_name, extractor, min_confidence).await?;
 
-            if let Some(evidence) = find_evidence(&evidence_path, evidence_id)? {
-                // Found the evidence, now display it
-                return display_evidence(&evidence, &content_dir).await;
-            }
+    println!();
+    println!("Extraction complete ({}):", extractor_name);
+    println!("  Resolved:     {}", counts.resolved);
+    println!("  Ambiguous:    {}", counts.ambiguous);
+    println!("  Unresolved:   {}", counts.unresolved);
+    print_below_threshold(&counts, min_confidence);
+
+    Ok(())
+}
+
+/// Run an extractor against a content directory and ground its claims
+async fn extract_into(
+    content_dir: &Path,
+    extractor_name: &str,
+    extractor: &crate::config::ExtractorConfig,
+    min_confidence: Option<f64>,
+) -> Result<GroundingCounts> {
+    let metadata: ContentMetadata = {
+        let content = tokio::fs::read_to_string(content_dir.join("metadata.json"))
+            .await
+            .with_context(|| {
+                format!("Failed to read metadata.json in {}", content_dir.display())
+            })?;
+        serde_json::from_str(&content).context("Failed to parse metadata.json")?
+    };
+
+    let artifact = TRANSCRIPT_ARTIFACTS
+        .iter()
+        .find(|name| content_dir.join(name).exists())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No transcript found in {} (looked for {})",
+                content_dir.display(),
+                TRANSCRIPT_ARTIFACTS.join(", ")
+            )
+        })?;
+    let transcript = tokio::fs::read_to_string(content_dir.join(artifact)).await?;
+
+    // Extract from another artifact (e.g. wisdom.md) but ground in the transcript
+    let mut claims = match &extractor.input_artifact {
+        Some(input_artifact) => {
+            let input_path = content_dir.join(input_artifact);
+            let input = tokio::fs::read_to_string(&input_path)
+                .await
+                .with_context(|| format!("Failed to read {}", input_path.display()))?;
+            run_extractor(extractor_name, extractor, &input).await?
+        }
+        None => run_extractor(extractor_name, extractor, &transcript).await?,
+    };
+    if let Some(input_artifact) = &extractor.input_artifact {
+        for claim in &mut claims {
+            claim
+                .source_artifact
+                .get_or_insert_with(|| input_artifact.clone());
         }
     }
 
-    anyhow::bail!("Evidence not found: {}", evidence_id)
+    ground_claims(
+        content_dir,
+        &metadata.id,
+        &transcript,
+        artifact,
+        &claims,
+        extractor_name,
+        min_confidence,
+    )
+}
+
+/// Execute the `evidence show` command
+pub async fn execute_show(
+    evidence_id: &str,
+    min_confidence: Option<f64>,
+    context: usize,
+    bytes: Option<usize>,
+) -> Result<()> {
+    let (evidence, content_dir) = locate_evidence(evidence_id).await?;
+
+    if !meets_confidence(evidence.confidence, min_confidence) {
+        println!(
+            "Evidence {} hidden: confidence {:.2} is below {:.2}",
+            evidence.id,
+            evidence.confidence,
+            min_confidence.unwrap_or_default()
+        );
+        return Ok(());
+    }
+
+    display_evidence(&evidence, &content_dir, context, bytes).await
+}
+
+/// Render the lines covering `[start, end)` plus `context` lines either side.
+///
+/// Span lines are marked with `>`.
+fn render_snippet(transcript: &str, start: usize, end: usize, context: usize) -> Vec<String> {
+    let lines: Vec<&str> = transcript.lines().collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let start = floor_char_boundary(transcript, start);
+    let first = offset_to_line_col(transcript, start).line - 1;
+    // A span ending right after a newline doesn't reach into the next line
+    let last_byte = floor_char_boundary(transcript, end.max(start + 1) - 1);
+    let last = offset_to_line_col(transcript, last_byte).line - 1;
+    let last = last.min(lines.len() - 1);
+
+    let from = first.saturating_sub(context);
+    let to = (last + context).min(lines.len() - 1);
+
+    (from..=to)
+        .map(|i| {
+            let marker = if (first..=last).contains(&i) {
+                '>'
+            } else {
+                ' '
+            };
+            format!("{} {}", marker, lines[i])
+        })
+        .collect()
+}
+
+/// Step `offset` back to the nearest char boundary, clamped to the text.
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
 }
 
 /// Display evidence details
-async fn display_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<()> {
+async fn display_evidence(
+    evidence: &Evidence,
+    content_dir: &PathBuf,
+    context: usize,
+    bytes: Option<usize>,
+) -> Result<()> {
     println!("Evidence ID: {}", evidence.id);
     println!("Content ID:  {}", evidence.content_id);
     println!("Status:      {:?}", evidence.status);
     println!("Confidence:  {:.2}", evidence.confidence);
     println!("Extractor:   {}", evidence.extractor);
+    if let Some(source) = &evidence.source_artifact {
+        println!("Claim from:  {}", content_dir.join(source).display());
+    }
     println!("Timestamp:   {}", evidence.ts);
     println!();
     println!("Claim:");
@@ -492,6 +683,7 @@ async fn display_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<
 
     if let Some(span) = &evidence.span {
         let artifact_path = content_dir.join(&span.artifact);
+        let mut anchor = span.anchor_text.clone();
         println!();
         println!("Source Location:");
         println!("  File: {}", artifact_path.display());
@@ -510,24 +702,26 @@ async fn display_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<
             let start = span.utf8_byte_offset[0];
             let end = span.utf8_byte_offset[1].min(transcript.len());
 
-            if start < transcript.len() {
-                let snippet = &transcript[start..end];
+            if start < transcript.len() && transcript.is_char_boundary(start) {
                 println!();
                 println!("Snippet:");
                 println!("  ---");
-                for line in snippet.lines().take(5) {
+                for line in render_snippet(&transcript, start, end, context) {
                     println!("  {}", line);
                 }
-                if snippet.lines().count() > 5 {
-                    println!("  ...");
-                }
                 println!("  ---");
+
+                if let Some(window) = bytes {
+                    if transcript.is_char_boundary(end) {
+                        anchor = Some(extract_anchor_text(&transcript, start, end, window));
+                    }
+                }
             }
         } else {
             println!("  (artifact file not found)");
         }
 
-        if let Some(anchor) = &span.anchor_text {
+        if let Some(anchor) = &anchor {
             println!();
             println!("Anchor text: {}", anchor);
         }
@@ -546,33 +740,162 @@ async fn display_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<
     Ok(())
 }
 
+/// Execute the `evidence delete` command
+pub async fn execute_delete(evidence_id: &str, reason: Option<&str>) -> Result<()> {
+    let (evidence, content_dir) = locate_evidence(evidence_id).await?;
+    EvidenceStore::open(&content_dir).tombstone(&evidence, reason)?;
+
+    println!("Deleted evidence {}", evidence.id);
+    println!("  Claim: {}", evidence.claim);
+    println!(
+        "Run `arkai evidence compact {}` to drop it from evidence.jsonl",
+        evidence.content_id
+    );
+    Ok(())
+}
+
+/// Execute the `evidence compact` command
+pub async fn execute_compact(content_id: &str) -> Result<()> {
+    let content_dir = find_content_directory(content_id).await?;
+    let dropped = EvidenceStore::open(&content_dir).compact()?;
+
+    println!("Compacted evidence for: {}", content_dir.display());
+    println!(
+        "  Dropped: {} deleted entr{}",
+        dropped,
+        if dropped == 1 { "y" } else { "ies" }
+    );
+    Ok(())
+}
+
 /// Execute the `evidence open` command
 pub async fn execute_open(evidence_id: &str) -> Result<()> {
-    // Search through all content directories for evidence.jsonl files
-    for content_type in [ContentType::YouTube, ContentType::Web, ContentType::Other] {
-        let type_dir = crate::config::content_type_dir(content_type)?;
+    let (evidence, content_dir) = locate_evidence(evidence_id).await?;
+    open_evidence(&evidence, &content_dir).await
+}
 
-        if !type_dir.exists() {
+/// Where one evidence span starts, as an editor position
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SpanPosition {
+    evidence_id: String,
+    path: PathBuf,
+    line: usize,
+    col: usize,
+}
+
+impl SpanPosition {
+    /// `file:line:col`, as taken by `code -g` and made clickable by terminals
+    fn target(&self) -> String {
+        format!("{}:{}:{}", self.path.display(), self.line, self.col)
+    }
+}
+
+/// A stored span whose offsets don't fit its artifact (stale or corrupt)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InvalidSpan {
+    evidence_id: String,
+    artifact: String,
+    reason: String,
+}
+
+/// Check stored span offsets against the artifact text before indexing with
+/// them. Returns why they can't be used, if they can't.
+fn check_span_offsets(text: &str, offsets: [usize; 2]) -> Option<String> {
+    let [start, end] = offsets;
+    if start > end || end > text.len() {
+        return Some(format!(
+            "bytes {}-{} out of range for {} byte artifact",
+            start,
+            end,
+            text.len()
+        ));
+    }
+    if !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+        return Some(format!("bytes {}-{} split a UTF-8 character", start, end));
+    }
+    None
+}
+
+/// Editor positions of every evidence span in a content directory, in
+/// evidence order, plus the spans whose offsets don't fit their artifact.
+/// Evidence without a span, or whose artifact is missing, is skipped.
+fn span_positions(content_dir: &Path) -> Result<(Vec<SpanPosition>, Vec<InvalidSpan>)> {
+    let evidence = EvidenceStore::open(content_dir).load_all()?;
+    let mut artifacts: HashMap<String, Option<String>> = HashMap::new();
+    let mut positions = Vec::new();
+    let mut invalid = Vec::new();
+
+    for evidence in evidence {
+        let Some(span) = evidence.span else {
+            continue;
+        };
+        let path = content_dir.join(&span.artifact);
+        let text = artifacts
+            .entry(span.artifact.clone())
+            .or_insert_with(|| std::fs::read_to_string(&path).ok());
+        let Some(text) = text else {
+            continue;
+        };
+
+        if let Some(reason) = check_span_offsets(text, span.utf8_byte_offset) {
+            invalid.push(InvalidSpan {
+                evidence_id: evidence.id,
+                artifact: span.artifact,
+                reason,
+            });
             continue;
         }
 
-        let mut entries = tokio::fs::read_dir(&type_dir).await?;
+        let line_col = offset_to_line_col(text, span.utf8_byte_offset[0]);
+        positions.push(SpanPosition {
+            evidence_id: evidence.id,
+            path,
+            line: line_col.line,
+            col: line_col.col,
+        });
+    }
 
-        while let Some(entry) = entries.next_entry().await? {
-            let content_dir = entry.path();
-            let evidence_path = content_dir.join("evidence.jsonl");
+    Ok((positions, invalid))
+}
 
-            if let Some(evidence) = find_evidence(&evidence_path, evidence_id)? {
-                // Found the evidence, now open it
-                return open_evidence(&evidence, &content_dir).await;
-            }
+/// Execute the `evidence open --all` command: list every span of a content
+/// item as a clickable position and open the editor at the first
+pub async fn execute_open_all(content_id: &str) -> Result<()> {
+    let content_dir = find_content_directory(content_id).await?;
+    let (positions, invalid) = span_positions(&content_dir)?;
+
+    if !invalid.is_empty() {
+        println!(
+            "Skipped {} evidence with invalid spans (run `arkai evidence validate`):",
+            invalid.len()
+        );
+        for span in &invalid {
+            println!("  {}  {}: {}", span.evidence_id, span.artifact, span.reason);
         }
     }
 
-    anyhow::bail!("Evidence not found: {}", evidence_id)
+    if positions.is_empty() {
+        println!("No evidence with a source location for {}", content_id);
+        return Ok(());
+    }
+
+    println!("{} evidence spans:", positions.len());
+    for position in &positions {
+        println!("  {}  {}", position.target(), position.evidence_id);
+    }
+
+    let first = &positions[0];
+    match editor::open_at(&first.path, first.line, first.col)? {
+        Some(command) => println!("Opened: {}", command),
+        None => {
+            println!("\nNo editor could be started (set ARKAI_EDITOR); use the positions above.")
+        }
+    }
+
+    Ok(())
 }
 
-/// Open evidence in VS Code
+/// Open evidence in the configured editor
 async fn open_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<()> {
     let span = evidence.span.as_ref().ok_or_else(|| {
         anyhow::anyhow!(
@@ -592,57 +915,258 @@ async fn open_evidence(evidence: &Evidence, content_dir: &PathBuf) -> Result<()>
 
     // Load transcript and compute line:col
     let transcript = tokio::fs::read_to_string(&artifact_path).await?;
+    if let Some(reason) = check_span_offsets(&transcript, span.utf8_byte_offset) {
+        anyhow::bail!(
+            "Evidence {} has an invalid span in {}: {}",
+            evidence.id,
+            artifact_path.display(),
+            reason
+        );
+    }
     let line_col = offset_to_line_col(&transcript, span.utf8_byte_offset[0]);
 
-    // Try to open in VS Code
-    let vscode_arg = format!(
-        "{}:{}:{}",
-        artifact_path.display(),
-        line_col.line,
-        line_col.col
-    );
-
-    println!("Opening in VS Code: {}", vscode_arg);
-
-    let result = Command::new("code").args(["-g", &vscode_arg]).status();
-
-    match result {
-        Ok(status) if status.success() => {
-            println!("Opened successfully.");
-            Ok(())
-        }
-        Ok(_) => {
-            println!();
-            println!("VS Code command failed. You can manually open:");
-            println!("  File: {}", artifact_path.display());
-            println!("  Line: {}, Column: {}", line_col.line, line_col.col);
-            Ok(())
-        }
-        Err(_) => {
-            println!();
-            println!("VS Code ('code' command) not found in PATH.");
+    match editor::open_at(&artifact_path, line_col.line, line_col.col)? {
+        Some(command) => println!("Opened: {}", command),
+        None => {
+            println!(
+                "No editor could be started (set ARKAI_EDITOR, e.g. 'nvim +{{line}} {{file}}')."
+            );
             println!();
             println!("To open manually:");
             println!("  File: {}", artifact_path.display());
             println!("  Line: {}, Column: {}", line_col.line, line_col.col);
-            println!();
-            println!("Or run:");
-            println!("  code -g \"{}\"", vscode_arg);
-            Ok(())
         }
     }
+    Ok(())
+}
+
+/// Execute the `evidence reindex` command for one content item or, with
+/// `all`, every item with evidence
+pub async fn execute_reindex(content_id: Option<&str>, all: bool) -> Result<()> {
+    let content_dirs = match content_id {
+        Some(content_id) if !all => vec![find_content_directory(content_id).await?],
+        _ => all_content_dirs().await?,
+    };
+
+    let digests = DigestCache::open_default().await?;
+    for content_dir in content_dirs {
+        if !EvidenceStore::open(&content_dir).evidence_path().exists() {
+            continue;
+        }
+        print!("{}", reindex_digests(&content_dir, &digests).await?);
+    }
+    digests.save().await?;
+
+    Ok(())
+}
+
+/// Record in `metadata.json` the digest of each artifact whose evidence
+/// spans all verify, so `validate` can skip per-span checks while the
+/// artifact is unchanged. Artifacts with stale spans get no digest. Returns
+/// the report.
+pub(crate) async fn reindex_digests(content_dir: &Path, digests: &DigestCache) -> Result<String> {
+    let mut out = String::new();
+    writeln!(out, "Reindexing digests for: {}", content_dir.display())?;
+
+    let metadata_path = content_dir.join("metadata.json");
+    if !metadata_path.exists() {
+        writeln!(out, "  No metadata.json, skipping")?;
+        return Ok(out);
+    }
+
+    let evidence_list = EvidenceStore::open(content_dir).load_all()?;
+    let mut by_artifact: BTreeMap<&str, Vec<&Span>> = BTreeMap::new();
+    for span in evidence_list.iter().filter_map(|e| e.span.as_ref()) {
+        by_artifact.entry(&span.artifact).or_default().push(span);
+    }
+
+    let mut artifact_digests = serde_json::Map::new();
+    for (artifact_name, spans) in &by_artifact {
+        let artifact_path = content_dir.join(artifact_name);
+        let Ok(content) = tokio::fs::read(&artifact_path).await else {
+            writeln!(out, "  {}: MISSING", artifact_name)?;
+            continue;
+        };
+
+        let stale = spans
+            .iter()
+            .filter(|span| span.verify(&content) != VerifyResult::Valid)
+            .count();
+        if stale > 0 {
+            writeln!(
+                out,
+                "  {}: {} stale span(s), not indexed",
+                artifact_name, stale
+            )?;
+            continue;
+        }
+
+        let digest = digests.digest(&artifact_path).await?;
+        writeln!(out, "  {}: {}", artifact_name, digest)?;
+        artifact_digests.insert(artifact_name.to_string(), digest.into());
+    }
+
+    // Merge into the existing metadata, keeping the fields we don't own
+    let content = tokio::fs::read_to_string(&metadata_path).await?;
+    let mut metadata: serde_json::Value = serde_json::from_str(&content)
+        .with_context(|| format!("Invalid metadata: {}", metadata_path.display()))?;
+    let Some(fields) = metadata.as_object_mut() else {
+        anyhow::bail!("Metadata is not an object: {}", metadata_path.display());
+    };
+    fields.insert("artifact_digests".to_string(), artifact_digests.into());
+    tokio::fs::write(&metadata_path, serde_json::to_string_pretty(&metadata)?).await?;
+
+    Ok(out)
 }
 
 /// Execute the `evidence validate` command
-pub async fn execute_validate(content_id: &str) -> Result<()> {
+pub async fn execute_validate(content_id: &str, range: &EvidenceRange) -> Result<()> {
     let content_dir = find_content_directory(content_id).await?;
 
-    println!("Validating evidence for: {}", content_dir.display());
-    println!();
+    let digests = DigestCache::open_default().await?;
+    print!(
+        "{}",
+        validate_report(&content_dir, content_id, range, &digests)
+            .await?
+            .report
+    );
+    digests.save().await?;
+
+    Ok(())
+}
 
-    let evidence_path = content_dir.join("evidence.jsonl");
+/// Execute the `evidence validate --all` command: validate every content item
+/// with evidence, `parallel` at a time, then print the combined summary
+pub async fn execute_validate_all(range: &EvidenceRange, parallel: usize) -> Result<()> {
+    let digests = Arc::new(DigestCache::open_default().await?);
+    let results = validate_all(all_content_dirs().await?, range, parallel, &digests).await?;
+    digests.save().await?;
+
+    let mut total = ValidationCounts::default();
+    for (_, validation) in &results {
+        print!("{}", validation.report);
+        println!();
+        total += validation.counts;
+    }
+
+    println!("Validated {} content item(s)", results.len());
+    println!("  Total evidence: {}", total.total);
+    println!("  Valid:          {}", total.valid);
+    println!("  Stale:          {}", total.stale);
+    println!("  Unresolved:     {}", total.unresolved);
+    if total.artifact_missing > 0 {
+        println!("  Artifact missing: {}", total.artifact_missing);
+    }
+
+    Ok(())
+}
+
+/// Evidence counts from validating one content item
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct ValidationCounts {
+    total: usize,
+    valid: usize,
+    stale: usize,
+    unresolved: usize,
+    artifact_missing: usize,
+}
+
+impl std::ops::AddAssign for ValidationCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.total += other.total;
+        self.valid += other.valid;
+        self.stale += other.stale;
+        self.unresolved += other.unresolved;
+        self.artifact_missing += other.artifact_missing;
+    }
+}
+
+/// A validation report and the counts in its summary
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Validation {
+    report: String,
+    counts: ValidationCounts,
+}
+
+/// The content ID recorded in a content directory's metadata, else the
+/// directory name
+pub(crate) async fn content_id_of(content_dir: &Path) -> String {
+    let metadata = tokio::fs::read_to_string(content_dir.join("metadata.json"))
+        .await
+        .ok()
+        .and_then(|content| serde_json::from_str::<ContentMetadata>(&content).ok());
+    match metadata {
+        Some(metadata) => metadata.id,
+        None => content_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    }
+}
+
+/// Validate each of `content_dirs` that has evidence, up to `parallel` at a
+/// time, sharing `digests` between them. Results are sorted by content ID, so
+/// they don't depend on which item finished first.
+async fn validate_all(
+    content_dirs: Vec<PathBuf>,
+    range: &EvidenceRange,
+    parallel: usize,
+    digests: &Arc<DigestCache>,
+) -> Result<Vec<(String, Validation)>> {
+    let permits = Arc::new(Semaphore::new(parallel.max(1)));
+    let mut tasks = JoinSet::new();
+
+    for content_dir in content_dirs {
+        if !EvidenceStore::open(&content_dir).evidence_path().exists() {
+            continue;
+        }
+        let permits = permits.clone();
+        let range = range.clone();
+        let digests = digests.clone();
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await?;
+            let content_id = content_id_of(&content_dir).await;
+            let validation = validate_report(&content_dir, &content_id, &range, &digests).await?;
+            anyhow::Ok((content_id, validation))
+        });
+    }
+
+    let mut results = Vec::new();
+    while let Some(result) = tasks.join_next().await {
+        results.push(result.context("Validation task panicked")??);
+    }
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(results)
+}
+
+/// Validate a content directory's evidence within `range`, emitting
+/// `EvidenceValidated` events and returning the report. Artifacts and evidence
+/// are visited in sorted order so the report (and any slice of it) is stable
+/// across runs. Artifact digests come from `digests`, so an artifact already
+/// hashed by `reindex` isn't hashed again.
+async fn validate_report(
+    content_dir: &Path,
+    content_id: &str,
+    range: &EvidenceRange,
+    digests: &DigestCache,
+) -> Result<Validation> {
+    let mut out = String::new();
+
+    writeln!(out, "Validating evidence for: {}", content_dir.display())?;
+    if !range.is_full() {
+        let limit = range.limit.map_or("all".to_string(), |n| n.to_string());
+        writeln!(
+            out,
+            "Range: artifact {}, offset {}, limit {}",
+            range.artifact.as_deref().unwrap_or("any"),
+            range.offset,
+            limit
+        )?;
+    }
+    writeln!(out)?;
+
+    let store = EvidenceStore::open(content_dir);
     let metadata_path = content_dir.join("metadata.json");
-    let events_path = content_dir.join("events.jsonl");
 
     // Load metadata with artifact_digests if available
     let metadata: Option<MetadataWithDigests> = if metadata_path.exists() {
@@ -652,11 +1176,11 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
         None
     };
 
-    // Load all evidence
-    let evidence_list = load_all_evidence(&evidence_path)?;
+    // Load the evidence to check
+    let evidence_list = store.load_all_in_range(range)?;
 
     if evidence_list.is_empty() {
-        println!("No evidence found in evidence.jsonl");
+        writeln!(out, "No evidence found in evidence.jsonl")?;
 
         // Still emit event
         let event = EvidenceEvent::EvidenceValidated {
@@ -667,13 +1191,16 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
             stale_count: 0,
             unresolved_count: 0,
         };
-        append_event(&events_path, &event)?;
+        store.append_event(&event)?;
 
-        return Ok(());
+        return Ok(Validation {
+            report: out,
+            counts: ValidationCounts::default(),
+        });
     }
 
     // Group evidence by artifact
-    let mut by_artifact: HashMap<String, Vec<&Evidence>> = HashMap::new();
+    let mut by_artifact: BTreeMap<String, Vec<&Evidence>> = BTreeMap::new();
     let mut unresolved_count = 0;
 
     for evidence in &evidence_list {
@@ -691,18 +1218,29 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
     let mut total_stale = 0;
     let mut artifact_missing_count = 0;
 
+    // Cross-referenced claim artifacts must still exist alongside the grounding artifact
+    let mut missing_sources: BTreeMap<&str, usize> = BTreeMap::new();
+    for evidence in &evidence_list {
+        if let Some(source) = evidence.source_artifact.as_deref() {
+            if !content_dir.join(source).exists() {
+                *missing_sources.entry(source).or_default() += 1;
+            }
+        }
+    }
+
     // Validate each artifact group
     for (artifact_name, evidence_group) in &by_artifact {
         let artifact_path = content_dir.join(artifact_name);
 
-        println!("Artifact: {}", artifact_name);
+        writeln!(out, "Artifact: {}", artifact_name)?;
 
         if !artifact_path.exists() {
-            println!("  Status: MISSING");
-            println!(
+            writeln!(out, "  Status: MISSING")?;
+            writeln!(
+                out,
                 "  Evidence count: {} (all marked artifact_missing)",
                 evidence_group.len()
-            );
+            )?;
             artifact_missing_count += evidence_group.len();
 
             // Emit event for missing artifact
@@ -714,25 +1252,21 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
                 stale_count: 0,
                 unresolved_count: evidence_group.len(),
             };
-            append_event(&events_path, &event)?;
+            store.append_event(&event)?;
 
             continue;
         }
 
-        // Load transcript for validation
-        let transcript = tokio::fs::read_to_string(&artifact_path).await?;
-        let transcript_bytes = transcript.as_bytes();
-
         // Check for digest fast-path
         let mut use_fast_path = false;
         if let Some(ref meta) = metadata {
             if let Some(stored_digest) = meta.artifact_digests.get(artifact_name) {
-                let current_digest = crate::evidence::compute_hash(transcript_bytes);
+                let current_digest = digests.verified_digest(&artifact_path).await?;
                 if &current_digest == stored_digest {
                     use_fast_path = true;
-                    println!("  Digest: OK (fast-path - skipping per-span checks)");
+                    writeln!(out, "  Digest: OK (fast-path - skipping per-span checks)")?;
                 } else {
-                    println!("  Digest: CHANGED (checking individual spans)");
+                    writeln!(out, "  Digest: CHANGED (checking individual spans)")?;
                 }
             }
         }
@@ -740,7 +1274,7 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
         if use_fast_path {
             // All evidence for this artifact is valid
             total_valid += evidence_group.len();
-            println!("  Valid: {}", evidence_group.len());
+            writeln!(out, "  Valid: {}", evidence_group.len())?;
 
             let event = EvidenceEvent::EvidenceValidated {
                 content_id: content_id.to_string(),
@@ -750,36 +1284,41 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
                 stale_count: 0,
                 unresolved_count: 0,
             };
-            append_event(&events_path, &event)?;
+            store.append_event(&event)?;
         } else {
+            // Load transcript for validation
+            let transcript = tokio::fs::read_to_string(&artifact_path).await?;
+            let transcript_bytes = transcript.as_bytes();
+
             // Validate each span individually
             let mut valid = 0;
             let mut stale = 0;
 
             for evidence in evidence_group {
-                if let Some(span) = &evidence.span {
-                    let start = span.utf8_byte_offset[0];
-                    let end = span.utf8_byte_offset[1];
-
-                    if end <= transcript_bytes.len() {
-                        let current_hash = compute_slice_hash(transcript_bytes, start, end);
-                        if current_hash == span.slice_sha256 {
-                            valid += 1;
-                        } else {
-                            stale += 1;
-                            println!(
-                                "    STALE: {} (hash mismatch at {}:{})",
-                                evidence.id, start, end
-                            );
-                        }
-                    } else {
+                let Some(span) = &evidence.span else {
+                    continue;
+                };
+                let [start, end] = span.utf8_byte_offset;
+
+                match span.verify(transcript_bytes) {
+                    VerifyResult::Valid => valid += 1,
+                    VerifyResult::Stale => {
                         stale += 1;
-                        println!(
+                        writeln!(
+                            out,
+                            "    STALE: {} (hash mismatch at {}:{})",
+                            evidence.id, start, end
+                        )?;
+                    }
+                    VerifyResult::OutOfBounds => {
+                        stale += 1;
+                        writeln!(
+                            out,
                             "    STALE: {} (offset {} out of bounds, file size {})",
                             evidence.id,
                             end,
                             transcript_bytes.len()
-                        );
+                        )?;
                     }
                 }
             }
@@ -787,7 +1326,7 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
             total_valid += valid;
             total_stale += stale;
 
-            println!("  Valid: {}, Stale: {}", valid, stale);
+            writeln!(out, "  Valid: {}, Stale: {}", valid, stale)?;
 
             let event = EvidenceEvent::EvidenceValidated {
                 content_id: content_id.to_string(),
@@ -797,25 +1336,839 @@ pub async fn execute_validate(content_id: &str) -> Result<()> {
                 stale_count: stale,
                 unresolved_count: 0,
             };
-            append_event(&events_path, &event)?;
+            store.append_event(&event)?;
         }
     }
 
     // Print summary
-    println!();
-    println!("Summary:");
-    println!("  Total evidence: {}", evidence_list.len());
-    println!("  Valid:          {}", total_valid);
-    println!("  Stale:          {}", total_stale);
-    println!("  Unresolved:     {}", unresolved_count);
+    writeln!(out)?;
+    writeln!(out, "Summary:")?;
+    writeln!(out, "  Total evidence: {}", evidence_list.len())?;
+    writeln!(out, "  Valid:          {}", total_valid)?;
+    writeln!(out, "  Stale:          {}", total_stale)?;
+    writeln!(out, "  Unresolved:     {}", unresolved_count)?;
     if artifact_missing_count > 0 {
-        println!("  Artifact missing: {}", artifact_missing_count);
+        writeln!(out, "  Artifact missing: {}", artifact_missing_count)?;
+    }
+    for (source, count) in &missing_sources {
+        writeln!(
+            out,
+            "  Claim source missing: {} ({} evidence)",
+            source, count
+        )?;
     }
 
     if total_stale > 0 || artifact_missing_count > 0 {
-        println!();
-        println!("Some evidence needs re-extraction due to transcript changes.");
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Some evidence needs re-extraction due to transcript changes."
+        )?;
+    }
+
+    Ok(Validation {
+        report: out,
+        counts: ValidationCounts {
+            total: evidence_list.len(),
+            valid: total_valid,
+            stale: total_stale,
+            unresolved: unresolved_count,
+            artifact_missing: artifact_missing_count,
+        },
+    })
+}
+
+/// Resolution counts for a set of evidence
+#[derive(Debug, Default, Clone, PartialEq)]
+struct StatusCounts {
+    resolved: usize,
+    ambiguous: usize,
+    unresolved: usize,
+    confidence_sum: f64,
+}
+
+impl StatusCounts {
+    fn add(&mut self, evidence: &Evidence) {
+        match evidence.status {
+            Status::Resolved => self.resolved += 1,
+            Status::Ambiguous => self.ambiguous += 1,
+            Status::Unresolved => self.unresolved += 1,
+        }
+        self.confidence_sum += evidence.confidence;
+    }
+
+    fn total(&self) -> usize {
+        self.resolved + self.ambiguous + self.unresolved
+    }
+
+    /// Percentage of evidence grounded to a span (resolved or ambiguous)
+    fn resolution_rate(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => (self.resolved + self.ambiguous) as f64 * 100.0 / total as f64,
+        }
+    }
+
+    fn average_confidence(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.confidence_sum / total as f64,
+        }
+    }
+}
+
+/// Aggregate evidence quality statistics
+#[derive(Debug, Default, Clone, PartialEq)]
+struct EvidenceStats {
+    overall: StatusCounts,
+    by_extractor: BTreeMap<String, StatusCounts>,
+}
+
+impl EvidenceStats {
+    fn add_all(&mut self, evidence: &[Evidence]) {
+        for e in evidence {
+            self.overall.add(e);
+            self.by_extractor
+                .entry(e.extractor.clone())
+                .or_default()
+                .add(e);
+        }
+    }
+
+    fn render(&self) -> String {
+        let o = &self.overall;
+        let mut out = String::new();
+
+        out.push_str(&format!("Total evidence:  {}\n", o.total()));
+        out.push_str(&format!("  Resolved:      {}\n", o.resolved));
+        out.push_str(&format!("  Ambiguous:     {}\n", o.ambiguous));
+        out.push_str(&format!("  Unresolved:    {}\n", o.unresolved));
+        out.push_str(&format!("Resolution rate: {:.1}%\n", o.resolution_rate()));
+        out.push_str(&format!("Avg confidence:  {:.2}\n", o.average_confidence()));
+
+        if !self.by_extractor.is_empty() {
+            out.push_str(&format!(
+                "\n{:<24} {:>6} {:>9} {:>10} {:>6} {:>8}\n",
+                "EXTRACTOR", "TOTAL", "RESOLVED", "UNRESOLVED", "RATE", "AVG CONF"
+            ));
+            for (extractor, counts) in &self.by_extractor {
+                out.push_str(&format!(
+                    "{:<24} {:>6} {:>9} {:>10} {:>5.1}% {:>8.2}\n",
+                    extractor,
+                    counts.total(),
+                    counts.resolved + counts.ambiguous,
+                    counts.unresolved,
+                    counts.resolution_rate(),
+                    counts.average_confidence()
+                ));
+            }
+        }
+
+        out
+    }
+}
+
+/// All content directories across the library's content-type directories
+pub(crate) async fn all_content_dirs() -> Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+
+    for content_type in [ContentType::YouTube, ContentType::Web, ContentType::Other] {
+        let type_dir = crate::config::content_type_dir(content_type)?;
+        if !type_dir.exists() {
+            continue;
+        }
+
+        let mut entries = tokio::fs::read_dir(&type_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir() {
+                dirs.push(entry.path());
+            }
+        }
+    }
+
+    Ok(dirs)
+}
+
+/// Execute the `evidence stats` command
+pub async fn execute_stats(
+    content_id: Option<&str>,
+    all: bool,
+    min_confidence: Option<f64>,
+) -> Result<()> {
+    let dirs = match content_id {
+        Some(id) if !all => vec![find_content_directory(id).await?],
+        _ => all_content_dirs().await?,
+    };
+
+    let mut stats = EvidenceStats::default();
+    let mut items_with_evidence = 0;
+
+    for dir in &dirs {
+        let mut evidence = EvidenceStore::open(dir).load_all()?;
+        evidence.retain(|e| meets_confidence(e.confidence, min_confidence));
+        if !evidence.is_empty() {
+            items_with_evidence += 1;
+        }
+        stats.add_all(&evidence);
     }
 
+    match content_id {
+        Some(_) if !all => println!("Evidence stats for: {}", dirs[0].display()),
+        _ => println!(
+            "Evidence stats across {} content item(s)",
+            items_with_evidence
+        ),
+    }
+    if let Some(min) = min_confidence {
+        println!("(confidence >= {:.2})", min);
+    }
+    println!();
+    print!("{}", stats.render());
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ExtractorConfig;
+    use crate::evidence::{compute_slice_hash, Span};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_extract_grounds_claims_from_external_command() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("metadata.json"),
+            r#"{"id": "abcdef0123456789"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("transcript.txt"),
+            "The quick brown fox jumps over the lazy dog.",
+        )
+        .unwrap();
+
+        let output = r#"[
+            {"claim": "A fox jumps", "quote": "brown fox jumps", "confidence": 0.9},
+            {"claim": "A cat sleeps", "quote": "the cat sleeps", "confidence": 0.7}
+        ]"#;
+        let extractor = ExtractorConfig {
+            command: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                format!("cat >/dev/null; echo '{}'", output),
+            ],
+            ..Default::default()
+        };
+
+        let counts = extract_into(dir.path(), "fake", &extractor, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            counts,
+            GroundingCounts {
+                resolved: 1,
+                ambiguous: 0,
+                unresolved: 1,
+                below_threshold: 0,
+            }
+        );
+
+        let evidence = EvidenceStore::open(dir.path()).load_all().unwrap();
+        assert_eq!(evidence.len(), 2);
+        assert!(evidence.iter().all(|e| e.extractor == "fake"));
+        assert!(evidence.iter().all(|e| e.content_id == "abcdef0123456789"));
+
+        let resolved = evidence
+            .iter()
+            .find(|e| e.status == Status::Resolved)
+            .unwrap();
+        assert_eq!(resolved.span.as_ref().unwrap().artifact, "transcript.txt");
+
+        let events = std::fs::read_to_string(dir.path().join("events.jsonl")).unwrap();
+        assert_eq!(events.lines().count(), 2);
+    }
+
+    fn evidence_fixture(status: Status, extractor: &str, confidence: f64) -> Evidence {
+        let mut e = Evidence::new_unresolved(
+            format!("ev_{}_{}", extractor, confidence),
+            "abcdef0123456789".to_string(),
+            "claim".to_string(),
+            "quote".to_string(),
+            "00".to_string(),
+            false,
+            confidence,
+            extractor.to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        );
+        e.status = status;
+        e
+    }
+
+    #[test]
+    fn test_evidence_stats_over_fixture_file() {
+        let dir = TempDir::new().unwrap();
+        let store = EvidenceStore::open(dir.path());
+        let lines: Vec<String> = [
+            evidence_fixture(Status::Resolved, "extract_claims", 0.9),
+            evidence_fixture(Status::Resolved, "extract_claims", 0.8),
+            evidence_fixture(Status::Ambiguous, "extract_claims", 0.7),
+            evidence_fixture(Status::Unresolved, "local", 0.2),
+        ]
+        .iter()
+        .map(|e| serde_json::to_string(e).unwrap())
+        .collect();
+        std::fs::write(store.evidence_path(), lines.join("\n")).unwrap();
+
+        let mut stats = EvidenceStats::default();
+        stats.add_all(&store.load_all().unwrap());
+
+        assert_eq!(stats.overall.total(), 4);
+        assert_eq!(stats.overall.resolved, 2);
+        assert_eq!(stats.overall.ambiguous, 1);
+        assert_eq!(stats.overall.unresolved, 1);
+        assert!((stats.overall.resolution_rate() - 75.0).abs() < 1e-9);
+        assert!((stats.overall.average_confidence() - 0.65).abs() < 1e-9);
+
+        assert_eq!(stats.by_extractor["extract_claims"].total(), 3);
+        assert!((stats.by_extractor["local"].resolution_rate()).abs() < 1e-9);
+
+        let rendered = stats.render();
+        assert!(rendered.contains("Resolution rate: 75.0%"));
+        assert!(rendered.contains("local"));
+    }
+
+    fn low_confidence_fixture() -> (TempDir, ExtractorConfig) {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("metadata.json"),
+            r#"{"id": "abcdef0123456789"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("transcript.txt"), "a weak claim here").unwrap();
+
+        let extractor = ExtractorConfig {
+            command: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                r#"cat >/dev/null; echo '[{"claim":"c","quote":"weak claim","confidence":0.3}]'"#
+                    .to_string(),
+            ],
+            ..Default::default()
+        };
+        (dir, extractor)
+    }
+
+    #[tokio::test]
+    async fn test_min_confidence_drops_low_confidence_claims() {
+        let (dir, extractor) = low_confidence_fixture();
+
+        let counts = extract_into(dir.path(), "fake", &extractor, Some(0.5))
+            .await
+            .unwrap();
+
+        assert_eq!(counts.below_threshold, 1);
+        assert_eq!(counts.resolved, 0);
+        assert!(EvidenceStore::open(dir.path())
+            .load_all()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_min_confidence_keeps_claims_above_threshold() {
+        let (dir, extractor) = low_confidence_fixture();
+
+        let counts = extract_into(dir.path(), "fake", &extractor, Some(0.2))
+            .await
+            .unwrap();
+
+        assert_eq!(counts.below_threshold, 0);
+        assert_eq!(counts.resolved, 1);
+        assert_eq!(EvidenceStore::open(dir.path()).load_all().unwrap().len(), 1);
+    }
+
+    fn span_fixture(artifact: &str, start: usize, end: usize, text: &str) -> Span {
+        Span {
+            artifact: artifact.to_string(),
+            utf8_byte_offset: [start, end],
+            slice_sha256: compute_slice_hash(text.as_bytes(), start, end),
+            anchor_text: None,
+            video_timestamp: None,
+        }
+    }
+
+    fn resolved_fixture(id: &str, span: Span) -> Evidence {
+        Evidence::new_resolved(
+            id.to_string(),
+            "abcdef0123456789".to_string(),
+            "claim".to_string(),
+            "quote".to_string(),
+            "00".to_string(),
+            span,
+            0.9,
+            "test".to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_span_positions_for_all_evidence() {
+        let dir = TempDir::new().unwrap();
+        let transcript = "first line\nsecond line here\nthird";
+        let summary = "caf\u{e9} summary";
+        std::fs::write(dir.path().join("transcript.txt"), transcript).unwrap();
+        std::fs::write(dir.path().join("summary.md"), summary).unwrap();
+
+        let unresolved = Evidence::new_unresolved(
+            "ev_none".to_string(),
+            "abcdef0123456789".to_string(),
+            "claim".to_string(),
+            "missing".to_string(),
+            "00".to_string(),
+            false,
+            0.9,
+            "test".to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        );
+        let evidence = [
+            resolved_fixture(
+                "ev_third",
+                span_fixture("transcript.txt", 28, 33, transcript),
+            ),
+            resolved_fixture(
+                "ev_second",
+                span_fixture("transcript.txt", 18, 22, transcript),
+            ),
+            unresolved,
+            resolved_fixture("ev_summary", span_fixture("summary.md", 6, 13, summary)),
+            resolved_fixture("ev_gone", span_fixture("deleted.txt", 0, 1, "x")),
+            // Stale offsets: past the end, and inside the two-byte 'é'
+            resolved_fixture(
+                "ev_stale",
+                span_fixture("transcript.txt", 30, 90, &"x".repeat(90)),
+            ),
+            resolved_fixture("ev_split", span_fixture("summary.md", 4, 6, summary)),
+        ];
+        let lines: Vec<String> = evidence
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        std::fs::write(dir.path().join("evidence.jsonl"), lines.join("\n")).unwrap();
+
+        let (positions, invalid) = span_positions(dir.path()).unwrap();
+        let found: Vec<(&str, String, usize, usize)> = positions
+            .iter()
+            .map(|p| {
+                let file = p.path.file_name().unwrap().to_string_lossy().into_owned();
+                (p.evidence_id.as_str(), file, p.line, p.col)
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("ev_summary", "summary.md".to_string(), 1, 6),
+                ("ev_second", "transcript.txt".to_string(), 2, 8),
+                ("ev_third", "transcript.txt".to_string(), 3, 1),
+            ]
+        );
+        assert!(positions[1].target().ends_with("transcript.txt:2:8"));
+
+        let mut skipped: Vec<&str> = invalid.iter().map(|s| s.evidence_id.as_str()).collect();
+        skipped.sort();
+        assert_eq!(skipped, vec!["ev_split", "ev_stale"]);
+    }
+
+    #[tokio::test]
+    async fn test_validation_report_is_deterministic() {
+        let dir = TempDir::new().unwrap();
+        let transcript = "alpha beta gamma delta";
+        let summary = "one two three";
+        for name in ["a.txt", "b.txt", "c.txt", "d.txt"] {
+            std::fs::write(dir.path().join(name), transcript).unwrap();
+        }
+        std::fs::write(dir.path().join("summary.md"), summary).unwrap();
+
+        // Appended out of order, across several artifacts
+        let evidence = [
+            resolved_fixture("ev_3", span_fixture("d.txt", 6, 10, transcript)),
+            resolved_fixture("ev_1", span_fixture("summary.md", 4, 7, summary)),
+            resolved_fixture("ev_2", span_fixture("a.txt", 0, 5, transcript)),
+            resolved_fixture(
+                "ev_4",
+                span_fixture("c.txt", 11, 16, "some other text here"),
+            ),
+            resolved_fixture("ev_5", span_fixture("b.txt", 0, 5, transcript)),
+        ];
+        let lines: Vec<String> = evidence
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        std::fs::write(dir.path().join("evidence.jsonl"), lines.join("\n")).unwrap();
+
+        let first = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        let second = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        assert_eq!(first, second);
+
+        let order: Vec<&str> = first
+            .lines()
+            .filter_map(|l| l.strip_prefix("Artifact: "))
+            .collect();
+        assert_eq!(
+            order,
+            vec!["a.txt", "b.txt", "c.txt", "d.txt", "summary.md"]
+        );
+
+        let ids: Vec<String> = EvidenceStore::open(dir.path())
+            .load_all()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.id)
+            .collect();
+        assert_eq!(ids, vec!["ev_2", "ev_5", "ev_4", "ev_3", "ev_1"]);
+    }
+
+    #[tokio::test]
+    async fn test_cross_artifact_evidence_grounds_in_transcript() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("metadata.json"),
+            r#"{"id": "abcdef0123456789"}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("source.md"),
+            "Speaker: compounding beats intensity every time.",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("wisdom.md"), "- Be consistent").unwrap();
+
+        // The extractor sees wisdom.md; its quote resolves in source.md
+        let extractor = ExtractorConfig {
+            command: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                r#"grep -q consistent && echo '[{"claim":"Be consistent","quote":"compounding beats intensity"}]'"#
+                    .to_string(),
+            ],
+            input_artifact: Some("wisdom.md".to_string()),
+            ..Default::default()
+        };
+
+        let counts = extract_into(dir.path(), "wisdom", &extractor, None)
+            .await
+            .unwrap();
+        assert_eq!(counts.resolved, 1);
+
+        let evidence = EvidenceStore::open(dir.path()).load_all().unwrap();
+        assert_eq!(evidence[0].source_artifact.as_deref(), Some("wisdom.md"));
+        assert_eq!(evidence[0].span.as_ref().unwrap().artifact, "source.md");
+
+        let report = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        assert!(report.contains("Artifact: source.md"));
+        assert!(report.contains("Valid: 1, Stale: 0"));
+
+        // Validation checks the grounding artifact, and flags a vanished claim source
+        std::fs::write(
+            dir.path().join("source.md"),
+            "rewritten transcript text here",
+        )
+        .unwrap();
+        std::fs::remove_file(dir.path().join("wisdom.md")).unwrap();
+        let report = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        assert!(report.contains("Valid: 0, Stale: 1"));
+        assert!(report.contains("Claim source missing: wisdom.md (1 evidence)"));
+    }
+
+    #[test]
+    fn test_render_snippet_respects_context() {
+        let transcript = "one\ntwo\nthree\nfour\nfive\nsix\nseven\n";
+        let start = transcript.find("four").unwrap();
+        let end = start + "four".len();
+
+        assert_eq!(
+            render_snippet(transcript, start, end, 2),
+            vec!["  two", "  three", "> four", "  five", "  six"]
+        );
+        assert_eq!(render_snippet(transcript, start, end, 0), vec!["> four"]);
+
+        // Clamped at both ends of the transcript
+        assert_eq!(render_snippet(transcript, start, end, 10).len(), 7);
+
+        // A multi-line span marks every line it covers, including one that
+        // ends on a newline
+        let start = transcript.find("two").unwrap();
+        let end = transcript.find("four").unwrap();
+        assert_eq!(
+            render_snippet(transcript, start, end, 1),
+            vec!["  one", "> two", "> three", "  four"]
+        );
+    }
+
+    #[test]
+    fn test_render_snippet_handles_multibyte_text() {
+        let transcript = "un\ncafé crème\ntrois\n";
+        let start = transcript.find("café").unwrap();
+        let end = start + "café".len();
+
+        assert_eq!(
+            render_snippet(transcript, start, end, 1),
+            vec!["  un", "> café crème", "  trois"]
+        );
+
+        // Offsets inside a multibyte char are snapped back, not sliced
+        let inside = transcript.find('è').unwrap() + 1;
+        assert_eq!(
+            render_snippet(transcript, inside, inside + 1, 0),
+            vec!["> café crème"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deleted_evidence_is_excluded_from_show_and_validate() {
+        let dir = TempDir::new().unwrap();
+        let transcript = "alpha beta gamma";
+        std::fs::write(dir.path().join("t.txt"), transcript).unwrap();
+
+        let good = resolved_fixture("ev_good", span_fixture("t.txt", 0, 5, transcript));
+        // Stale: hashed against different text
+        let bad = resolved_fixture("ev_bad", span_fixture("t.txt", 6, 10, "xxxxxx wxyz"));
+        let store = EvidenceStore::open(dir.path());
+        store.append(&[good, bad.clone()]).unwrap();
+
+        let report = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        assert!(report.contains("Valid: 1, Stale: 1"));
+
+        store.tombstone(&bad, Some("bad extractor")).unwrap();
+
+        assert!(store.find("ev_bad").unwrap().is_none());
+        assert!(store.find("ev_good").unwrap().is_some());
+        let report = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &DigestCache::new(),
+        )
+        .await
+        .unwrap()
+        .report;
+        assert!(report.contains("Valid: 1, Stale: 0"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_range_and_artifact_scope() {
+        let dir = TempDir::new().unwrap();
+        let transcript = "alpha beta gamma delta";
+        std::fs::write(dir.path().join("a.txt"), transcript).unwrap();
+        std::fs::write(dir.path().join("b.txt"), transcript).unwrap();
+
+        let store = EvidenceStore::open(dir.path());
+        store
+            .append(&[
+                resolved_fixture("ev_1", span_fixture("a.txt", 0, 5, transcript)),
+                resolved_fixture("ev_2", span_fixture("a.txt", 6, 10, transcript)),
+                resolved_fixture("ev_3", span_fixture("b.txt", 0, 5, "xxxxx")),
+                resolved_fixture("ev_4", span_fixture("b.txt", 11, 16, transcript)),
+            ])
+            .unwrap();
+        let report = |range: EvidenceRange| {
+            let dir = dir.path().to_path_buf();
+            async move {
+                validate_report(&dir, "abcdef0123456789", &range, &DigestCache::new())
+                    .await
+                    .unwrap()
+                    .report
+            }
+        };
+
+        // Sorted order is a.txt (ev_1, ev_2), then b.txt (ev_3 stale, ev_4)
+        let sliced = report(EvidenceRange {
+            offset: 1,
+            limit: Some(2),
+            ..Default::default()
+        })
+        .await;
+        assert!(sliced.contains("Range: artifact any, offset 1, limit 2"));
+        assert!(sliced.contains("Total evidence: 2"));
+        assert!(sliced.contains("STALE: ev_3"));
+        let artifacts: Vec<&str> = sliced
+            .lines()
+            .filter_map(|l| l.strip_prefix("Artifact: "))
+            .collect();
+        assert_eq!(artifacts, vec!["a.txt", "b.txt"]);
+
+        let scoped = report(EvidenceRange {
+            artifact: Some("a.txt".to_string()),
+            ..Default::default()
+        })
+        .await;
+        assert!(scoped.contains("Total evidence: 2"));
+        assert!(scoped.contains("Valid: 2, Stale: 0"));
+        assert!(!scoped.contains("b.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_parallel_validation_matches_sequential() {
+        let library = TempDir::new().unwrap();
+        let transcript = "alpha beta gamma delta";
+        let mut dirs = Vec::new();
+        for i in 0..6 {
+            let dir = library.path().join(format!("item-{}", i));
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("a.txt"), transcript).unwrap();
+            std::fs::write(
+                dir.join("metadata.json"),
+                format!(r#"{{"id": "content{:02}"}}"#, 5 - i),
+            )
+            .unwrap();
+            // Every other item has a stale span
+            let stale_text = if i % 2 == 0 {
+                transcript
+            } else {
+                "xxxxxxxxxxxx"
+            };
+            EvidenceStore::open(&dir)
+                .append(&[
+                    resolved_fixture("ev_1", span_fixture("a.txt", 0, 5, transcript)),
+                    resolved_fixture("ev_2", span_fixture("a.txt", 6, 10, stale_text)),
+                ])
+                .unwrap();
+            dirs.push(dir);
+        }
+        // Items without evidence are skipped
+        std::fs::create_dir_all(library.path().join("empty")).unwrap();
+        dirs.push(library.path().join("empty"));
+
+        let totals = |results: &[(String, Validation)]| {
+            let mut total = ValidationCounts::default();
+            for (_, validation) in results {
+                total += validation.counts;
+            }
+            total
+        };
+
+        let range = EvidenceRange::default();
+        let digests = Arc::new(DigestCache::new());
+        let sequential = validate_all(dirs.clone(), &range, 1, &digests)
+            .await
+            .unwrap();
+        let parallel = validate_all(dirs, &range, 4, &digests).await.unwrap();
+
+        assert_eq!(totals(&sequential), totals(&parallel));
+        assert_eq!(
+            totals(&parallel),
+            ValidationCounts {
+                total: 12,
+                valid: 9,
+                stale: 3,
+                ..Default::default()
+            }
+        );
+        let ids: Vec<&str> = parallel.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "content00",
+                "content01",
+                "content02",
+                "content03",
+                "content04",
+                "content05"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reindex_then_validate_hashes_each_artifact_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let dir = TempDir::new().unwrap();
+        let transcript = "alpha beta gamma delta";
+        let summary = "one two three";
+        std::fs::write(dir.path().join("a.txt"), transcript).unwrap();
+        std::fs::write(dir.path().join("summary.md"), summary).unwrap();
+        std::fs::write(
+            dir.path().join("metadata.json"),
+            r#"{"id": "abcdef0123456789", "title": "Kept"}"#,
+        )
+        .unwrap();
+        EvidenceStore::open(dir.path())
+            .append(&[
+                resolved_fixture("ev_1", span_fixture("a.txt", 0, 5, transcript)),
+                resolved_fixture("ev_2", span_fixture("a.txt", 6, 10, transcript)),
+                resolved_fixture("ev_3", span_fixture("summary.md", 4, 7, summary)),
+            ])
+            .unwrap();
+
+        let hashes = Arc::new(AtomicUsize::new(0));
+        let counter = hashes.clone();
+        let digests = DigestCache::new().with_hash(move |bytes| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            crate::evidence::compute_hash(bytes)
+        });
+
+        reindex_digests(dir.path(), &digests).await.unwrap();
+        let metadata: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(dir.path().join("metadata.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(metadata["title"], "Kept");
+        assert_eq!(
+            metadata["artifact_digests"]["a.txt"],
+            crate::evidence::compute_hash(transcript.as_bytes())
+        );
+
+        let validation = validate_report(
+            dir.path(),
+            "abcdef0123456789",
+            &EvidenceRange::default(),
+            &digests,
+        )
+        .await
+        .unwrap();
+        assert_eq!(validation.counts.valid, 3);
+        assert_eq!(validation.report.matches("fast-path").count(), 2);
+        assert_eq!(hashes.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,98 @@
+//! Process exit codes, so scripts can tell failures apart.
+//!
+//! | Code | Meaning                                          |
+//! |------|--------------------------------------------------|
+//! | 0    | Success (including partially completed runs)     |
+//! | 1    | Any other error                                  |
+//! | 2    | The run failed                                   |
+//! | 3    | The run stopped at a safety limit                |
+//! | 4    | The pipeline could not be found                  |
+//! | 5    | The input was missing, unreadable, or empty      |
+//! | 6    | The run was cancelled                            |
+//! | 64   | Invalid command line (unknown flag, missing arg) |
+//! | 130  | Interrupted by a second Ctrl+C                   |
+
+use thiserror::Error;
+
+use crate::domain::RunState;
+use crate::error::ArkaiError;
+
+pub const SUCCESS: i32 = 0;
+pub const ERROR: i32 = 1;
+pub const RUN_FAILED: i32 = 2;
+pub const SAFETY_LIMIT: i32 = 3;
+pub const PIPELINE_NOT_FOUND: i32 = 4;
+pub const INVALID_INPUT: i32 = 5;
+pub const CANCELLED: i32 = 6;
+/// `EX_USAGE` from sysexits.h, well clear of the run outcomes
+pub const USAGE: i32 = 64;
+/// 128 + SIGINT, what shells report for a process killed by Ctrl+C
+pub const INTERRUPTED: i32 = 130;
+
+/// An error that should end the process with a specific exit code
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct ExitError {
+    pub code: i32,
+    message: String,
+}
+
+impl ExitError {
+    pub fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn pipeline_not_found(message: impl Into<String>) -> Self {
+        Self::new(PIPELINE_NOT_FOUND, message)
+    }
+
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new(INVALID_INPUT, message)
+    }
+}
+
+/// Exit code for an error returned from `Cli::execute`
+pub fn for_error(error: &anyhow::Error) -> i32 {
+    if let Some(exit) = error.downcast_ref::<ExitError>() {
+        return exit.code;
+    }
+    match error.downcast_ref::<ArkaiError>() {
+        Some(ArkaiError::PipelineNotFound { .. }) => PIPELINE_NOT_FOUND,
+        Some(ArkaiError::Safety(_)) => SAFETY_LIMIT,
+        _ => ERROR,
+    }
+}
+
+/// Exit code for a run's final state
+pub fn for_state(state: &RunState) -> i32 {
+    match state {
+        RunState::Completed | RunState::PartiallyCompleted { .. } => SUCCESS,
+        RunState::Failed { .. } => RUN_FAILED,
+        RunState::SafetyLimitReached { .. } => SAFETY_LIMIT,
+        RunState::Cancelled => CANCELLED,
+        RunState::Running | RunState::Paused | RunState::Interrupted => ERROR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_exit_code_survives_context() {
+        let error = Err::<(), _>(ExitError::pipeline_not_found("missing"))
+            .context("Failed to start run")
+            .unwrap_err();
+        assert_eq!(for_error(&error), PIPELINE_NOT_FOUND);
+        assert_eq!(for_error(&anyhow::anyhow!("other")), ERROR);
+
+        let missing = anyhow::Error::from(ArkaiError::PipelineNotFound {
+            path: "pipelines/missing.yaml".into(),
+        });
+        assert_eq!(for_error(&missing), PIPELINE_NOT_FOUND);
+    }
+}
@@ -0,0 +1,71 @@
+//! Live-tailing for `arkai status <run> --follow`.
+//!
+//! Polls the run's `events.jsonl`, printing each event as it's appended, and
+//! returns once a terminal event (completed, failed, safety limit) arrives.
+
+use std::io::Write;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::core::EventStore;
+use crate::domain::{Event, Run};
+
+/// How often `arkai status --follow` checks for new events
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One line of followed output for an event
+pub fn format_event(event: &Event) -> String {
+    format!(
+        "{} {:?}{} - {}",
+        event.timestamp.format("%H:%M:%S"),
+        event.event_type,
+        event
+            .step_id
+            .as_ref()
+            .map(|step| format!(" [{}]", step))
+            .unwrap_or_default(),
+        event.payload_summary
+    )
+}
+
+/// Follow a run until it finishes, writing each new event to `out`.
+///
+/// A run that has already finished prints its final state immediately.
+pub async fn follow_run<W: Write>(
+    store: &EventStore,
+    out: &mut W,
+    poll_interval: Duration,
+) -> Result<Run> {
+    let mut seen = 0;
+    let mut events: Vec<Event> = Vec::new();
+    // A compacted log starts from its snapshot
+    let snapshot = store.snapshot().await?.map(|snapshot| snapshot.run);
+
+    loop {
+        let new_events = store.events_after(seen).await?;
+        let before = events.len();
+        if let Some((line, _)) = new_events.last() {
+            seen = *line;
+        }
+        events.extend(new_events.into_iter().map(|(_, event)| event));
+
+        let run = Run::from_snapshot(snapshot.clone(), &events).filter(Run::is_finished);
+        if let (0, Some(run)) = (before, &run) {
+            // Nothing to tail; just report how it ended
+            writeln!(out, "Run {} already finished", run.id)?;
+        } else {
+            for event in &events[before..] {
+                writeln!(out, "{}", format_event(event))?;
+            }
+        }
+        out.flush().context("Failed to flush followed output")?;
+
+        if let Some(run) = run {
+            writeln!(out, "Final state: {:?}", run.state)?;
+            return Ok(run);
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
@@ -0,0 +1,785 @@
+//! Library management CLI commands.
+//!
+//! Commands for working with cataloged content:
+//! - `arkai library` - List items (default)
+//! - `arkai library import <dir>` - Bulk-import existing markdown
+//! - `arkai library show <id>` - Show an item's details, artifacts, and related content
+//! - `arkai library link <a> <b>` / `unlink <a> <b>` - Manage relationships
+//! - `arkai library reprocess <id> --pipeline <name>` - Regenerate artifacts from source.md
+//! - `arkai library reindex` - Rebuild the search index, artifact digests, and catalog artifact lists
+
+use std::path::{Path, PathBuf};
+
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+
+use crate::config;
+use crate::core::{Orchestrator, Pipeline, Step};
+use crate::domain::{Run, RunState};
+use crate::evidence::{DigestCache, EvidenceStore};
+use crate::library::{
+    import_directory, Catalog, CatalogItem, ContentType, ImportOptions, LibraryContent,
+};
+
+/// Library subcommands
+#[derive(Subcommand, Debug)]
+pub enum LibraryCommands {
+    /// Import a directory of existing markdown/text files
+    Import {
+        /// Directory to import (searched recursively)
+        dir: PathBuf,
+
+        /// Content type for imported items (youtube, web, other)
+        #[arg(long = "type", default_value = "other")]
+        content_type: ContentType,
+
+        /// Tags to apply (can be specified multiple times)
+        #[arg(short, long)]
+        tag: Vec<String>,
+    },
+
+    /// Show a cataloged item's details, artifacts, and related content
+    Show {
+        /// Content ID (or unique prefix)
+        content_id: String,
+
+        /// Print the contents of a specific artifact instead
+        #[arg(long)]
+        artifact: Option<String>,
+    },
+
+    /// Mark two items as related (e.g. a video and its companion article)
+    Link {
+        /// First content ID (or unique prefix)
+        a: String,
+
+        /// Second content ID (or unique prefix)
+        b: String,
+    },
+
+    /// Remove a relationship between two items
+    Unlink {
+        /// First content ID (or unique prefix)
+        a: String,
+
+        /// Second content ID (or unique prefix)
+        b: String,
+    },
+
+    /// Regenerate artifacts from the stored source.md by running a pipeline
+    Reprocess {
+        /// Content ID (or unique prefix)
+        content_id: String,
+
+        /// Pipeline to run (from pipelines/ directory)
+        #[arg(short, long)]
+        pipeline: String,
+
+        /// Keep the previous version of each overwritten artifact as <name>.md.bak
+        #[arg(long)]
+        backup: bool,
+    },
+
+    /// Rebuild library indexes in one pass (all of them unless some are named)
+    Reindex {
+        /// Rebuild the search index (the SQLite store)
+        #[arg(long)]
+        search: bool,
+
+        /// Record artifact digests for fast-path evidence validation
+        #[arg(long)]
+        digests: bool,
+
+        /// Refresh each catalog item's artifact list
+        #[arg(long)]
+        artifacts: bool,
+    },
+}
+
+/// Execute a library subcommand
+pub async fn execute(command: LibraryCommands) -> Result<()> {
+    match command {
+        LibraryCommands::Import {
+            dir,
+            content_type,
+            tag,
+        } => execute_import(&dir, content_type, tag).await,
+        LibraryCommands::Show {
+            content_id,
+            artifact,
+        } => execute_show(&content_id, artifact.as_deref()).await,
+        LibraryCommands::Link { a, b } => execute_link(&a, &b).await,
+        LibraryCommands::Unlink { a, b } => execute_unlink(&a, &b).await,
+        LibraryCommands::Reprocess {
+            content_id,
+            pipeline,
+            backup,
+        } => execute_reprocess(&content_id, &pipeline, backup).await,
+        LibraryCommands::Reindex {
+            search,
+            digests,
+            artifacts,
+        } => {
+            // No flags means everything
+            let all = !(search || digests || artifacts);
+            execute_reindex(search || all, digests || all, artifacts || all).await
+        }
+    }
+}
+
+/// Bulk-import a directory into the library
+async fn execute_import(dir: &Path, content_type: ContentType, tags: Vec<String>) -> Result<()> {
+    if !dir.is_dir() {
+        anyhow::bail!("Not a directory: {}", dir.display());
+    }
+
+    let type_dir = config::content_type_dir(content_type)?;
+    let mut catalog = Catalog::load().await?;
+    let options = ImportOptions { content_type, tags };
+
+    eprintln!("📥 Importing {} into {}", dir.display(), type_dir.display());
+
+    let report = import_directory(dir, &type_dir, &mut catalog, &options).await?;
+    catalog.save().await?;
+
+    for (path, id) in &report.imported {
+        eprintln!("   + {} {}", id, path.display());
+    }
+    for (path, id) in &report.skipped {
+        eprintln!("   = {} {} (already imported)", id, path.display());
+    }
+
+    eprintln!(
+        "\n✅ Imported {} file(s), skipped {} duplicate(s)",
+        report.imported.len(),
+        report.skipped.len()
+    );
+
+    Ok(())
+}
+
+/// Rebuild the requested indexes across the whole library
+async fn execute_reindex(search: bool, digests: bool, artifacts: bool) -> Result<()> {
+    let content_dirs = super::evidence::all_content_dirs().await?;
+
+    let store = if search {
+        Some(crate::store::Store::open(
+            &crate::store::StoreConfig::default_path()?,
+        )?)
+    } else {
+        None
+    };
+    let digest_cache = if digests {
+        Some(DigestCache::open_default().await?)
+    } else {
+        None
+    };
+    let mut catalog = if artifacts {
+        Some(Catalog::load().await?)
+    } else {
+        None
+    };
+
+    let counts = reindex_library(
+        &content_dirs,
+        store.as_ref(),
+        digest_cache.as_ref(),
+        catalog.as_mut(),
+    )
+    .await?;
+
+    if let Some(digest_cache) = &digest_cache {
+        digest_cache.save().await?;
+    }
+    if let Some(catalog) = &catalog {
+        catalog.save().await?;
+    }
+
+    eprintln!("✅ Reindexed {} content dir(s)", content_dirs.len());
+    if search {
+        eprintln!("   Search:    {} item(s)", counts.search);
+    }
+    if digests {
+        eprintln!("   Digests:   {} item(s) with evidence", counts.digests);
+    }
+    if artifacts {
+        eprintln!("   Artifacts: {} catalog item(s)", counts.artifacts);
+    }
+    if counts.errors > 0 {
+        eprintln!("   Errors:    {} (see log)", counts.errors);
+    }
+
+    Ok(())
+}
+
+/// Number of content dirs each index was rebuilt for
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct ReindexCounts {
+    search: usize,
+    digests: usize,
+    artifacts: usize,
+    errors: usize,
+}
+
+/// Rebuild indexes for each of `content_dirs` in a single walk: the search
+/// index when given a `store`, artifact digests when given a `digests` cache,
+/// and catalog artifact lists when given a `catalog`. A directory that fails
+/// is logged and counted, and doesn't stop the walk.
+async fn reindex_library(
+    content_dirs: &[PathBuf],
+    store: Option<&crate::store::Store>,
+    digests: Option<&DigestCache>,
+    mut catalog: Option<&mut Catalog>,
+) -> Result<ReindexCounts> {
+    let mut counts = ReindexCounts::default();
+
+    for dir in content_dirs {
+        if !dir.join("metadata.json").is_file() {
+            continue;
+        }
+
+        if let Some(store) = store {
+            match crate::store::import::import_library_dir(store, dir) {
+                Ok(()) => counts.search += 1,
+                Err(e) => {
+                    tracing::warn!(dir = %dir.display(), error = %e, "Failed to index for search");
+                    counts.errors += 1;
+                }
+            }
+        }
+
+        if let Some(digests) = digests {
+            if EvidenceStore::open(dir).evidence_path().exists() {
+                match super::evidence::reindex_digests(dir, digests).await {
+                    Ok(_) => counts.digests += 1,
+                    Err(e) => {
+                        tracing::warn!(dir = %dir.display(), error = %e, "Failed to record digests");
+                        counts.errors += 1;
+                    }
+                }
+            }
+        }
+
+        if let Some(catalog) = catalog.as_deref_mut() {
+            let id = super::evidence::content_id_of(dir).await;
+            if let Some(item) = catalog.items.iter_mut().find(|item| item.id.as_str() == id) {
+                item.artifacts = LibraryContent::list_artifacts_in(dir).await?;
+                counts.artifacts += 1;
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
+/// Show a cataloged item's details, or one of its artifacts
+async fn execute_show(content_id: &str, artifact: Option<&str>) -> Result<()> {
+    let catalog = Catalog::load().await?;
+    let item = catalog.resolve(content_id)?;
+    let content_dir = LibraryContent::find_content_dir(&item.id, item.content_type).await?;
+
+    match artifact {
+        Some(name) => {
+            let dir = content_dir
+                .ok_or_else(|| anyhow::anyhow!("No content directory for {}", item.id))?;
+            print!("{}", read_artifact(&dir, name).await?);
+        }
+        None => print!(
+            "{}",
+            describe_content(&catalog, item, content_dir.as_deref()).await?
+        ),
+    }
+
+    Ok(())
+}
+
+/// Render the detail view for a cataloged item
+async fn describe_content(
+    catalog: &Catalog,
+    item: &CatalogItem,
+    content_dir: Option<&Path>,
+) -> Result<String> {
+    let mut out = String::new();
+
+    writeln!(out, "ID:        {}", item.id)?;
+    writeln!(out, "Title:     {}", item.title)?;
+    writeln!(out, "URL:       {}", item.url)?;
+    writeln!(out, "Type:      {}", item.content_type)?;
+    if !item.tags.is_empty() {
+        writeln!(out, "Tags:      {}", item.tags.join(", "))?;
+    }
+    writeln!(
+        out,
+        "Processed: {}",
+        item.processed_at.format("%Y-%m-%d %H:%M:%S UTC")
+    )?;
+    if let Some(run_id) = &item.run_id {
+        writeln!(out, "Run ID:    {}", run_id)?;
+    }
+
+    match content_dir {
+        Some(dir) => {
+            writeln!(out, "Directory: {}", dir.display())?;
+
+            let artifacts = LibraryContent::list_artifacts_in(dir).await?;
+            if artifacts.is_empty() {
+                writeln!(out, "Artifacts: (none)")?;
+            } else {
+                writeln!(out, "Artifacts:")?;
+                for name in artifacts {
+                    let size = tokio::fs::metadata(dir.join(format!("{}.md", name)))
+                        .await
+                        .map(|m| m.len())
+                        .unwrap_or(0);
+                    writeln!(out, "  {:<20} {} bytes", name, size)?;
+                }
+            }
+
+            writeln!(out, "Evidence:  {}", count_evidence(dir)?)?;
+        }
+        None => writeln!(out, "Directory: (not found)")?,
+    }
+
+    let related = catalog.related(&item.id);
+    if related.is_empty() {
+        writeln!(out, "Related:   (none)")?;
+    } else {
+        writeln!(out, "Related:")?;
+        for other in related {
+            writeln!(
+                out,
+                "  {}  {} [{}]",
+                other.id, other.title, other.content_type
+            )?;
+        }
+    }
+
+    Ok(out)
+}
+
+/// Count live evidence in a content directory (tombstoned entries excluded)
+fn count_evidence(dir: &Path) -> Result<usize> {
+    Ok(EvidenceStore::open(dir).load_all()?.len())
+}
+
+/// Read a named artifact from a content directory
+async fn read_artifact(dir: &Path, name: &str) -> Result<String> {
+    let path = dir.join(format!("{}.md", name));
+    if !path.exists() {
+        let available = LibraryContent::list_artifacts_in(dir).await?;
+        anyhow::bail!(
+            "Artifact '{}' not found (available: {})",
+            name,
+            available.join(", ")
+        );
+    }
+
+    tokio::fs::read_to_string(&path)
+        .await
+        .with_context(|| format!("Failed to read artifact: {}", path.display()))
+}
+
+/// Link two items as related
+async fn execute_link(a: &str, b: &str) -> Result<()> {
+    let mut catalog = Catalog::load().await?;
+    let a = catalog.resolve(a)?.id.clone();
+    let b = catalog.resolve(b)?.id.clone();
+
+    catalog.link(&a, &b)?;
+    catalog.save().await?;
+
+    eprintln!("🔗 Linked {} <-> {}", a, b);
+    Ok(())
+}
+
+/// Remove the link between two items
+async fn execute_unlink(a: &str, b: &str) -> Result<()> {
+    let mut catalog = Catalog::load().await?;
+    let a = catalog.resolve(a)?.id.clone();
+    let b = catalog.resolve(b)?.id.clone();
+
+    if catalog.unlink(&a, &b) {
+        catalog.save().await?;
+        eprintln!("✂️  Unlinked {} <-> {}", a, b);
+    } else {
+        eprintln!("{} and {} were not linked", a, b);
+    }
+    Ok(())
+}
+
+/// Re-run a pipeline over an item's stored source.md and replace its artifacts
+async fn execute_reprocess(content_id: &str, pipeline_name: &str, backup: bool) -> Result<()> {
+    let mut catalog = Catalog::load().await?;
+    let item = catalog.resolve(content_id)?.clone();
+    let content_dir = LibraryContent::find_content_dir(&item.id, item.content_type)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No content directory for {}", item.id))?;
+
+    let source_path = content_dir.join("source.md");
+    let source = tokio::fs::read_to_string(&source_path)
+        .await
+        .with_context(|| format!("Failed to read {}", source_path.display()))?;
+
+    let pipeline = super::load_pipeline(pipeline_name)?;
+
+    eprintln!("🔄 Reprocessing: {}", item.title);
+    eprintln!("   Pipeline: {}", pipeline.name);
+
+    let run = Orchestrator::new().run_pipeline(&pipeline, source).await?;
+    if run.state != RunState::Completed {
+        anyhow::bail!(
+            "Reprocessing run {} ended in state {:?}; artifacts left unchanged",
+            run.id,
+            run.state
+        );
+    }
+
+    let outputs = artifact_outputs(&pipeline, &run);
+    let written = write_artifacts(&content_dir, &outputs, backup).await?;
+    let stale = super::evidence::invalidate_evidence(
+        &content_dir,
+        item.id.as_str(),
+        &written,
+        &format!("artifact regenerated by pipeline '{}'", pipeline.name),
+    )?;
+
+    if let Some(entry) = catalog.items.iter_mut().find(|i| i.id == item.id) {
+        for name in &written {
+            if !entry.artifacts.contains(name) {
+                entry.artifacts.push(name.clone());
+            }
+        }
+        entry.run_id = Some(run.id.to_string());
+    }
+    catalog.save().await?;
+
+    eprintln!(
+        "\n✅ Updated {} artifact(s): {}",
+        written.len(),
+        written.join(", ")
+    );
+    if stale > 0 {
+        eprintln!("   ⚠️  Marked {} evidence record(s) stale", stale);
+    }
+    eprintln!("   Run: {}", run.id);
+
+    Ok(())
+}
+
+/// A run's artifacts as `(file name, content)`, sorted by file name.
+///
+/// Run artifacts are keyed by step name; each is written under its step's
+/// `artifact_name` when it has one.
+fn artifact_outputs(pipeline: &Pipeline, run: &Run) -> Vec<(String, String)> {
+    let mut outputs: Vec<(String, String)> = run
+        .artifacts
+        .iter()
+        .map(|(step_name, artifact)| {
+            let file_name = pipeline
+                .get_step(step_name)
+                .map_or(step_name.as_str(), Step::artifact_file_name);
+            (file_name.to_string(), artifact.content.clone())
+        })
+        .collect();
+    outputs.sort();
+    outputs
+}
+
+/// Write regenerated artifacts into a content directory, optionally backing up
+/// the previous versions. The source artifact is never overwritten.
+async fn write_artifacts(
+    content_dir: &Path,
+    outputs: &[(String, String)],
+    backup: bool,
+) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+
+    for (name, content) in outputs {
+        if name == "source" {
+            continue;
+        }
+
+        let path = content_dir.join(format!("{}.md", name));
+        if backup && path.exists() {
+            let backup_path = content_dir.join(format!("{}.md.bak", name));
+            tokio::fs::copy(&path, &backup_path)
+                .await
+                .with_context(|| format!("Failed to back up {}", path.display()))?;
+        }
+
+        tokio::fs::write(&path, content)
+            .await
+            .with_context(|| format!("Failed to write artifact: {}", path.display()))?;
+        written.push(name.clone());
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn fixture() -> (TempDir, Catalog, CatalogItem) {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("metadata.json"), "{}").unwrap();
+        std::fs::write(dir.path().join("transcript.md"), "hello world").unwrap();
+        std::fs::write(dir.path().join("summary.md"), "short").unwrap();
+
+        let item = CatalogItem::new("https://example.com/post", "A Post", ContentType::Web)
+            .with_tags(vec!["rust".to_string()])
+            .with_artifact("transcript");
+
+        // Three entries, one of them tombstoned
+        let evidence: Vec<_> = ["ev_1", "ev_2", "ev_3"]
+            .iter()
+            .map(|id| {
+                crate::evidence::Evidence::new_unresolved(
+                    id.to_string(),
+                    item.id.to_string(),
+                    "claim".to_string(),
+                    "quote".to_string(),
+                    "00".to_string(),
+                    false,
+                    0.9,
+                    "test".to_string(),
+                    "2026-01-01T00:00:00Z".to_string(),
+                )
+            })
+            .collect();
+        let store = EvidenceStore::open(dir.path());
+        store.append(&evidence).unwrap();
+        store.tombstone(&evidence[2], None).unwrap();
+        let other = CatalogItem::new(
+            "https://youtube.com/watch?v=abc",
+            "A Talk",
+            ContentType::YouTube,
+        );
+
+        let mut catalog = Catalog::new();
+        catalog.add(item.clone());
+        catalog.add(other.clone());
+        catalog.link(&item.id, &other.id).unwrap();
+
+        (dir, catalog, item)
+    }
+
+    #[tokio::test]
+    async fn test_describe_content_renders_details() {
+        let (dir, catalog, item) = fixture();
+
+        let out = describe_content(&catalog, &item, Some(dir.path()))
+            .await
+            .unwrap();
+
+        assert!(out.contains("Title:     A Post"));
+        assert!(out.contains("URL:       https://example.com/post"));
+        assert!(out.contains("Type:      web"));
+        assert!(out.contains("Tags:      rust"));
+        assert!(out.contains("Processed: "));
+        assert!(out.contains("summary              5 bytes"));
+        assert!(out.contains("transcript           11 bytes"));
+        assert!(out.contains("Evidence:  2"));
+        assert!(out.contains("A Talk [youtube]"));
+    }
+
+    #[tokio::test]
+    async fn test_read_artifact() {
+        let (dir, _, _) = fixture();
+
+        assert_eq!(
+            read_artifact(dir.path(), "transcript").await.unwrap(),
+            "hello world"
+        );
+
+        let err = read_artifact(dir.path(), "missing").await.unwrap_err();
+        assert!(err.to_string().contains("available: summary, transcript"));
+    }
+
+    #[tokio::test]
+    async fn test_reprocess_updates_artifact_and_marks_evidence_stale() {
+        let (dir, _, item) = fixture();
+        std::fs::write(dir.path().join("source.md"), "original source").unwrap();
+
+        let span = |artifact: &str| crate::evidence::Span {
+            artifact: artifact.to_string(),
+            utf8_byte_offset: [0, 5],
+            slice_sha256: "00".to_string(),
+            anchor_text: None,
+            video_timestamp: None,
+        };
+        let evidence: Vec<String> = [("ev_sum", "summary.md"), ("ev_tr", "transcript.md")]
+            .iter()
+            .map(|(id, artifact)| {
+                let e = crate::evidence::Evidence::new_resolved(
+                    id.to_string(),
+                    item.id.to_string(),
+                    "claim".to_string(),
+                    "short".to_string(),
+                    "00".to_string(),
+                    span(artifact),
+                    0.9,
+                    "test".to_string(),
+                    "2026-01-01T00:00:00Z".to_string(),
+                );
+                serde_json::to_string(&e).unwrap()
+            })
+            .collect();
+        std::fs::write(dir.path().join("evidence.jsonl"), evidence.join("\n")).unwrap();
+
+        let outputs = vec![
+            ("source".to_string(), "ignored".to_string()),
+            ("summary".to_string(), "regenerated".to_string()),
+        ];
+        let written = write_artifacts(dir.path(), &outputs, true).await.unwrap();
+        assert_eq!(written, vec!["summary".to_string()]);
+
+        let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).unwrap();
+        assert_eq!(read("summary.md"), "regenerated");
+        assert_eq!(read("summary.md.bak"), "short");
+        assert_eq!(read("source.md"), "original source");
+
+        let stale = super::super::evidence::invalidate_evidence(
+            dir.path(),
+            item.id.as_str(),
+            &written,
+            "regenerated",
+        )
+        .unwrap();
+        assert_eq!(stale, 1);
+
+        let events: serde_json::Value =
+            serde_json::from_str(read("events.jsonl").lines().last().unwrap()).unwrap();
+        assert_eq!(events["type"], "EvidenceInvalidated");
+        assert_eq!(events["artifact"], "summary.md");
+        assert_eq!(events["evidence_ids"], serde_json::json!(["ev_sum"]));
+    }
+
+    #[test]
+    fn test_artifact_outputs_use_artifact_names() {
+        let pipeline = Pipeline::from_yaml(
+            r#"
+name: reprocess
+description: Test pipeline
+steps:
+  - name: extract_wisdom
+    adapter: fabric
+    action: extract_wisdom
+    artifact_name: wisdom
+  - name: summary
+    adapter: fabric
+    action: summarize
+"#,
+        )
+        .unwrap();
+
+        let mut run = Run::new(uuid::Uuid::new_v4(), pipeline.name.clone(), String::new());
+        for (step, content) in [("extract_wisdom", "ideas"), ("summary", "short")] {
+            run.artifacts.insert(
+                step.to_string(),
+                crate::domain::Artifact::new(
+                    step.to_string(),
+                    crate::domain::ArtifactType::StepOutput,
+                    content.to_string(),
+                ),
+            );
+        }
+
+        assert_eq!(
+            artifact_outputs(&pipeline, &run),
+            vec![
+                ("summary".to_string(), "short".to_string()),
+                ("wisdom".to_string(), "ideas".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reindex_populates_all_indexes_after_import() {
+        let source = TempDir::new().unwrap();
+        std::fs::write(source.path().join("one.md"), "alpha beta gamma").unwrap();
+        std::fs::write(source.path().join("two.md"), "delta epsilon").unwrap();
+
+        let library = TempDir::new().unwrap();
+        let mut catalog = Catalog::new();
+        let options = ImportOptions {
+            content_type: ContentType::Other,
+            tags: Vec::new(),
+        };
+        let report = import_directory(source.path(), library.path(), &mut catalog, &options)
+            .await
+            .unwrap();
+        assert_eq!(report.imported.len(), 2);
+
+        // Artifacts and evidence added after the import
+        let content_dirs: Vec<PathBuf> = std::fs::read_dir(library.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        let first = &content_dirs[0];
+        std::fs::write(first.join("summary.md"), "short").unwrap();
+        let source_text = std::fs::read_to_string(first.join("source.md")).unwrap();
+        let evidence = crate::evidence::Evidence::new_resolved(
+            "ev_1".to_string(),
+            super::super::evidence::content_id_of(first).await,
+            "claim".to_string(),
+            "alpha".to_string(),
+            "00".to_string(),
+            crate::evidence::Span {
+                artifact: "source.md".to_string(),
+                utf8_byte_offset: [0, 5],
+                slice_sha256: crate::evidence::compute_slice_hash(source_text.as_bytes(), 0, 5),
+                anchor_text: None,
+                video_timestamp: None,
+            },
+            0.9,
+            "test".to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        );
+        EvidenceStore::open(first).append(&[evidence]).unwrap();
+
+        let store = crate::store::Store::open_memory().unwrap();
+        let digests = DigestCache::new();
+        let counts = reindex_library(
+            &content_dirs,
+            Some(&store),
+            Some(&digests),
+            Some(&mut catalog),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            counts,
+            ReindexCounts {
+                search: 2,
+                digests: 1,
+                artifacts: 2,
+                errors: 0,
+            }
+        );
+        assert_eq!(crate::store::queries::count_items(&store, None).unwrap(), 2);
+
+        let metadata: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(first.join("metadata.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            metadata["artifact_digests"]["source.md"],
+            crate::evidence::compute_hash(source_text.as_bytes())
+        );
+
+        let id = super::super::evidence::content_id_of(first).await;
+        let item = catalog
+            .items
+            .iter()
+            .find(|item| item.id.as_str() == id)
+            .unwrap();
+        assert_eq!(
+            item.artifacts,
+            vec!["source".to_string(), "summary".to_string()]
+        );
+    }
+}
@@ -3,20 +3,31 @@
 //! Provides commands for running pipelines, checking status,
 //! listing runs, resuming failed runs, and managing the content library.
 
-use std::io::{self, Read};
-use std::path::PathBuf;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
-use uuid::Uuid;
+use tokio_util::sync::CancellationToken;
 
 use crate::adapters::{Adapter, FabricAdapter, ACTION_WEB, ACTION_YOUTUBE};
-use crate::core::{Orchestrator, Pipeline};
+use crate::core::{EventStore, Orchestrator, Pipeline, SafetyDefaults, TimeoutOverrides};
 use crate::library::{Catalog, CatalogItem, ContentType, LibraryContent};
 
+pub mod batch;
 pub mod capture;
+pub mod clipboard;
+pub mod doctor;
+pub mod editor;
+pub mod encoding;
 pub mod evidence;
+pub mod exit_code;
+pub mod follow;
+pub mod library;
+pub mod progress;
+pub mod serve;
 pub mod triage;
 pub mod voice;
 
@@ -25,6 +36,15 @@ pub mod voice;
 #[command(name = "arkai")]
 #[command(author, version, about, long_about = None)]
 pub struct Cli {
+    /// Suppress status banners and logs; print only output and errors
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Print machine-readable JSON instead of tables (status, runs, voice
+    /// list, doctor, today)
+    #[arg(long, global = true)]
+    pub json: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -34,7 +54,8 @@ pub enum Commands {
     /// Run a pipeline
     Run {
         /// Pipeline name (will look for pipelines/<name>.yaml)
-        pipeline_name: String,
+        #[arg(required_unless_present = "resume_batch")]
+        pipeline_name: Option<String>,
 
         /// Input file (reads from stdin if not provided)
         #[arg(short, long)]
@@ -43,12 +64,91 @@ pub enum Commands {
         /// Read input from stdin
         #[arg(long)]
         stdin: bool,
+
+        /// Read input from the system clipboard
+        #[arg(long, conflicts_with_all = ["input", "stdin"])]
+        clipboard: bool,
+
+        /// Copy the final output to the system clipboard instead of printing it
+        #[arg(long)]
+        to_clipboard: bool,
+
+        /// With --to-clipboard, also print the final output to stdout
+        #[arg(long, requires = "to_clipboard")]
+        also_print: bool,
+
+        /// Run the pipeline once per file in this directory
+        #[arg(long, conflicts_with_all = ["input", "stdin", "clipboard", "to_clipboard"])]
+        input_dir: Option<PathBuf>,
+
+        /// File name pattern for --input-dir
+        #[arg(long, default_value = "*", requires = "input_dir")]
+        glob: String,
+
+        /// Maximum parallel runs for --input-dir or --resume-batch
+        #[arg(long, default_value = "1")]
+        concurrency: usize,
+
+        /// Resume an interrupted --input-dir batch, skipping completed files
+        #[arg(long, conflicts_with_all = ["pipeline_name", "input", "stdin", "clipboard", "input_dir"])]
+        resume_batch: Option<String>,
+
+        /// Override the pipeline's per-step timeout (seconds, tighter or looser).
+        /// Steps with their own timeout_seconds keep it unless --override-step-timeouts
+        #[arg(long, value_name = "SECS")]
+        step_timeout: Option<u64>,
+
+        /// Override the pipeline's total run timeout (seconds)
+        #[arg(long, value_name = "SECS")]
+        run_timeout: Option<u64>,
+
+        /// Apply --step-timeout to steps with an explicit timeout_seconds as well
+        #[arg(long, requires = "step_timeout")]
+        override_step_timeouts: bool,
+
+        /// Cap retries across all steps of the run; the run stops once it is spent
+        #[arg(long, value_name = "N")]
+        retry_budget: Option<u32>,
+
+        /// Fail instead of warning about likely problems, such as step
+        /// timeouts and retries that can't fit in the run timeout
+        #[arg(long)]
+        strict: bool,
+
+        /// Use a previous run's final output as input (run ID or unique prefix)
+        #[arg(long, conflicts_with_all = ["input", "stdin", "clipboard", "input_dir", "resume_batch"])]
+        from_run: Option<String>,
+
+        /// With --from-run, use this step's artifact instead of the final one
+        #[arg(long, requires = "from_run")]
+        step: Option<String>,
+
+        /// Print estimated tokens and cost per step without running the pipeline
+        #[arg(long, conflicts_with_all = ["input_dir", "resume_batch", "to_clipboard"])]
+        estimate: bool,
+
+        /// Encoding of the input file or stdin (e.g. latin1, windows-1252)
+        #[arg(
+            long,
+            value_name = "LABEL",
+            default_value = encoding::DEFAULT_INPUT_ENCODING,
+            conflicts_with_all = ["clipboard", "from_run", "input_dir", "resume_batch"]
+        )]
+        input_encoding: String,
     },
 
     /// Check the status of a run
     Status {
         /// Run ID (UUID)
         run_id: String,
+
+        /// Keep printing events as they're appended until the run finishes
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Also show the arkai/Fabric versions and platform the run used
+        #[arg(long, conflicts_with = "follow")]
+        env: bool,
     },
 
     /// List recent runs
@@ -56,6 +156,19 @@ pub enum Commands {
         /// Maximum number of runs to show
         #[arg(short, long, default_value = "10")]
         limit: usize,
+
+        /// Skip this many of the most recent runs (for paging)
+        #[arg(long, default_value = "0")]
+        offset: usize,
+
+        /// Rewrite the run index from the run directories
+        #[arg(long)]
+        rebuild_index: bool,
+
+        /// Record runs left running by a process that died as interrupted,
+        /// so they can be resumed
+        #[arg(long)]
+        recover: bool,
     },
 
     /// Resume a failed run
@@ -64,9 +177,34 @@ pub enum Commands {
         run_id: String,
     },
 
-    /// Start as HTTP server (stub - not yet implemented)
+    /// Verify a run's event log for internal consistency
+    Verify {
+        /// Run ID (UUID)
+        run_id: String,
+
+        /// Pipeline name, to check step counts against its length
+        #[arg(short, long)]
+        pipeline: Option<String>,
+
+        /// Also verify the events.sig HMAC chain (requires ARKAI_EVENT_HMAC_KEY)
+        #[arg(long)]
+        signatures: bool,
+    },
+
+    /// Compact a finished run's event log into a snapshot
+    Compact {
+        /// Run ID (UUID, or a unique prefix)
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        run_id: Option<String>,
+
+        /// Compact every finished run, skipping those that can't be compacted
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Serve the run API over HTTP
     Serve {
-        /// Address to bind to
+        /// Address to bind to (host:port, or :port for all interfaces)
         #[arg(short, long, default_value = ":9000")]
         address: String,
     },
@@ -89,8 +227,11 @@ pub enum Commands {
         title: Option<String>,
     },
 
-    /// List items in the library
+    /// List items in the library (or manage it via subcommands)
     Library {
+        #[command(subcommand)]
+        command: Option<library::LibraryCommands>,
+
         /// Filter by content type
         #[arg(short, long, value_enum)]
         content_type: Option<IngestType>,
@@ -101,15 +242,14 @@ pub enum Commands {
     },
 
     /// Show resolved configuration (debug)
-    Config,
-
-    /// Show operator diagnostics
-    Doctor {
-        /// Output machine-readable JSON
-        #[arg(long)]
-        json: bool,
+    Config {
+        #[command(subcommand)]
+        command: Option<ConfigCommands>,
     },
 
+    /// Check the installation (Fabric, ffmpeg, Whisper, config, paths)
+    Doctor,
+
     /// Search the library
     Search {
         /// Search query
@@ -196,11 +336,7 @@ pub enum Commands {
     },
 
     /// Show active captures grouped by horizon
-    Today {
-        /// Output as JSON (for scripting/OpenClaw integration)
-        #[arg(long)]
-        json: bool,
-    },
+    Today,
 
     /// Mark a capture as done
     Done {
@@ -229,6 +365,21 @@ pub enum IngestType {
     Web,
 }
 
+/// Config subcommands
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommands {
+    /// Show the effective configuration
+    Show {
+        /// Print every resolved value as a flat key/value list
+        #[arg(long)]
+        resolved: bool,
+
+        /// Annotate each value with its source (env/config/default); implies --resolved
+        #[arg(long)]
+        sources: bool,
+    },
+}
+
 /// Store management subcommands
 #[derive(Subcommand, Debug)]
 pub enum StoreCommands {
@@ -264,57 +415,189 @@ impl From<IngestType> for ContentType {
 }
 
 impl Cli {
-    /// Execute the CLI command
-    pub async fn execute(self) -> Result<()> {
+    /// Execute the CLI command, returning the process exit code.
+    ///
+    /// Commands that end a run report its outcome as the code rather than
+    /// exiting, so `main` can flush exported telemetry first.
+    pub async fn execute(self) -> Result<i32> {
+        let quiet = self.quiet;
+        let json = self.json;
         match self.command {
             Commands::Run {
-                pipeline_name,
+                pipeline_name: Some(pipeline_name),
+                resume_batch: None,
+                input_dir: None,
+                estimate: false,
                 input,
                 stdin,
-            } => run_pipeline(&pipeline_name, input, stdin).await,
-            Commands::Status { run_id } => show_status(&run_id).await,
-            Commands::Runs { limit } => list_runs(limit).await,
-            Commands::Resume { run_id } => resume_run(&run_id).await,
-            Commands::Serve { address } => serve(&address).await,
+                clipboard,
+                to_clipboard,
+                also_print,
+                step_timeout,
+                run_timeout,
+                override_step_timeouts,
+                retry_budget,
+                strict,
+                from_run,
+                step,
+                input_encoding,
+                ..
+            } => {
+                let source = RunInput::new(input, stdin, clipboard, &input_encoding)?;
+                let output = RunOutput {
+                    to_clipboard,
+                    also_print,
+                    quiet,
+                };
+                let from_run = from_run.map(|run_id| FromRun { run_id, step });
+                let timeouts = TimeoutOverrides {
+                    step_timeout_seconds: step_timeout,
+                    run_timeout_seconds: run_timeout,
+                    override_step_timeouts,
+                    max_total_retries: retry_budget,
+                };
+                run_pipeline(&pipeline_name, source, from_run, output, &timeouts, strict).await
+            }
+            Commands::Resume { run_id } => resume_run(&run_id, quiet).await,
             Commands::Ingest {
                 url,
                 content_type,
                 tags,
                 title,
             } => ingest_content(&url, content_type, tags, title).await,
-            Commands::Config => show_config().await,
-            Commands::Doctor { json } => run_doctor(json).await,
-            Commands::Library {
-                content_type,
-                limit,
-            } => list_library(content_type, limit).await,
-            Commands::Search {
-                query,
-                semantic,
-                limit,
-            } => search_library(&query, semantic, limit).await,
-            Commands::Store { command } => execute_store(command).await,
-            Commands::Show { content_id, full } => show_content(&content_id, full).await,
             Commands::Reprocess { content_id } => reprocess_content(&content_id).await,
-            Commands::Pattern {
-                pattern_name,
-                input,
-                save,
-                tags,
-            } => run_pattern(&pattern_name, input, save, tags).await,
-            Commands::Evidence { command } => execute_evidence(command).await,
-            Commands::Voice { command } => voice::execute(command).await,
-            Commands::Capture {
-                text,
-                kind,
-                tag,
-                due,
-            } => capture::execute_capture(text, kind, tag, due).await,
-            Commands::Today { json } => triage::execute_today(json).await,
-            Commands::Done { item_id } => triage::execute_done(item_id).await,
-            Commands::Snooze { item_id, until } => {
-                triage::execute_snooze(item_id, until).await
+            command => execute_command(command, json)
+                .await
+                .map(|()| exit_code::SUCCESS),
+        }
+    }
+}
+
+/// Execute a command that has no run outcome to report
+async fn execute_command(command: Commands, json: bool) -> Result<()> {
+    match command {
+        Commands::Run {
+            resume_batch: Some(batch_id),
+            concurrency,
+            step_timeout,
+            run_timeout,
+            override_step_timeouts,
+            retry_budget,
+            strict,
+            ..
+        } => {
+            let timeouts = TimeoutOverrides {
+                step_timeout_seconds: step_timeout,
+                run_timeout_seconds: run_timeout,
+                override_step_timeouts,
+                max_total_retries: retry_budget,
+            };
+            batch::execute_resume_batch(&batch_id, concurrency, &timeouts, strict).await
+        }
+        Commands::Run {
+            pipeline_name: Some(pipeline_name),
+            input_dir: Some(dir),
+            glob,
+            concurrency,
+            step_timeout,
+            run_timeout,
+            override_step_timeouts,
+            retry_budget,
+            strict,
+            ..
+        } => {
+            let timeouts = TimeoutOverrides {
+                step_timeout_seconds: step_timeout,
+                run_timeout_seconds: run_timeout,
+                override_step_timeouts,
+                max_total_retries: retry_budget,
+            };
+            batch::execute_batch(&pipeline_name, &dir, &glob, concurrency, &timeouts, strict)
+                .await
+        }
+        Commands::Run {
+            pipeline_name: Some(pipeline_name),
+            input,
+            stdin,
+            clipboard,
+            from_run,
+            step,
+            estimate: true,
+            input_encoding,
+            ..
+        } => {
+            let source = RunInput::new(input, stdin, clipboard, &input_encoding)?;
+            let from_run = from_run.map(|run_id| FromRun { run_id, step });
+            estimate_run(&pipeline_name, source, from_run).await
+        }
+        Commands::Run {
+            pipeline_name: None,
+            ..
+        } => anyhow::bail!("A pipeline name is required"),
+        // Handled in `Cli::execute`
+        Commands::Run { .. }
+        | Commands::Resume { .. }
+        | Commands::Ingest { .. }
+        | Commands::Reprocess { .. } => unreachable!("run commands report an exit code"),
+        Commands::Status {
+            run_id,
+            follow,
+            env,
+        } => show_status(&run_id, follow, env, json).await,
+        Commands::Runs {
+            limit,
+            offset,
+            rebuild_index,
+            recover,
+        } => list_runs(limit, offset, rebuild_index, recover, json).await,
+        Commands::Verify {
+            run_id,
+            pipeline,
+            signatures,
+        } => verify_run(&run_id, pipeline, signatures).await,
+        Commands::Compact { run_id, all } => compact_runs(run_id.as_deref(), all).await,
+        Commands::Serve { address } => serve::execute_serve(&address).await,
+        Commands::Config { command } => match command {
+            Some(ConfigCommands::Show { resolved, sources }) if resolved || sources => {
+                show_resolved_config(sources).await
             }
+            Some(ConfigCommands::Show { .. }) | None => show_config().await,
+        },
+        Commands::Doctor => run_doctor(json).await,
+        Commands::Library {
+            command: Some(command),
+            ..
+        } => library::execute(command).await,
+        Commands::Library {
+            command: None,
+            content_type,
+            limit,
+        } => list_library(content_type, limit).await,
+        Commands::Search {
+            query,
+            semantic,
+            limit,
+        } => search_library(&query, semantic, limit).await,
+        Commands::Store { command } => execute_store(command).await,
+        Commands::Show { content_id, full } => show_content(&content_id, full).await,
+        Commands::Pattern {
+            pattern_name,
+            input,
+            save,
+            tags,
+        } => run_pattern(&pattern_name, input, save, tags).await,
+        Commands::Evidence { command } => execute_evidence(command).await,
+        Commands::Voice { command } => voice::execute(command, json).await,
+        Commands::Capture {
+            text,
+            kind,
+            tag,
+            due,
+        } => capture::execute_capture(text, kind, tag, due).await,
+        Commands::Today => triage::execute_today(json).await,
+        Commands::Done { item_id } => triage::execute_done(item_id).await,
+        Commands::Snooze { item_id, until } => {
+            triage::execute_snooze(item_id, until).await
         }
     }
 }
@@ -322,94 +605,358 @@ impl Cli {
 /// Execute evidence subcommands
 async fn execute_evidence(command: evidence::EvidenceCommands) -> Result<()> {
     match command {
-        evidence::EvidenceCommands::Ground { content_dir } => {
-            evidence::execute_ground(&content_dir).await
+        evidence::EvidenceCommands::Ground {
+            content_dir,
+            min_confidence,
+        } => evidence::execute_ground(&content_dir, min_confidence).await,
+        evidence::EvidenceCommands::Extract {
+            content_id,
+            extractor,
+            min_confidence,
+        } => evidence::execute_extract(&content_id, &extractor, min_confidence).await,
+        evidence::EvidenceCommands::Show {
+            evidence_id,
+            min_confidence,
+            context,
+            bytes,
+        } => evidence::execute_show(&evidence_id, min_confidence, context, bytes).await,
+        evidence::EvidenceCommands::Open { evidence_id, all } => {
+            if all {
+                evidence::execute_open_all(&evidence_id).await
+            } else {
+                evidence::execute_open(&evidence_id).await
+            }
         }
-        evidence::EvidenceCommands::Show { evidence_id } => {
-            evidence::execute_show(&evidence_id).await
+        evidence::EvidenceCommands::Stats {
+            content_id,
+            all,
+            min_confidence,
+        } => evidence::execute_stats(content_id.as_deref(), all, min_confidence).await,
+        evidence::EvidenceCommands::Delete {
+            evidence_id,
+            reason,
+        } => evidence::execute_delete(&evidence_id, reason.as_deref()).await,
+        evidence::EvidenceCommands::Compact { content_id } => {
+            evidence::execute_compact(&content_id).await
         }
-        evidence::EvidenceCommands::Open { evidence_id } => {
-            evidence::execute_open(&evidence_id).await
+        evidence::EvidenceCommands::Reindex { content_id, all } => {
+            evidence::execute_reindex(content_id.as_deref(), all).await
         }
-        evidence::EvidenceCommands::Validate { content_id } => {
-            evidence::execute_validate(&content_id).await
+        evidence::EvidenceCommands::Validate {
+            content_id,
+            all,
+            parallel,
+            artifact,
+            offset,
+            limit,
+        } => {
+            let range = crate::evidence::EvidenceRange {
+                artifact,
+                offset,
+                limit,
+            };
+            match content_id {
+                Some(content_id) if !all => evidence::execute_validate(&content_id, &range).await,
+                _ => evidence::execute_validate_all(&range, parallel as usize).await,
+            }
         }
     }
 }
 
+/// Where `arkai run` sends the final step's output
+#[derive(Debug, Clone, Copy, Default)]
+struct RunOutput {
+    to_clipboard: bool,
+    also_print: bool,
+    /// Skip the status banners on stderr
+    quiet: bool,
+}
+
+/// A previous run's artifact to use as input
+struct FromRun {
+    run_id: String,
+    step: Option<String>,
+}
+
+/// A token cancelled on the first Ctrl+C, so the run in flight stops with
+/// `RunCancelled` (and can be resumed) rather than dying mid-step. A second
+/// Ctrl+C exits at once with code 130.
+fn cancel_on_ctrl_c() -> CancellationToken {
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            cancel.cancel();
+            eprintln!("Cancelling run (Ctrl+C again to exit now)...");
+            if tokio::signal::ctrl_c().await.is_ok() {
+                std::process::exit(exit_code::INTERRUPTED);
+            }
+        }
+    });
+    token
+}
+
 /// Run a pipeline with the given input
 async fn run_pipeline(
     pipeline_name: &str,
-    input_file: Option<PathBuf>,
-    use_stdin: bool,
-) -> Result<()> {
+    source: RunInput,
+    from_run: Option<FromRun>,
+    output: RunOutput,
+    timeouts: &TimeoutOverrides,
+    strict: bool,
+) -> Result<i32> {
     // Load the pipeline
-    let pipeline = load_pipeline(pipeline_name)?;
-
-    // Get input
-    let input = if let Some(path) = input_file {
-        std::fs::read_to_string(&path)
-            .with_context(|| format!("Failed to read input file: {}", path.display()))?
-    } else if use_stdin || atty::isnt(atty::Stream::Stdin) {
-        // Read from stdin if --stdin flag or if stdin is piped
-        let mut buffer = String::new();
-        io::stdin()
-            .read_to_string(&mut buffer)
-            .context("Failed to read from stdin")?;
-        buffer
-    } else {
-        anyhow::bail!("No input provided. Use --input <file> or pipe to stdin");
-    };
-
-    if input.trim().is_empty() {
-        anyhow::bail!("Input is empty");
+    let pipeline = load_run_pipeline(pipeline_name, timeouts, strict)?;
+    let input = read_run_input(source, from_run).await?;
+
+    // Execute the pipeline, with a step spinner on interactive terminals
+    let spinner = progress::StepSpinner::for_stderr(output.quiet);
+    let mut orchestrator = Orchestrator::new().with_cancellation(cancel_on_ctrl_c());
+
+    // Print the final step's output live when it's going to the terminal
+    let streamed = Arc::new(Mutex::new(String::new()));
+    if !output.to_clipboard && io::stdout().is_terminal() {
+        let spinner = spinner.clone();
+        let streamed = streamed.clone();
+        orchestrator = orchestrator.with_output_stream(move |chunk| {
+            streamed.lock().unwrap().push_str(chunk);
+            let print = || {
+                let mut stdout = io::stdout();
+                let _ = stdout.write_all(chunk.as_bytes());
+                let _ = stdout.flush();
+            };
+            match &spinner {
+                Some(spinner) => spinner.suspend(print),
+                None => print(),
+            }
+        });
     }
 
-    // Execute the pipeline
-    let orchestrator = Orchestrator::new();
-    let run = orchestrator.run_pipeline(&pipeline, input).await?;
+    let run = match spinner {
+        Some(spinner) => {
+            let run = orchestrator
+                .run_pipeline_with_progress(&pipeline, input, &|step| spinner.update(step))
+                .await;
+            spinner.finish();
+            run?
+        }
+        None => orchestrator.run_pipeline(&pipeline, input).await?,
+    };
 
     // Print results
     match &run.state {
-        crate::domain::RunState::Completed => {
-            // Print the final output
-            if let Some(last_step) = pipeline.steps.last() {
-                if let Some(artifact) = run.artifacts.get(&last_step.name) {
-                    println!("{}", artifact.content);
-                }
-            }
-            eprintln!("\n[Run {} completed successfully]", run.id);
+        crate::domain::RunState::Completed | crate::domain::RunState::PartiallyCompleted { .. } => {
+            let streamed = streamed.lock().unwrap().clone();
+            emit_completed_run(&pipeline, &run, &output, &streamed, false)?;
         }
         crate::domain::RunState::Failed { error } => {
             eprintln!("\n[Run {} failed: {}]", run.id, error);
-            std::process::exit(1);
         }
         crate::domain::RunState::SafetyLimitReached { limit } => {
             eprintln!(
                 "\n[Run {} stopped: safety limit reached - {}]",
                 run.id, limit
             );
-            std::process::exit(1);
         }
+        crate::domain::RunState::Cancelled => {
+            eprintln!(
+                "\n[Run {} cancelled; continue with: arkai resume {}]",
+                run.id, run.id
+            );
+        }
+        _ if output.quiet => {}
         _ => {
             eprintln!("\n[Run {} in state: {:?}]", run.id, run.state);
         }
     }
 
+    Ok(exit_code::for_state(&run.state))
+}
+
+/// Print (or copy) a completed run's final output, then its banner.
+///
+/// With failed optional or disabled steps at the end, the final output is
+/// that of the last step that produced one. Output already streamed live
+/// (`streamed`) isn't printed again, but a streamed attempt that wasn't the
+/// one accepted doesn't count.
+fn emit_completed_run(
+    pipeline: &Pipeline,
+    run: &crate::domain::Run,
+    output: &RunOutput,
+    streamed: &str,
+    resumed: bool,
+) -> Result<()> {
+    let final_artifact = pipeline
+        .steps
+        .iter()
+        .rev()
+        .find_map(|step| run.artifacts.get(&step.name))
+        .filter(|artifact| artifact.content != streamed);
+    if let Some(artifact) = final_artifact {
+        clipboard::emit_output(
+            &artifact.content,
+            output.to_clipboard,
+            output.also_print,
+            &clipboard::SystemClipboard,
+            &mut io::stdout(),
+        )?;
+    }
+
+    let resumed = if resumed { "resumed and " } else { "" };
+    match &run.state {
+        _ if output.quiet => {}
+        crate::domain::RunState::PartiallyCompleted { failed_steps } => eprintln!(
+            "\n[Run {} {}partially completed; failed steps: {}]",
+            run.id,
+            resumed,
+            failed_steps.join(", ")
+        ),
+        _ => eprintln!("\n[Run {} {}completed successfully]", run.id, resumed),
+    }
+    Ok(())
+}
+
+/// Where `arkai run` reads its input when not from a previous run
+struct RunInput {
+    input_file: Option<PathBuf>,
+    use_stdin: bool,
+    use_clipboard: bool,
+    /// Encoding of the file or stdin (`--input-encoding`)
+    encoding: &'static encoding_rs::Encoding,
+}
+
+impl RunInput {
+    fn new(
+        input_file: Option<PathBuf>,
+        use_stdin: bool,
+        use_clipboard: bool,
+        encoding_label: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            input_file,
+            use_stdin,
+            use_clipboard,
+            encoding: encoding::parse_label(encoding_label)?,
+        })
+    }
+}
+
+/// Read `arkai run` input from the first configured source
+async fn read_run_input(source: RunInput, from_run: Option<FromRun>) -> Result<String> {
+    let RunInput {
+        input_file,
+        use_stdin,
+        use_clipboard,
+        encoding,
+    } = source;
+
+    let input = if let Some(from_run) = from_run {
+        let run_id = EventStore::resolve_run_id(&from_run.run_id).await?;
+        Orchestrator::new()
+            .load_run_output(run_id, from_run.step.as_deref())
+            .await?
+    } else if use_clipboard {
+        clipboard::read_clipboard_input(&clipboard::SystemClipboard)?
+    } else if let Some(path) = input_file {
+        let bytes = std::fs::read(&path).map_err(|e| {
+            exit_code::ExitError::invalid_input(format!(
+                "Failed to read input file: {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        encoding::decode(&bytes, encoding)
+            .with_context(|| format!("Failed to decode input file: {}", path.display()))?
+    } else if use_stdin || atty::isnt(atty::Stream::Stdin) {
+        // Read from stdin if --stdin flag or if stdin is piped
+        let mut buffer = Vec::new();
+        io::stdin()
+            .read_to_end(&mut buffer)
+            .context("Failed to read from stdin")?;
+        encoding::decode(&buffer, encoding).context("Failed to decode stdin")?
+    } else {
+        return Err(exit_code::ExitError::invalid_input(
+            "No input provided. Use --input <file>, --clipboard, or pipe to stdin",
+        )
+        .into());
+    };
+
+    if input.trim().is_empty() {
+        return Err(exit_code::ExitError::invalid_input("Input is empty").into());
+    }
+
+    Ok(input)
+}
+
+/// Print estimated tokens and cost for a run without executing it
+async fn estimate_run(
+    pipeline_name: &str,
+    source: RunInput,
+    from_run: Option<FromRun>,
+) -> Result<()> {
+    let pipeline = load_pipeline(pipeline_name)?;
+    let input = read_run_input(source, from_run).await?;
+    let estimate = crate::config::config()?.cost.estimate(&pipeline, &input);
+
+    println!(
+        "{:<20} {:<24} {:>10} {:>10} {:>10}",
+        "STEP", "ACTION", "IN TOK", "OUT TOK", "USD"
+    );
+    println!("{}", "-".repeat(78));
+    for step in &estimate.steps {
+        println!(
+            "{:<20} {:<24} {:>10} {:>10} {:>10.4}",
+            step.step, step.action, step.input_tokens, step.output_tokens, step.cost_usd
+        );
+    }
+    println!("{}", "-".repeat(78));
+    println!(
+        "{:<45} {:>21} {:>10.4}",
+        "TOTAL",
+        estimate.total_tokens(),
+        estimate.total_cost_usd()
+    );
+    eprintln!(
+        "\nWorst-case runtime: {}s (run timeout {}s)",
+        pipeline.worst_case_runtime().as_secs(),
+        pipeline.safety_limits.run_timeout_seconds
+    );
+    eprintln!("[Estimate only; pipeline '{}' was not run]", pipeline.name);
+
     Ok(())
 }
 
 /// Show the status of a run
-async fn show_status(run_id_str: &str) -> Result<()> {
-    let run_id =
-        Uuid::parse_str(run_id_str).with_context(|| format!("Invalid run ID: {}", run_id_str))?;
+async fn show_status(run_id_str: &str, follow: bool, show_env: bool, json: bool) -> Result<()> {
+    let run_id = EventStore::resolve_run_id(run_id_str).await?;
+
+    if follow {
+        let store = EventStore::open(run_id).await?;
+        follow::follow_run(&store, &mut io::stdout(), follow::POLL_INTERVAL).await?;
+        return Ok(());
+    }
 
     let orchestrator = Orchestrator::new();
     let run = orchestrator.get_run_status(run_id).await?;
+    let stale = is_stale(run.id, &run.state).await?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&run_status_json(&run, stale)?)?
+        );
+        return Ok(());
+    }
 
     println!("Run ID: {}", run.id);
     println!("Pipeline: {}", run.pipeline_name);
     println!("State: {:?}", run.state);
+    if stale {
+        println!(
+            "  No process is executing this run; it was interrupted. `arkai resume {}` to continue it",
+            run.id
+        );
+    }
     println!("Started: {}", run.started_at);
     if let Some(completed) = run.completed_at {
         println!("Completed: {}", completed);
@@ -420,13 +967,240 @@ async fn show_status(run_id_str: &str) -> Result<()> {
         println!("  {}: {:?}", step, status);
     }
 
+    if !run.artifact_metadata.is_empty() {
+        let mut artifacts: Vec<_> = run.artifact_metadata.iter().collect();
+        artifacts.sort_by_key(|(step, _)| step.as_str());
+        println!("\nArtifacts:");
+        for (step, metadata) in artifacts {
+            println!(
+                "  {}: {} ({} bytes)",
+                step, metadata.artifact_type, metadata.byte_len
+            );
+        }
+    }
+
+    if show_env {
+        println!("\nEnvironment:");
+        match run.environment() {
+            Some(environment) => {
+                for line in environment.to_string().lines() {
+                    println!("  {}", line);
+                }
+            }
+            None => println!("  (not recorded; run predates environment snapshots)"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a run its log says is running has no process executing it, so
+/// the process died mid-run. Read-only: `arkai runs --recover` records it.
+async fn is_stale(run_id: uuid::Uuid, state: &crate::domain::RunState) -> Result<bool> {
+    if *state != crate::domain::RunState::Running {
+        return Ok(false);
+    }
+    Ok(!EventStore::open(run_id).await?.is_locked())
+}
+
+/// `arkai status --json`: the run as stored, plus the fields `status`
+/// derives from it
+fn run_status_json(run: &crate::domain::Run, stale: bool) -> Result<serde_json::Value> {
+    let mut value = serde_json::to_value(run)?;
+    let object = value
+        .as_object_mut()
+        .context("Run did not serialize to an object")?;
+    object.insert(
+        "state_label".to_string(),
+        run_state_label(&run.state).into(),
+    );
+    object.insert("finished".to_string(), run.is_finished().into());
+    object.insert("stale".to_string(), stale.into());
+    let mut steps: Vec<_> = run.step_statuses.iter().collect();
+    steps.sort_by_key(|(step, _)| step.as_str());
+    object.insert(
+        "steps".to_string(),
+        steps
+            .into_iter()
+            .map(|(step, status)| serde_json::json!({ "name": step, "status": status }))
+            .collect(),
+    );
+    object.insert(
+        "environment".to_string(),
+        serde_json::to_value(run.environment())?,
+    );
+    Ok(value)
+}
+
+/// Short state name shown by `arkai runs`
+fn run_state_label(state: &crate::domain::RunState) -> &'static str {
+    match state {
+        crate::domain::RunState::Running => "running",
+        crate::domain::RunState::Completed => "completed",
+        crate::domain::RunState::PartiallyCompleted { .. } => "partial",
+        crate::domain::RunState::Failed { .. } => "failed",
+        crate::domain::RunState::Paused => "paused",
+        crate::domain::RunState::SafetyLimitReached { .. } => "safety-limit",
+        crate::domain::RunState::Cancelled => "cancelled",
+        crate::domain::RunState::Interrupted => "interrupted",
+    }
+}
+
+/// Verify a run's event log and report anomalies with their line numbers
+async fn verify_run(
+    run_id_str: &str,
+    pipeline_name: Option<String>,
+    check_signatures: bool,
+) -> Result<()> {
+    use crate::core::signing::HMAC_KEY_ENV;
+    use crate::core::EventSigner;
+
+    let run_id = EventStore::resolve_run_id(run_id_str).await?;
+
+    let store = EventStore::open(run_id).await?;
+    let numbered = store.replay_with_lines().await?;
+    let snapshot = store.snapshot().await?;
+    if numbered.is_empty() && snapshot.is_none() {
+        anyhow::bail!("Run {} not found", run_id);
+    }
+    // Only the events after a snapshot are still in the log to check
+    let compacted = snapshot
+        .map(|snapshot| format!(" ({} compacted into snapshot)", snapshot.seq))
+        .unwrap_or_default();
+
+    let step_count = match pipeline_name {
+        Some(name) => Some(load_pipeline(&name)?.steps.len()),
+        None => None,
+    };
+
+    let (lines, events): (Vec<usize>, Vec<_>) = numbered.into_iter().unzip();
+    let anomalies = crate::domain::verify_events(&events, step_count);
+    let mut failed = !anomalies.is_empty();
+
+    if anomalies.is_empty() {
+        println!(
+            "✓ Run {}: {} events verified{}",
+            run_id,
+            events.len(),
+            compacted
+        );
+    } else {
+        println!("✗ Run {}: {} anomalies found", run_id, anomalies.len());
+        for anomaly in &anomalies {
+            println!(
+                "  {}:{}: {}",
+                store.location(),
+                lines[anomaly.index],
+                anomaly.kind
+            );
+        }
+    }
+
+    if check_signatures {
+        let signer = EventSigner::from_env()
+            .with_context(|| format!("--signatures requires {} to be set", HMAC_KEY_ENV))?;
+        let mismatches = store.verify_signatures(&signer).await?;
+
+        if mismatches.is_empty() {
+            println!("✓ Signature chain verified");
+        } else {
+            failed = true;
+            println!("✗ Signature chain: {} mismatches", mismatches.len());
+            for mismatch in &mismatches {
+                println!("  {}: {}", store.signatures_path().display(), mismatch);
+            }
+        }
+    }
+
+    if failed {
+        anyhow::bail!("Event log verification failed for run {}", run_id);
+    }
+
+    Ok(())
+}
+
+/// Compact one run's event log, or every run's with `all`
+async fn compact_runs(run_id_str: Option<&str>, all: bool) -> Result<()> {
+    if !all {
+        let run_id = EventStore::resolve_run_id(run_id_str.unwrap_or_default()).await?;
+        match EventStore::open(run_id).await?.compact().await? {
+            Some(count) => println!("Compacted run {}: {} events into snapshot", run_id, count),
+            None => println!("Run {}: nothing to compact", run_id),
+        }
+        return Ok(());
+    }
+
+    let (mut compacted, mut skipped) = (0, 0);
+    for run_id in EventStore::list_runs().await? {
+        match EventStore::open(run_id).await?.compact().await {
+            Ok(Some(count)) => {
+                compacted += 1;
+                println!("Compacted run {}: {} events into snapshot", run_id, count);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                skipped += 1;
+                println!("Skipped run {}: {}", run_id, e);
+            }
+        }
+    }
+    println!("{} runs compacted, {} skipped", compacted, skipped);
     Ok(())
 }
 
 /// List recent runs
-async fn list_runs(limit: usize) -> Result<()> {
+async fn list_runs(
+    limit: usize,
+    offset: usize,
+    rebuild_index: bool,
+    recover: bool,
+    json: bool,
+) -> Result<()> {
     let orchestrator = Orchestrator::new();
-    let runs = orchestrator.list_runs(limit).await?;
+    if recover {
+        for run_id in orchestrator.recover_interrupted_runs().await? {
+            eprintln!(
+                "Run {} was interrupted; `arkai resume {}` to continue it",
+                run_id, run_id
+            );
+        }
+    }
+
+    if rebuild_index {
+        let index = crate::core::RunIndex::open()?;
+        let count = index.rebuild().await?;
+        let message = format!("Indexed {} runs in {}", count, index.path().display());
+        if json {
+            eprintln!("{}", message);
+        } else {
+            println!("{}", message);
+        }
+    }
+
+    let runs = orchestrator.list_run_summaries(offset, limit).await?;
+    let mut stale = std::collections::HashSet::new();
+    for run in &runs {
+        if is_stale(run.id, &run.state).await? {
+            stale.insert(run.id);
+        }
+    }
+
+    if json {
+        let runs: Vec<_> = runs
+            .iter()
+            .map(|run| {
+                serde_json::json!({
+                    "id": run.id,
+                    "pipeline": run.pipeline,
+                    "state": run_state_label(&run.state),
+                    "stale": stale.contains(&run.id),
+                    "started_at": run.started_at,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&runs)?);
+        return Ok(());
+    }
 
     if runs.is_empty() {
         println!("No runs found");
@@ -436,24 +1210,28 @@ async fn list_runs(limit: usize) -> Result<()> {
     println!("{:<38} {:<20} {:<15}", "RUN ID", "PIPELINE", "STATE");
     println!("{}", "-".repeat(75));
 
-    for run in runs {
-        let state_str = match &run.state {
-            crate::domain::RunState::Running => "running".to_string(),
-            crate::domain::RunState::Completed => "completed".to_string(),
-            crate::domain::RunState::Failed { .. } => "failed".to_string(),
-            crate::domain::RunState::Paused => "paused".to_string(),
-            crate::domain::RunState::SafetyLimitReached { .. } => "safety-limit".to_string(),
+    for run in &runs {
+        let state = if stale.contains(&run.id) {
+            "running (stale)"
+        } else {
+            run_state_label(&run.state)
         };
-        println!("{:<38} {:<20} {:<15}", run.id, run.pipeline_name, state_str);
+        println!("{:<38} {:<20} {:<15}", run.id, run.pipeline, state);
+    }
+    if !stale.is_empty() {
+        println!("\nStale runs were left running by a process that died; `arkai runs --recover` marks them interrupted");
     }
 
     Ok(())
 }
 
-async fn collect_doctor_report() -> Result<serde_json::Value> {
+/// The `arkai doctor --json` report. v2 added `checks`, the `warn` status
+/// for failed optional checks, and `paths: null` when the config doesn't load.
+fn collect_doctor_report(
+    fabric: &FabricAdapter,
+    checks: &[doctor::Check],
+) -> Result<serde_json::Value> {
     let generated_at = chrono::Utc::now().to_rfc3339();
-    let config = crate::config::config()?;
-    let fabric = FabricAdapter::new();
     let diagnostics = fabric.binary_diagnostics();
 
     let mut issues = Vec::new();
@@ -465,20 +1243,28 @@ async fn collect_doctor_report() -> Result<serde_json::Value> {
         }));
     }
 
-    let status = if issues.is_empty() { "ok" } else { "fail" };
+    let status = if issues.is_empty() {
+        doctor::overall_status(checks)
+    } else {
+        "fail"
+    };
+    let paths = crate::config::config().ok().map(|config| {
+        serde_json::json!({
+            "home": config.home.display().to_string(),
+            "library": config.library.display().to_string(),
+            "config_file": config.config_file.as_ref().map(|path| path.display().to_string()),
+        })
+    });
 
     Ok(serde_json::json!({
-        "schema_version": "arkai-doctor-v1",
+        "schema_version": "arkai-doctor-v2",
         "generated_at": generated_at,
         "health": {
             "status": status,
             "issues": issues,
         },
-        "paths": {
-            "home": config.home.display().to_string(),
-            "library": config.library.display().to_string(),
-            "config_file": config.config_file.as_ref().map(|path| path.display().to_string()),
-        },
+        "checks": checks,
+        "paths": paths,
         "fabric": {
             "requested_binary": diagnostics.requested_binary,
             "selected_binary": diagnostics.selected_binary,
@@ -491,7 +1277,9 @@ async fn collect_doctor_report() -> Result<serde_json::Value> {
 }
 
 async fn run_doctor(json_output: bool) -> Result<()> {
-    let report = collect_doctor_report().await?;
+    let fabric = FabricAdapter::new();
+    let checks = doctor::run_checks(&fabric).await;
+    let report = collect_doctor_report(&fabric, &checks)?;
 
     if json_output {
         println!("{}", serde_json::to_string_pretty(&report)?);
@@ -526,16 +1314,20 @@ async fn run_doctor(json_output: bool) -> Result<()> {
         println!("Fabric error: {}", error);
     }
 
+    println!();
+    for check in &checks {
+        println!("{}", check);
+    }
+
     Ok(())
 }
 
 /// Resume a failed run
-async fn resume_run(run_id_str: &str) -> Result<()> {
-    let run_id =
-        Uuid::parse_str(run_id_str).with_context(|| format!("Invalid run ID: {}", run_id_str))?;
+async fn resume_run(run_id_str: &str, quiet: bool) -> Result<i32> {
+    let run_id = EventStore::resolve_run_id(run_id_str).await?;
 
     // First get the run to find out which pipeline and input
-    let orchestrator = Orchestrator::new();
+    let orchestrator = Orchestrator::new().with_cancellation(cancel_on_ctrl_c());
     let existing_run = orchestrator.get_run_status(run_id).await?;
 
     // Load the pipeline
@@ -548,36 +1340,47 @@ async fn resume_run(run_id_str: &str) -> Result<()> {
 
     // Print results
     match &run.state {
-        crate::domain::RunState::Completed => {
-            if let Some(last_step) = pipeline.steps.last() {
-                if let Some(artifact) = run.artifacts.get(&last_step.name) {
-                    println!("{}", artifact.content);
-                }
-            }
-            eprintln!("\n[Run {} resumed and completed successfully]", run.id);
+        crate::domain::RunState::Completed | crate::domain::RunState::PartiallyCompleted { .. } => {
+            let output = RunOutput {
+                to_clipboard: false,
+                also_print: false,
+                quiet,
+            };
+            emit_completed_run(&pipeline, &run, &output, "", true)?;
         }
         crate::domain::RunState::Failed { error } => {
             eprintln!("\n[Run {} failed again: {}]", run.id, error);
-            std::process::exit(1);
         }
+        crate::domain::RunState::SafetyLimitReached { limit } => {
+            eprintln!(
+                "\n[Run {} stopped: safety limit reached - {}]",
+                run.id, limit
+            );
+        }
+        crate::domain::RunState::Cancelled => {
+            eprintln!(
+                "\n[Run {} cancelled; continue with: arkai resume {}]",
+                run.id, run.id
+            );
+        }
+        _ if quiet => {}
         _ => {
             eprintln!("\n[Run {} in state: {:?}]", run.id, run.state);
         }
     }
 
-    Ok(())
+    Ok(exit_code::for_state(&run.state))
 }
 
-/// Start HTTP server (stub)
-async fn serve(address: &str) -> Result<()> {
-    anyhow::bail!(
-        "HTTP server mode not yet implemented. Would serve on {}",
-        address
-    )
+/// Load and validate a pipeline by name
+fn load_pipeline(name: &str) -> Result<Pipeline> {
+    let pipeline = read_pipeline(name)?;
+    pipeline.validate()?;
+    Ok(pipeline)
 }
 
-/// Load a pipeline by name
-fn load_pipeline(name: &str) -> Result<Pipeline> {
+/// Load a pipeline by name without validating it
+fn read_pipeline(name: &str) -> Result<Pipeline> {
     // Look in pipelines/ directory
     let pipeline_path = PathBuf::from("pipelines").join(format!("{}.yaml", name));
 
@@ -585,24 +1388,47 @@ fn load_pipeline(name: &str) -> Result<Pipeline> {
         // Try looking in the current directory
         let alt_path = PathBuf::from(format!("{}.yaml", name));
         if alt_path.exists() {
-            let pipeline = Pipeline::from_file(&alt_path)?;
-            pipeline.validate()?;
-            return Ok(pipeline);
+            return read_pipeline_file(&alt_path);
         }
 
-        anyhow::bail!(
+        return Err(exit_code::ExitError::pipeline_not_found(format!(
             "Pipeline '{}' not found. Looked for:\n  - {}\n  - {}",
             name,
             pipeline_path.display(),
             alt_path.display()
-        );
+        ))
+        .into());
     }
 
-    let pipeline = Pipeline::from_file(&pipeline_path)?;
+    read_pipeline_file(&pipeline_path)
+}
+
+/// Load and validate the pipeline at `path`
+fn load_pipeline_file(path: &Path) -> Result<Pipeline> {
+    let pipeline = read_pipeline_file(path)?;
     pipeline.validate()?;
     Ok(pipeline)
 }
 
+/// Load the pipeline at `path` without validating it
+fn read_pipeline_file(path: &Path) -> Result<Pipeline> {
+    // Config safety settings fill in whatever the pipeline leaves unset
+    let defaults = SafetyDefaults::from_config(crate::config::config()?);
+    Ok(Pipeline::from_file_with_defaults(path, &defaults)?)
+}
+
+/// Load a pipeline for `arkai run` and validate it once its timeout
+/// overrides are applied. With `--strict`, validation warnings are errors.
+fn load_run_pipeline(name: &str, timeouts: &TimeoutOverrides, strict: bool) -> Result<Pipeline> {
+    let pipeline = read_pipeline(name)?.with_timeout_overrides(timeouts);
+    if strict {
+        pipeline.validate_strict()?;
+    } else {
+        pipeline.validate()?;
+    }
+    Ok(pipeline)
+}
+
 // Fallback for atty if not available
 mod atty {
     pub enum Stream {
@@ -805,12 +1631,7 @@ fn run_cmd(cmd: &str, args: &[&str]) -> Result<String> {
 
 /// Detect content type from URL
 fn detect_content_type(url: &str) -> ContentType {
-    let url_lower = url.to_lowercase();
-    if url_lower.contains("youtube.com") || url_lower.contains("youtu.be") {
-        ContentType::YouTube
-    } else {
-        ContentType::Web
-    }
+    ContentType::detect(url)
 }
 
 /// Get YouTube video title using yt-dlp
@@ -886,6 +1707,14 @@ fn create_ingest_pipeline(content_type: ContentType) -> Pipeline {
                 input_from: InputSource::PipelineInput(PipelineInputMarker::PipelineInput),
                 retry_policy: RetryPolicy::default(),
                 timeout_seconds: Some(120),
+                continue_on_error: false,
+                artifact_name: None,
+                output_format: None,
+                output_schema: None,
+                clean_output: Default::default(),
+                enabled: true,
+                depends_on: Vec::new(),
+                compiled_schema: Default::default(),
             },
             Step {
                 name: "wisdom".to_string(),
@@ -896,6 +1725,14 @@ fn create_ingest_pipeline(content_type: ContentType) -> Pipeline {
                 },
                 retry_policy: RetryPolicy::default(),
                 timeout_seconds: Some(180),
+                continue_on_error: false,
+                artifact_name: None,
+                output_format: None,
+                output_schema: None,
+                clean_output: Default::default(),
+                enabled: true,
+                depends_on: Vec::new(),
+                compiled_schema: Default::default(),
             },
             Step {
                 name: "summary".to_string(),
@@ -906,8 +1743,18 @@ fn create_ingest_pipeline(content_type: ContentType) -> Pipeline {
                 },
                 retry_policy: RetryPolicy::default(),
                 timeout_seconds: Some(120),
+                continue_on_error: false,
+                artifact_name: None,
+                output_format: None,
+                output_schema: None,
+                clean_output: Default::default(),
+                enabled: true,
+                depends_on: Vec::new(),
+                compiled_schema: Default::default(),
             },
         ],
+        max_parallel: None,
+        evidence: None,
     }
 }
 
@@ -917,7 +1764,7 @@ async fn ingest_content(
     content_type: Option<IngestType>,
     tags: Option<String>,
     title: Option<String>,
-) -> Result<()> {
+) -> Result<i32> {
     // Detect or use specified content type
     let ct = content_type
         .map(ContentType::from)
@@ -925,7 +1772,9 @@ async fn ingest_content(
 
     // YouTube: use audio download + Whisper (fabric -y is broken due to PO token)
     if matches!(ct, ContentType::YouTube) {
-        return ingest_youtube(url, tags, title).await;
+        return ingest_youtube(url, tags, title)
+            .await
+            .map(|()| exit_code::SUCCESS);
     }
 
     eprintln!("📥 Ingesting {} content from: {}", ct, url);
@@ -992,15 +1841,15 @@ async fn ingest_content(
         crate::domain::RunState::Failed { error } => {
             eprintln!("\n❌ Ingestion failed: {}", error);
             eprintln!("   Run: {}", run.id);
-            std::process::exit(1);
+            return Ok(exit_code::RUN_FAILED);
         }
         _ => {
             eprintln!("\n⚠️ Ingestion ended in unexpected state: {:?}", run.state);
-            std::process::exit(1);
+            return Ok(exit_code::for_state(&run.state));
         }
     }
 
-    Ok(())
+    Ok(exit_code::SUCCESS)
 }
 
 /// List items in the library
@@ -1472,7 +2321,7 @@ async fn show_content(content_id: &str, full: bool) -> Result<()> {
 }
 
 /// Reprocess a library item
-async fn reprocess_content(content_id: &str) -> Result<()> {
+async fn reprocess_content(content_id: &str) -> Result<i32> {
     let catalog = Catalog::load().await?;
 
     // Find the item by ID prefix match
@@ -1551,6 +2400,29 @@ async fn show_config() -> Result<()> {
     Ok(())
 }
 
+/// Show the effective configuration as a flat key/value list
+async fn show_resolved_config(with_sources: bool) -> Result<()> {
+    let cfg = crate::config::config()?;
+    let entries = cfg.resolved_entries();
+    let width = entries.iter().map(|e| e.key.len()).max().unwrap_or(0);
+
+    for entry in entries {
+        if with_sources {
+            println!(
+                "{:<width$}  {}  ({})",
+                entry.key,
+                entry.value,
+                entry.source.as_str(),
+                width = width
+            );
+        } else {
+            println!("{:<width$}  {}", entry.key, entry.value, width = width);
+        }
+    }
+
+    Ok(())
+}
+
 /// Run a Fabric pattern directly
 async fn run_pattern(
     pattern_name: &str,
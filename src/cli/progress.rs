@@ -0,0 +1,68 @@
+//! Step spinner for `arkai run`.
+//!
+//! Shows the current step and how long it has been running, so a slow Fabric
+//! step doesn't look like a hang. Only drawn when stderr is a terminal.
+
+use std::io::IsTerminal;
+use std::time::Duration;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::core::{StepProgress, StepProgressKind};
+
+/// Spinner driven by the orchestrator's progress callback
+#[derive(Clone)]
+pub struct StepSpinner {
+    bar: ProgressBar,
+}
+
+impl StepSpinner {
+    /// A spinner on stderr, or `None` when it shouldn't be shown
+    pub fn for_stderr(quiet: bool) -> Option<Self> {
+        if quiet || !std::io::stderr().is_terminal() {
+            return None;
+        }
+
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(
+            ProgressStyle::with_template("{spinner} {msg} ({elapsed})")
+                .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+        );
+        bar.enable_steady_tick(Duration::from_millis(120));
+        Some(Self { bar })
+    }
+
+    /// Update the spinner for a step event
+    pub fn update(&self, progress: &StepProgress) {
+        let label = format!(
+            "[{}/{}] {}",
+            progress.index + 1,
+            progress.total,
+            progress.step
+        );
+        match progress.kind {
+            StepProgressKind::Started => {
+                self.bar.reset_elapsed();
+                self.bar.set_message(label);
+            }
+            StepProgressKind::Completed => {
+                self.bar
+                    .println(format!("✓ {} ({:.1?})", label, self.bar.elapsed()))
+            }
+            StepProgressKind::Failed => {
+                self.bar
+                    .println(format!("✗ {} ({:.1?})", label, self.bar.elapsed()))
+            }
+        }
+    }
+
+    /// Hide the spinner while `f` writes to the terminal
+    pub fn suspend(&self, f: impl FnOnce()) {
+        self.bar.suspend(f);
+    }
+
+    /// Remove the spinner line
+    pub fn finish(&self) {
+        self.bar.finish_and_clear();
+    }
+}
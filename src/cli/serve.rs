@@ -0,0 +1,491 @@
+//! HTTP API for `arkai serve`.
+//!
+//! Exposes runs over plain HTTP/1 with JSON bodies:
+//!
+//! - `POST /runs` with `{"pipeline": ..., "input": ...}` starts a run and
+//!   returns `{"id": ...}` as soon as it has started (202)
+//! - `GET /runs/:id` returns the run reconstructed from its event log
+//! - `GET /runs?limit=&offset=` lists recent runs, most recent first
+//! - `POST /runs/:id/resume` resumes a run in the background (202)
+//! - `GET /healthz` checks that Fabric is reachable (200 or 503) and
+//!   reports the Fabric rate limiter's throttle state (`null` if unlimited)
+//!
+//! Runs execute in background tasks on one shared [`Orchestrator`], so
+//! requests don't wait for pipelines and runs share the Fabric rate limit
+//! and circuit breaker. Each run's event log is written by one run at a
+//! time: the server refuses (409) to resume a run it is still executing,
+//! and the run lock refuses runs held by another process.
+//!
+//! Requests aren't authenticated, so pipelines are only loaded by plain name
+//! from `pipelines/`. The default `:9000` listens on all interfaces; bind
+//! `127.0.0.1:<port>` to keep the server local. To keep web pages from
+//! driving it through the browser, a `Host` naming anything but the bound
+//! address (DNS rebinding) is refused, as is a `POST` that isn't
+//! `application/json` (which browsers won't send cross-site without a
+//! preflight). Bodies are capped at [`MAX_BODY_BYTES`].
+//!
+//! On Ctrl+C the server stops accepting requests and cancels the runs it is
+//! executing, waiting for each to record `RunCancelled` so it can be resumed.
+
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use anyhow::{Context, Result};
+use http_body::Limited;
+use hyper::header::{CONTENT_TYPE, HOST};
+use hyper::http::uri::Authority;
+use hyper::server::conn::AddrIncoming;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde::Deserialize;
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use uuid::Uuid;
+
+use crate::adapters::{Adapter, FabricAdapter};
+use crate::core::{EventStore, Orchestrator, Pipeline};
+
+/// Runs listed by `GET /runs` without a `limit`
+const DEFAULT_LIST_LIMIT: usize = 10;
+
+/// Largest request body accepted: the default 10MB input limit plus room
+/// for JSON escaping
+pub const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// State shared by all requests
+struct ServerState {
+    orchestrator: Orchestrator,
+    /// Address the server is bound to, for checking `Host`
+    addr: SocketAddr,
+    /// Runs this server is executing
+    active: Mutex<HashSet<Uuid>>,
+    /// Background run and resume tasks, awaited on shutdown
+    tasks: TaskTracker,
+}
+
+impl ServerState {
+    /// Mark `run_id` as executing here; false if it already is
+    fn claim(&self, run_id: Uuid) -> bool {
+        self.active.lock().unwrap().insert(run_id)
+    }
+
+    fn release(&self, run_id: Uuid) {
+        self.active.lock().unwrap().remove(&run_id);
+    }
+}
+
+/// `POST /runs` body
+#[derive(Debug, Deserialize)]
+struct StartRun {
+    pipeline: String,
+    input: String,
+}
+
+/// Parse `--address`: `host:port`, or `:port` for all interfaces
+pub fn parse_address(address: &str) -> Result<SocketAddr> {
+    let address = match address.strip_prefix(':') {
+        Some(port) => format!("0.0.0.0:{}", port),
+        None => address.to_string(),
+    };
+    address
+        .to_socket_addrs()
+        .with_context(|| format!("Invalid address '{}': expected host:port", address))?
+        .next()
+        .with_context(|| format!("Address '{}' did not resolve", address))
+}
+
+/// Serve the API on `address` until Ctrl+C, then cancel in-flight runs
+pub async fn execute_serve(address: &str) -> Result<()> {
+    let addr = parse_address(address)?;
+    let incoming = AddrIncoming::bind(&addr).with_context(|| format!("Failed to bind {}", addr))?;
+    let cancel = CancellationToken::new();
+    let state = Arc::new(ServerState {
+        orchestrator: Orchestrator::new().with_cancellation(cancel.clone()),
+        addr: incoming.local_addr(),
+        active: Mutex::new(HashSet::new()),
+        tasks: TaskTracker::new(),
+    });
+    // Runs a previous server was executing when it died can be resumed
+    for run_id in state.orchestrator.recover_interrupted_runs().await? {
+        eprintln!("Run {} was interrupted; resume it to continue", run_id);
+    }
+    eprintln!("Listening on http://{}", state.addr);
+
+    let service_state = state.clone();
+    let make_service = make_service_fn(move |_| {
+        let state = service_state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(handle(&state, request).await) }
+            }))
+        }
+    });
+    let served = Server::builder(incoming)
+        .serve(make_service)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await
+        .context("HTTP server failed");
+
+    // Runs record RunCancelled rather than being abandoned mid-step
+    let running = state.active.lock().unwrap().len();
+    if running > 0 {
+        eprintln!("Cancelling {} in-flight run(s)...", running);
+    }
+    cancel.cancel();
+    state.tasks.close();
+    state.tasks.wait().await;
+    served
+}
+
+/// Route one request
+async fn handle(state: &Arc<ServerState>, request: Request<Body>) -> Response<Body> {
+    if let Err(error) = check_request(state.addr, &request) {
+        return json_response(error.status, &serde_json::json!({ "error": error.message }));
+    }
+
+    let path: Vec<_> = request
+        .uri()
+        .path()
+        .trim_matches('/')
+        .split('/')
+        .map(str::to_string)
+        .collect();
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+
+    let result = match (request.method(), path.as_slice()) {
+        (&Method::GET, ["healthz"]) => healthz(state).await,
+        (&Method::GET, ["runs"]) => list_runs(state, query_params(&request)).await,
+        (&Method::POST, ["runs"]) => start_run(state, request).await,
+        (&Method::GET, ["runs", id]) => get_run(state, id).await,
+        (&Method::POST, ["runs", id, "resume"]) => resume_run(state, id).await,
+        _ => Err(ApiError::new(StatusCode::NOT_FOUND, "Not found")),
+    };
+
+    match result {
+        Ok((status, body)) => json_response(status, &body),
+        Err(error) => json_response(error.status, &serde_json::json!({ "error": error.message })),
+    }
+}
+
+/// An error response
+#[derive(Debug)]
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, message: impl ToString) -> Self {
+        Self {
+            status,
+            message: message.to_string(),
+        }
+    }
+
+    /// An internal error, with its causes
+    fn internal(error: anyhow::Error) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, format!("{:#}", error))
+    }
+}
+
+type ApiResult = std::result::Result<(StatusCode, serde_json::Value), ApiError>;
+
+/// Refuse requests a web page could have made on the caller's behalf: a
+/// `Host` other than the bound address, or a `POST` that isn't JSON
+fn check_request(addr: SocketAddr, request: &Request<Body>) -> std::result::Result<(), ApiError> {
+    let host = request
+        .headers()
+        .get(HOST)
+        .and_then(|host| host.to_str().ok());
+    if !host.is_some_and(|host| host_matches(host, addr)) {
+        return Err(ApiError::new(
+            StatusCode::MISDIRECTED_REQUEST,
+            format!("Host must be {}", addr),
+        ));
+    }
+
+    if request.method() == Method::POST {
+        let is_json = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"));
+        if !is_json {
+            return Err(ApiError::new(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "POST requests must have Content-Type: application/json",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a `Host` header names the address the server is bound to:
+/// its IP (any IP when bound to all interfaces) or, on loopback,
+/// `localhost`, with the bound port
+fn host_matches(host: &str, addr: SocketAddr) -> bool {
+    let Ok(authority) = host.parse::<Authority>() else {
+        return false;
+    };
+    if authority.port_u16().unwrap_or(80) != addr.port() {
+        return false;
+    }
+    let name = authority
+        .host()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    match name.parse::<IpAddr>() {
+        Ok(ip) => addr.ip().is_unspecified() || ip == addr.ip(),
+        Err(_) => {
+            name.eq_ignore_ascii_case("localhost")
+                && (addr.ip().is_loopback() || addr.ip().is_unspecified())
+        }
+    }
+}
+
+fn json_response(status: StatusCode, body: &serde_json::Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("static response parts are valid")
+}
+
+fn query_params(request: &Request<Body>) -> HashMap<String, String> {
+    request
+        .uri()
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+async fn healthz(state: &ServerState) -> ApiResult {
+    let throttle = state.orchestrator.throttle_state();
+    match FabricAdapter::new().health_check().await {
+        Ok(()) => Ok((
+            StatusCode::OK,
+            serde_json::json!({ "status": "ok", "fabric_rate_limit": throttle }),
+        )),
+        Err(e) => Ok((
+            StatusCode::SERVICE_UNAVAILABLE,
+            serde_json::json!({
+                "status": "unavailable",
+                "error": format!("{:#}", e),
+                "fabric_rate_limit": throttle,
+            }),
+        )),
+    }
+}
+
+async fn list_runs(state: &ServerState, params: HashMap<String, String>) -> ApiResult {
+    let number = |name: &str, default: usize| match params.get(name) {
+        Some(value) => value.parse().map_err(|_| {
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                format!("'{}' must be a number", name),
+            )
+        }),
+        None => Ok(default),
+    };
+    let limit = number("limit", DEFAULT_LIST_LIMIT)?;
+    let offset = number("offset", 0)?;
+
+    let runs = state
+        .orchestrator
+        .list_run_summaries(offset, limit)
+        .await
+        .map_err(|e| ApiError::internal(e.into()))?;
+    let runs = serde_json::to_value(runs).map_err(|e| ApiError::internal(e.into()))?;
+    Ok((StatusCode::OK, runs))
+}
+
+async fn get_run(state: &ServerState, id: &str) -> ApiResult {
+    let run_id = resolve_run_id(id).await?;
+    let run = state
+        .orchestrator
+        .get_run_status(run_id)
+        .await
+        .map_err(|e| ApiError::new(StatusCode::NOT_FOUND, e))?;
+    let run = serde_json::to_value(run).map_err(|e| ApiError::internal(e.into()))?;
+    Ok((StatusCode::OK, run))
+}
+
+/// Whether `name` names a file directly inside `pipelines/`
+fn is_plain_pipeline_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && !name.contains(['/', '\\'])
+        && !name.contains("..")
+}
+
+/// A pipeline from `pipelines/` in the directory the server runs in. Names
+/// come from request bodies, so any that could leave that directory are
+/// refused.
+fn load_pipeline(name: &str) -> std::result::Result<Pipeline, ApiError> {
+    if !is_plain_pipeline_name(name) {
+        return Err(ApiError::new(
+            StatusCode::BAD_REQUEST,
+            format!("Invalid pipeline name '{}'", name),
+        ));
+    }
+    let path = Path::new("pipelines").join(format!("{}.yaml", name));
+    if !path.is_file() {
+        return Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            format!("Pipeline '{}' not found in pipelines/", name),
+        ));
+    }
+    super::load_pipeline_file(&path)
+        .map_err(|e| ApiError::new(StatusCode::BAD_REQUEST, format!("{:#}", e)))
+}
+
+async fn resolve_run_id(id: &str) -> std::result::Result<Uuid, ApiError> {
+    EventStore::resolve_run_id(id)
+        .await
+        .map_err(|e| ApiError::new(StatusCode::NOT_FOUND, format!("{:#}", e)))
+}
+
+/// Start a run in the background, answering once it has an ID
+async fn start_run(state: &Arc<ServerState>, request: Request<Body>) -> ApiResult {
+    let body = hyper::body::to_bytes(Limited::new(request.into_body(), MAX_BODY_BYTES))
+        .await
+        .map_err(|e| match e.downcast_ref::<http_body::LengthLimitError>() {
+            Some(_) => ApiError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                format!("Request body is over {} bytes", MAX_BODY_BYTES),
+            ),
+            None => ApiError::new(StatusCode::BAD_REQUEST, e),
+        })?;
+    let StartRun { pipeline, input } = serde_json::from_slice(&body).map_err(|e| {
+        ApiError::new(
+            StatusCode::BAD_REQUEST,
+            format!("Expected {{\"pipeline\", \"input\"}}: {}", e),
+        )
+    })?;
+    let pipeline = load_pipeline(&pipeline)?;
+
+    // The run's first event carries its ID
+    let (started_tx, started_rx) = oneshot::channel();
+    let started_tx = Mutex::new(Some(started_tx));
+    let claimed = Arc::new(OnceLock::new());
+    let task_state = state.clone();
+    let run = state.tasks.spawn(async move {
+        let (listener_state, listener_claimed) = (task_state.clone(), claimed.clone());
+        let result = task_state
+            .orchestrator
+            .run_pipeline_with_listener(&pipeline, input, move |event| {
+                if listener_claimed.set(event.run_id).is_ok() {
+                    listener_state.claim(event.run_id);
+                    if let Some(tx) = started_tx.lock().unwrap().take() {
+                        let _ = tx.send(event.run_id);
+                    }
+                }
+            })
+            .await;
+        if let Some(run_id) = claimed.get() {
+            task_state.release(*run_id);
+        }
+        result
+    });
+
+    match started_rx.await {
+        Ok(run_id) => Ok((StatusCode::ACCEPTED, serde_json::json!({ "id": run_id }))),
+        // Never started: report why
+        Err(_) => match run.await {
+            Ok(Err(e)) => Err(ApiError::internal(e.into())),
+            Ok(Ok(run)) => Ok((StatusCode::ACCEPTED, serde_json::json!({ "id": run.id }))),
+            Err(e) => Err(ApiError::internal(e.into())),
+        },
+    }
+}
+
+/// Resume a run in the background
+async fn resume_run(state: &Arc<ServerState>, id: &str) -> ApiResult {
+    let run_id = resolve_run_id(id).await?;
+    let existing = state
+        .orchestrator
+        .get_run_status(run_id)
+        .await
+        .map_err(|e| ApiError::new(StatusCode::NOT_FOUND, e))?;
+    let pipeline = load_pipeline(&existing.pipeline_name)?;
+
+    if !state.claim(run_id) {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            format!("Run {} is already executing", run_id),
+        ));
+    }
+    let task_state = state.clone();
+    state.tasks.spawn(async move {
+        if let Err(e) = task_state
+            .orchestrator
+            .resume_run(run_id, &pipeline, existing.input)
+            .await
+        {
+            tracing::error!(%run_id, error = %e, "Resume failed");
+        }
+        task_state.release(run_id);
+    });
+
+    Ok((StatusCode::ACCEPTED, serde_json::json!({ "id": run_id })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(
+            parse_address(":9000").unwrap(),
+            "0.0.0.0:9000".parse().unwrap()
+        );
+        assert_eq!(
+            parse_address("127.0.0.1:8080").unwrap(),
+            "127.0.0.1:8080".parse().unwrap()
+        );
+        assert!(parse_address("localhost:8080").is_ok());
+        assert!(parse_address("9000").is_err());
+        assert!(parse_address(":http").is_err());
+    }
+
+    #[test]
+    fn test_host_must_name_the_bound_address() {
+        let loopback: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+        assert!(host_matches("127.0.0.1:8080", loopback));
+        assert!(host_matches("localhost:8080", loopback));
+        assert!(!host_matches("127.0.0.1:9090", loopback));
+        assert!(!host_matches("127.0.0.1", loopback));
+        assert!(!host_matches("evil.example:8080", loopback));
+        assert!(!host_matches("10.0.0.5:8080", loopback));
+
+        let ipv6: SocketAddr = "[::1]:8080".parse().unwrap();
+        assert!(host_matches("[::1]:8080", ipv6));
+        assert!(host_matches("localhost:8080", ipv6));
+
+        // All interfaces: any IP, but never a DNS name that could rebind
+        let any: SocketAddr = "0.0.0.0:8080".parse().unwrap();
+        assert!(host_matches("192.168.1.20:8080", any));
+        assert!(!host_matches("evil.example:8080", any));
+    }
+
+    #[test]
+    fn test_pipeline_names_stay_in_pipelines_dir() {
+        assert!(is_plain_pipeline_name("echo"));
+        assert!(is_plain_pipeline_name("summarize-v2"));
+        for name in ["", "../../tmp/x", "a/b", "a\\b", ".hidden", "x..y", ".."] {
+            assert!(!is_plain_pipeline_name(name), "{}", name);
+        }
+    }
+}
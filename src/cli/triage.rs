@@ -4,7 +4,7 @@
 //! capped at 5 per section, deterministic output, shame-free language.
 
 use anyhow::{Context, Result};
-use chrono::{Local, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Utc};
 use serde_json::json;
 
 use crate::store::queries::{self, Item};
@@ -252,27 +252,36 @@ pub async fn execute_snooze(item_id: String, until: String) -> Result<()> {
 /// - RFC3339 datetime: "2026-03-25T09:00:00+00:00"
 /// - ISO date: "2026-03-25" (interpreted as start-of-day in local timezone, stored as UTC)
 pub(crate) fn parse_snooze_until(until: &str) -> Result<String> {
+    match parse_date_or_datetime(until)? {
+        Some(dt) => Ok(dt.to_rfc3339()),
+        None => anyhow::bail!(
+            "Cannot parse snooze date: '{}'. Use YYYY-MM-DD or RFC3339 format.",
+            until
+        ),
+    }
+}
+
+/// Parse an RFC3339 datetime or an ISO date (local start-of-day) as UTC.
+/// Returns `None` if `s` is neither.
+pub(crate) fn parse_date_or_datetime(s: &str) -> Result<Option<DateTime<Utc>>> {
     // Try RFC3339 datetime first
-    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(until) {
-        return Ok(dt.with_timezone(&Utc).to_rfc3339());
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Ok(Some(dt.with_timezone(&Utc)));
     }
 
     // Try ISO date — interpret in local timezone, convert to UTC
-    if let Ok(date) = NaiveDate::parse_from_str(until, "%Y-%m-%d") {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
         let local_midnight = date
             .and_hms_opt(0, 0, 0)
             .ok_or_else(|| anyhow::anyhow!("Invalid date"))?;
         let local_dt = Local
             .from_local_datetime(&local_midnight)
             .single()
-            .ok_or_else(|| anyhow::anyhow!("Ambiguous local time for date: {}", until))?;
-        return Ok(local_dt.with_timezone(&Utc).to_rfc3339());
+            .ok_or_else(|| anyhow::anyhow!("Ambiguous local time for date: {}", s))?;
+        return Ok(Some(local_dt.with_timezone(&Utc)));
     }
 
-    anyhow::bail!(
-        "Cannot parse snooze date: '{}'. Use YYYY-MM-DD or RFC3339 format.",
-        until
-    )
+    Ok(None)
 }
 
 // ─────────────────────────────────────────────────────────────────
@@ -4,6 +4,9 @@
 //! - `arkai voice status` - Show queue status
 //! - `arkai voice scan` - Scan and queue files once
 //! - `arkai voice watch` - Watch for new files continuously
+//! - `arkai voice export` - Write transcripts into an Obsidian vault
+//! - `arkai voice stats` - Summarize capture volume
+//! - `arkai voice install-service` - Run watch + process at login (launchd/systemd)
 
 use std::sync::Arc;
 
@@ -11,7 +14,15 @@ use anyhow::{Context, Result};
 use clap::Subcommand;
 
 use crate::adapters::{ClawdbotClient, TelegramClient};
-use crate::ingest::{transcribe, VoiceMemoWatcher, VoiceQueue, WatcherConfig};
+use crate::core::Orchestrator;
+use crate::ingest::service;
+use crate::ingest::{
+    check_silence, deliver_all, export_to_vault, exportable, find_duplicate, normalized_text_hash,
+    record_outcome, summarize_item, BatchTranscriber, DeliveryStatus, Destination, DuplicatePolicy,
+    Heartbeat, NoteFormat, Notifications, NotifyEvent, QueueItem, RequirePolicy, TranscribeOptions,
+    TranscriptCache, TranscriptResult, VoiceMemoWatcher, VoiceQueue, WatcherConfig,
+    WatcherLiveness, WhisperBinary, DEFAULT_SUMMARY_PATTERN,
+};
 
 /// Voice capture subcommands
 #[derive(Subcommand, Debug)]
@@ -35,6 +46,10 @@ pub enum VoiceCommands {
         /// Path to watch (defaults to Voice Memos directory)
         #[arg(short, long)]
         path: Option<String>,
+
+        /// Show a desktop notification for each new memo (or `notifications.enabled`)
+        #[arg(long)]
+        notify: bool,
     },
 
     /// Process pending voice memos (send to Claudia via Telegram or Clawdbot)
@@ -43,11 +58,16 @@ pub enum VoiceCommands {
         #[arg(long)]
         once: bool,
 
-        /// Route: "telegram" (send raw audio) or "clawdbot" (transcribe + send text)
+        /// Route: "telegram" (send raw audio), "clawdbot" (transcribe + send text),
+        /// or "both" (transcript to clawdbot and raw audio to telegram)
         #[arg(long, default_value = "telegram")]
         route: String,
 
-        /// Whisper model for transcription (clawdbot route only)
+        /// With --route both: mark done when all destinations succeed, or any
+        #[arg(long, value_enum, default_value = "all")]
+        require: RequirePolicy,
+
+        /// Whisper model for transcription (clawdbot and both routes)
         #[arg(long, default_value = "base")]
         model: String,
 
@@ -70,46 +90,173 @@ pub enum VoiceCommands {
         /// Show what would be processed without actually processing
         #[arg(long)]
         dry_run: bool,
+
+        /// Always re-run Whisper instead of using cached transcripts
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Show a desktop notification when a memo is delivered or fails
+        #[arg(long)]
+        notify: bool,
     },
 
     /// List all items in the queue
     List {
-        /// Filter by status (pending, processing, done, failed)
+        /// Filter by status (pending, processing, done, failed, skipped)
         #[arg(short, long)]
         status: Option<String>,
 
         /// Maximum number of items to show
         #[arg(short, long, default_value = "20")]
         limit: usize,
+
+        /// Show per-destination delivery status and errors under each item
+        #[arg(short, long)]
+        verbose: bool,
     },
 
+    /// Summarize capture volume: items, audio duration, size, days without captures
+    Stats {
+        /// Only memos recorded in this window: 30d, 2w, 12h, YYYY-MM-DD or RFC3339
+        #[arg(long)]
+        since: Option<String>,
+    },
+
+    /// Write transcripts of done items into an Obsidian vault as notes
+    Export {
+        /// Vault (or folder) to write notes into
+        #[arg(long)]
+        vault: std::path::PathBuf,
+
+        /// Only memos recorded at or after this time (YYYY-MM-DD or RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Note template file (overrides `voice_export.template`)
+        #[arg(long)]
+        template: Option<std::path::PathBuf>,
+
+        /// File name pattern, e.g. "{{date}} {{title}}" (overrides `voice_export.filename`)
+        #[arg(long)]
+        filename: Option<String>,
+
+        /// Folder inside the vault (overrides `voice_export.subfolder`)
+        #[arg(long)]
+        subfolder: Option<std::path::PathBuf>,
+
+        /// Summarize transcripts that have no summary or note yet and include it in the note
+        #[arg(long)]
+        summarize: bool,
+
+        /// Fabric pattern used by --summarize (overrides `voice_export.summary_pattern`)
+        #[arg(long)]
+        summary_pattern: Option<String>,
+
+        /// Rewrite notes already in the vault, re-summarizing their memos with --summarize
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Install login services running `voice watch` and `voice process` (launchd or systemd)
+    InstallService {
+        /// Route passed to `voice process`
+        #[arg(long, default_value = "telegram")]
+        route: String,
+
+        /// Print the service files instead of installing them
+        #[arg(long)]
+        print: bool,
+    },
+
+    /// Stop and remove the services written by install-service
+    UninstallService,
+
     /// Show configuration
     Config,
+
+    /// Delete all cached transcripts
+    ClearCache,
 }
 
 /// Execute a voice command
-pub async fn execute(command: VoiceCommands) -> Result<()> {
+pub async fn execute(command: VoiceCommands, json: bool) -> Result<()> {
     match command {
         VoiceCommands::Status => execute_status().await,
         VoiceCommands::Scan { path } => execute_scan(path).await,
-        VoiceCommands::Watch { once, path } => execute_watch(once, path).await,
+        VoiceCommands::Watch { once, path, notify } => execute_watch(once, path, notify).await,
         VoiceCommands::Process {
             once,
             route,
+            require,
             model,
             bot_token,
             chat_id,
             limit,
             max_hours,
             dry_run,
+            no_cache,
+            notify,
         } => {
+            let options = TranscribeOptions {
+                use_cache: !no_cache,
+                ..TranscribeOptions::new(model)
+            };
             execute_process(
-                once, &route, &model, bot_token, chat_id, limit, max_hours, dry_run,
+                once, &route, require, &options, bot_token, chat_id, limit, max_hours, dry_run,
+                notify,
+            )
+            .await
+        }
+        VoiceCommands::List {
+            status,
+            limit,
+            verbose,
+        } => execute_list(status, limit, verbose, json).await,
+        VoiceCommands::Stats { since } => execute_stats(since.as_deref()).await,
+        VoiceCommands::Export {
+            vault,
+            since,
+            template,
+            filename,
+            subfolder,
+            summarize,
+            summary_pattern,
+            force,
+        } => {
+            let export_config = &crate::config::config()?.voice_export;
+            let mut format = NoteFormat::from_config(export_config)?;
+            if let Some(path) = template {
+                format.template = Some(
+                    std::fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?,
+                );
+            }
+            if let Some(filename) = filename {
+                format.filename = filename;
+            }
+            if let Some(subfolder) = subfolder {
+                format.subfolder = Some(subfolder);
+            }
+            let summary_pattern = summarize.then(|| {
+                summary_pattern
+                    .or_else(|| export_config.summary_pattern.clone())
+                    .unwrap_or_else(|| DEFAULT_SUMMARY_PATTERN.to_string())
+            });
+            execute_export(
+                &vault,
+                since.as_deref(),
+                &format,
+                summary_pattern.as_deref(),
+                force,
             )
             .await
         }
-        VoiceCommands::List { status, limit } => execute_list(status, limit).await,
+        VoiceCommands::InstallService { route, print } => {
+            execute_install_service(&route, print).await
+        }
+        VoiceCommands::UninstallService => execute_uninstall_service().await,
         VoiceCommands::Config => execute_config().await,
+        VoiceCommands::ClearCache => execute_clear_cache().await,
     }
 }
 
@@ -132,6 +279,7 @@ async fn execute_status() -> Result<()> {
     println!("  Processing: {}", status.processing);
     println!("  Done:       {}", status.done);
     println!("  Failed:     {}", status.failed);
+    println!("  Skipped:    {}", status.skipped);
     println!("  Total:      {}", status.total());
     println!();
 
@@ -143,6 +291,7 @@ async fn execute_status() -> Result<()> {
                 crate::domain::VoiceQueueStatus::Processing => "PROC",
                 crate::domain::VoiceQueueStatus::Done => "DONE",
                 crate::domain::VoiceQueueStatus::Failed => "FAIL",
+                crate::domain::VoiceQueueStatus::Skipped => "SKIP",
             };
             println!(
                 "  [{}] {} ({})",
@@ -163,6 +312,12 @@ async fn execute_status() -> Result<()> {
         println!("✓ Watch path exists");
     }
 
+    let last_seen = Heartbeat::new(Heartbeat::default_path()?).last_seen().await;
+    match WatcherLiveness::from_last_seen(last_seen, chrono::Utc::now()) {
+        liveness @ WatcherLiveness::Alive { .. } => println!("✓ {}", liveness),
+        liveness => println!("⚠️  {}", liveness),
+    }
+
     Ok(())
 }
 
@@ -192,7 +347,14 @@ async fn execute_scan(path: Option<String>) -> Result<()> {
     if result.errors > 0 {
         println!("  Errors:              {}", result.errors);
     }
+    if result.missing_tools > 0 {
+        println!("  Need ffmpeg (.qta):  {}", result.missing_tools);
+    }
     println!("  Total scanned:       {}", result.total_scanned());
+    if let Some(diagnostic) = &result.media_tools_missing {
+        println!();
+        println!("⚠️  {}", diagnostic);
+    }
 
     if result.new_files > 0 {
         println!();
@@ -203,11 +365,12 @@ async fn execute_scan(path: Option<String>) -> Result<()> {
 }
 
 /// Watch for new files
-async fn execute_watch(once: bool, path: Option<String>) -> Result<()> {
+async fn execute_watch(once: bool, path: Option<String>, notify: bool) -> Result<()> {
     let mut config = WatcherConfig::default();
     if let Some(p) = path {
         config.watch_path = p.into();
     }
+    config.heartbeat_path = Some(Heartbeat::default_path()?);
 
     let watcher = VoiceMemoWatcher::with_config(config.clone());
     let queue = Arc::new(VoiceQueue::open_default().await?);
@@ -217,6 +380,9 @@ async fn execute_watch(once: bool, path: Option<String>) -> Result<()> {
         println!("📂 Scanning once: {}", config.watch_path.display());
 
         let result = watcher.scan_once(&queue).await?;
+        if let Some(diagnostic) = &result.media_tools_missing {
+            println!("⚠️  {}", diagnostic);
+        }
 
         if result.new_files > 0 {
             println!("✅ Queued {} new file(s)", result.new_files);
@@ -234,12 +400,16 @@ async fn execute_watch(once: bool, path: Option<String>) -> Result<()> {
 
     // Initial scan
     let initial = watcher.scan_once(&queue).await?;
+    if let Some(diagnostic) = &initial.media_tools_missing {
+        println!("⚠️  {}", diagnostic);
+    }
     if initial.new_files > 0 {
         println!("📥 Initial scan: {} new file(s) queued", initial.new_files);
     }
 
     // Start watching
     let (mut event_rx, handle) = watcher.watch(queue).await?;
+    let notifications = Notifications::system(&crate::config::config()?.notifications, notify);
 
     // Set up Ctrl+C handler
     let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel::<()>();
@@ -252,11 +422,13 @@ async fn execute_watch(once: bool, path: Option<String>) -> Result<()> {
     loop {
         tokio::select! {
             Some(event) = event_rx.recv() => {
-                println!(
-                    "📥 New audio: {} ({})",
-                    event.path.file_name().unwrap_or_default().to_string_lossy(),
-                    &event.hash[..8]
-                );
+                let file_name = event.path.file_name().unwrap_or_default().to_string_lossy();
+                println!("📥 New audio: {} ({})", file_name, &event.hash[..8]);
+                notifications
+                    .send(NotifyEvent::Queued {
+                        file_name: &file_name,
+                    })
+                    .await;
             }
             _ = &mut stop_rx => {
                 println!();
@@ -270,31 +442,51 @@ async fn execute_watch(once: bool, path: Option<String>) -> Result<()> {
     Ok(())
 }
 
-/// Safety caps for processing
+/// Safety caps and notifications for processing
 struct ProcessCaps {
     limit: Option<u32>,
     max_hours: Option<f32>,
     dry_run: bool,
+    notifications: Notifications,
+}
+
+impl ProcessCaps {
+    /// How many of `pending` items the next batch can reach: one with
+    /// `--once`, and no more than `--limit` has left after `processed`
+    fn batch_size(&self, once: bool, pending: usize, processed: u32) -> usize {
+        let size = if once { 1 } else { pending };
+        match self.limit {
+            Some(limit) => size.min(limit.saturating_sub(processed) as usize),
+            None => size,
+        }
+    }
 }
 
 /// Process pending voice memos and send to Claudia
 async fn execute_process(
     once: bool,
     route: &str,
-    model: &str,
+    require: RequirePolicy,
+    options: &TranscribeOptions,
     bot_token: Option<String>,
     chat_id: Option<String>,
     limit: Option<u32>,
     max_hours: Option<f32>,
     dry_run: bool,
+    notify: bool,
 ) -> Result<()> {
     let queue = VoiceQueue::open_default().await?;
     let caps = ProcessCaps {
         limit,
         max_hours,
         dry_run,
+        notifications: Notifications::system(&crate::config::config()?.notifications, notify),
     };
 
+    crate::config::config()?
+        .normalize_target
+        .check_route(route)?;
+
     // Handle dry-run mode
     if dry_run {
         return execute_dry_run(&queue, &caps).await;
@@ -303,9 +495,15 @@ async fn execute_process(
     match route {
         "telegram" => execute_process_telegram(once, bot_token, chat_id, &queue, &caps).await,
         "clawdbot" => {
-            execute_process_clawdbot(once, model, chat_id.as_deref(), &queue, &caps).await
+            execute_process_clawdbot(once, options, chat_id.as_deref(), &queue, &caps).await
+        }
+        "both" => {
+            execute_process_both(once, require, options, bot_token, chat_id, &queue, &caps).await
         }
-        _ => anyhow::bail!("Unknown route: {}. Use 'telegram' or 'clawdbot'", route),
+        _ => anyhow::bail!(
+            "Unknown route: {}. Use 'telegram', 'clawdbot' or 'both'",
+            route
+        ),
     }
 }
 
@@ -421,6 +619,131 @@ async fn execute_dry_run(queue: &VoiceQueue, caps: &ProcessCaps) -> Result<()> {
     Ok(())
 }
 
+/// Show capture volume
+async fn execute_stats(since: Option<&str>) -> Result<()> {
+    let since = since.map(parse_since).transpose()?;
+    let queue = VoiceQueue::open_default().await?;
+    let stats = queue
+        .stats(since)
+        .await
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    println!();
+    match since {
+        Some(since) => println!("Voice Capture Stats (since {})", since.format("%Y-%m-%d")),
+        None => println!("Voice Capture Stats"),
+    }
+    println!("══════════════════════════════════════════════════════════════");
+    println!();
+    println!("  Items:      {}", stats.total());
+    println!(
+        "  Audio:      {:.1} h",
+        stats.total_duration_seconds / 3600.0
+    );
+    println!("  Size:       {}", format_size(stats.total_bytes));
+    println!(
+        "  Per day:    {:.1} over {} days",
+        stats.average_per_day(),
+        stats.days
+    );
+    println!();
+    println!("  Pending:    {}", stats.by_status.pending);
+    println!("  Processing: {}", stats.by_status.processing);
+    println!("  Done:       {}", stats.by_status.done);
+    println!("  Failed:     {}", stats.by_status.failed);
+    println!("  Skipped:    {}", stats.by_status.skipped);
+    println!();
+
+    if !stats.empty_days.is_empty() {
+        println!(
+            "Days without captures ({} of {}):",
+            stats.empty_days.len(),
+            stats.days
+        );
+        for day in &stats.empty_days {
+            println!("  {}", day);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// The watch and process services for the running binary
+fn voice_services(route: &str) -> Result<Vec<service::ServiceSpec>> {
+    let binary = std::env::current_exe().context("Failed to locate the arkai binary")?;
+    let log_dir = crate::config::config()?.home.join("logs");
+    let env = service::service_env(|key| std::env::var(key).ok());
+    Ok(service::voice_services(&binary, route, &log_dir, env))
+}
+
+/// Write and load login services for watch + process
+async fn execute_install_service(route: &str, print: bool) -> Result<()> {
+    if !matches!(route, "telegram" | "clawdbot" | "both") {
+        anyhow::bail!(
+            "Unknown route: {}. Use 'telegram', 'clawdbot' or 'both'",
+            route
+        );
+    }
+    let platform = service::ServicePlatform::current();
+    let home = dirs::home_dir().context("Cannot determine home directory")?;
+    let services = voice_services(route)?;
+
+    if print {
+        for spec in &services {
+            println!("# {}", spec.install_path(platform, &home).display());
+            println!("{}", spec.render(platform));
+        }
+        return Ok(());
+    }
+
+    for path in service::install(&services, platform, &home).await? {
+        println!("✅ Installed {}", path.display());
+    }
+    println!(
+        "   Logs: {}",
+        crate::config::config()?.home.join("logs").display()
+    );
+    Ok(())
+}
+
+/// Stop and remove the login services
+async fn execute_uninstall_service() -> Result<()> {
+    let platform = service::ServicePlatform::current();
+    let home = dirs::home_dir().context("Cannot determine home directory")?;
+    let removed = service::uninstall(&voice_services("telegram")?, platform, &home).await?;
+    if removed.is_empty() {
+        println!("ℹ️  No voice services installed");
+    }
+    for path in removed {
+        println!("🗑️  Removed {}", path.display());
+    }
+    Ok(())
+}
+
+/// Parse `--since`: a relative window (`30d`, `2w`, `12h`) back from now, or
+/// a date or RFC3339 time
+fn parse_since(since: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+    let relative = since
+        .strip_suffix(['d', 'w', 'h'])
+        .and_then(|n| n.parse::<i64>().ok())
+        .map(|n| match since.chars().last() {
+            Some('w') => chrono::Duration::weeks(n),
+            Some('h') => chrono::Duration::hours(n),
+            _ => chrono::Duration::days(n),
+        });
+    if let Some(window) = relative {
+        return Ok(chrono::Utc::now() - window);
+    }
+
+    super::triage::parse_date_or_datetime(since)?.with_context(|| {
+        format!(
+            "Cannot parse --since '{}'. Use 30d, 2w, 12h, YYYY-MM-DD or RFC3339 format.",
+            since
+        )
+    })
+}
+
 /// Format file size in human-readable form
 fn format_size(bytes: u64) -> String {
     if bytes >= 1_048_576 {
@@ -432,15 +755,8 @@ fn format_size(bytes: u64) -> String {
     }
 }
 
-/// Process via Telegram (send raw audio)
-async fn execute_process_telegram(
-    once: bool,
-    bot_token: Option<String>,
-    chat_id: Option<String>,
-    queue: &VoiceQueue,
-    caps: &ProcessCaps,
-) -> Result<()> {
-    // Get credentials from args or env
+/// Telegram client from args or env
+fn telegram_client(bot_token: Option<String>, chat_id: Option<String>) -> Result<TelegramClient> {
     let bot_token = bot_token
         .or_else(|| std::env::var("TELEGRAM_BOT_TOKEN").ok())
         .context("Missing Telegram bot token. Set --bot-token or TELEGRAM_BOT_TOKEN env var")?;
@@ -449,7 +765,18 @@ async fn execute_process_telegram(
         .or_else(|| std::env::var("TELEGRAM_CHAT_ID").ok())
         .context("Missing Telegram chat ID. Set --chat-id or TELEGRAM_CHAT_ID env var")?;
 
-    let client = TelegramClient::new(bot_token, chat_id);
+    Ok(TelegramClient::new(bot_token, chat_id))
+}
+
+/// Process via Telegram (send raw audio)
+async fn execute_process_telegram(
+    once: bool,
+    bot_token: Option<String>,
+    chat_id: Option<String>,
+    queue: &VoiceQueue,
+    caps: &ProcessCaps,
+) -> Result<()> {
+    let client = telegram_client(bot_token, chat_id)?;
 
     println!("🦞 Processing voice queue → Claudia (Telegram)");
     if caps.limit.is_some() || caps.max_hours.is_some() {
@@ -504,18 +831,51 @@ async fn execute_process_telegram(
 
             println!("📤 Sending: {} ({})", item.data.file_name, &item.id[..8]);
 
+            if skip_if_silent(queue, &item).await? {
+                if once {
+                    return Ok(());
+                }
+                continue;
+            }
+
             queue.mark_processing(&item.id).await?;
 
             match client.send_voice_memo(&item.data.file_path).await {
                 Ok(msg_id) => {
                     println!("   ✅ Sent! (message_id: {})", msg_id);
+                    queue
+                        .record_delivery(
+                            &item.id,
+                            "telegram",
+                            &DeliveryStatus::Sent(msg_id.to_string()),
+                        )
+                        .await?;
                     queue.mark_done(&item.id).await?;
                     processed_count += 1;
                     total_duration += item_duration;
+                    caps.notifications
+                        .send(NotifyEvent::Delivered {
+                            file_name: &item.data.file_name,
+                            route: "telegram",
+                        })
+                        .await;
                 }
                 Err(e) => {
                     println!("   ❌ Failed: {}", e);
+                    queue
+                        .record_delivery(
+                            &item.id,
+                            "telegram",
+                            &DeliveryStatus::Failed(e.to_string()),
+                        )
+                        .await?;
                     queue.mark_failed(&item.id, &e.to_string()).await?;
+                    caps.notifications
+                        .send(NotifyEvent::Failed {
+                            file_name: &item.data.file_name,
+                            error: &e.to_string(),
+                        })
+                        .await;
                 }
             }
 
@@ -534,10 +894,59 @@ async fn execute_process_telegram(
     Ok(())
 }
 
+/// Mark `item` skipped if its audio is too short or silent to be worth
+/// sending. Returns whether it was skipped.
+async fn skip_if_silent(queue: &VoiceQueue, item: &QueueItem) -> Result<bool> {
+    let thresholds = &crate::config::config()?.transcriber.silence;
+    let reason = check_silence(&item.data.file_path, item.data.duration_seconds, thresholds).await;
+    let Some(reason) = reason else {
+        return Ok(false);
+    };
+
+    println!("   ⏭️  Skipped: {}", reason);
+    queue.mark_skipped(&item.id, &reason).await?;
+    Ok(true)
+}
+
+/// Warn about, or with `transcriber.duplicates: skip` mark skipped, an item
+/// whose transcript matches an already-processed one. Returns whether it was
+/// skipped.
+async fn skip_if_duplicate(
+    queue: &VoiceQueue,
+    item: &QueueItem,
+    transcript: &TranscriptResult,
+) -> Result<bool> {
+    let policy = crate::config::config()?.transcriber.duplicates;
+    if policy == DuplicatePolicy::Off {
+        return Ok(false);
+    }
+    // An empty transcript has nothing to compare
+    let Some(hash) = normalized_text_hash(&transcript.text) else {
+        return Ok(false);
+    };
+    let items = queue.replay().await.map_err(|e| anyhow::anyhow!("{}", e))?;
+    let Some(original) = find_duplicate(items.values(), &item.id, &hash) else {
+        return Ok(false);
+    };
+
+    let reason = format!(
+        "probable duplicate of {} ({})",
+        original.data.file_name,
+        &original.id[..8]
+    );
+    if policy == DuplicatePolicy::Skip {
+        println!("   ⏭️  Skipped: {}", reason);
+        queue.mark_skipped(&item.id, &reason).await?;
+        return Ok(true);
+    }
+    println!("   ⚠️  Sending anyway: {}", reason);
+    Ok(false)
+}
+
 /// Process via Clawdbot (transcribe locally, send text to VPS)
 async fn execute_process_clawdbot(
     once: bool,
-    model: &str,
+    options: &TranscribeOptions,
     telegram_chat_id: Option<&str>,
     queue: &VoiceQueue,
     caps: &ProcessCaps,
@@ -549,7 +958,10 @@ async fn execute_process_clawdbot(
     let deliver_to_telegram = telegram_chat_id.is_some();
 
     println!("🦞 Processing voice queue → Claudia (Clawdbot)");
-    println!("   Model: {}", model);
+    println!("   Model: {}", options.model);
+    if !options.use_cache {
+        println!("   Transcript cache: disabled");
+    }
     if deliver_to_telegram {
         println!("   Telegram delivery: enabled");
     }
@@ -581,6 +993,17 @@ async fn execute_process_clawdbot(
             continue;
         }
 
+        // One backend session for the whole batch, so the model loads once
+        let batch_size = caps.batch_size(once, pending.len(), processed_count);
+        let mut transcriber = BatchTranscriber::for_backlog(batch_size, options.clone()).await?;
+        if batch_size > 1 {
+            println!(
+                "📦 {} pending, transcribing via {}",
+                batch_size,
+                transcriber.describe()
+            );
+        }
+
         for item in pending {
             // Check limit cap
             if let Some(limit) = caps.limit {
@@ -609,13 +1032,20 @@ async fn execute_process_clawdbot(
                 &item.id[..8]
             );
 
+            if skip_if_silent(queue, &item).await? {
+                if once {
+                    return Ok(());
+                }
+                continue;
+            }
+
             queue.mark_processing(&item.id).await?;
 
             // Step 1: Transcribe locally
-            println!("   📝 Transcribing with Whisper ({})...", model);
+            println!("   📝 Transcribing with Whisper ({})...", options.model);
             let audio_path = std::path::PathBuf::from(&item.data.file_path);
 
-            let transcript = match transcribe(&audio_path, model).await {
+            let transcript = match transcriber.transcribe(&audio_path).await {
                 Ok(t) => {
                     println!(
                         "   ✅ Transcribed ({:.0}s, {} chars)",
@@ -626,21 +1056,33 @@ async fn execute_process_clawdbot(
                 }
                 Err(e) => {
                     println!("   ❌ Transcription failed: {}", e);
-                    queue
-                        .mark_failed(&item.id, &format!("Transcription failed: {}", e))
-                        .await?;
+                    let error = format!("Transcription failed: {}", e);
+                    queue.mark_failed(&item.id, &error).await?;
+                    caps.notifications
+                        .send(NotifyEvent::Failed {
+                            file_name: &item.data.file_name,
+                            error: &error,
+                        })
+                        .await;
                     if once {
                         return Ok(());
                     }
                     continue;
                 }
             };
+            queue.record_transcript(&item.id, &transcript).await?;
+            if skip_if_duplicate(queue, &item, &transcript).await? {
+                if once {
+                    return Ok(());
+                }
+                continue;
+            }
 
             // Step 2: Send to Clawdbot
             println!("   📤 Sending to Claudia...");
             match client
                 .send_voice_intake(
-                    &transcript.text,
+                    &transcript.labeled_text(),
                     &item.id,
                     transcript.duration_seconds,
                     deliver_to_telegram,
@@ -648,17 +1090,38 @@ async fn execute_process_clawdbot(
                 )
                 .await
             {
-                Ok(_resp) => {
+                Ok(resp) => {
                     println!("   ✅ Sent to Claudia!");
+                    queue
+                        .record_delivery(&item.id, "clawdbot", &DeliveryStatus::Sent(resp.status))
+                        .await?;
                     queue.mark_done(&item.id).await?;
                     processed_count += 1;
                     total_duration += item_duration;
+                    caps.notifications
+                        .send(NotifyEvent::Delivered {
+                            file_name: &item.data.file_name,
+                            route: "clawdbot",
+                        })
+                        .await;
                 }
                 Err(e) => {
                     println!("   ❌ Failed to send: {}", e);
                     queue
-                        .mark_failed(&item.id, &format!("Clawdbot send failed: {}", e))
+                        .record_delivery(
+                            &item.id,
+                            "clawdbot",
+                            &DeliveryStatus::Failed(e.to_string()),
+                        )
                         .await?;
+                    let error = format!("Clawdbot send failed: {}", e);
+                    queue.mark_failed(&item.id, &error).await?;
+                    caps.notifications
+                        .send(NotifyEvent::Failed {
+                            file_name: &item.data.file_name,
+                            error: &error,
+                        })
+                        .await;
                 }
             }
 
@@ -666,6 +1129,7 @@ async fn execute_process_clawdbot(
                 return Ok(());
             }
         }
+        report_batch_failures(&transcriber);
 
         if once {
             break;
@@ -677,8 +1141,289 @@ async fn execute_process_clawdbot(
     Ok(())
 }
 
+/// List the files a batch couldn't transcribe (each is already marked failed)
+fn report_batch_failures(transcriber: &BatchTranscriber) {
+    let failures = transcriber.failures();
+    if failures.is_empty() {
+        return;
+    }
+    println!(
+        "⚠️  {} file(s) in this batch failed to transcribe:",
+        failures.len()
+    );
+    for (path, error) in failures {
+        println!("   {}: {}", path.display(), error);
+    }
+}
+
+/// Raw audio to Telegram
+struct TelegramDestination<'a>(&'a TelegramClient);
+
+#[async_trait::async_trait]
+impl Destination for TelegramDestination<'_> {
+    fn name(&self) -> &str {
+        "telegram"
+    }
+
+    async fn deliver(&self, item: &QueueItem, _transcript: &TranscriptResult) -> Result<String> {
+        let message_id = self.0.send_voice_memo(&item.data.file_path).await?;
+        Ok(message_id.to_string())
+    }
+}
+
+/// Transcript to Claudia via Clawdbot
+struct ClawdbotDestination<'a>(&'a ClawdbotClient);
+
+#[async_trait::async_trait]
+impl Destination for ClawdbotDestination<'_> {
+    fn name(&self) -> &str {
+        "clawdbot"
+    }
+
+    async fn deliver(&self, item: &QueueItem, transcript: &TranscriptResult) -> Result<String> {
+        let response = self
+            .0
+            .send_voice_intake(
+                &transcript.labeled_text(),
+                &item.id,
+                transcript.duration_seconds,
+                false,
+                None,
+            )
+            .await?;
+        Ok(response.status)
+    }
+}
+
+/// Process via both routes: transcribe locally, send the transcript to
+/// Clawdbot and the raw audio to Telegram
+async fn execute_process_both(
+    once: bool,
+    require: RequirePolicy,
+    options: &TranscribeOptions,
+    bot_token: Option<String>,
+    chat_id: Option<String>,
+    queue: &VoiceQueue,
+    caps: &ProcessCaps,
+) -> Result<()> {
+    let clawdbot = ClawdbotClient::from_env()
+        .context("Clawdbot client setup failed. Set CLAWDBOT_TOKEN env var")?;
+    let telegram = telegram_client(bot_token, chat_id)?;
+    let clawdbot = ClawdbotDestination(&clawdbot);
+    let telegram = TelegramDestination(&telegram);
+    let destinations: [&dyn Destination; 2] = [&clawdbot, &telegram];
+
+    println!("🦞 Processing voice queue → Claudia (Clawdbot + Telegram)");
+    println!("   Model: {}", options.model);
+    println!(
+        "   Require: {}",
+        match require {
+            RequirePolicy::All => "all destinations",
+            RequirePolicy::Any => "any destination",
+        }
+    );
+    println!();
+
+    let mut processed_count = 0u32;
+    let mut total_duration = 0.0f32;
+
+    loop {
+        let pending = queue.get_pending().await?;
+
+        if pending.is_empty() {
+            if once {
+                println!("✅ No pending items in queue");
+                break;
+            }
+            println!("⏳ Waiting for new items... (Ctrl+C to stop)");
+            tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+            continue;
+        }
+
+        let batch_size = caps.batch_size(once, pending.len(), processed_count);
+        let mut transcriber = BatchTranscriber::for_backlog(batch_size, options.clone()).await?;
+
+        for item in pending {
+            if let Some(limit) = caps.limit {
+                if processed_count >= limit {
+                    println!("⛔ Reached --limit {} cap", limit);
+                    return Ok(());
+                }
+            }
+
+            let item_duration = item.data.duration_seconds.unwrap_or(0.0);
+            if let Some(max_hours) = caps.max_hours {
+                if total_duration / 3600.0 >= max_hours {
+                    println!(
+                        "⛔ Reached --max-hours {} cap ({:.1} min processed)",
+                        max_hours,
+                        total_duration / 60.0
+                    );
+                    return Ok(());
+                }
+            }
+
+            println!(
+                "🎙️  Processing: {} ({})",
+                item.data.file_name,
+                &item.id[..8]
+            );
+
+            if skip_if_silent(queue, &item).await? {
+                if once {
+                    return Ok(());
+                }
+                continue;
+            }
+
+            queue.mark_processing(&item.id).await?;
+
+            println!("   📝 Transcribing with Whisper ({})...", options.model);
+            let transcript = match transcriber.transcribe(&item.data.file_path).await {
+                Ok(t) => t,
+                Err(e) => {
+                    println!("   ❌ Transcription failed: {}", e);
+                    let error = format!("Transcription failed: {}", e);
+                    queue.mark_failed(&item.id, &error).await?;
+                    caps.notifications
+                        .send(NotifyEvent::Failed {
+                            file_name: &item.data.file_name,
+                            error: &error,
+                        })
+                        .await;
+                    if once {
+                        return Ok(());
+                    }
+                    continue;
+                }
+            };
+            queue.record_transcript(&item.id, &transcript).await?;
+            if skip_if_duplicate(queue, &item, &transcript).await? {
+                if once {
+                    return Ok(());
+                }
+                continue;
+            }
+
+            let outcome = deliver_all(&destinations, &item, &transcript).await;
+            for (name, result) in &outcome.results {
+                match result {
+                    Ok(receipt) => println!("   ✅ {}: sent ({})", name, receipt),
+                    Err(e) => println!("   ❌ {}: {}", name, e),
+                }
+            }
+
+            if record_outcome(queue, &item.id, &outcome, require).await? {
+                processed_count += 1;
+                total_duration += item_duration;
+                caps.notifications
+                    .send(NotifyEvent::Delivered {
+                        file_name: &item.data.file_name,
+                        route: "clawdbot + telegram",
+                    })
+                    .await;
+            } else {
+                caps.notifications
+                    .send(NotifyEvent::Failed {
+                        file_name: &item.data.file_name,
+                        error: &outcome.failures().unwrap_or_default(),
+                    })
+                    .await;
+            }
+
+            if once {
+                return Ok(());
+            }
+        }
+        report_batch_failures(&transcriber);
+
+        if once {
+            break;
+        }
+
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    }
+
+    Ok(())
+}
+
+/// Export transcripts to a vault
+async fn execute_export(
+    vault: &std::path::Path,
+    since: Option<&str>,
+    format: &NoteFormat,
+    summary_pattern: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    let since = match since {
+        Some(s) => Some(super::triage::parse_date_or_datetime(s)?.with_context(|| {
+            format!(
+                "Cannot parse --since '{}'. Use YYYY-MM-DD or RFC3339 format.",
+                s
+            )
+        })?),
+        None => None,
+    };
+
+    let queue = VoiceQueue::open_default().await?;
+    let mut items = queue.replay().await.map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    if let Some(pattern) = summary_pattern {
+        let orchestrator = Orchestrator::new();
+        // A note already in the vault isn't rewritten, so summarizing its
+        // memo would be wasted unless forced
+        let mut unsummarized = Vec::new();
+        for item in exportable(items.values(), since) {
+            let exported = match &item.transcript {
+                Some(transcript) => format.note_path(vault, item, transcript)?.exists(),
+                None => false,
+            };
+            if force || (item.summary.is_none() && !exported) {
+                unsummarized.push(item.clone());
+            }
+        }
+        for item in &unsummarized {
+            println!(
+                "  📝 Summarizing {} with '{}'...",
+                item.data.file_name, pattern
+            );
+            match summarize_item(&orchestrator, &queue, item, pattern).await {
+                Ok((_, run_id)) => println!("     ✓ run {}", run_id),
+                Err(e) => println!("     ❌ {:#}", e),
+            }
+        }
+        if !unsummarized.is_empty() {
+            items = queue.replay().await.map_err(|e| anyhow::anyhow!("{}", e))?;
+        }
+    }
+
+    let selected = exportable(items.values(), since);
+    if selected.is_empty() {
+        println!("No transcribed items to export");
+        return Ok(());
+    }
+
+    let report = export_to_vault(&selected, vault, format, force).await?;
+    for path in &report.written {
+        println!("  ✓ {}", path.display());
+    }
+    println!();
+    println!(
+        "Exported {} note(s) to {} ({} already present)",
+        report.written.len(),
+        vault.display(),
+        report.existing.len()
+    );
+    Ok(())
+}
+
 /// List queue items
-async fn execute_list(status_filter: Option<String>, limit: usize) -> Result<()> {
+async fn execute_list(
+    status_filter: Option<String>,
+    limit: usize,
+    verbose: bool,
+    json: bool,
+) -> Result<()> {
     let queue = VoiceQueue::open_default().await?;
     let items = queue.replay().await.map_err(|e| anyhow::anyhow!("{}", e))?;
 
@@ -696,6 +1441,32 @@ async fn execute_list(status_filter: Option<String>, limit: usize) -> Result<()>
 
     filtered.sort_by(|a, b| b.data.detected_at.cmp(&a.data.detected_at));
 
+    if json {
+        let items: Vec<_> = filtered
+            .iter()
+            .take(limit)
+            .map(|item| {
+                serde_json::json!({
+                    "id": item.id,
+                    "status": item.status.to_string(),
+                    "file_name": item.data.file_name,
+                    "file_path": item.data.file_path,
+                    "file_size": item.data.file_size,
+                    "detected_at": item.data.detected_at,
+                    "duration_seconds": item.data.duration_seconds,
+                    "started_at": item.started_at,
+                    "completed_at": item.completed_at,
+                    "retry_count": item.retry_count,
+                    "deliveries": item.deliveries,
+                    "skip_reason": item.skip_reason,
+                    "error": item.error,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
     if filtered.is_empty() {
         println!("No items in queue");
         if status_filter.is_some() {
@@ -727,6 +1498,20 @@ async fn execute_list(status_filter: Option<String>, limit: usize) -> Result<()>
             file_name,
             detected
         );
+
+        if verbose {
+            let mut deliveries: Vec<_> = item.deliveries.iter().collect();
+            deliveries.sort_by(|a, b| a.0.cmp(b.0));
+            for (destination, status) in deliveries {
+                println!("{:<14} → {}: {}", "", destination, status);
+            }
+            if let Some(reason) = &item.skip_reason {
+                println!("{:<14} skipped: {}", "", reason);
+            }
+            if let Some(error) = &item.error {
+                println!("{:<14} error: {}", "", error);
+            }
+        }
     }
 
     let total = filtered.len();
@@ -754,6 +1539,35 @@ async fn execute_config() -> Result<()> {
         "Queue file:       {}",
         VoiceQueue::default_path()?.display()
     );
+    println!(
+        "Transcript cache: {}",
+        TranscriptCache::open_default()?.dir().display()
+    );
+    match WhisperBinary::from_config() {
+        Ok(whisper) => println!(
+            "Whisper:          {} ({:?})",
+            whisper.path.display(),
+            whisper.flavor
+        ),
+        Err(e) => println!("Whisper:          ⚠️  {}", e),
+    }
+    let silence = &crate::config::config()?.transcriber.silence;
+    if silence.enabled {
+        println!(
+            "Silence skip:     < {:.1}s or mean volume <= {:.1} dB",
+            silence.min_duration_seconds, silence.max_mean_volume_db
+        );
+    } else {
+        println!("Silence skip:     disabled");
+    }
+    println!(
+        "Normalize .qta:   {:?}",
+        crate::config::config()?.normalize_target
+    );
+    println!(
+        "Duplicates:       {:?}",
+        crate::config::config()?.transcriber.duplicates
+    );
     println!();
 
     // Check if path exists
@@ -784,3 +1598,15 @@ async fn execute_config() -> Result<()> {
 
     Ok(())
 }
+
+/// Delete cached transcripts
+async fn execute_clear_cache() -> Result<()> {
+    let cache = TranscriptCache::open_default()?;
+    let removed = cache.clear().await?;
+    println!(
+        "✓ Removed {} cached transcript(s) from {}",
+        removed,
+        cache.dir().display()
+    );
+    Ok(())
+}
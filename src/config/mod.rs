@@ -18,6 +18,14 @@ use std::sync::OnceLock;
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
+use crate::adapters::circuit_breaker::CircuitBreakerConfig;
+use crate::core::cost::CostModel;
+use crate::core::event_backend::EventStorage;
+use crate::core::rate_limit::RateLimit;
+use crate::evidence::MatchOptions;
+use crate::ingest::{
+    DuplicatePolicy, NormalizeTarget, NotificationConfig, SilenceThresholds, WhisperFlavor,
+};
 use crate::library::content::ContentType;
 
 /// Global cached configuration (stores Result to handle init errors)
@@ -36,6 +44,29 @@ pub struct ConfigFile {
     pub fabric: Option<FabricConfig>,
     #[serde(default)]
     pub safety: Option<SafetyConfig>,
+    #[serde(default)]
+    pub evidence: Option<EvidenceConfig>,
+    /// Rates for `arkai run --estimate`
+    #[serde(default)]
+    pub cost: Option<CostModel>,
+    /// Where new runs store their events (`jsonl` or `sqlite`)
+    #[serde(default)]
+    pub storage: Option<EventStorage>,
+    /// Command template for opening files at a position (`nvim +{line} {file}`)
+    #[serde(default)]
+    pub editor: Option<String>,
+    /// Whisper binary used by `arkai voice process`
+    #[serde(default)]
+    pub transcriber: Option<TranscriberConfig>,
+    /// Note format for `arkai voice export`
+    #[serde(default)]
+    pub voice_export: Option<VoiceExportConfig>,
+    /// Desktop notifications from `arkai voice watch` / `process`
+    #[serde(default)]
+    pub notifications: Option<NotificationConfig>,
+    /// What captured `.qta` memos are converted to (`m4a`, `wav16k`, `none`)
+    #[serde(default)]
+    pub normalize_target: Option<NormalizeTarget>,
     /// Catch-all for unknown keys (obsidian, linkedin, etc.)
     #[serde(flatten)]
     pub extra: std::collections::HashMap<String, serde_yaml::Value>,
@@ -57,6 +88,10 @@ pub struct FabricConfig {
     pub binary: Option<String>,
     pub patterns_dir: Option<String>,
     pub custom_patterns: Option<String>,
+    /// Throttle for Fabric calls across all runs in the process
+    pub rate_limit: Option<RateLimit>,
+    /// When to stop calling Fabric after repeated failures
+    pub circuit_breaker: Option<CircuitBreakerConfig>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -87,6 +122,73 @@ pub struct SafetyConfig {
     pub max_input_size_bytes: Option<usize>,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct EvidenceConfig {
+    /// Named evidence extractors for `arkai evidence extract`
+    #[serde(default)]
+    pub extractors: HashMap<String, ExtractorConfig>,
+    /// Fallbacks tried when a quote has no exact match in the transcript
+    #[serde(default)]
+    pub matching: MatchOptions,
+    /// Keep artifact digests in `digest_cache.json` between commands
+    #[serde(default)]
+    pub persist_digests: bool,
+}
+
+/// Which Whisper CLI to run for voice transcription
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct TranscriberConfig {
+    /// Binary name or path (auto-detected from PATH when unset)
+    pub binary: Option<String>,
+    /// Flag dialect, when it can't be told from the binary name
+    pub flavor: Option<WhisperFlavor>,
+    /// Extra arguments appended to every invocation
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    /// When a memo is skipped as non-speech instead of transcribed
+    #[serde(default)]
+    pub silence: SilenceThresholds,
+    /// What to do when a transcript matches an already-processed memo
+    #[serde(default)]
+    pub duplicates: DuplicatePolicy,
+}
+
+/// How `arkai voice export` names and formats notes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct VoiceExportConfig {
+    /// Note template file (relative to the project root) with `{{date}}`,
+    /// `{{duration}}`, `{{transcript}}`, `{{filename}}`, `{{tags}}` etc.
+    pub template: Option<String>,
+    /// File name pattern, e.g. `{{date}} {{title}}` (`.md` is appended)
+    pub filename: Option<String>,
+    /// Folder inside the vault to write notes into
+    pub subfolder: Option<String>,
+    /// Tags offered to templates as `{{tags}}`
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Fabric pattern for `--summarize` (default `summarize`)
+    pub summary_pattern: Option<String>,
+}
+
+/// An external claim extractor: either a command (argv) or a Fabric pattern.
+/// Either way it receives the transcript on stdin and prints a JSON array of
+/// `{claim, quote, confidence}` objects.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExtractorConfig {
+    /// Command and arguments to run (first element relative to the project root)
+    #[serde(default)]
+    pub command: Vec<String>,
+    /// Fabric pattern to run instead of a command
+    pub pattern: Option<String>,
+    /// Timeout for one extraction run
+    pub timeout_seconds: Option<u64>,
+    /// Drop claims below this confidence (overridable with --min-confidence)
+    pub min_confidence: Option<f64>,
+    /// Artifact fed to the extractor instead of the transcript (e.g. `wisdom.md`).
+    /// Claims are still grounded in the transcript and cross-reference this artifact.
+    pub input_artifact: Option<String>,
+}
+
 /// Resolved configuration with absolute paths
 #[derive(Debug, Clone)]
 pub struct ResolvedConfig {
@@ -98,10 +200,88 @@ pub struct ResolvedConfig {
     pub content_types: HashMap<String, String>,
     /// Optional explicit Fabric binary override from env/config
     pub fabric_binary: Option<FabricBinaryOverride>,
+    /// Rate limit for Fabric calls, if configured
+    pub fabric_rate_limit: Option<RateLimit>,
+    /// Circuit breaker for Fabric calls
+    pub fabric_circuit_breaker: CircuitBreakerConfig,
+    /// Event log storage for new runs
+    pub storage: EventStorage,
     /// Path to config file (if found)
     pub config_file: Option<PathBuf>,
     /// Safety settings
     pub safety: SafetySettings,
+    /// Configured evidence extractors by name
+    pub extractors: HashMap<String, ExtractorConfig>,
+    /// How evidence quotes are matched against transcripts
+    pub evidence_matching: MatchOptions,
+    /// Whether artifact digests are cached on disk between commands
+    pub persist_digests: bool,
+    /// Cost model for run estimates
+    pub cost: CostModel,
+    /// Editor command template from `ARKAI_EDITOR` or config `editor`
+    pub editor: Option<String>,
+    /// Whisper binary settings (`WHISPER_PATH` overrides the binary)
+    pub transcriber: TranscriberConfig,
+    /// Voice export note format (template path resolved)
+    pub voice_export: VoiceExportConfig,
+    /// Which voice events raise desktop notifications
+    pub notifications: NotificationConfig,
+    /// Conversion target for captured `.qta` memos
+    pub normalize_target: NormalizeTarget,
+    /// Where each resolved value came from
+    pub sources: ConfigSources,
+}
+
+/// Origin of a resolved configuration value
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValueSource {
+    /// Environment variable
+    Env,
+    /// Config file (.arkai/config.yaml)
+    Config,
+    /// Built-in default
+    #[default]
+    Default,
+}
+
+impl ValueSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Env => "env",
+            Self::Config => "config",
+            Self::Default => "default",
+        }
+    }
+
+    fn from_config(present: bool) -> Self {
+        if present {
+            Self::Config
+        } else {
+            Self::Default
+        }
+    }
+}
+
+/// Per-value sources for a `ResolvedConfig`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigSources {
+    pub home: ValueSource,
+    pub library: ValueSource,
+    pub content_types: ValueSource,
+    pub fabric_binary: ValueSource,
+    pub editor: ValueSource,
+    pub transcriber_binary: ValueSource,
+    pub safety_max_steps: ValueSource,
+    pub safety_timeout_seconds: ValueSource,
+    pub safety_max_input_size_bytes: ValueSource,
+}
+
+/// A single resolved value, as shown by `arkai config show --resolved`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEntry {
+    pub key: &'static str,
+    pub value: String,
+    pub source: ValueSource,
 }
 
 #[derive(Debug, Clone)]
@@ -137,6 +317,113 @@ impl ResolvedConfig {
             self.library.join(type_key)
         }
     }
+
+    /// Flatten the effective configuration into key/value/source entries
+    pub fn resolved_entries(&self) -> Vec<ResolvedEntry> {
+        let entry = |key, value: String, source| ResolvedEntry { key, value, source };
+
+        let mut content_types: Vec<_> = self.content_types.iter().collect();
+        content_types.sort();
+        let content_types = if content_types.is_empty() {
+            "(defaults)".to_string()
+        } else {
+            content_types
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        vec![
+            entry(
+                "config_file",
+                self.config_file
+                    .as_ref()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "(none)".to_string()),
+                ValueSource::from_config(self.config_file.is_some()),
+            ),
+            entry("home", self.home.display().to_string(), self.sources.home),
+            entry(
+                "library",
+                self.library.display().to_string(),
+                self.sources.library,
+            ),
+            entry("content_types", content_types, self.sources.content_types),
+            entry(
+                "fabric.binary",
+                self.fabric_binary
+                    .as_ref()
+                    .map(|f| f.value.clone())
+                    .unwrap_or_else(|| "(auto-detect)".to_string()),
+                self.sources.fabric_binary,
+            ),
+            entry(
+                "fabric.rate_limit",
+                self.fabric_rate_limit
+                    .map(|limit| {
+                        format!("{}/min (burst {})", limit.requests_per_minute, limit.burst)
+                    })
+                    .unwrap_or_else(|| "(none)".to_string()),
+                ValueSource::from_config(self.fabric_rate_limit.is_some()),
+            ),
+            entry(
+                "fabric.circuit_breaker",
+                if self.fabric_circuit_breaker.failure_threshold == 0 {
+                    "(off)".to_string()
+                } else {
+                    format!(
+                        "open after {} failures for {}s",
+                        self.fabric_circuit_breaker.failure_threshold,
+                        self.fabric_circuit_breaker.cooldown_seconds
+                    )
+                },
+                ValueSource::from_config(
+                    self.fabric_circuit_breaker != CircuitBreakerConfig::default(),
+                ),
+            ),
+            entry(
+                "storage",
+                self.storage.to_string(),
+                ValueSource::from_config(self.storage != EventStorage::default()),
+            ),
+            entry(
+                "evidence.persist_digests",
+                self.persist_digests.to_string(),
+                ValueSource::from_config(self.persist_digests),
+            ),
+            entry(
+                "editor",
+                self.editor
+                    .clone()
+                    .unwrap_or_else(|| "(code -g, then $EDITOR)".to_string()),
+                self.sources.editor,
+            ),
+            entry(
+                "transcriber.binary",
+                self.transcriber
+                    .binary
+                    .clone()
+                    .unwrap_or_else(|| "(auto-detect)".to_string()),
+                self.sources.transcriber_binary,
+            ),
+            entry(
+                "safety.max_steps",
+                self.safety.max_steps.to_string(),
+                self.sources.safety_max_steps,
+            ),
+            entry(
+                "safety.timeout_seconds",
+                self.safety.timeout_seconds.to_string(),
+                self.sources.safety_timeout_seconds,
+            ),
+            entry(
+                "safety.max_input_size_bytes",
+                self.safety.max_input_size_bytes.to_string(),
+                self.sources.safety_max_input_size_bytes,
+            ),
+        ]
+    }
 }
 
 /// Find config file by searching current directory and parents
@@ -219,12 +506,36 @@ fn load_config() -> Result<ResolvedConfig> {
         .context("Failed to determine home directory")?
         .join(".arkai");
 
-    // Check for config file
-    let config_file = find_config_file();
-
-    let env_fabric_binary = std::env::var("ARKAI_FABRIC_BIN").ok();
+    load_config_from(
+        &|key| std::env::var(key).ok(),
+        find_config_file(),
+        default_home,
+    )
+}
 
-    let (home, library, content_types, safety, fabric_binary) =
+/// Resolve configuration from an env lookup, optional config file, and default home.
+///
+/// Split out from `load_config` so precedence can be tested without mutating
+/// the process environment.
+fn load_config_from(
+    env: &dyn Fn(&str) -> Option<String>,
+    config_file: Option<PathBuf>,
+    default_home: PathBuf,
+) -> Result<ResolvedConfig> {
+    let env_fabric_binary = env("ARKAI_FABRIC_BIN");
+    let mut sources = ConfigSources::default();
+
+    let mut config_editor = None;
+    let mut transcriber = TranscriberConfig::default();
+    let mut voice_export = VoiceExportConfig::default();
+    let mut notifications = NotificationConfig::default();
+    let mut normalize_target = NormalizeTarget::default();
+    let mut fabric_rate_limit = None;
+    let mut fabric_circuit_breaker = CircuitBreakerConfig::default();
+    let mut storage = EventStorage::default();
+    let mut persist_digests = false;
+
+    let (home, library, content_types, safety, fabric_binary, extractors, evidence_matching, cost) =
         if let Some(ref config_path) = config_file {
             // Config file found - use it as base
             let config = load_config_file(config_path)?;
@@ -236,10 +547,12 @@ fn load_config() -> Result<ResolvedConfig> {
                 .unwrap_or(Path::new("."));
 
             // Resolve home path
-            let home = if let Ok(env_home) = std::env::var("ARKAI_HOME") {
+            let home = if let Some(env_home) = env("ARKAI_HOME") {
+                sources.home = ValueSource::Env;
                 PathBuf::from(env_home)
             } else if let Some(ref home_path) = config.paths.home {
                 // home is relative to .arkai/ directory
+                sources.home = ValueSource::Config;
                 let arkai_dir = config_path.parent().unwrap_or(Path::new("."));
                 resolve_path(arkai_dir, home_path)
             } else {
@@ -247,9 +560,11 @@ fn load_config() -> Result<ResolvedConfig> {
             };
 
             // Resolve library path
-            let library = if let Ok(env_lib) = std::env::var("ARKAI_LIBRARY") {
+            let library = if let Some(env_lib) = env("ARKAI_LIBRARY") {
+                sources.library = ValueSource::Env;
                 PathBuf::from(env_lib)
             } else if let Some(ref lib_path) = config.paths.library {
+                sources.library = ValueSource::Config;
                 resolve_path(base_dir, lib_path)
             } else {
                 home.join("library")
@@ -257,6 +572,9 @@ fn load_config() -> Result<ResolvedConfig> {
 
             // Content type mappings
             let content_types = config.paths.content_types;
+            if !content_types.is_empty() {
+                sources.content_types = ValueSource::Config;
+            }
 
             let fabric_binary = resolve_fabric_binary_override(
                 env_fabric_binary.clone(),
@@ -268,34 +586,91 @@ fn load_config() -> Result<ResolvedConfig> {
             );
 
             // Safety settings
+            let defaults = SafetySettings::default();
+            let safety_config = config.safety.as_ref();
+            let max_steps = safety_config.and_then(|s| s.max_steps);
+            let timeout_seconds = safety_config.and_then(|s| s.timeout_seconds);
+            let max_input_size_bytes = safety_config.and_then(|s| s.max_input_size_bytes);
+
+            sources.safety_max_steps = ValueSource::from_config(max_steps.is_some());
+            sources.safety_timeout_seconds = ValueSource::from_config(timeout_seconds.is_some());
+            sources.safety_max_input_size_bytes =
+                ValueSource::from_config(max_input_size_bytes.is_some());
+
             let safety = SafetySettings {
-                max_steps: config
-                    .safety
-                    .as_ref()
-                    .and_then(|s| s.max_steps)
-                    .unwrap_or(50),
-                timeout_seconds: config
-                    .safety
-                    .as_ref()
-                    .and_then(|s| s.timeout_seconds)
-                    .unwrap_or(600),
-                max_input_size_bytes: config
-                    .safety
-                    .as_ref()
-                    .and_then(|s| s.max_input_size_bytes)
-                    .unwrap_or(1_048_576),
+                max_steps: max_steps.unwrap_or(defaults.max_steps),
+                timeout_seconds: timeout_seconds.unwrap_or(defaults.timeout_seconds),
+                max_input_size_bytes: max_input_size_bytes.unwrap_or(defaults.max_input_size_bytes),
             };
 
-            (home, library, content_types, safety, fabric_binary)
+            config_editor = config.editor;
+            transcriber = config.transcriber.unwrap_or_default();
+            if let Some(binary) = transcriber.binary.as_mut() {
+                *binary = resolve_command_value(base_dir, binary);
+            }
+            voice_export = config.voice_export.unwrap_or_default();
+            if let Some(template) = voice_export.template.as_mut() {
+                *template = resolve_path(base_dir, template).display().to_string();
+            }
+            notifications = config.notifications.unwrap_or_default();
+            normalize_target = config.normalize_target.unwrap_or_default();
+            fabric_rate_limit = config.fabric.as_ref().and_then(|fabric| fabric.rate_limit);
+            if fabric_rate_limit.is_some_and(|limit| limit.requests_per_minute == 0) {
+                anyhow::bail!(
+                    "fabric.rate_limit.requests_per_minute must be at least 1 in {}",
+                    config_path.display()
+                );
+            }
+            if let Some(breaker) = config
+                .fabric
+                .as_ref()
+                .and_then(|fabric| fabric.circuit_breaker)
+            {
+                fabric_circuit_breaker = breaker;
+            }
+            storage = config.storage.unwrap_or_default();
+            let evidence = config.evidence.unwrap_or_default();
+            persist_digests = evidence.persist_digests;
+
+            // Extractor commands resolve like the fabric binary
+            let extractors = evidence
+                .extractors
+                .into_iter()
+                .map(|(name, mut extractor)| {
+                    if let Some(program) = extractor.command.first_mut() {
+                        *program = resolve_command_value(base_dir, program);
+                    }
+                    (name, extractor)
+                })
+                .collect();
+
+            (
+                home,
+                library,
+                content_types,
+                safety,
+                fabric_binary,
+                extractors,
+                evidence.matching,
+                config.cost.unwrap_or_default(),
+            )
         } else {
             // No config file - use env vars or defaults
-            let home = std::env::var("ARKAI_HOME")
-                .map(PathBuf::from)
-                .unwrap_or_else(|_| default_home.clone());
+            let home = match env("ARKAI_HOME") {
+                Some(env_home) => {
+                    sources.home = ValueSource::Env;
+                    PathBuf::from(env_home)
+                }
+                None => default_home.clone(),
+            };
 
-            let library = std::env::var("ARKAI_LIBRARY")
-                .map(PathBuf::from)
-                .unwrap_or_else(|_| home.join("library"));
+            let library = match env("ARKAI_LIBRARY") {
+                Some(env_lib) => {
+                    sources.library = ValueSource::Env;
+                    PathBuf::from(env_lib)
+                }
+                None => home.join("library"),
+            };
 
             let fabric_binary = resolve_fabric_binary_override(env_fabric_binary, None, None);
 
@@ -305,16 +680,60 @@ fn load_config() -> Result<ResolvedConfig> {
                 HashMap::new(),
                 SafetySettings::default(),
                 fabric_binary,
+                HashMap::new(),
+                MatchOptions::default(),
+                CostModel::default(),
             )
         };
 
+    if let Some(ref fabric_binary) = fabric_binary {
+        sources.fabric_binary = match fabric_binary.source {
+            FabricBinaryOverrideSource::Env => ValueSource::Env,
+            FabricBinaryOverrideSource::Config => ValueSource::Config,
+        };
+    }
+
+    let editor = match env("ARKAI_EDITOR").filter(|e| !e.trim().is_empty()) {
+        Some(editor) => {
+            sources.editor = ValueSource::Env;
+            Some(editor)
+        }
+        None => {
+            sources.editor = ValueSource::from_config(config_editor.is_some());
+            config_editor
+        }
+    };
+
+    match env("WHISPER_PATH").filter(|p| !p.trim().is_empty()) {
+        Some(binary) => {
+            sources.transcriber_binary = ValueSource::Env;
+            transcriber.binary = Some(binary);
+        }
+        None => {
+            sources.transcriber_binary = ValueSource::from_config(transcriber.binary.is_some());
+        }
+    }
+
     Ok(ResolvedConfig {
         home,
         library,
         content_types,
         fabric_binary,
+        fabric_rate_limit,
+        fabric_circuit_breaker,
+        storage,
         config_file,
         safety,
+        extractors,
+        evidence_matching,
+        persist_digests,
+        cost,
+        editor,
+        transcriber,
+        voice_export,
+        notifications,
+        normalize_target,
+        sources,
     })
 }
 
@@ -347,6 +766,11 @@ pub fn runs_dir() -> Result<PathBuf> {
     Ok(config()?.home.join("runs"))
 }
 
+/// Get the content-addressed objects directory ($ARKAI_HOME/objects)
+pub fn objects_dir() -> Result<PathBuf> {
+    Ok(config()?.home.join("objects"))
+}
+
 /// Get the library directory.
 pub fn library_dir() -> Result<PathBuf> {
     Ok(config()?.library.clone())
@@ -381,6 +805,16 @@ pub fn voice_cache_dir() -> Result<PathBuf> {
     Ok(config()?.home.join("voice_cache"))
 }
 
+/// Get the transcript cache directory ($ARKAI_HOME/transcript_cache/)
+pub fn transcript_cache_dir() -> Result<PathBuf> {
+    Ok(config()?.home.join("transcript_cache"))
+}
+
+/// Get the artifact digest cache path ($ARKAI_HOME/digest_cache.json)
+pub fn digest_cache_path() -> Result<PathBuf> {
+    Ok(config()?.home.join("digest_cache.json"))
+}
+
 /// Get the content directory for a specific content type
 pub fn content_type_dir(content_type: ContentType) -> Result<PathBuf> {
     Ok(config()?.content_type_dir(content_type))
@@ -469,8 +903,21 @@ safety:
             .into_iter()
             .collect(),
             fabric_binary: None,
+            fabric_rate_limit: None,
+            fabric_circuit_breaker: CircuitBreakerConfig::default(),
+            storage: EventStorage::default(),
             config_file: None,
             safety: SafetySettings::default(),
+            extractors: HashMap::new(),
+            evidence_matching: MatchOptions::default(),
+            persist_digests: false,
+            cost: CostModel::default(),
+            editor: None,
+            transcriber: TranscriberConfig::default(),
+            voice_export: VoiceExportConfig::default(),
+            notifications: NotificationConfig::default(),
+            normalize_target: NormalizeTarget::default(),
+            sources: ConfigSources::default(),
         };
 
         assert_eq!(
@@ -542,4 +989,200 @@ safety:
         assert_eq!(fabric_binary.value, "/repo/./bin/fabric-ai");
         assert_eq!(fabric_binary.source, FabricBinaryOverrideSource::Config);
     }
+
+    #[test]
+    fn test_resolved_entries_reflect_library_env_override() {
+        let env = |key: &str| match key {
+            "ARKAI_LIBRARY" => Some("/env/library".to_string()),
+            _ => None,
+        };
+
+        let config = load_config_from(&env, None, PathBuf::from("/default/.arkai")).unwrap();
+
+        assert_eq!(config.library, PathBuf::from("/env/library"));
+        assert_eq!(config.sources.library, ValueSource::Env);
+        assert_eq!(config.sources.home, ValueSource::Default);
+
+        let entries = config.resolved_entries();
+        let library = entries.iter().find(|e| e.key == "library").unwrap();
+        assert_eq!(library.value, "/env/library");
+        assert_eq!(library.source, ValueSource::Env);
+
+        let home = entries.iter().find(|e| e.key == "home").unwrap();
+        assert_eq!(home.value, "/default/.arkai");
+        assert_eq!(home.source, ValueSource::Default);
+    }
+
+    #[test]
+    fn test_fabric_rate_limit_from_config() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "fabric:\n  rate_limit:\n    requests_per_minute: 20\n",
+        )
+        .unwrap();
+
+        let config =
+            load_config_from(&|_| None, Some(config_path.clone()), PathBuf::from("/d")).unwrap();
+        assert_eq!(
+            config.fabric_rate_limit,
+            Some(RateLimit {
+                requests_per_minute: 20,
+                burst: 1,
+            })
+        );
+
+        std::fs::write(
+            &config_path,
+            "fabric:\n  rate_limit:\n    requests_per_minute: 0\n",
+        )
+        .unwrap();
+        let err = load_config_from(&|_| None, Some(config_path), PathBuf::from("/d")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("requests_per_minute must be at least 1"));
+    }
+
+    #[test]
+    fn test_editor_env_overrides_config() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(&config_path, "editor: \"nvim +{line} {file}\"\n").unwrap();
+
+        let config =
+            load_config_from(&|_| None, Some(config_path.clone()), PathBuf::from("/d")).unwrap();
+        assert_eq!(config.editor.as_deref(), Some("nvim +{line} {file}"));
+        assert_eq!(config.sources.editor, ValueSource::Config);
+
+        let env = |key: &str| (key == "ARKAI_EDITOR").then(|| "hx {file}:{line}".to_string());
+        let config = load_config_from(&env, Some(config_path), PathBuf::from("/d")).unwrap();
+        assert_eq!(config.editor.as_deref(), Some("hx {file}:{line}"));
+        assert_eq!(config.sources.editor, ValueSource::Env);
+    }
+
+    #[test]
+    fn test_transcriber_config_and_whisper_path_override() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "transcriber:\n  binary: bin/whisper-cli\n  flavor: cpp\n  extra_args: [\"-t\", \"8\"]\n",
+        )
+        .unwrap();
+
+        let config =
+            load_config_from(&|_| None, Some(config_path.clone()), PathBuf::from("/d")).unwrap();
+        assert_eq!(
+            config.transcriber.binary,
+            Some(temp.path().join("bin/whisper-cli").display().to_string())
+        );
+        assert_eq!(config.transcriber.flavor, Some(WhisperFlavor::Cpp));
+        assert_eq!(config.transcriber.extra_args, vec!["-t", "8"]);
+        assert_eq!(config.sources.transcriber_binary, ValueSource::Config);
+
+        let env = |key: &str| (key == "WHISPER_PATH").then(|| "/opt/whisper".to_string());
+        let config = load_config_from(&env, Some(config_path), PathBuf::from("/d")).unwrap();
+        assert_eq!(config.transcriber.binary.as_deref(), Some("/opt/whisper"));
+        assert_eq!(config.transcriber.extra_args, vec!["-t", "8"]);
+        assert_eq!(config.sources.transcriber_binary, ValueSource::Env);
+    }
+
+    #[test]
+    fn test_config_sources_from_config_file() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "paths:\n  library: /cfg/library\nsafety:\n  max_steps: 7\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(
+            &|_| None,
+            Some(config_path),
+            PathBuf::from("/default/.arkai"),
+        )
+        .unwrap();
+
+        assert_eq!(config.library, PathBuf::from("/cfg/library"));
+        assert_eq!(config.sources.library, ValueSource::Config);
+        assert_eq!(config.safety.max_steps, 7);
+        assert_eq!(config.sources.safety_max_steps, ValueSource::Config);
+        assert_eq!(config.sources.safety_timeout_seconds, ValueSource::Default);
+    }
+
+    #[test]
+    fn test_config_loads_evidence_extractors() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "evidence:\n  extractors:\n    local:\n      command: [python3, scripts/extract.py]\n      min_confidence: 0.4\n    fabric:\n      pattern: extract_claims\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&|_| None, Some(config_path), PathBuf::from("/d")).unwrap();
+
+        let local = &config.extractors["local"];
+        assert_eq!(local.command, vec!["python3", "scripts/extract.py"]);
+        assert_eq!(local.min_confidence, Some(0.4));
+        assert_eq!(
+            config.extractors["fabric"].pattern.as_deref(),
+            Some("extract_claims")
+        );
+    }
+
+    #[test]
+    fn test_config_loads_evidence_matching() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "evidence:\n  matching:\n    punctuation: true\n  persist_digests: true\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&|_| None, Some(config_path), PathBuf::from("/d")).unwrap();
+
+        assert!(config.evidence_matching.punctuation);
+        assert!(config.persist_digests);
+        // Unset fields keep their defaults
+        assert_eq!(
+            config.evidence_matching.unicode,
+            MatchOptions::default().unicode
+        );
+    }
+
+    #[test]
+    fn test_config_loads_cost_rates() {
+        let temp = TempDir::new().unwrap();
+        let arkai_dir = temp.path().join(".arkai");
+        std::fs::create_dir_all(&arkai_dir).unwrap();
+        let config_path = arkai_dir.join("config.yaml");
+        std::fs::write(
+            &config_path,
+            "cost:\n  chars_per_token: 3.5\n  actions:\n    summarize:\n      input_per_1k: 0.01\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&|_| None, Some(config_path), PathBuf::from("/d")).unwrap();
+
+        assert_eq!(config.cost.chars_per_token, 3.5);
+        assert_eq!(config.cost.actions["summarize"].input_per_1k, 0.01);
+        // Unset fields keep their defaults
+        assert_eq!(config.cost.default, crate::core::ActionRate::default());
+    }
 }
@@ -0,0 +1,157 @@
+//! Cleaning of adapter output before it's stored.
+//!
+//! Models often wrap the useful part of a response in chatter ("Here is your
+//! summary:", "Let me know if you need anything else") or in a code fence.
+//! Steps that set `clean_output` get that stripped, so downstream steps chain
+//! on the content itself. Off by default: cleaning rewrites the output.
+//!
+//! ```yaml
+//! steps:
+//!   - name: extract
+//!     action: extract_json
+//!     clean_output: true          # built-in cleaning only
+//!   - name: summary
+//!     action: summarize
+//!     clean_output:               # built-ins plus extra patterns to remove
+//!       - "(?m)^Note:.*$"
+//! ```
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Leading "Sure! Here is the summary:" line
+const PREAMBLE: &str = r"(?i)\A\s*(?:(?:sure|certainly|of course|okay)[!,.]?\s+)?(?:here(?:\s+is|'s|\s+are)|below\s+is)\b[^\n]*:[ \t]*\r?\n";
+
+/// Trailing "Let me know if..." / "I hope this helps" line
+const TRAILER: &str = r"(?i)\n[ \t]*(?:let me know|i hope (?:this|that)|feel free to|if you (?:need|have|would like|want))[^\n]*\s*\z";
+
+/// Output that is entirely one fenced code block
+const FENCE: &str = r"(?s)\A\s*```[\w+-]*[ \t]*\r?\n(.*?)\r?\n[ \t]*```\s*\z";
+
+/// A step's `clean_output` setting: on/off, or extra patterns (which also
+/// turn cleaning on)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CleanOutput {
+    Enabled(bool),
+    Patterns(Vec<String>),
+}
+
+impl Default for CleanOutput {
+    fn default() -> Self {
+        Self::Enabled(false)
+    }
+}
+
+impl CleanOutput {
+    /// Build the cleaner for this setting, or `None` when cleaning is off
+    pub fn cleaner(&self) -> Result<Option<OutputCleaner>, regex::Error> {
+        match self {
+            Self::Enabled(false) => Ok(None),
+            Self::Enabled(true) => OutputCleaner::new(&[]).map(Some),
+            Self::Patterns(patterns) => OutputCleaner::new(patterns).map(Some),
+        }
+    }
+}
+
+/// Strips model chatter and unwraps code fences
+#[derive(Debug, Clone)]
+pub struct OutputCleaner {
+    preamble: Regex,
+    trailer: Regex,
+    fence: Regex,
+    extra: Vec<Regex>,
+}
+
+impl OutputCleaner {
+    /// A cleaner with the built-in rules plus `extra` patterns to remove
+    pub fn new(extra: &[String]) -> Result<Self, regex::Error> {
+        Ok(Self {
+            preamble: Regex::new(PREAMBLE)?,
+            trailer: Regex::new(TRAILER)?,
+            fence: Regex::new(FENCE)?,
+            extra: extra
+                .iter()
+                .map(|p| Regex::new(p))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Clean `output`: remove extra patterns, then the preamble and trailer,
+    /// then unwrap a code fence around the whole remainder
+    pub fn clean(&self, output: &str) -> String {
+        let mut cleaned = output.to_string();
+        for pattern in &self.extra {
+            cleaned = pattern.replace_all(&cleaned, "").into_owned();
+        }
+
+        cleaned = self.preamble.replace(&cleaned, "").into_owned();
+        cleaned = self.trailer.replace(&cleaned, "").into_owned();
+
+        if let Some(inner) = self.fence.captures(&cleaned).and_then(|c| c.get(1)) {
+            // Only a single block: a "```" line inside means several blocks
+            // with text between them, which isn't ours to unwrap
+            if !inner
+                .as_str()
+                .lines()
+                .any(|line| line.trim_start().starts_with("```"))
+            {
+                cleaned = inner.as_str().to_string();
+            }
+        }
+
+        cleaned.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean(output: &str) -> String {
+        OutputCleaner::new(&[]).unwrap().clean(output)
+    }
+
+    #[test]
+    fn test_unwraps_code_fence() {
+        assert_eq!(clean("```json\n{\"ok\": true}\n```\n"), "{\"ok\": true}");
+        assert_eq!(clean("```\nplain\n```"), "plain");
+        assert_eq!(
+            clean("Here is the JSON you asked for:\n```json\n[1, 2]\n```"),
+            "[1, 2]"
+        );
+
+        // Several blocks with prose between them are left alone
+        let mixed = "```\na\n```\nand\n```\nb\n```";
+        assert_eq!(clean(mixed), mixed);
+    }
+
+    #[test]
+    fn test_strips_preamble_and_trailer() {
+        assert_eq!(
+            clean("Sure! Here is your summary:\n\n- Point one\n- Point two\n\nLet me know if you need anything else."),
+            "- Point one\n- Point two"
+        );
+        assert_eq!(clean("Here's the result:\nbody"), "body");
+        assert_eq!(clean("body\nI hope this helps!"), "body");
+
+        // Content that merely mentions the phrases is kept
+        let content = "# Notes\nHere is where the argument turns: scale.\nMore.";
+        assert_eq!(clean(content), content);
+    }
+
+    #[test]
+    fn test_extra_patterns_and_setting() {
+        let cleaner = CleanOutput::Patterns(vec![r"(?m)^Note:.*\n?".to_string()])
+            .cleaner()
+            .unwrap()
+            .unwrap();
+        assert_eq!(cleaner.clean("Note: generated\nbody"), "body");
+
+        assert!(CleanOutput::default().cleaner().unwrap().is_none());
+        assert!(CleanOutput::Enabled(true).cleaner().unwrap().is_some());
+        assert!(CleanOutput::Patterns(vec!["(".to_string()])
+            .cleaner()
+            .is_err());
+    }
+}
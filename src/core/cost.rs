@@ -0,0 +1,277 @@
+//! Token and cost estimation for pipeline runs.
+//!
+//! Estimates are computed from the input size alone, without calling any
+//! adapter: each step's input is converted to tokens with a chars-per-token
+//! ratio, its output is assumed to be a fixed fraction of its input, and the
+//! configured per-action rates turn both into USD.
+//!
+//! Rates come from the `cost:` section of `.arkai/config.yaml`:
+//!
+//! ```yaml
+//! cost:
+//!   chars_per_token: 4.0
+//!   default:
+//!     input_per_1k: 0.003
+//!     output_per_1k: 0.015
+//!     output_ratio: 0.25
+//!   actions:
+//!     summarize:
+//!       output_ratio: 0.1
+//! ```
+//!
+//! `default` applies to Fabric steps without an `actions` entry; other
+//! adapters (e.g. shell) are counted at zero cost unless listed there.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::pipeline::{AdapterType, InputSource, Pipeline, Step};
+
+/// Per-action pricing
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ActionRate {
+    /// USD per 1,000 input tokens
+    pub input_per_1k: f64,
+    /// USD per 1,000 output tokens
+    pub output_per_1k: f64,
+    /// Expected output length as a fraction of input length
+    pub output_ratio: f64,
+}
+
+impl ActionRate {
+    /// A rate for steps that don't call a model
+    pub const FREE: Self = Self {
+        input_per_1k: 0.0,
+        output_per_1k: 0.0,
+        output_ratio: 1.0,
+    };
+}
+
+impl Default for ActionRate {
+    fn default() -> Self {
+        Self {
+            input_per_1k: 0.003,
+            output_per_1k: 0.015,
+            output_ratio: 0.25,
+        }
+    }
+}
+
+/// Configurable cost model (the `cost:` config section)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostModel {
+    /// Characters per token used to convert input size to tokens
+    pub chars_per_token: f64,
+    /// Rate for Fabric steps without an action-specific entry
+    pub default: ActionRate,
+    /// Rates keyed by step action (e.g. a Fabric pattern name)
+    pub actions: HashMap<String, ActionRate>,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self {
+            chars_per_token: 4.0,
+            default: ActionRate::default(),
+            actions: HashMap::new(),
+        }
+    }
+}
+
+/// Estimate for a single step
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepEstimate {
+    pub step: String,
+    pub action: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost_usd: f64,
+}
+
+/// Estimate for a whole run
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunEstimate {
+    pub steps: Vec<StepEstimate>,
+}
+
+impl RunEstimate {
+    /// Total input + output tokens across all steps
+    pub fn total_tokens(&self) -> u64 {
+        self.steps
+            .iter()
+            .map(|s| s.input_tokens + s.output_tokens)
+            .sum()
+    }
+
+    /// Total estimated cost in USD
+    pub fn total_cost_usd(&self) -> f64 {
+        self.steps.iter().map(|s| s.cost_usd).sum()
+    }
+}
+
+impl CostModel {
+    /// Rate applied to a step
+    pub fn rate_for(&self, step: &Step) -> ActionRate {
+        match self.actions.get(&step.action) {
+            Some(rate) => *rate,
+            None if step.adapter == AdapterType::Fabric => self.default,
+            None => ActionRate::FREE,
+        }
+    }
+
+    /// Convert a character count to tokens (rounded up)
+    pub fn tokens_for_chars(&self, chars: usize) -> u64 {
+        if self.chars_per_token <= 0.0 {
+            return chars as u64;
+        }
+        (chars as f64 / self.chars_per_token).ceil() as u64
+    }
+
+    /// Estimate a pipeline run over `input` without executing it. Disabled
+    /// steps don't run, so they cost nothing and aren't listed.
+    pub fn estimate(&self, pipeline: &Pipeline, input: &str) -> RunEstimate {
+        let input_chars = input.chars().count();
+        // Estimated output size of each step so far, for chained inputs
+        let mut output_chars: HashMap<&str, usize> = HashMap::new();
+        let mut last_output = input_chars;
+
+        let steps = pipeline
+            .steps
+            .iter()
+            .filter(|step| step.enabled)
+            .map(|step| {
+                let chars = match &step.input_from {
+                    InputSource::PipelineInput(_) => input_chars,
+                    InputSource::PreviousStep {
+                        previous_step: name,
+                    }
+                    | InputSource::Artifact { artifact: name } => output_chars
+                        .get(name.as_str())
+                        .copied()
+                        .unwrap_or(last_output),
+                    InputSource::Static { value } => match value {
+                        serde_json::Value::String(s) => s.chars().count(),
+                        other => other.to_string().chars().count(),
+                    },
+                    InputSource::Env { env, default } => std::env::var(env)
+                        .ok()
+                        .or_else(|| default.clone())
+                        .map(|v| v.chars().count())
+                        .unwrap_or(0),
+                    InputSource::Files { glob, .. } => glob_size(glob),
+                };
+
+                let rate = self.rate_for(step);
+                let out_chars = (chars as f64 * rate.output_ratio).ceil() as usize;
+                output_chars.insert(step.name.as_str(), out_chars);
+                last_output = out_chars;
+
+                let input_tokens = self.tokens_for_chars(chars);
+                let output_tokens = self.tokens_for_chars(out_chars);
+                StepEstimate {
+                    step: step.name.clone(),
+                    action: step.action.clone(),
+                    input_tokens,
+                    output_tokens,
+                    cost_usd: input_tokens as f64 / 1000.0 * rate.input_per_1k
+                        + output_tokens as f64 / 1000.0 * rate.output_per_1k,
+                }
+            })
+            .collect();
+
+        RunEstimate { steps }
+    }
+}
+
+/// Total size in bytes of files matching a glob (0 if the pattern is invalid)
+fn glob_size(pattern: &str) -> usize {
+    glob::glob(pattern)
+        .map(|paths| {
+            paths
+                .flatten()
+                .filter_map(|path| std::fs::metadata(path).ok())
+                .map(|meta| meta.len() as usize)
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pipeline(steps: usize) -> Pipeline {
+        let mut yaml = String::from("name: estimate\ndescription: d\nsteps:\n");
+        for i in 0..steps {
+            let input = if i == 0 {
+                "pipeline_input".to_string()
+            } else {
+                format!("{{previous_step: step{}}}", i - 1)
+            };
+            yaml.push_str(&format!(
+                "  - name: step{}\n    adapter: fabric\n    action: summarize\n    input_from: {}\n",
+                i, input
+            ));
+        }
+        Pipeline::from_yaml(&yaml).unwrap()
+    }
+
+    #[test]
+    fn test_estimate_scales_with_input_length() {
+        let model = CostModel::default();
+        let short = model.estimate(&pipeline(1), &"a".repeat(4_000));
+        let long = model.estimate(&pipeline(1), &"a".repeat(40_000));
+
+        assert_eq!(short.steps[0].input_tokens, 1_000);
+        assert_eq!(long.steps[0].input_tokens, 10_000);
+        assert!(long.total_cost_usd() > short.total_cost_usd() * 9.9);
+    }
+
+    #[test]
+    fn test_estimate_scales_with_step_count() {
+        let model = CostModel::default();
+        let input = "a".repeat(4_000);
+        let one = model.estimate(&pipeline(1), &input);
+        let three = model.estimate(&pipeline(3), &input);
+
+        assert_eq!(three.steps.len(), 3);
+        assert!(three.total_tokens() > one.total_tokens());
+        assert!(three.total_cost_usd() > one.total_cost_usd());
+        // Chained steps see the previous step's (smaller) output
+        assert!(three.steps[1].input_tokens < three.steps[0].input_tokens);
+    }
+
+    #[test]
+    fn test_estimate_skips_disabled_steps() {
+        let model = CostModel::default();
+        let input = "a".repeat(4_000);
+        let mut partly_disabled = pipeline(3);
+        partly_disabled.steps[2].enabled = false;
+
+        let estimate = model.estimate(&partly_disabled, &input);
+        assert_eq!(estimate.steps.len(), 2);
+        assert_eq!(
+            estimate.total_cost_usd(),
+            model.estimate(&pipeline(2), &input).total_cost_usd()
+        );
+    }
+
+    #[test]
+    fn test_action_rates_override_default() {
+        let mut model = CostModel::default();
+        model.actions.insert(
+            "summarize".to_string(),
+            ActionRate {
+                input_per_1k: 1.0,
+                output_per_1k: 0.0,
+                output_ratio: 0.0,
+            },
+        );
+
+        let estimate = model.estimate(&pipeline(1), &"a".repeat(4_000));
+        assert!((estimate.total_cost_usd() - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,260 @@
+//! Storage backends for run event logs.
+//!
+//! [`EventStore`] keeps artifacts, the run lock, signing and listeners, and
+//! hands the event log itself to an [`EventStoreBackend`]:
+//!
+//! - [`JsonlBackend`]: `events.jsonl` in the run directory (the default)
+//! - `SqliteBackend` (`sqlite` feature): rows in `runs/events.db` keyed by
+//!   `(run_id, seq)`, with an index on idempotency keys and a `runs` table
+//!   of summaries for listing
+//!
+//! The backend for new runs is chosen in `.arkai/config.yaml`:
+//!
+//! ```yaml
+//! storage: sqlite
+//! ```
+//!
+//! Runs that already have an `events.jsonl` keep using it, so runs logged
+//! before switching can still be read and resumed.
+//!
+//! [`EventStore`]: super::EventStore
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader};
+
+use crate::domain::{Event, EventType, Run, RunSummary};
+use crate::error::Result;
+
+use super::run_index::{RunIndex, INDEX_FILE};
+
+/// Where new runs store their events (`storage:` in config)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventStorage {
+    /// `events.jsonl` per run
+    #[default]
+    Jsonl,
+    /// `runs/events.db` (requires the `sqlite` feature)
+    Sqlite,
+}
+
+impl fmt::Display for EventStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventStorage::Jsonl => write!(f, "jsonl"),
+            EventStorage::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+/// One run's event log. Events are passed in already serialized, so the
+/// stored text is exactly what the signature chain covers.
+#[async_trait]
+pub trait EventStoreBackend: Send + Sync {
+    /// Where the log lives, for messages (`<location>:<seq>`)
+    fn location(&self) -> String;
+
+    /// Append `event`, serialized as `line`
+    async fn append(&self, event: &Event, line: &str) -> Result<()>;
+
+    /// Every serialized event in order, with its 1-based sequence number
+    async fn lines(&self) -> Result<Vec<(usize, String)>>;
+
+    /// Serialized events after sequence number `seen`
+    async fn lines_after(&self, seen: usize) -> Result<Vec<(usize, String)>> {
+        let mut lines = self.lines().await?;
+        lines.retain(|(seq, _)| *seq > seen);
+        Ok(lines)
+    }
+
+    /// Whether a `StepCompleted` event with `idempotency_key` was logged
+    async fn is_step_completed(&self, idempotency_key: &str) -> Result<bool>;
+
+    /// Summary of the run, from its events
+    async fn summary(&self) -> Result<Option<RunSummary>>;
+
+    /// Record `summary` as the run's entry in run listings
+    async fn record_summary(&self, summary: &RunSummary) -> Result<()>;
+}
+
+/// Parse one serialized event
+pub(crate) fn parse_event(line: &str) -> Result<Event> {
+    Ok(serde_json::from_str(line).with_context(|| format!("Failed to parse event: {}", line))?)
+}
+
+/// A run's summary from its first event and its last. When the last event
+/// [ends the run] it alone decides state and completion; otherwise (still
+/// running, or resumed) `replay` is called for the whole log.
+///
+/// [ends the run]: EventType::ends_run
+pub(crate) fn summarize(
+    first: &Event,
+    last: &Event,
+    replay: impl FnOnce() -> Result<Vec<Event>>,
+) -> Result<Option<RunSummary>> {
+    let run = if last.event_type.ends_run() {
+        let mut run = Run::from_events(std::slice::from_ref(first));
+        if let Some(run) = run.as_mut() {
+            run.apply_event(last);
+        }
+        run
+    } else {
+        Run::from_events(&replay()?)
+    };
+
+    Ok(run.as_ref().map(RunSummary::from))
+}
+
+/// `events.jsonl` in the run directory
+#[derive(Debug, Clone)]
+pub struct JsonlBackend {
+    path: PathBuf,
+}
+
+impl JsonlBackend {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The run index beside the run's directory
+    fn run_index(&self) -> Option<RunIndex> {
+        let runs_dir = self.path.parent()?.parent()?;
+        Some(RunIndex::new(runs_dir.join(INDEX_FILE)))
+    }
+}
+
+#[async_trait]
+impl EventStoreBackend for JsonlBackend {
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+
+    async fn append(&self, _event: &Event, line: &str) -> Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("Failed to open events file: {}", self.path.display()))?;
+
+        file.write_all(format!("{}\n", line).as_bytes())
+            .await
+            .context("Failed to write event")?;
+        file.flush().await.context("Failed to flush event")?;
+        Ok(())
+    }
+
+    async fn lines(&self) -> Result<Vec<(usize, String)>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let file = File::open(&self.path)
+            .await
+            .with_context(|| format!("Failed to open events file: {}", self.path.display()))?;
+
+        let mut lines = BufReader::new(file).lines();
+        let mut numbered = Vec::new();
+        let mut line_number = 0;
+
+        while let Some(line) = lines.next_line().await? {
+            line_number += 1;
+            if !line.trim().is_empty() {
+                numbered.push((line_number, line));
+            }
+        }
+
+        Ok(numbered)
+    }
+
+    async fn is_step_completed(&self, idempotency_key: &str) -> Result<bool> {
+        for (_, line) in self.lines().await? {
+            let event = parse_event(&line)?;
+            if event.idempotency_key == idempotency_key
+                && event.event_type == EventType::StepCompleted
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Reads the first and last lines of the log, and the rest only when the
+    /// last event doesn't end the run
+    async fn summary(&self) -> Result<Option<RunSummary>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let Some((first, last)) = first_and_last_lines(&self.path).await? else {
+            return Ok(None);
+        };
+        let (first, last) = (parse_event(&first)?, parse_event(&last)?);
+
+        let events = if last.event_type.ends_run() {
+            Vec::new()
+        } else {
+            self.lines().await?
+        };
+        summarize(&first, &last, || {
+            events.iter().map(|(_, line)| parse_event(line)).collect()
+        })
+    }
+
+    async fn record_summary(&self, summary: &RunSummary) -> Result<()> {
+        match self.run_index() {
+            Some(index) => index.upsert(summary).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// The first and last non-empty lines of a file, reading the end backwards
+/// in blocks rather than the whole file
+async fn first_and_last_lines(path: &Path) -> Result<Option<(String, String)>> {
+    const BLOCK: u64 = 8 * 1024;
+
+    let mut file = File::open(path)
+        .await
+        .with_context(|| format!("Failed to open events file: {}", path.display()))?;
+
+    let mut first = None;
+    let mut lines = BufReader::new(&mut file).lines();
+    while let Some(line) = lines.next_line().await? {
+        if !line.trim().is_empty() {
+            first = Some(line);
+            break;
+        }
+    }
+    let Some(first) = first else {
+        return Ok(None);
+    };
+
+    // Grow a tail buffer from the end until it holds a whole non-empty line
+    let mut end = file.seek(std::io::SeekFrom::End(0)).await?;
+    let mut tail: Vec<u8> = Vec::new();
+    loop {
+        let start = end.saturating_sub(BLOCK);
+        let mut block = vec![0; (end - start) as usize];
+        file.seek(std::io::SeekFrom::Start(start)).await?;
+        file.read_exact(&mut block).await?;
+        block.extend_from_slice(&tail);
+        tail = block;
+        end = start;
+
+        let text = String::from_utf8_lossy(&tail);
+        let trimmed = text.trim_end();
+        match trimmed.rfind('\n') {
+            Some(newline) if !trimmed[newline + 1..].trim().is_empty() => {
+                return Ok(Some((first, trimmed[newline + 1..].trim().to_string())));
+            }
+            _ if start == 0 => return Ok(Some((first, trimmed.trim().to_string()))),
+            _ => {}
+        }
+    }
+}
@@ -1,19 +1,47 @@
 //! Append-only event store with file-based persistence.
 //!
 //! Events are stored as newline-delimited JSON (JSONL) for simplicity
-//! and easy debugging/inspection.
+//! and easy debugging/inspection, or in SQLite with `storage: sqlite` (see
+//! [`event_backend`](super::event_backend)). Artifact content lives in the
+//! shared [`ObjectStore`]; the run's `artifacts/` directory holds
+//! `<name>.ref` references to it. Runs written before that keep inline
+//! `<name>.md` files, which are still read.
+//!
+//! A finished run's JSONL log can be compacted into a [`RunSnapshot`];
+//! replay then starts from the snapshot.
 
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use anyhow::{Context, Result};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
 use sha2::{Digest, Sha256};
-use tokio::fs::{self, File, OpenOptions};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
 use uuid::Uuid;
 
-use crate::domain::{Event, EventType};
+use crate::domain::{Event, EventType, Run, RunSummary};
+use crate::error::{ArkaiError, Result};
+
+use super::event_backend::{parse_event, EventStorage, EventStoreBackend, JsonlBackend};
+use super::objects::ObjectStore;
+use super::run_index::{RunIndex, INDEX_FILE};
+use super::signing::{EventSigner, SignatureMismatch};
+use super::snapshot::{replace_file, RunSnapshot, SNAPSHOT_FILE};
+
+/// Observer called synchronously with each event after it is appended
+pub type EventListener = Arc<dyn Fn(&Event) + Send + Sync>;
+
+/// Held while a process executes a run; released when dropped (or when the
+/// process dies)
+#[derive(Debug)]
+pub struct RunLock {
+    _file: std::fs::File,
+}
 
-/// File-based event store using JSONL format
+/// Event store for one run: its event log, artifacts and lock
 pub struct EventStore {
     /// Directory containing the run
     run_dir: PathBuf,
@@ -21,13 +49,55 @@ pub struct EventStore {
     /// Path to the events.jsonl file
     events_path: PathBuf,
 
+    /// Where the events are kept
+    backend: Box<dyn EventStoreBackend>,
+
     /// Path to artifacts directory
     artifacts_dir: PathBuf,
+
+    /// Content-addressed storage the artifact references point into
+    objects: ObjectStore,
+
+    /// Signs appended lines into events.sig when an HMAC key is configured
+    signer: Option<EventSigner>,
+
+    /// Notified of every appended event
+    listener: Option<EventListener>,
+
+    /// Serializes appends from concurrent steps; holds what the next append
+    /// needs from the last one
+    append_lock: Mutex<AppendState>,
+}
+
+/// What an append carries over from the previous one
+#[derive(Debug)]
+struct AppendState {
+    /// Timestamp of the last appended event
+    timestamp: DateTime<Utc>,
+
+    /// Last line of events.sig: `None` until the first signed append reads
+    /// it, then kept up to date so later appends don't reread the file
+    signature: Option<Option<String>>,
+}
+
+impl AppendState {
+    fn new() -> Self {
+        Self {
+            timestamp: DateTime::<Utc>::MIN_UTC,
+            signature: None,
+        }
+    }
 }
 
 impl EventStore {
-    /// Create or open an event store for a run
+    /// Create or open an event store for a run, with the configured storage
     pub async fn open(run_id: Uuid) -> Result<Self> {
+        Self::open_with_storage(run_id, crate::config::config()?.storage).await
+    }
+
+    /// Create or open an event store for a run. A run that already has an
+    /// events.jsonl keeps using it whatever `storage` is.
+    pub async fn open_with_storage(run_id: Uuid, storage: EventStorage) -> Result<Self> {
         let base_dir = Self::base_directory()?;
         let run_dir = base_dir.join(run_id.to_string());
         let artifacts_dir = run_dir.join("artifacts");
@@ -41,24 +111,82 @@ impl EventStore {
         })?;
 
         let events_path = run_dir.join("events.jsonl");
+        let backend: Box<dyn EventStoreBackend> = match storage {
+            EventStorage::Sqlite if !events_path.exists() => {
+                Box::new(open_sqlite(&base_dir, run_id)?)
+            }
+            _ => Box::new(JsonlBackend::new(events_path.clone())),
+        };
 
         Ok(Self {
             run_dir,
             events_path,
+            backend,
             artifacts_dir,
+            objects: ObjectStore::open()?,
+            signer: EventSigner::from_env(),
+            listener: None,
+            append_lock: Mutex::new(AppendState::new()),
         })
     }
 
+    /// Use an explicit signer instead of the one from `ARKAI_EVENT_HMAC_KEY`
+    pub fn with_signer(mut self, signer: Option<EventSigner>) -> Self {
+        self.signer = signer;
+        self
+    }
+
+    /// Notify `listener` of each event once it has been written
+    pub fn with_listener(mut self, listener: EventListener) -> Self {
+        self.listener = Some(listener);
+        self
+    }
+
     /// Get the base directory for all runs (~/.arkai/runs or $ARKAI_HOME/runs)
     pub fn base_directory() -> Result<PathBuf> {
-        crate::config::runs_dir()
+        Ok(crate::config::runs_dir()?)
     }
 
-    /// Get the path to the events file
+    /// Get the path to the events file (used by JSONL storage)
     pub fn events_path(&self) -> &Path {
         &self.events_path
     }
 
+    /// Where the event log is kept, for messages
+    pub fn location(&self) -> String {
+        self.backend.location()
+    }
+
+    /// Take the run's lock, failing if another process is executing it
+    pub fn lock(&self) -> Result<RunLock> {
+        let path = self.run_dir.join("run.lock");
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open run lock: {}", path.display()))?;
+        file.try_lock_exclusive().with_context(|| {
+            format!("Another process is executing this run ({})", path.display())
+        })?;
+        Ok(RunLock { _file: file })
+    }
+
+    /// Whether a process holds the run's lock, i.e. is executing it. Only
+    /// probes the lock: a run nobody holds stays unlocked.
+    pub fn is_locked(&self) -> bool {
+        let Ok(file) = std::fs::File::open(self.run_dir.join("run.lock")) else {
+            return false;
+        };
+        // A shared lock only conflicts with an executing run's exclusive one
+        file.try_lock_shared().is_err()
+    }
+
+    /// Get the path to the signature sidecar file
+    pub fn signatures_path(&self) -> PathBuf {
+        self.run_dir.join("events.sig")
+    }
+
     /// Get the run directory
     pub fn run_dir(&self) -> &Path {
         &self.run_dir
@@ -69,20 +197,37 @@ impl EventStore {
         &self.artifacts_dir
     }
 
-    /// Store an artifact to disk
-    pub async fn store_artifact(&self, step_name: &str, content: &str) -> Result<PathBuf> {
-        let artifact_path = self.artifacts_dir.join(format!("{}.md", step_name));
+    /// Get the object store artifact content is kept in
+    pub fn objects(&self) -> &ObjectStore {
+        &self.objects
+    }
 
-        fs::write(&artifact_path, content)
-            .await
-            .with_context(|| format!("Failed to write artifact: {}", artifact_path.display()))?;
+    /// Store an artifact as an object, referenced from `<file_name>.ref`.
+    /// Returns the path of the reference.
+    pub async fn store_artifact(&self, file_name: &str, content: &str) -> Result<PathBuf> {
+        let ref_path = self.artifacts_dir.join(format!("{}.ref", file_name));
+        self.objects.put_ref(&ref_path, content).await?;
+
+        // A re-run step replaces an inline artifact from before references
+        let inline_path = self.artifacts_dir.join(format!("{}.md", file_name));
+        if inline_path.exists() {
+            fs::remove_file(&inline_path)
+                .await
+                .with_context(|| format!("Failed to remove artifact: {}", inline_path.display()))?;
+        }
 
-        Ok(artifact_path)
+        Ok(ref_path)
     }
 
-    /// Load an artifact from disk
-    pub async fn load_artifact(&self, step_name: &str) -> Result<Option<String>> {
-        let artifact_path = self.artifacts_dir.join(format!("{}.md", step_name));
+    /// Load an artifact by file name, resolving its object reference or
+    /// reading an inline `<file_name>.md`
+    pub async fn load_artifact(&self, file_name: &str) -> Result<Option<String>> {
+        let ref_path = self.artifacts_dir.join(format!("{}.ref", file_name));
+        if ref_path.exists() {
+            return self.objects.resolve_ref(&ref_path).await.map(Some);
+        }
+
+        let artifact_path = self.artifacts_dir.join(format!("{}.md", file_name));
 
         if !artifact_path.exists() {
             return Ok(None);
@@ -95,6 +240,24 @@ impl EventStore {
         Ok(Some(content))
     }
 
+    /// Load the artifact a step produced, following a custom `artifact_name`
+    /// recorded on its `StepCompleted` event
+    pub async fn load_step_artifact(&self, step_name: &str) -> Result<Option<String>> {
+        let file_name = self
+            .history()
+            .await?
+            .into_iter()
+            .rev()
+            .find(|e| {
+                e.event_type == EventType::StepCompleted && e.step_id.as_deref() == Some(step_name)
+            })
+            .and_then(|e| e.payload)
+            .and_then(|payload| payload.get("artifact")?.as_str().map(String::from));
+
+        self.load_artifact(file_name.as_deref().unwrap_or(step_name))
+            .await
+    }
+
     /// List all artifacts in this run
     pub async fn list_artifacts(&self) -> Result<Vec<String>> {
         let mut artifacts = Vec::new();
@@ -107,8 +270,13 @@ impl EventStore {
 
         while let Some(entry) = entries.next_entry().await? {
             if let Some(name) = entry.file_name().to_str() {
-                if name.ends_with(".md") {
-                    artifacts.push(name.trim_end_matches(".md").to_string());
+                let artifact = name
+                    .strip_suffix(".ref")
+                    .or_else(|| name.strip_suffix(".md"));
+                if let Some(artifact) = artifact {
+                    if !artifacts.iter().any(|a| a == artifact) {
+                        artifacts.push(artifact.to_string());
+                    }
                 }
             }
         }
@@ -118,73 +286,266 @@ impl EventStore {
 
     /// Append an event to the log
     pub async fn append(&self, event: &Event) -> Result<()> {
+        // Write each event and its signature as a unit, and keep timestamps
+        // in log order when concurrent steps race to append
+        let mut state = self.append_lock.lock().await;
+        let restamped;
+        let event = if event.timestamp < state.timestamp {
+            restamped = Event {
+                timestamp: state.timestamp,
+                ..event.clone()
+            };
+            &restamped
+        } else {
+            event
+        };
+        state.timestamp = event.timestamp;
+
+        let json = serde_json::to_string(event).context("Failed to serialize event")?;
+        self.backend.append(event, &json).await?;
+
+        if let Some(ref signer) = self.signer {
+            self.append_signature(signer, &mut state, &json).await?;
+        }
+
+        if let Some(ref listener) = self.listener {
+            listener(event);
+        }
+
+        if event.event_type.ends_run() {
+            self.update_index().await;
+        }
+
+        Ok(())
+    }
+
+    /// The run index beside this run's directory
+    pub fn run_index(&self) -> Option<RunIndex> {
+        Some(RunIndex::new(self.run_dir.parent()?.join(INDEX_FILE)))
+    }
+
+    /// Record `summary` as this run's entry in run listings (the run index,
+    /// or the `runs` table with SQLite storage)
+    pub async fn record_summary(&self, summary: &RunSummary) -> Result<()> {
+        self.backend.record_summary(summary).await
+    }
+
+    /// Record this run's current summary in the run index. Failures are
+    /// logged, not returned: the index can always be rebuilt.
+    async fn update_index(&self) {
+        let result = match self.summary().await {
+            Ok(Some(summary)) => self.record_summary(&summary).await,
+            Ok(None) => Ok(()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to update run index: {}", e);
+        }
+    }
+
+    /// Append the chained signature for a just-written event line to events.sig
+    async fn append_signature(
+        &self,
+        signer: &EventSigner,
+        state: &mut AppendState,
+        line: &str,
+    ) -> Result<()> {
+        let sig_path = self.signatures_path();
+        let previous = match state.signature.take() {
+            Some(previous) => previous,
+            None => read_nonempty_lines(&sig_path).await?.pop(),
+        };
+        let signature = signer.sign(previous.as_deref(), line);
+
         let mut file = OpenOptions::new()
             .create(true)
             .append(true)
-            .open(&self.events_path)
+            .open(&sig_path)
             .await
-            .with_context(|| {
-                format!("Failed to open events file: {}", self.events_path.display())
-            })?;
-
-        let json = serde_json::to_string(event).context("Failed to serialize event")?;
-        file.write_all(format!("{}\n", json).as_bytes())
+            .with_context(|| format!("Failed to open signature file: {}", sig_path.display()))?;
+        file.write_all(format!("{}\n", signature).as_bytes())
+            .await
+            .context("Failed to write event signature")?;
+        file.flush()
             .await
-            .context("Failed to write event")?;
-        file.flush().await.context("Failed to flush event")?;
+            .context("Failed to flush event signature")?;
 
+        state.signature = Some(Some(signature));
         Ok(())
     }
 
-    /// Replay all events in order
+    /// Recompute the signature chain over events.jsonl and compare with events.sig
+    pub async fn verify_signatures(&self, signer: &EventSigner) -> Result<Vec<SignatureMismatch>> {
+        let lines: Vec<String> = self
+            .backend
+            .lines()
+            .await?
+            .into_iter()
+            .map(|(_, line)| line)
+            .collect();
+        let signatures = read_nonempty_lines(&self.signatures_path()).await?;
+
+        Ok(signer.verify_chain(&lines, &signatures))
+    }
+
+    /// Replay all events in order; for a compacted run, the events after
+    /// its snapshot
     pub async fn replay(&self) -> Result<Vec<Event>> {
-        if !self.events_path.exists() {
-            return Ok(Vec::new());
-        }
+        Ok(self
+            .replay_with_lines()
+            .await?
+            .into_iter()
+            .map(|(_, event)| event)
+            .collect())
+    }
 
-        let file = File::open(&self.events_path).await.with_context(|| {
-            format!("Failed to open events file: {}", self.events_path.display())
-        })?;
+    /// Replay all events in order, paired with their 1-based line numbers in
+    /// events.jsonl (sequence numbers with SQLite storage)
+    pub async fn replay_with_lines(&self) -> Result<Vec<(usize, Event)>> {
+        self.after_snapshot(parse_lines(self.backend.lines().await?)?)
+            .await
+    }
 
-        let reader = BufReader::new(file);
-        let mut lines = reader.lines();
-        let mut events = Vec::new();
+    /// Get the path to the run's snapshot, written by [`compact`](Self::compact)
+    pub fn snapshot_path(&self) -> PathBuf {
+        self.run_dir.join(SNAPSHOT_FILE)
+    }
 
-        while let Some(line) = lines.next_line().await? {
-            if line.trim().is_empty() {
-                continue;
-            }
-            let event: Event = serde_json::from_str(&line)
-                .with_context(|| format!("Failed to parse event: {}", line))?;
-            events.push(event);
-        }
+    /// The run's snapshot, if its log was compacted
+    pub async fn snapshot(&self) -> Result<Option<RunSnapshot>> {
+        RunSnapshot::load(&self.snapshot_path()).await
+    }
 
+    /// Drop events the snapshot already holds
+    async fn after_snapshot(&self, events: Vec<(usize, Event)>) -> Result<Vec<(usize, Event)>> {
+        Ok(match self.snapshot().await? {
+            Some(snapshot) => snapshot.tail(events, |(_, event)| event.id),
+            None => events,
+        })
+    }
+
+    /// The snapshot's `StepCompleted` events followed by the events after it
+    async fn history(&self) -> Result<Vec<Event>> {
+        let mut events = self
+            .snapshot()
+            .await?
+            .map(|snapshot| snapshot.step_completions)
+            .unwrap_or_default();
+        events.extend(self.replay().await?);
         Ok(events)
     }
 
+    /// Reconstruct the run from its snapshot, if any, and the events after it
+    pub async fn load_run(&self) -> Result<Option<Run>> {
+        let snapshot = self.snapshot().await?.map(|snapshot| snapshot.run);
+        Ok(Run::from_snapshot(snapshot, &self.replay().await?))
+    }
+
+    /// Summarize the run from the first and last events of its log, reading
+    /// the rest only when the last event doesn't end the run
+    pub async fn summary(&self) -> Result<Option<RunSummary>> {
+        if self.snapshot_path().exists() {
+            return Ok(self.load_run().await?.as_ref().map(RunSummary::from));
+        }
+        self.backend.summary().await
+    }
+
+    /// Events appended after the first `seen` lines of events.jsonl, for tailing
+    /// a live run. Pass the last returned line number back in to continue.
+    pub async fn events_after(&self, seen: usize) -> Result<Vec<(usize, Event)>> {
+        self.after_snapshot(parse_lines(self.backend.lines_after(seen).await?)?)
+            .await
+    }
+
     /// Check if a step is already completed (idempotency check)
     pub async fn is_step_completed(&self, idempotency_key: &str) -> Result<bool> {
+        if let Some(snapshot) = self.snapshot().await? {
+            let completed = snapshot
+                .step_completions
+                .iter()
+                .any(|e| e.idempotency_key == idempotency_key);
+            if completed {
+                return Ok(true);
+            }
+        }
+        self.backend.is_step_completed(idempotency_key).await
+    }
+
+    /// Fold a finished run's JSONL log into its snapshot, leaving an empty
+    /// log. Returns how many events were folded in, or `None` if there was
+    /// nothing to compact.
+    ///
+    /// Fails for runs that are executing or haven't finished, for signed
+    /// logs (the signature chain covers every line) and for SQLite storage.
+    pub async fn compact(&self) -> Result<Option<usize>> {
+        let _lock = self.lock()?;
+        if !self.events_path.exists() {
+            return Err(anyhow::anyhow!(
+                "Run has no events.jsonl to compact (only JSONL event logs can be compacted)"
+            )
+            .into());
+        }
+        if self.signatures_path().exists() {
+            return Err(anyhow::anyhow!(
+                "Signed event logs can't be compacted: events.sig covers every line"
+            )
+            .into());
+        }
+
+        let previous = self.snapshot().await?;
         let events = self.replay().await?;
+        let Some(last) = events.last() else {
+            return Ok(None);
+        };
+        let run = Run::from_snapshot(previous.as_ref().map(|s| s.run.clone()), &events)
+            .context("Failed to reconstruct run state")?;
+        if run.is_running() {
+            return Err(anyhow::anyhow!(
+                "Run {} hasn't finished; only finished runs can be compacted",
+                run.id
+            )
+            .into());
+        }
 
-        let completed = events.iter().any(|e| {
-            e.idempotency_key == idempotency_key && matches!(e.event_type, EventType::StepCompleted)
-        });
+        let (seq, mut step_completions) = match previous {
+            Some(previous) => (previous.seq, previous.step_completions),
+            None => (0, Vec::new()),
+        };
+        step_completions.extend(
+            events
+                .iter()
+                .filter(|e| e.event_type == EventType::StepCompleted)
+                .cloned(),
+        );
+        let snapshot = RunSnapshot {
+            seq: seq + events.len(),
+            last_event_id: last.id,
+            run,
+            step_completions,
+        };
 
-        Ok(completed)
+        // Snapshot first: until the log is replaced, replay skips the
+        // events the snapshot holds
+        snapshot.save(&self.snapshot_path()).await?;
+        replace_file(&self.events_path, b"").await?;
+
+        Ok(Some(events.len()))
     }
 
-    /// Find events matching a predicate
+    /// Find events matching a predicate, among the snapshot's
+    /// `StepCompleted` events and the events after it
     pub async fn find_events<F>(&self, predicate: F) -> Result<Vec<Event>>
     where
         F: Fn(&Event) -> bool,
     {
-        let events = self.replay().await?;
+        let events = self.history().await?;
         Ok(events.into_iter().filter(predicate).collect())
     }
 
-    /// Get the last event of a specific type
+    /// Get the last event of a specific type (for a compacted run, only
+    /// `StepCompleted` events are kept from before the snapshot)
     pub async fn last_event_of_type(&self, event_type: EventType) -> Result<Option<Event>> {
-        let events = self.replay().await?;
+        let events = self.history().await?;
         Ok(events
             .into_iter()
             .rev()
@@ -214,14 +575,147 @@ impl EventStore {
 
         Ok(runs)
     }
+
+    /// Summaries of `run_ids` for listing. JSONL runs come from the run
+    /// index; with SQLite storage, runs in the database come from its `runs`
+    /// table, falling back to their events for runs not yet recorded there.
+    pub async fn summaries(run_ids: &[Uuid]) -> Result<Vec<RunSummary>> {
+        let index = RunIndex::open()?;
+        if crate::config::config()?.storage != EventStorage::Sqlite {
+            return index.summaries(run_ids).await;
+        }
+
+        let base_dir = Self::base_directory()?;
+        let mut recorded = sqlite_summaries(&base_dir)?;
+        let mut summaries = Vec::with_capacity(run_ids.len());
+        let mut jsonl_runs = Vec::new();
+        for run_id in run_ids {
+            if let Some(summary) = recorded.remove(run_id) {
+                summaries.push(summary);
+            } else if base_dir
+                .join(run_id.to_string())
+                .join("events.jsonl")
+                .exists()
+            {
+                jsonl_runs.push(*run_id);
+            } else if let Ok(Some(summary)) = Self::open(*run_id).await?.summary().await {
+                summaries.push(summary);
+            }
+        }
+        summaries.extend(index.summaries(&jsonl_runs).await?);
+        Ok(summaries)
+    }
+
+    /// Resolve a full run ID or a unique prefix of one (e.g. the first 8
+    /// characters shown in run listings)
+    pub async fn resolve_run_id(id_or_prefix: &str) -> Result<Uuid> {
+        if let Ok(run_id) = Uuid::parse_str(id_or_prefix) {
+            return Ok(run_id);
+        }
+        match_run_id(&Self::list_runs().await?, id_or_prefix)
+    }
 }
 
-/// Generate an idempotency key for a step
+/// Find the single run ID in `candidates` starting with `prefix`
+fn match_run_id(candidates: &[Uuid], prefix: &str) -> Result<Uuid> {
+    let prefix = prefix.trim().to_lowercase();
+    if prefix.is_empty() {
+        return Err(ArkaiError::RunNotFound(prefix));
+    }
+
+    let matches: Vec<&Uuid> = candidates
+        .iter()
+        .filter(|id| id.to_string().starts_with(&prefix))
+        .collect();
+
+    match matches.as_slice() {
+        [run_id] => Ok(**run_id),
+        [] => Err(ArkaiError::RunNotFound(prefix)),
+        _ => Err(ArkaiError::AmbiguousRunId {
+            prefix,
+            count: matches.len(),
+        }),
+    }
+}
+
+/// Parse serialized events, keeping their line or sequence numbers
+fn parse_lines(lines: Vec<(usize, String)>) -> Result<Vec<(usize, Event)>> {
+    lines
+        .into_iter()
+        .map(|(number, line)| Ok((number, parse_event(&line)?)))
+        .collect()
+}
+
+#[cfg(feature = "sqlite")]
+fn open_sqlite(base_dir: &Path, run_id: Uuid) -> Result<super::sqlite_events::SqliteBackend> {
+    super::sqlite_events::SqliteBackend::open(
+        base_dir.join(super::sqlite_events::DATABASE_FILE),
+        run_id,
+    )
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn open_sqlite(_base_dir: &Path, _run_id: Uuid) -> Result<JsonlBackend> {
+    Err(sqlite_unavailable())
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_summaries(base_dir: &Path) -> Result<std::collections::HashMap<Uuid, RunSummary>> {
+    super::sqlite_events::SqliteBackend::summaries(
+        &base_dir.join(super::sqlite_events::DATABASE_FILE),
+    )
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn sqlite_summaries(_base_dir: &Path) -> Result<std::collections::HashMap<Uuid, RunSummary>> {
+    Err(sqlite_unavailable())
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn sqlite_unavailable() -> ArkaiError {
+    anyhow::anyhow!("`storage: sqlite` requires arkai built with the `sqlite` feature").into()
+}
+
+/// Read all non-empty lines of a file (empty if the file doesn't exist)
+async fn read_nonempty_lines(path: &Path) -> Result<Vec<String>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Generate the legacy idempotency key for a step (`{run_id}:{step}:{input_hash}`).
+///
+/// Runs are now keyed with [`generate_step_idempotency_key`]; this form is still
+/// checked so runs logged before step indices were added resume correctly.
 pub fn generate_idempotency_key(run_id: Uuid, step_name: &str, input: &str) -> String {
     let input_hash = hash_input(input);
     format!("{}:{}:{}", run_id, step_name, input_hash)
 }
 
+/// Generate the idempotency key for the step at `step_index`
+/// (`{run_id}:{step_index}:{step}:{input_hash}`).
+///
+/// The index keeps keys distinct even if two steps share a name and input.
+pub fn generate_step_idempotency_key(
+    run_id: Uuid,
+    step_index: usize,
+    step_name: &str,
+    input: &str,
+) -> String {
+    let input_hash = hash_input(input);
+    format!("{}:{}:{}:{}", run_id, step_index, step_name, input_hash)
+}
+
 /// Hash input content (first 16 chars of SHA256)
 pub fn hash_input(input: &str) -> String {
     let mut hasher = Sha256::new();
@@ -239,7 +733,7 @@ mod hex {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::StepStatus;
+    use crate::domain::{Run, RunState, StepStatus};
     use serde_json::json;
     use tempfile::TempDir;
 
@@ -256,7 +750,12 @@ mod tests {
         let store = EventStore {
             run_dir: run_dir.clone(),
             events_path: run_dir.join("events.jsonl"),
+            backend: Box::new(JsonlBackend::new(run_dir.join("events.jsonl"))),
             artifacts_dir,
+            objects: ObjectStore::new(temp_dir.path().join("objects")),
+            signer: None,
+            listener: None,
+            append_lock: Mutex::new(AppendState::new()),
         };
 
         (store, temp_dir)
@@ -394,6 +893,226 @@ mod tests {
         assert!(store.is_step_completed(&idem_key).await.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_artifacts_are_references_and_inline_still_load() {
+        let (store, _temp) = create_test_store().await;
+
+        let ref_path = store
+            .store_artifact("summary", "the summary")
+            .await
+            .unwrap();
+        assert!(ref_path.ends_with("summary.ref"));
+        assert_eq!(
+            store.load_artifact("summary").await.unwrap().as_deref(),
+            Some("the summary")
+        );
+
+        // A run from before references keeps its inline artifact
+        std::fs::write(store.artifacts_dir().join("legacy.md"), "old content").unwrap();
+        assert_eq!(
+            store.load_artifact("legacy").await.unwrap().as_deref(),
+            Some("old content")
+        );
+        assert_eq!(store.load_artifact("missing").await.unwrap(), None);
+
+        // Re-storing an inline artifact replaces it with a reference
+        store.store_artifact("legacy", "new content").await.unwrap();
+        assert!(!store.artifacts_dir().join("legacy.md").exists());
+        assert_eq!(
+            store.load_artifact("legacy").await.unwrap().as_deref(),
+            Some("new content")
+        );
+
+        let mut artifacts = store.list_artifacts().await.unwrap();
+        artifacts.sort();
+        assert_eq!(artifacts, vec!["legacy", "summary"]);
+    }
+
+    #[tokio::test]
+    async fn test_summary_matches_full_reconstruction() {
+        let (store, _temp) = create_test_store().await;
+        let run_id = Uuid::new_v4();
+        let event = |event_type, step: Option<&str>| {
+            Event::new(
+                run_id,
+                step.map(String::from),
+                event_type,
+                format!("{}:{:?}", run_id, event_type),
+                "event".to_string(),
+                StepStatus::Running,
+            )
+        };
+        async fn check(store: &EventStore) -> RunSummary {
+            let full = Run::from_events(&store.replay().await.unwrap()).unwrap();
+            let summary = store.summary().await.unwrap().unwrap();
+            assert_eq!(summary, RunSummary::from(&full));
+            summary
+        }
+
+        assert_eq!(store.summary().await.unwrap(), None);
+
+        store
+            .append(
+                &event(EventType::RunStarted, None)
+                    .with_payload(json!({ "pipeline": "big", "source": "test" })),
+            )
+            .await
+            .unwrap();
+        // Plenty of step events, the last one far longer than a read block
+        for i in 0..2000 {
+            store
+                .append(&event(
+                    EventType::StepCompleted,
+                    Some(&format!("step{}", i)),
+                ))
+                .await
+                .unwrap();
+        }
+        let mut failed = event(EventType::RunFailed, None);
+        failed.error = Some("x".repeat(20_000));
+        store.append(&failed).await.unwrap();
+
+        let summary = check(&store).await;
+        assert_eq!(summary.pipeline, "big");
+        assert!(matches!(summary.state, RunState::Failed { .. }));
+        assert!(summary.completed_at.is_some());
+
+        // Resumed after failing: the last line doesn't end the run
+        store
+            .append(&event(EventType::StepStarted, Some("step2000")))
+            .await
+            .unwrap();
+        check(&store).await;
+
+        store
+            .append(
+                &event(EventType::RunPartiallyCompleted, None)
+                    .with_payload(json!({ "failed_steps": ["step7"] })),
+            )
+            .await
+            .unwrap();
+        let summary = check(&store).await;
+        assert_eq!(
+            summary.state,
+            RunState::PartiallyCompleted {
+                failed_steps: vec!["step7".to_string()]
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compact_folds_log_into_snapshot() {
+        let (store, _temp) = create_test_store().await;
+        let run_id = Uuid::new_v4();
+        let event = |event_type, step: Option<&str>, key: &str| {
+            Event::new(
+                run_id,
+                step.map(String::from),
+                event_type,
+                format!("{}:{}", run_id, key),
+                "event".to_string(),
+                StepStatus::Running,
+            )
+        };
+        let started = event(EventType::RunStarted, None, "start")
+            .with_payload(json!({ "pipeline": "p", "source": "test" }));
+        store.append(&started).await.unwrap();
+        store
+            .append(&event(EventType::StepStarted, Some("step1"), "step1"))
+            .await
+            .unwrap();
+        store
+            .append(&event(EventType::StepCompleted, Some("step1"), "step1"))
+            .await
+            .unwrap();
+        let mut failed = event(EventType::RunFailed, None, "failed");
+        failed.error = Some("boom".to_string());
+        store.append(&failed).await.unwrap();
+
+        let full_log = std::fs::read_to_string(&store.events_path).unwrap();
+        let mut events = store.replay().await.unwrap();
+        let full = RunSummary::from(&Run::from_events(&events).unwrap());
+
+        assert_eq!(store.compact().await.unwrap(), Some(4));
+        assert!(store.replay().await.unwrap().is_empty());
+        assert_eq!(std::fs::read_to_string(&store.events_path).unwrap(), "");
+        assert_eq!(store.summary().await.unwrap(), Some(full.clone()));
+        assert_eq!(
+            RunSummary::from(&store.load_run().await.unwrap().unwrap()),
+            full
+        );
+        assert!(store
+            .is_step_completed(&format!("{}:step1", run_id))
+            .await
+            .unwrap());
+        assert!(store
+            .last_event_of_type(EventType::StepCompleted)
+            .await
+            .unwrap()
+            .is_some());
+        assert_eq!(store.compact().await.unwrap(), None);
+
+        // Events appended later apply on top of the snapshot
+        let resumed = event(EventType::StepStarted, Some("step2"), "step2");
+        store.append(&resumed).await.unwrap();
+        events.push(resumed.clone());
+        let summary = store.summary().await.unwrap().unwrap();
+        assert_eq!(
+            summary,
+            RunSummary::from(&Run::from_events(&events).unwrap())
+        );
+
+        // Crash after the snapshot was written but before the log was
+        // replaced: the folded events are skipped, not applied twice
+        std::fs::write(
+            &store.events_path,
+            format!("{}{}\n", full_log, serde_json::to_string(&resumed).unwrap()),
+        )
+        .unwrap();
+        let events = store.replay().await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].id, resumed.id);
+        assert_eq!(store.summary().await.unwrap(), Some(summary));
+
+        // Unfinished runs aren't compacted
+        store
+            .append(&event(EventType::RunStarted, None, "restart"))
+            .await
+            .unwrap();
+        assert_eq!(
+            store.summary().await.unwrap().unwrap().state,
+            RunState::Running
+        );
+        assert!(store.compact().await.is_err());
+    }
+
+    #[test]
+    fn test_match_run_id_prefix() {
+        let a = Uuid::parse_str("1a2b3c4d-0000-4000-8000-000000000001").unwrap();
+        let b = Uuid::parse_str("1a2b9999-0000-4000-8000-000000000002").unwrap();
+        let runs = [a, b];
+
+        assert_eq!(match_run_id(&runs, "1a2b3c").unwrap(), a);
+        assert_eq!(match_run_id(&runs, "1A2B99").unwrap(), b);
+        assert!(match_run_id(&runs, "1a2b")
+            .unwrap_err()
+            .to_string()
+            .contains("ambiguous"));
+        assert!(match_run_id(&runs, "ffff").is_err());
+        assert!(match_run_id(&runs, "").is_err());
+    }
+
+    #[test]
+    fn test_step_idempotency_key_includes_index() {
+        let run_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let first = generate_step_idempotency_key(run_id, 0, "summarize", "same input");
+        let second = generate_step_idempotency_key(run_id, 1, "summarize", "same input");
+
+        assert_ne!(first, second);
+        assert!(first.starts_with("550e8400-e29b-41d4-a716-446655440000:0:summarize:"));
+        assert_eq!(first.split(':').nth(3).unwrap().len(), 16);
+    }
+
     #[test]
     fn test_idempotency_key_format() {
         let run_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
@@ -418,4 +1137,108 @@ mod tests {
         assert_ne!(hash1, hash3);
         assert_eq!(hash1.len(), 16); // 8 bytes = 16 hex chars
     }
+
+    #[tokio::test]
+    async fn test_signed_log_verifies_and_detects_tampering() {
+        let (store, _temp) = create_test_store().await;
+        let signer = EventSigner::new("audit-key");
+        let store = store.with_signer(Some(signer.clone()));
+        let run_id = Uuid::new_v4();
+
+        for i in 0..3 {
+            let event = Event::new(
+                run_id,
+                Some(format!("step{}", i)),
+                EventType::StepStarted,
+                format!("{}:step{}:abc", run_id, i),
+                format!("Step {} started", i),
+                StepStatus::Running,
+            );
+            store.append(&event).await.unwrap();
+        }
+
+        // Untouched log verifies
+        assert!(store.verify_signatures(&signer).await.unwrap().is_empty());
+
+        // A different key does not
+        let wrong = EventSigner::new("other-key");
+        assert_eq!(store.verify_signatures(&wrong).await.unwrap().len(), 3);
+
+        // Editing one event line breaks verification at that line
+        let content = std::fs::read_to_string(store.events_path()).unwrap();
+        std::fs::write(
+            store.events_path(),
+            content.replace("Step 1 started", "Step 1 tampered"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            store.verify_signatures(&signer).await.unwrap(),
+            vec![SignatureMismatch::Invalid { index: 1 }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reopened_store_continues_signature_chain() {
+        let (store, temp) = create_test_store().await;
+        let signer = EventSigner::new("audit-key");
+        let store = store.with_signer(Some(signer.clone()));
+        let run_id = Uuid::new_v4();
+        let event = |i: usize| {
+            Event::new(
+                run_id,
+                Some(format!("step{}", i)),
+                EventType::StepStarted,
+                format!("{}:step{}:abc", run_id, i),
+                format!("Step {} started", i),
+                StepStatus::Running,
+            )
+        };
+        store.append(&event(0)).await.unwrap();
+        store.append(&event(1)).await.unwrap();
+
+        // A new store over the same log picks the chain up from events.sig
+        let reopened = EventStore {
+            run_dir: store.run_dir.clone(),
+            events_path: store.events_path.clone(),
+            backend: Box::new(JsonlBackend::new(store.events_path.clone())),
+            artifacts_dir: store.artifacts_dir.clone(),
+            objects: ObjectStore::new(temp.path().join("objects")),
+            signer: Some(signer.clone()),
+            listener: None,
+            append_lock: Mutex::new(AppendState::new()),
+        };
+        reopened.append(&event(2)).await.unwrap();
+        reopened.append(&event(3)).await.unwrap();
+
+        assert!(reopened
+            .verify_signatures(&signer)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            read_nonempty_lines(&store.signatures_path())
+                .await
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_store_writes_no_signatures() {
+        let (store, _temp) = create_test_store().await;
+        let run_id = Uuid::new_v4();
+        let event = Event::new(
+            run_id,
+            None,
+            EventType::RunStarted,
+            format!("{}:start", run_id),
+            "Run started".to_string(),
+            StepStatus::Running,
+        );
+        store.append(&event).await.unwrap();
+
+        assert!(!store.signatures_path().exists());
+    }
 }
@@ -2,17 +2,54 @@
 //!
 //! This module contains:
 //! - EventStore: Append-only event logging
+//! - EventBackend: JSONL or SQLite storage for event logs
+//! - Objects: Content-addressed artifact storage
+//! - RunIndex: Run summaries for fast listing
 //! - Pipeline: Pipeline definitions and loading
 //! - Safety: Safety limits and enforcement
+//! - Cost: Token and cost estimation
+//! - RateLimit: Throttling Fabric calls to a configured rate
+//! - Clean: Stripping model chatter from step output
+//! - Signing: Optional HMAC chain over event log lines
+//! - Snapshot: Compacted event logs
+//! - Template: Minimal `{{name}}` substitution
 //! - Orchestrator: Main execution engine
 
+pub mod clean;
+pub mod cost;
+pub mod event_backend;
 pub mod event_store;
+pub mod objects;
 pub mod orchestrator;
 pub mod pipeline;
+pub mod rate_limit;
+pub mod run_index;
 pub mod safety;
+pub mod signing;
+pub mod snapshot;
+#[cfg(feature = "sqlite")]
+pub mod sqlite_events;
+pub mod template;
 
 // Re-export commonly used types
-pub use event_store::{generate_idempotency_key, hash_input, EventStore};
-pub use orchestrator::Orchestrator;
-pub use pipeline::{AdapterType, InputSource, Pipeline, RetryPolicy, Step};
-pub use safety::{SafetyLimits, SafetyTracker, SafetyViolation};
+pub use clean::{CleanOutput, OutputCleaner};
+pub use cost::{ActionRate, CostModel, RunEstimate, StepEstimate};
+pub use event_backend::{EventStorage, EventStoreBackend, JsonlBackend};
+pub use event_store::{
+    generate_idempotency_key, generate_step_idempotency_key, hash_input, EventListener, EventStore,
+    RunLock,
+};
+pub use objects::ObjectStore;
+pub use orchestrator::{Orchestrator, StepProgress, StepProgressKind};
+pub use pipeline::{
+    AdapterType, EvidenceSpec, InputSource, OutputFormat, OutputSchema, Pipeline, RetryPolicy,
+    Step, StepPlan, TimeoutOverrides, ACTION_LIBRARY_STORE,
+};
+pub use rate_limit::{RateLimit, RateLimiter, ThrottleState};
+pub use run_index::RunIndex;
+pub use safety::{SafetyDefaults, SafetyLimits, SafetyTracker, SafetyViolation};
+pub use signing::{EventSigner, SignatureMismatch};
+pub use snapshot::RunSnapshot;
+#[cfg(feature = "sqlite")]
+pub use sqlite_events::SqliteBackend;
+pub use template::TemplateError;
@@ -0,0 +1,145 @@
+//! Content-addressed object storage for run artifacts.
+//!
+//! Artifact content is written once to `~/.arkai/objects/<sha256>` and each
+//! run directory holds a small `<name>.ref` file naming the object, so runs
+//! that produce identical output (the same fetched transcript, say) share a
+//! single copy on disk. Objects are immutable: a write of content that
+//! already exists is a no-op.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use sha2::{Digest, Sha256};
+use tokio::fs;
+use uuid::Uuid;
+
+use crate::error::Result;
+
+/// Prefix of the digest line in a `.ref` file
+const REF_PREFIX: &str = "sha256:";
+
+/// Directory of objects named by the SHA-256 of their content
+#[derive(Debug, Clone)]
+pub struct ObjectStore {
+    dir: PathBuf,
+}
+
+impl ObjectStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The objects directory in arkai home
+    pub fn open() -> Result<Self> {
+        Ok(Self::new(crate::config::objects_dir()?))
+    }
+
+    /// Get the objects directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Hex SHA-256 digest of `content`, the object's name
+    pub fn digest(content: &[u8]) -> String {
+        hex::encode(Sha256::digest(content))
+    }
+
+    /// Path of the object with `digest`
+    pub fn object_path(&self, digest: &str) -> PathBuf {
+        self.dir.join(digest)
+    }
+
+    /// Store `content`, returning its digest. Content already present is
+    /// not rewritten.
+    pub async fn put(&self, content: &[u8]) -> Result<String> {
+        let digest = Self::digest(content);
+        let path = self.object_path(&digest);
+        if path.exists() {
+            return Ok(digest);
+        }
+
+        fs::create_dir_all(&self.dir).await.with_context(|| {
+            format!("Failed to create objects directory: {}", self.dir.display())
+        })?;
+        // Write then rename, so a reader never sees a partial object
+        let tmp = self.dir.join(format!(".{}.{}.tmp", digest, Uuid::new_v4()));
+        fs::write(&tmp, content)
+            .await
+            .with_context(|| format!("Failed to write object: {}", tmp.display()))?;
+        fs::rename(&tmp, &path)
+            .await
+            .with_context(|| format!("Failed to store object: {}", path.display()))?;
+        Ok(digest)
+    }
+
+    /// Read the object with `digest`
+    pub async fn get(&self, digest: &str) -> Result<String> {
+        let path = self.object_path(digest);
+        let content = fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("Failed to read object: {}", path.display()))?;
+        Ok(content)
+    }
+
+    /// Store `content` and write a reference to it at `ref_path`
+    pub async fn put_ref(&self, ref_path: &Path, content: &str) -> Result<String> {
+        let digest = self.put(content.as_bytes()).await?;
+        fs::write(ref_path, format!("{}{}\n", REF_PREFIX, digest))
+            .await
+            .with_context(|| format!("Failed to write reference: {}", ref_path.display()))?;
+        Ok(digest)
+    }
+
+    /// Read the content referenced by the file at `ref_path`
+    pub async fn resolve_ref(&self, ref_path: &Path) -> Result<String> {
+        let reference = fs::read_to_string(ref_path)
+            .await
+            .with_context(|| format!("Failed to read reference: {}", ref_path.display()))?;
+        let digest = parse_ref(&reference)
+            .ok_or_else(|| anyhow::anyhow!("Invalid object reference in {}", ref_path.display()))?;
+        self.get(digest).await
+    }
+}
+
+/// The digest named by the contents of a `.ref` file
+fn parse_ref(reference: &str) -> Option<&str> {
+    let digest = reference.trim().strip_prefix(REF_PREFIX)?;
+    (digest.len() == 64 && digest.bytes().all(|b| b.is_ascii_hexdigit())).then_some(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_identical_content_is_stored_once() {
+        let temp = TempDir::new().unwrap();
+        let objects = ObjectStore::new(temp.path().join("objects"));
+
+        let first = objects
+            .put_ref(&temp.path().join("a.ref"), "same output")
+            .await
+            .unwrap();
+        let second = objects
+            .put_ref(&temp.path().join("b.ref"), "same output")
+            .await
+            .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(std::fs::read_dir(objects.dir()).unwrap().count(), 1);
+
+        assert_eq!(
+            objects
+                .resolve_ref(&temp.path().join("b.ref"))
+                .await
+                .unwrap(),
+            "same output"
+        );
+
+        std::fs::write(temp.path().join("bad.ref"), "sha256:nope").unwrap();
+        assert!(objects
+            .resolve_ref(&temp.path().join("bad.ref"))
+            .await
+            .is_err());
+    }
+}
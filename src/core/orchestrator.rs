@@ -3,27 +3,139 @@
 //! Coordinates step execution, event logging, retry handling,
 //! and safety limit enforcement.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::process::Stdio;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result};
+use futures_util::future::join_all;
+use futures_util::TryStreamExt;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::process::Command;
-use tracing::{debug, error, info, instrument, warn};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, error, info, instrument, warn, Span};
 use uuid::Uuid;
 
-use crate::adapters::{Adapter, AdapterOutput, FabricAdapter};
-use crate::domain::{Artifact, Event, EventType, Run, StepStatus};
-
-use super::event_store::{generate_idempotency_key, EventStore};
-use super::pipeline::{AdapterType, InputSource, Pipeline, Step};
+use crate::adapters::{Adapter, AdapterOutput, CircuitBreaker, CircuitState, FabricAdapter};
+use crate::domain::environment::ENVIRONMENT_KEY;
+use crate::domain::{
+    Artifact, EnvironmentSnapshot, Event, EventType, Run, RunState, RunSummary, StepStatus,
+};
+use crate::error::{ArkaiError, Result as ArkaiResult};
+use crate::evidence::{ground_claim, parse_extractor_output, Status};
+use crate::library::{ContentId, LibraryContent};
+
+use super::event_store::{
+    generate_idempotency_key, generate_step_idempotency_key, EventListener, EventStore,
+};
+use super::pipeline::{
+    AdapterType, EvidenceSpec, InputSource, Pipeline, Step, StepPlan, ACTION_LIBRARY_STORE,
+};
+use super::rate_limit::{RateLimiter, ThrottleState};
 use super::safety::{SafetyLimits, SafetyTracker, SafetyViolation};
 
+/// Fail clearly when `step` takes its input from a disabled step, rather
+/// than with a missing-artifact error
+fn check_input_enabled(pipeline: &Pipeline, step: &Step) -> Result<()> {
+    match pipeline.disabled_input(step) {
+        Some(disabled) => Err(anyhow::anyhow!(
+            "Step '{}' takes its input from step '{}', which is disabled",
+            step.name,
+            disabled
+        )),
+        None => Ok(()),
+    }
+}
+
+/// What a run's steps have produced so far
+struct ExecutionState {
+    /// Artifacts of finished steps, by step name
+    artifacts: HashMap<String, Artifact>,
+    tracker: SafetyTracker,
+    /// `continue_on_error` steps that failed
+    failed_steps: Vec<String>,
+}
+
+impl ExecutionState {
+    fn new(artifacts: HashMap<String, Artifact>) -> Self {
+        Self {
+            artifacts,
+            tracker: SafetyTracker::new(),
+            failed_steps: Vec::new(),
+        }
+    }
+}
+
+/// Why a run stops before finishing its steps
+enum Halt {
+    Failed(anyhow::Error),
+    SafetyLimit(SafetyViolation),
+    Cancelled,
+}
+
+/// A step starting or finishing, reported by
+/// [`Orchestrator::run_pipeline_with_progress`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepProgress {
+    /// Step name
+    pub step: String,
+    /// 0-based position of the step in the pipeline
+    pub index: usize,
+    /// Number of steps in the pipeline
+    pub total: usize,
+    /// What happened
+    pub kind: StepProgressKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepProgressKind {
+    Started,
+    Completed,
+    Failed,
+}
+
+impl StepProgress {
+    fn started(step: &Step, index: usize, total: usize) -> Self {
+        Self {
+            step: step.name.clone(),
+            index,
+            total,
+            kind: StepProgressKind::Started,
+        }
+    }
+
+    fn finished(step: &Step, index: usize, total: usize, succeeded: bool) -> Self {
+        Self {
+            kind: if succeeded {
+                StepProgressKind::Completed
+            } else {
+                StepProgressKind::Failed
+            },
+            ..Self::started(step, index, total)
+        }
+    }
+}
+
+/// Receives chunks of a step's output as they are produced
+type OutputSink = dyn Fn(&str) + Send + Sync;
+
 /// Main pipeline orchestrator
 pub struct Orchestrator {
-    /// Fabric adapter for pattern execution
-    fabric_adapter: FabricAdapter,
+    /// Adapter for `adapter: fabric` steps
+    fabric_adapter: Arc<dyn Adapter>,
+
+    /// Cancels runs between steps and interrupts the in-flight step
+    cancel: Option<CancellationToken>,
+
+    /// Receives the final step's Fabric output as it is produced
+    output_stream: Option<Arc<OutputSink>>,
+
+    /// Throttles `adapter: fabric` calls
+    rate_limiter: Option<Arc<RateLimiter>>,
+
+    /// Fails steps fast while their adapter keeps failing
+    circuit_breakers: HashMap<AdapterType, Arc<CircuitBreaker>>,
 }
 
 impl Default for Orchestrator {
@@ -35,24 +147,165 @@ impl Default for Orchestrator {
 impl Orchestrator {
     /// Create a new orchestrator
     pub fn new() -> Self {
+        let fabric_breaker = crate::config::config()
+            .map(|config| config.fabric_circuit_breaker)
+            .unwrap_or_default();
+        let circuit_breakers = CircuitBreaker::from_config("fabric", &fabric_breaker)
+            .map(|breaker| (AdapterType::Fabric, Arc::new(breaker)))
+            .into_iter()
+            .collect();
+
         Self {
-            fabric_adapter: FabricAdapter::new(),
+            fabric_adapter: Arc::new(FabricAdapter::new()),
+            cancel: None,
+            output_stream: None,
+            rate_limiter: RateLimiter::shared_fabric(),
+            circuit_breakers,
         }
     }
 
+    /// Run `adapter: fabric` steps through `adapter` instead of the Fabric
+    /// CLI (e.g. a stub in tests)
+    pub fn with_fabric_adapter(mut self, adapter: impl Adapter + 'static) -> Self {
+        self.fabric_adapter = Arc::new(adapter);
+        self
+    }
+
+    /// Versions and platform recorded in each run's `RunStarted` event
+    pub async fn environment_snapshot(&self) -> EnvironmentSnapshot {
+        EnvironmentSnapshot {
+            fabric_binary: self.fabric_adapter.binary_path(),
+            fabric_version: self.fabric_adapter.version().await,
+            ..EnvironmentSnapshot::current()
+        }
+    }
+
+    /// Cancel runs started by this orchestrator when `token` is cancelled.
+    ///
+    /// Cancellation is checked before each step and interrupts the step in
+    /// flight; the run ends in `RunState::Cancelled` and can be resumed.
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Pass the final step's output to `sink` chunk by chunk as Fabric
+    /// produces it, for printing live.
+    ///
+    /// Only the first attempt streams, and not at all when the final step
+    /// cleans or validates its output (see [`Step::post_processes_output`]),
+    /// so what was streamed may not be the accepted artifact; compare the
+    /// two before treating the output as shown.
+    pub fn with_output_stream(mut self, sink: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.output_stream = Some(Arc::new(sink));
+        self
+    }
+
+    /// Throttle `adapter: fabric` calls with `limiter` instead of the one
+    /// configured in `fabric.rate_limit` (`None` to not throttle)
+    pub fn with_rate_limiter(mut self, limiter: Option<Arc<RateLimiter>>) -> Self {
+        self.rate_limiter = limiter;
+        self
+    }
+
+    /// Guard `adapter` steps with `breaker` instead of the one configured
+    /// (`None` to never fail fast). Fabric has a breaker by default, from
+    /// `fabric.circuit_breaker`; shell steps have none.
+    pub fn with_circuit_breaker(
+        mut self,
+        adapter: AdapterType,
+        breaker: Option<Arc<CircuitBreaker>>,
+    ) -> Self {
+        match breaker {
+            Some(breaker) => self.circuit_breakers.insert(adapter, breaker),
+            None => self.circuit_breakers.remove(&adapter),
+        };
+        self
+    }
+
+    /// State of the circuit breaker for `adapter`, if it has one
+    pub fn circuit_state(&self, adapter: AdapterType) -> Option<CircuitState> {
+        self.circuit_breakers
+            .get(&adapter)
+            .map(|breaker| breaker.state())
+    }
+
+    /// State of the Fabric rate limiter, if one is configured
+    pub fn throttle_state(&self) -> Option<ThrottleState> {
+        self.rate_limiter.as_ref().map(|limiter| limiter.state())
+    }
+
     /// Execute a pipeline with the given input
-    #[instrument(skip(self, pipeline, input), fields(pipeline = %pipeline.name))]
-    pub async fn run_pipeline(&self, pipeline: &Pipeline, input: String) -> Result<Run> {
-        let run_id = Uuid::new_v4();
+    pub async fn run_pipeline(&self, pipeline: &Pipeline, input: String) -> ArkaiResult<Run> {
+        self.run_pipeline_with_listener(pipeline, input, |_| {})
+            .await
+    }
+
+    /// Execute a pipeline, calling `listener` synchronously with each event
+    /// as it is appended to the run's event log
+    pub async fn run_pipeline_with_listener(
+        &self,
+        pipeline: &Pipeline,
+        input: String,
+        listener: impl Fn(&Event) + Send + Sync + 'static,
+    ) -> ArkaiResult<Run> {
+        Ok(self
+            .execute_run(Uuid::new_v4(), pipeline, input, None, Arc::new(listener))
+            .await?)
+    }
+
+    /// Execute a pipeline, calling `progress` as each step starts and finishes
+    pub async fn run_pipeline_with_progress(
+        &self,
+        pipeline: &Pipeline,
+        input: String,
+        progress: &(dyn Fn(&StepProgress) + Send + Sync),
+    ) -> ArkaiResult<Run> {
+        Ok(self
+            .execute_run(
+                Uuid::new_v4(),
+                pipeline,
+                input,
+                Some(progress),
+                Arc::new(|_| {}),
+            )
+            .await?)
+    }
+
+    /// Execute a pipeline under a caller-chosen run ID (e.g. one recorded in a
+    /// batch manifest before the run starts)
+    pub async fn run_pipeline_with_id(
+        &self,
+        run_id: Uuid,
+        pipeline: &Pipeline,
+        input: String,
+    ) -> ArkaiResult<Run> {
+        Ok(self
+            .execute_run(run_id, pipeline, input, None, Arc::new(|_| {}))
+            .await?)
+    }
+
+    #[instrument(
+        name = "run_pipeline",
+        skip(self, pipeline, input, progress, listener),
+        fields(pipeline = %pipeline.name)
+    )]
+    async fn execute_run(
+        &self,
+        run_id: Uuid,
+        pipeline: &Pipeline,
+        input: String,
+        progress: Option<&(dyn Fn(&StepProgress) + Send + Sync)>,
+        listener: EventListener,
+    ) -> Result<Run> {
         info!(%run_id, "Starting pipeline execution");
 
         // Create event store for this run
-        let store = EventStore::open(run_id).await?;
+        let store = EventStore::open(run_id).await?.with_listener(listener);
+        let _lock = store.lock()?;
 
         // Initialize run state
         let mut run = Run::new(run_id, pipeline.name.clone(), input.clone());
-        let mut tracker = SafetyTracker::new();
-        let mut artifacts: HashMap<String, Artifact> = HashMap::new();
 
         // Log run start
         let start_event = Event::new(
@@ -62,126 +315,360 @@ impl Orchestrator {
             format!("{}:start", run_id),
             format!("Pipeline '{}' started", pipeline.name),
             StepStatus::Running,
-        );
+        )
+        .with_payload(serde_json::json!({
+            "pipeline": pipeline.name,
+            ENVIRONMENT_KEY: self.environment_snapshot().await,
+        }));
         store.append(&start_event).await?;
 
-        // Execute each step
-        for (step_idx, step) in pipeline.steps.iter().enumerate() {
-            run.current_step = step_idx;
-
-            // Safety check before each step
-            if let Err(violation) = pipeline.safety_limits.check(&tracker) {
-                return self
-                    .handle_safety_violation(&store, &mut run, violation)
-                    .await;
-            }
-
-            // Resolve input for this step
-            let step_input = self.resolve_input(&input, &artifacts, step)?;
-
-            // Validate input
-            pipeline.safety_limits.validate_input(&step_input, None)?;
+        let mut state = ExecutionState::new(HashMap::new());
+        let pending = (0..pipeline.steps.len()).collect();
+        if let Some(ended) = self
+            .execute_steps(
+                &store, &mut run, pipeline, &input, pending, &mut state, progress,
+            )
+            .await?
+        {
+            return Ok(ended);
+        }
 
-            // Execute step with retry
-            match self
-                .execute_step_with_retry(
-                    &store,
-                    &mut run,
-                    step,
-                    &step_input,
-                    &pipeline.safety_limits,
-                    &mut tracker,
-                )
-                .await
-            {
-                Ok(artifact) => {
-                    artifacts.insert(step.name.clone(), artifact.clone());
-                    run.artifacts.insert(step.name.clone(), artifact);
-                    tracker.record_step(step_input.len() as u64, 0);
-                }
-                Err(e) => {
-                    return self.handle_run_failure(&store, &mut run, e).await;
-                }
-            }
+        if let Some(ref spec) = pipeline.evidence {
+            self.ground_evidence(&store, &run, pipeline, spec).await?;
         }
 
         // Log run completion
-        self.complete_run(&store, &mut run).await
+        self.complete_run(&store, &mut run, state.failed_steps)
+            .await
     }
 
     /// Resume a previously failed run
-    #[instrument(skip(self, pipeline), fields(run_id = %run_id, pipeline = %pipeline.name))]
     pub async fn resume_run(
         &self,
         run_id: Uuid,
         pipeline: &Pipeline,
         input: String,
+    ) -> ArkaiResult<Run> {
+        Ok(self.execute_resume(run_id, pipeline, input).await?)
+    }
+
+    #[instrument(
+        name = "resume_run",
+        skip(self, pipeline, input),
+        fields(run_id = %run_id, pipeline = %pipeline.name)
+    )]
+    async fn execute_resume(
+        &self,
+        run_id: Uuid,
+        pipeline: &Pipeline,
+        input: String,
     ) -> Result<Run> {
         info!("Resuming run");
 
         let store = EventStore::open(run_id).await?;
+        let _lock = store.lock()?;
+        let snapshot = store.snapshot().await?;
         let events = store.replay().await?;
 
-        if events.is_empty() {
-            anyhow::bail!("No events found for run {}", run_id);
+        if snapshot.is_none() && events.is_empty() {
+            return Err(ArkaiError::RunNotFound(run_id.to_string()).into());
         }
 
-        // Reconstruct run state
-        let mut run = Run::from_events(&events).context("Failed to reconstruct run state")?;
+        // Reconstruct run state, starting from the snapshot of a compacted log
+        let (base, mut history) = match snapshot {
+            Some(snapshot) => (Some(snapshot.run), snapshot.step_completions),
+            None => (None, Vec::new()),
+        };
+        let mut run =
+            Run::from_snapshot(base, &events).context("Failed to reconstruct run state")?;
+        history.extend(events);
+        run.pipeline_name = pipeline.name.clone();
+        run.input = input.clone();
+
+        // Listed as running again until the resumed run ends
+        let summary = RunSummary {
+            state: RunState::Running,
+            completed_at: None,
+            ..RunSummary::from(&run)
+        };
+        if let Err(e) = store.record_summary(&summary).await {
+            warn!("Failed to update run index: {}", e);
+        }
 
-        let mut tracker = SafetyTracker::new();
-        let mut artifacts: HashMap<String, Artifact> = run.artifacts.clone();
+        let mut state = ExecutionState::new(run.artifacts.clone());
 
-        // Find the first incomplete step
-        let start_step = run.current_step;
+        // What finished steps spent still counts against the run's budgets
+        for event in &history {
+            if event.event_type == EventType::StepCompleted {
+                let usage = |key| event.payload.as_ref().and_then(|p| p.get(key));
+                state.tracker.record_usage(
+                    usage("tokens").and_then(|v| v.as_u64()),
+                    usage("cost_usd").and_then(|v| v.as_f64()),
+                );
+            }
+        }
 
-        info!(start_step, "Resuming from step");
+        let plan = pipeline.plan_resume(&history);
+        let mut pending = Vec::new();
+        for (step_idx, (step, step_plan)) in plan.iter().enumerate() {
+            if *step_plan == StepPlan::Run {
+                pending.push(step_idx);
+                continue;
+            }
+            // Later steps may take their input from a skipped step
+            if !state.artifacts.contains_key(&step.name) {
+                if let Some(content) = store.load_step_artifact(&step.name).await? {
+                    let mut artifact = Artifact::from_output(step.name.clone(), content);
+                    if let Some(metadata) = run.artifact_metadata.get(&step.name) {
+                        artifact.artifact_type = metadata.artifact_type;
+                        artifact.created_at = metadata.created_at;
+                    }
+                    state.artifacts.insert(step.name.clone(), artifact.clone());
+                    run.artifacts.insert(step.name.clone(), artifact);
+                }
+            }
+        }
 
-        // Execute remaining steps
-        for (step_idx, step) in pipeline.steps.iter().enumerate().skip(start_step) {
-            run.current_step = step_idx;
+        info!(?pending, "Resuming unfinished steps");
 
-            // Safety check
-            if let Err(violation) = pipeline.safety_limits.check(&tracker) {
-                return self
-                    .handle_safety_violation(&store, &mut run, violation)
-                    .await;
-            }
+        if let Some(ended) = self
+            .execute_steps(
+                &store, &mut run, pipeline, &input, pending, &mut state, None,
+            )
+            .await?
+        {
+            return Ok(ended);
+        }
 
-            // Resolve input
-            let step_input = self.resolve_input(&input, &artifacts, step)?;
+        if let Some(ref spec) = pipeline.evidence {
+            self.ground_evidence(&store, &run, pipeline, spec).await?;
+        }
 
-            // Check idempotency - skip if already completed
-            let idem_key = generate_idempotency_key(run_id, &step.name, &step_input);
-            if store.is_step_completed(&idem_key).await? {
-                info!(step = %step.name, "Step already completed, skipping");
-                continue;
+        self.complete_run(&store, &mut run, state.failed_steps)
+            .await
+    }
+
+    /// Execute the steps at `pending` (indices into `pipeline.steps`), each
+    /// once the steps it depends on have finished; steps not in `pending`
+    /// count as finished. Steps that become ready together run concurrently,
+    /// up to `pipeline.parallelism()`, and their results are applied in
+    /// pipeline order. Returns the ended run if a step fails it, it is
+    /// cancelled, or a safety limit is reached.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_steps(
+        &self,
+        store: &EventStore,
+        run: &mut Run,
+        pipeline: &Pipeline,
+        input: &str,
+        mut pending: Vec<usize>,
+        state: &mut ExecutionState,
+        progress: Option<&(dyn Fn(&StepProgress) + Send + Sync)>,
+    ) -> Result<Option<Run>> {
+        let total = pipeline.steps.len();
+        let notify = |event: StepProgress| {
+            if let Some(progress) = progress {
+                progress(&event);
             }
+        };
+        let mut finished: HashSet<&str> = pipeline
+            .steps
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !pending.contains(idx))
+            .map(|(_, step)| step.name.as_str())
+            .collect();
+
+        while !pending.is_empty() {
+            // References to unknown steps are left for resolve_input to report
+            let ready: Vec<usize> = pending
+                .iter()
+                .copied()
+                .filter(|&idx| {
+                    pipeline.steps[idx].dependencies().all(|dependency| {
+                        finished.contains(dependency) || pipeline.get_step(dependency).is_none()
+                    })
+                })
+                .take(pipeline.parallelism())
+                .collect();
+            if ready.is_empty() {
+                let waiting: Vec<&str> = pending
+                    .iter()
+                    .map(|&idx| pipeline.steps[idx].name.as_str())
+                    .collect();
+                let error = anyhow::anyhow!("Dependency cycle among steps: {}", waiting.join(", "));
+                return self.halt(store, run, Halt::Failed(error)).await.map(Some);
+            }
+            pending.retain(|idx| !ready.contains(idx));
+
+            // Prepare the ready steps; anything that would have stopped the
+            // run before a step stops it after the steps ahead of it finish
+            let mut batch: Vec<(usize, &Step, String)> = Vec::new();
+            let mut halt = None;
+            for idx in ready {
+                let step = &pipeline.steps[idx];
+                run.current_step = idx;
+
+                if self.is_cancelled() {
+                    halt = Some(Halt::Cancelled);
+                    break;
+                }
+
+                if !step.enabled {
+                    self.skip_disabled_step(store, run, step).await?;
+                    finished.insert(&step.name);
+                    continue;
+                }
+                if let Err(e) = check_input_enabled(pipeline, step) {
+                    halt = Some(Halt::Failed(e));
+                    break;
+                }
 
-            // Execute step
-            match self
-                .execute_step_with_retry(
-                    &store,
-                    &mut run,
+                // Safety check before each step, counting those starting with it
+                let mut tracker = state.tracker.clone();
+                tracker.steps_executed += batch.len() as u32;
+                if let Err(violation) = pipeline.safety_limits.check(&tracker) {
+                    halt = Some(Halt::SafetyLimit(violation));
+                    break;
+                }
+
+                // Resolve input for this step; a missing one (e.g. from a
+                // failed continue_on_error step) fails the run
+                let step_input = match self.resolve_input(
+                    input,
+                    &state.artifacts,
                     step,
-                    &step_input,
                     &pipeline.safety_limits,
-                    &mut tracker,
-                )
-                .await
-            {
-                Ok(artifact) => {
-                    artifacts.insert(step.name.clone(), artifact.clone());
-                    run.artifacts.insert(step.name.clone(), artifact);
-                    tracker.record_step(step_input.len() as u64, 0);
+                ) {
+                    Ok(step_input) => step_input,
+                    Err(e) => {
+                        halt = Some(Halt::Failed(e));
+                        break;
+                    }
+                };
+
+                // Validate input
+                pipeline.safety_limits.validate_input(&step_input, None)?;
+
+                // Check idempotency - skip if already completed
+                if self
+                    .is_step_completed(store, run.id, idx, step, &step_input)
+                    .await?
+                {
+                    info!(step = %step.name, "Step already completed, skipping");
+                    finished.insert(&step.name);
+                    continue;
                 }
-                Err(e) => {
-                    return self.handle_run_failure(&store, &mut run, e).await;
+
+                notify(StepProgress::started(step, idx, total));
+                run.step_statuses
+                    .insert(step.name.clone(), StepStatus::Running);
+                batch.push((idx, step, step_input));
+            }
+
+            // Execute the batch with retry, streaming the final step's raw
+            // output unless it's post-processed; steps still running at the
+            // run timeout are abandoned
+            let final_step = pipeline
+                .steps
+                .iter()
+                .rposition(|step| step.enabled)
+                .filter(|&idx| !pipeline.steps[idx].post_processes_output());
+            let remaining = pipeline.safety_limits.run_time_remaining(&state.tracker);
+            let tracker = Mutex::new(state.tracker.clone());
+            let results = {
+                let run: &Run = run;
+                let tracker = &tracker;
+                let executions = batch.iter().map(|(idx, step, step_input)| {
+                    let sink = self
+                        .output_stream
+                        .as_deref()
+                        .filter(|_| Some(*idx) == final_step);
+                    let execution = self.execute_step_with_retry(
+                        store,
+                        run,
+                        *idx,
+                        step,
+                        step_input,
+                        &pipeline.safety_limits,
+                        tracker,
+                        sink,
+                    );
+                    async move {
+                        tokio::time::timeout(remaining, execution)
+                            .await
+                            .unwrap_or_else(|_| {
+                                let tracker = tracker.lock().unwrap();
+                                Err(pipeline.safety_limits.run_timeout(&tracker).into())
+                            })
+                    }
+                });
+                self.until_cancelled(join_all(executions)).await
+            };
+            state.tracker = tracker.into_inner().unwrap();
+            let Some(results) = results else {
+                return self.halt(store, run, Halt::Cancelled).await.map(Some);
+            };
+
+            let mut failure = None;
+            for ((idx, step, step_input), result) in batch.into_iter().zip(results) {
+                notify(StepProgress::finished(step, idx, total, result.is_ok()));
+                finished.insert(&step.name);
+
+                match result {
+                    Ok(artifact) => {
+                        run.step_statuses
+                            .insert(step.name.clone(), StepStatus::Completed);
+                        run.artifact_metadata
+                            .insert(step.name.clone(), artifact.metadata());
+                        state.tracker.output_bytes += artifact.content.len() as u64;
+                        state.tracker.record_step(step_input.len() as u64, 0);
+                        state.artifacts.insert(step.name.clone(), artifact.clone());
+                        run.artifacts.insert(step.name.clone(), artifact);
+                    }
+                    Err(e) => {
+                        run.step_statuses
+                            .insert(step.name.clone(), StepStatus::Failed);
+                        // Timeouts end the run as a safety limit rather than
+                        // a failure; the run timeout and retry budget end it
+                        // even for optional steps
+                        let violation = e.downcast_ref::<SafetyViolation>().cloned();
+                        if let Some(
+                            violation @ (SafetyViolation::RunTimeout { .. }
+                            | SafetyViolation::RetryBudgetExceeded { .. }),
+                        ) = violation
+                        {
+                            failure = Some(Halt::SafetyLimit(violation));
+                        } else if step.continue_on_error {
+                            warn!(step = %step.name, error = %e, "Optional step failed, continuing");
+                            state.failed_steps.push(step.name.clone());
+                        } else if failure.is_none() {
+                            failure = Some(match violation {
+                                Some(violation) => Halt::SafetyLimit(violation),
+                                None => Halt::Failed(e),
+                            });
+                        }
+                    }
                 }
             }
+
+            if let Some(halt) = failure.or(halt) {
+                return self.halt(store, run, halt).await.map(Some);
+            }
         }
 
-        self.complete_run(&store, &mut run).await
+        Ok(None)
+    }
+
+    /// End `run` early for `halt`
+    async fn halt(&self, store: &EventStore, run: &mut Run, halt: Halt) -> Result<Run> {
+        match halt {
+            Halt::Failed(error) => self.handle_run_failure(store, run, error).await,
+            Halt::SafetyLimit(violation) => {
+                self.handle_safety_violation(store, run, violation).await
+            }
+            Halt::Cancelled => self.handle_cancellation(store, run).await,
+        }
     }
 
     fn validate_step_action(&self, step: &Step, limits: &SafetyLimits) -> Result<()> {
@@ -208,11 +695,12 @@ impl Orchestrator {
             .spawn()
             .with_context(|| format!("Failed to spawn shell command '{}'", action))?;
 
+        // A command may exit without reading (all of) its input
         if let Some(mut stdin) = child.stdin.take() {
-            stdin
-                .write_all(input.as_bytes())
-                .await
-                .context("Failed to write to shell stdin")?;
+            match stdin.write_all(input.as_bytes()).await {
+                Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => {}
+                result => result.context("Failed to write to shell stdin")?,
+            }
         }
 
         let mut stdout = child
@@ -240,10 +728,13 @@ impl Orchestrator {
                 let _ = child.wait().await;
                 stdout_task.abort();
                 stderr_task.abort();
-                anyhow::bail!(
-                    "Shell command '{}' timed out after {:?}",
-                    action,
-                    step_timeout
+                return Err(
+                    anyhow::Error::new(SafetyViolation::step_timeout(step_timeout)).context(
+                        format!(
+                            "Shell command '{}' timed out after {:?}",
+                            action, step_timeout
+                        ),
+                    ),
                 );
             }
         };
@@ -281,21 +772,74 @@ impl Orchestrator {
         Ok(AdapterOutput::new(stdout))
     }
 
-    /// Execute a step with retry logic
+    /// Deposit the step input into the library content dir for the pipeline's URL input
+    async fn store_in_library(
+        &self,
+        pipeline_input: &str,
+        step: &Step,
+        input: &str,
+    ) -> Result<AdapterOutput> {
+        let url = pipeline_input.trim();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            anyhow::bail!(
+                "Step '{}' uses {} but the pipeline input is not a URL",
+                step.name,
+                ACTION_LIBRARY_STORE
+            );
+        }
+
+        let path = LibraryContent::deposit_artifact(url, step.artifact_file_name(), input).await?;
+        info!(step = %step.name, path = %path.display(), "Stored artifact in library");
+
+        Ok(AdapterOutput::new(input.to_string()))
+    }
+
+    /// Whether the step at `step_idx` already completed with this input, under
+    /// either the current or the legacy (index-less) idempotency key
+    async fn is_step_completed(
+        &self,
+        store: &EventStore,
+        run_id: Uuid,
+        step_idx: usize,
+        step: &Step,
+        input: &str,
+    ) -> Result<bool> {
+        let key = generate_step_idempotency_key(run_id, step_idx, &step.name, input);
+        if store.is_step_completed(&key).await? {
+            return Ok(true);
+        }
+
+        let legacy_key = generate_idempotency_key(run_id, &step.name, input);
+        Ok(store.is_step_completed(&legacy_key).await?)
+    }
+
+    /// Execute a step with retry logic, in a `step` span recording the
+    /// current attempt
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(
+        name = "step",
+        skip_all,
+        fields(step = %step.name, adapter = %step.adapter, attempt = tracing::field::Empty)
+    )]
     async fn execute_step_with_retry(
         &self,
         store: &EventStore,
-        run: &mut Run,
+        run: &Run,
+        step_idx: usize,
         step: &Step,
         input: &str,
         limits: &SafetyLimits,
-        tracker: &mut SafetyTracker,
+        tracker: &Mutex<SafetyTracker>,
+        sink: Option<&OutputSink>,
     ) -> Result<Artifact> {
-        let idem_key = generate_idempotency_key(run.id, &step.name, input);
+        let idem_key = generate_step_idempotency_key(run.id, step_idx, &step.name, input);
         let timeout = step.timeout(limits);
 
         // Check idempotency first
-        if store.is_step_completed(&idem_key).await? {
+        if self
+            .is_step_completed(store, run.id, step_idx, step, input)
+            .await?
+        {
             debug!(step = %step.name, "Step already completed (idempotency check)");
             // Load artifact from events
             if let Some(artifact) = run.artifacts.get(&step.name) {
@@ -306,11 +850,65 @@ impl Orchestrator {
         }
 
         self.validate_step_action(step, limits)?;
+        let cleaner = step
+            .clean_output
+            .cleaner()
+            .context("Invalid clean_output pattern")?;
+        let schema = step.schema_validator().context("Invalid output_schema")?;
 
         let mut attempt = 0u32;
 
+        let mut throttled = Duration::ZERO;
+
+        // Usage across attempts, stored on the completion event so a resumed
+        // run counts it
+        let mut spent_tokens: Option<u64> = None;
+        let mut spent_cost_usd: Option<f64> = None;
+
+        let breaker = self
+            .circuit_breakers
+            .get(&step.adapter)
+            .filter(|_| step.action != ACTION_LIBRARY_STORE);
+
         loop {
             attempt += 1;
+            Span::current().record("attempt", attempt);
+
+            // Fail fast, without retrying, while the adapter's circuit is open
+            if let Some(Err(open)) = breaker.map(|breaker| breaker.check()) {
+                let fail_event = Event::new(
+                    run.id,
+                    Some(step.name.clone()),
+                    EventType::StepFailed,
+                    idem_key,
+                    format!("Step '{}' failed fast: {}", step.name, open),
+                    StepStatus::Failed,
+                )
+                .with_error(open.to_string())
+                .with_payload(serde_json::json!({ "circuit_state": CircuitState::Open }));
+                store.append(&fail_event).await?;
+
+                error!(
+                    step = %step.name,
+                    adapter = %open.adapter,
+                    retry_in_ms = open.retry_in.as_millis() as u64,
+                    "Step failed fast, circuit open"
+                );
+
+                return Err(open.into());
+            }
+
+            // Wait for the Fabric rate limit before starting the attempt
+            if step.adapter == AdapterType::Fabric && step.action != ACTION_LIBRARY_STORE {
+                if let Some(ref limiter) = self.rate_limiter {
+                    let waited = limiter.acquire().await;
+                    if !waited.is_zero() {
+                        debug!(step = %step.name, waited_ms = waited.as_millis() as u64, "Throttled by Fabric rate limit");
+                        throttled += waited;
+                    }
+                }
+            }
+
             let step_start = Instant::now();
 
             // Log step start
@@ -323,16 +921,30 @@ impl Orchestrator {
                 StepStatus::Running,
             );
             store.append(&start_event).await?;
-            run.step_statuses
-                .insert(step.name.clone(), StepStatus::Running);
 
             // Execute via adapter
             let result = match step.adapter {
-                AdapterType::Fabric => {
-                    self.fabric_adapter
-                        .execute(&step.action, input, timeout)
-                        .await
+                _ if step.action == ACTION_LIBRARY_STORE => {
+                    self.store_in_library(&run.input, step, input).await
                 }
+                // Retries don't stream again over the first attempt
+                AdapterType::Fabric => match sink.filter(|_| attempt == 1) {
+                    Some(sink) => self
+                        .fabric_adapter
+                        .execute_streaming(&step.action, input, timeout)
+                        .try_fold(String::new(), |mut content, chunk| {
+                            sink(&chunk);
+                            content.push_str(&chunk);
+                            async move { Ok(content) }
+                        })
+                        .await
+                        .map(AdapterOutput::new),
+                    None => {
+                        self.fabric_adapter
+                            .execute(&step.action, input, timeout)
+                            .await
+                    }
+                },
                 AdapterType::Shell => {
                     self.execute_shell_command(&step.action, input, timeout)
                         .await
@@ -341,19 +953,60 @@ impl Orchestrator {
 
             let duration_ms = step_start.elapsed().as_millis() as u64;
 
+            // Count what the call cost, even if its output is then rejected
+            let (tokens, cost_usd) = match result {
+                Ok(ref output) => (output.tokens_used, output.cost_usd),
+                Err(_) => (None, None),
+            };
+            if result.is_ok() {
+                tracker.lock().unwrap().record_usage(tokens, cost_usd);
+                spent_tokens = tokens
+                    .map(|t| spent_tokens.unwrap_or(0) + t)
+                    .or(spent_tokens);
+                spent_cost_usd = cost_usd
+                    .map(|c| spent_cost_usd.unwrap_or(0.0) + c)
+                    .or(spent_cost_usd);
+            }
+
+            if let Some(breaker) = breaker {
+                match result {
+                    Ok(_) => breaker.record_success(),
+                    Err(_) => breaker.record_failure(),
+                }
+            }
+
+            // Clean, then check any declared output format per attempt so
+            // retries can recover
+            let result = result.and_then(|mut output| {
+                if let Some(ref cleaner) = cleaner {
+                    output.content = cleaner.clean(&output.content);
+                }
+                if let Some(format) = step.output_format {
+                    format.validate(&output.content)?;
+                }
+                if let Some(ref schema) = schema {
+                    schema.validate(&output.content)?;
+                }
+                Ok(output)
+            });
+
             match result {
                 Ok(output) => {
                     // Validate output
                     limits.validate_output(&output.content)?;
 
-                    // Update tracker with output bytes
-                    tracker.output_bytes += output.content.len() as u64;
-
                     // Persist artifact to disk
-                    store.store_artifact(&step.name, &output.content).await?;
+                    store
+                        .store_artifact(step.artifact_file_name(), &output.content)
+                        .await?;
+
+                    let mut artifact = Artifact::from_output(step.name.clone(), output.content);
+                    if let Some(format) = step.output_format {
+                        artifact.artifact_type = format.into();
+                    }
 
                     // Log success
-                    let complete_event = Event::new(
+                    let mut complete_event = Event::new(
                         run.id,
                         Some(step.name.clone()),
                         EventType::StepCompleted,
@@ -362,16 +1015,56 @@ impl Orchestrator {
                         StepStatus::Completed,
                     )
                     .with_duration(duration_ms);
+                    let mut payload = serde_json::Map::new();
+                    payload.insert(
+                        "artifact_metadata".to_string(),
+                        serde_json::to_value(artifact.metadata())?,
+                    );
+                    if let Some(ref artifact_name) = step.artifact_name {
+                        payload.insert("artifact".to_string(), artifact_name.clone().into());
+                    }
+                    if let Some(format) = step.output_format {
+                        payload.insert("output_format".to_string(), serde_json::to_value(format)?);
+                    }
+                    if !throttled.is_zero() {
+                        payload.insert(
+                            "throttled_ms".to_string(),
+                            (throttled.as_millis() as u64).into(),
+                        );
+                    }
+                    if let Some(tokens) = spent_tokens {
+                        payload.insert("tokens".to_string(), tokens.into());
+                    }
+                    if let Some(cost_usd) = spent_cost_usd {
+                        payload.insert("cost_usd".to_string(), cost_usd.into());
+                    }
+                    complete_event = complete_event.with_payload(payload.into());
                     store.append(&complete_event).await?;
-                    run.step_statuses
-                        .insert(step.name.clone(), StepStatus::Completed);
 
-                    let artifact = Artifact::from_output(step.name.clone(), output.content);
+                    info!(duration_ms, tokens, cost_usd, "Step completed");
+
                     return Ok(artifact);
                 }
-                Err(e) => {
-                    // Check if we should retry
-                    if step.retry_policy.should_retry(attempt) {
+                Err(mut e) => {
+                    // Check if we should retry; not once this failure has
+                    // opened the adapter's circuit
+                    let circuit_open =
+                        breaker.is_some_and(|breaker| breaker.state() == CircuitState::Open);
+                    let mut retry = step.retry_policy.should_retry(attempt) && !circuit_open;
+
+                    // Each retry spends from the run's retry budget; once it
+                    // is spent the step fails and the run stops
+                    if retry {
+                        let mut tracker = tracker.lock().unwrap();
+                        tracker.total_retries += 1;
+                        if let Err(violation) = limits.check_retries(&tracker) {
+                            e = anyhow::Error::new(violation)
+                                .context(format!("{} (retry budget spent)", e));
+                            retry = false;
+                        }
+                    }
+
+                    if retry {
                         let delay = step.retry_policy.delay_for_attempt(attempt);
 
                         // Log retry
@@ -415,13 +1108,17 @@ impl Orchestrator {
                     )
                     .with_duration(duration_ms)
                     .with_error(e.to_string());
+                    let fail_event = match breaker {
+                        Some(breaker) => fail_event
+                            .with_payload(serde_json::json!({ "circuit_state": breaker.state() })),
+                        None => fail_event,
+                    };
                     store.append(&fail_event).await?;
-                    run.step_statuses
-                        .insert(step.name.clone(), StepStatus::Failed);
 
                     error!(
                         step = %step.name,
                         attempt,
+                        duration_ms,
                         error = %e,
                         "Step failed permanently"
                     );
@@ -438,6 +1135,7 @@ impl Orchestrator {
         pipeline_input: &str,
         artifacts: &HashMap<String, Artifact>,
         step: &Step,
+        limits: &SafetyLimits,
     ) -> Result<String> {
         match &step.input_from {
             InputSource::PipelineInput(_) => Ok(pipeline_input.to_string()),
@@ -464,7 +1162,49 @@ impl Orchestrator {
                     )
                 }),
 
-            InputSource::Static { value } => Ok(serde_json::to_string(value).unwrap_or_default()),
+            InputSource::Env { env, default } => match (std::env::var(env), default) {
+                (Ok(value), _) => Ok(value),
+                (Err(_), Some(default)) => Ok(default.clone()),
+                (Err(e), None) => Err(anyhow::anyhow!(
+                    "Step '{}' reads environment variable '{}': {}",
+                    step.name,
+                    env,
+                    e
+                )),
+            },
+
+            InputSource::Files { glob, separator } => {
+                let paths = glob::glob(glob)
+                    .with_context(|| {
+                        format!("Step '{}' has an invalid glob '{}'", step.name, glob)
+                    })?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                if paths.is_empty() {
+                    anyhow::bail!("Step '{}' glob '{}' matched no files", step.name, glob);
+                }
+
+                let mut contents = Vec::with_capacity(paths.len());
+                for path in paths.iter().filter(|p| p.is_file()) {
+                    // Denied or oversized files are never read
+                    limits.validate_input_path(path)?;
+                    let size = std::fs::metadata(path)
+                        .with_context(|| format!("Failed to stat input file: {}", path.display()))?
+                        .len();
+                    limits.validate_input_size(size)?;
+
+                    let content = std::fs::read_to_string(path).with_context(|| {
+                        format!("Failed to read input file: {}", path.display())
+                    })?;
+                    contents.push(content);
+                }
+                Ok(contents.join(separator.as_deref().unwrap_or("\n\n")))
+            }
+
+            // Strings are passed as-is; other values as JSON
+            InputSource::Static { value } => Ok(match value {
+                serde_json::Value::String(text) => text.clone(),
+                other => serde_json::to_string(other).unwrap_or_default(),
+            }),
         }
     }
 
@@ -526,53 +1266,348 @@ impl Orchestrator {
         Ok(run.clone())
     }
 
-    /// Complete a successful run
-    async fn complete_run(&self, store: &EventStore, run: &mut Run) -> Result<Run> {
-        info!(run_id = %run.id, "Run completed successfully");
+    fn is_cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+    }
+
+    /// Await `future` unless the run is cancelled first (`None`)
+    async fn until_cancelled<T>(&self, future: impl std::future::Future<Output = T>) -> Option<T> {
+        match &self.cancel {
+            Some(token) => tokio::select! {
+                _ = token.cancelled() => None,
+                output = future => Some(output),
+            },
+            None => Some(future.await),
+        }
+    }
+
+    /// Record a disabled step as skipped
+    async fn skip_disabled_step(
+        &self,
+        store: &EventStore,
+        run: &mut Run,
+        step: &Step,
+    ) -> Result<()> {
+        info!(step = %step.name, "Step disabled, skipping");
+        let event = Event::new(
+            run.id,
+            Some(step.name.clone()),
+            EventType::StepSkipped,
+            format!("{}:{}:skipped", run.id, step.name),
+            format!("Step '{}' is disabled", step.name),
+            StepStatus::Skipped,
+        );
+        store.append(&event).await?;
+        run.step_statuses
+            .insert(step.name.clone(), StepStatus::Skipped);
+        Ok(())
+    }
+
+    /// Handle a cancelled run
+    async fn handle_cancellation(&self, store: &EventStore, run: &mut Run) -> Result<Run> {
+        warn!(run_id = %run.id, step = run.current_step, "Run cancelled");
 
-        run.state = crate::domain::RunState::Completed;
+        run.state = crate::domain::RunState::Cancelled;
         run.completed_at = Some(chrono::Utc::now());
 
         let event = Event::new(
             run.id,
             None,
-            EventType::RunCompleted,
+            EventType::RunCancelled,
             format!("{}:complete", run.id),
-            format!("Pipeline '{}' completed", run.pipeline_name),
-            StepStatus::Completed,
+            format!("Run cancelled at step {}", run.current_step),
+            StepStatus::Skipped,
         );
         store.append(&event).await?;
 
         Ok(run.clone())
     }
 
+    /// Ground the claims step's output against the source step's output,
+    /// writing `evidence.jsonl` to the run directory.
+    ///
+    /// Grounding problems (e.g. malformed claims JSON) are logged as a failed
+    /// `EvidenceGrounded` event rather than failing an otherwise successful run.
+    async fn ground_evidence(
+        &self,
+        store: &EventStore,
+        run: &Run,
+        pipeline: &Pipeline,
+        spec: &EvidenceSpec,
+    ) -> Result<()> {
+        let summary = format!(
+            "Grounded claims from '{}' against '{}'",
+            spec.claims, spec.source
+        );
+        let event = |status| {
+            Event::new(
+                run.id,
+                Some(spec.claims.clone()),
+                EventType::EvidenceGrounded,
+                format!("{}:evidence", run.id),
+                summary.clone(),
+                status,
+            )
+        };
+
+        let event = match self.write_run_evidence(store, run, pipeline, spec).await {
+            Ok(counts) => {
+                info!(%counts, "Grounded pipeline evidence");
+                event(StepStatus::Completed).with_payload(counts)
+            }
+            Err(e) => {
+                warn!(error = %e, "Evidence grounding failed");
+                event(StepStatus::Failed).with_error(e.to_string())
+            }
+        };
+
+        Ok(store.append(&event).await?)
+    }
+
+    /// Write the run's `evidence.jsonl`, returning per-status counts
+    async fn write_run_evidence(
+        &self,
+        store: &EventStore,
+        run: &Run,
+        pipeline: &Pipeline,
+        spec: &EvidenceSpec,
+    ) -> Result<serde_json::Value> {
+        let artifact = |name: &str| {
+            run.artifacts
+                .get(name)
+                .map(|a| a.content.as_str())
+                .with_context(|| format!("Step '{}' produced no artifact", name))
+        };
+        let claims = parse_extractor_output(artifact(&spec.claims)?)?;
+        let transcript = artifact(&spec.source)?;
+
+        // Match the content ID the library assigns this input
+        let input = run.input.trim();
+        let content_id = if input.starts_with("http://") || input.starts_with("https://") {
+            ContentId::from_url(input)
+        } else {
+            ContentId::from_content(transcript.as_bytes())
+        };
+
+        // Spans name the artifact file the source step was stored under
+        let source_artifact = pipeline
+            .steps
+            .iter()
+            .find(|step| step.name == spec.source)
+            .map_or(spec.source.as_str(), Step::artifact_file_name);
+        let ts = chrono::Utc::now().to_rfc3339();
+        let matching = crate::config::config()?.evidence_matching;
+        let (mut resolved, mut ambiguous, mut unresolved) = (0, 0, 0);
+        let mut lines = String::new();
+
+        for claim in &claims {
+            let evidence = ground_claim(
+                content_id.as_str(),
+                transcript,
+                source_artifact,
+                claim,
+                &spec.claims,
+                &ts,
+                &matching,
+            );
+            match evidence.status {
+                Status::Resolved => resolved += 1,
+                Status::Ambiguous => ambiguous += 1,
+                Status::Unresolved => unresolved += 1,
+            }
+            lines.push_str(&serde_json::to_string(&evidence)?);
+            lines.push('\n');
+        }
+
+        let path = store.run_dir().join("evidence.jsonl");
+        tokio::fs::write(&path, lines)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(serde_json::json!({
+            "resolved": resolved,
+            "ambiguous": ambiguous,
+            "unresolved": unresolved,
+        }))
+    }
+
+    /// Complete a run whose required steps all succeeded.
+    ///
+    /// If any `continue_on_error` steps failed, the run ends `PartiallyCompleted`.
+    async fn complete_run(
+        &self,
+        store: &EventStore,
+        run: &mut Run,
+        failed_steps: Vec<String>,
+    ) -> Result<Run> {
+        run.completed_at = Some(chrono::Utc::now());
+
+        let event = if failed_steps.is_empty() {
+            info!(run_id = %run.id, "Run completed successfully");
+            run.state = crate::domain::RunState::Completed;
+
+            Event::new(
+                run.id,
+                None,
+                EventType::RunCompleted,
+                format!("{}:complete", run.id),
+                format!("Pipeline '{}' completed", run.pipeline_name),
+                StepStatus::Completed,
+            )
+        } else {
+            warn!(run_id = %run.id, ?failed_steps, "Run partially completed");
+
+            let event = Event::new(
+                run.id,
+                None,
+                EventType::RunPartiallyCompleted,
+                format!("{}:complete", run.id),
+                format!(
+                    "Pipeline '{}' completed with failed steps: {}",
+                    run.pipeline_name,
+                    failed_steps.join(", ")
+                ),
+                StepStatus::Completed,
+            )
+            .with_payload(serde_json::json!({ "failed_steps": failed_steps }));
+            run.state = crate::domain::RunState::PartiallyCompleted { failed_steps };
+            event
+        };
+        store.append(&event).await?;
+
+        Ok(run.clone())
+    }
+
     /// Get status of a run by ID
-    pub async fn get_run_status(&self, run_id: Uuid) -> Result<Run> {
+    pub async fn get_run_status(&self, run_id: Uuid) -> ArkaiResult<Run> {
+        Ok(self.replay_run(run_id).await?.0)
+    }
+
+    /// Find runs left running by a process that died (their log doesn't end
+    /// the run and nobody holds the run lock) and record `RunInterrupted`
+    /// on each, so they can be resumed. Returns the runs marked.
+    pub async fn recover_interrupted_runs(&self) -> ArkaiResult<Vec<Uuid>> {
+        let run_ids = EventStore::list_runs().await?;
+        let mut interrupted = Vec::new();
+
+        for summary in EventStore::summaries(&run_ids).await? {
+            if summary.state != RunState::Running {
+                continue;
+            }
+            let store = EventStore::open(summary.id).await?;
+            let Ok(_lock) = store.lock() else {
+                continue;
+            };
+
+            let events = store.replay().await?;
+            let folded = store.snapshot().await?.map_or(0, |snapshot| snapshot.seq);
+            let in_flight = events
+                .iter()
+                .rev()
+                .find(|e| e.event_type == EventType::StepStarted)
+                .and_then(|e| e.step_id.clone());
+            // A resume that died before logging anything: the log still
+            // ends in the earlier failure, only the index is stale
+            if events.last().is_some_and(|e| e.event_type.ends_run()) {
+                if let Some(summary) = store.summary().await? {
+                    store.record_summary(&summary).await?;
+                }
+                continue;
+            }
+
+            warn!(run_id = %summary.id, step = ?in_flight, "Run was interrupted");
+            let mut event = Event::new(
+                summary.id,
+                None,
+                EventType::RunInterrupted,
+                format!("{}:interrupted:{}", summary.id, folded + events.len()),
+                match &in_flight {
+                    Some(step) => format!("Run interrupted during step '{}'", step),
+                    None => "Run interrupted".to_string(),
+                },
+                StepStatus::Skipped,
+            );
+            if let Some(step) = in_flight {
+                event = event.with_payload(serde_json::json!({ "step": step }));
+            }
+            store.append(&event).await?;
+            interrupted.push(summary.id);
+        }
+
+        Ok(interrupted)
+    }
+
+    /// Reconstruct a run along with the events it was built from, reading
+    /// the event log once (for a compacted run, the events after its
+    /// snapshot). Read-only: nothing is appended.
+    pub async fn replay_run(&self, run_id: Uuid) -> ArkaiResult<(Run, Vec<Event>)> {
         let store = EventStore::open(run_id).await?;
+        let snapshot = store.snapshot().await?;
         let events = store.replay().await?;
 
-        if events.is_empty() {
-            anyhow::bail!("Run {} not found", run_id);
+        if snapshot.is_none() && events.is_empty() {
+            return Err(ArkaiError::RunNotFound(run_id.to_string()));
         }
 
-        Run::from_events(&events).context("Failed to reconstruct run state")
+        let run = Run::from_snapshot(snapshot.map(|s| s.run), &events)
+            .context("Failed to reconstruct run state")?;
+        Ok((run, events))
+    }
+
+    /// Load a run's output: the artifact of `step`, or of the last step that
+    /// completed if no step is named
+    pub async fn load_run_output(&self, run_id: Uuid, step: Option<&str>) -> ArkaiResult<String> {
+        let store = EventStore::open(run_id).await?;
+
+        let step = match step {
+            Some(step) => step.to_string(),
+            None => store
+                .last_event_of_type(EventType::StepCompleted)
+                .await?
+                .and_then(|event| event.step_id)
+                .ok_or_else(|| {
+                    ArkaiError::StepNotFound(format!("Run {} has no completed steps", run_id))
+                })?,
+        };
+
+        store.load_step_artifact(&step).await?.ok_or_else(|| {
+            ArkaiError::StepNotFound(format!(
+                "Run {} has no artifact for step '{}'",
+                run_id, step
+            ))
+        })
     }
 
     /// List recent runs
-    pub async fn list_runs(&self, limit: usize) -> Result<Vec<Run>> {
-        let run_ids = EventStore::list_runs().await?;
+    pub async fn list_runs(&self, limit: usize) -> ArkaiResult<Vec<Run>> {
         let mut runs = Vec::new();
 
-        for run_id in run_ids.into_iter().take(limit) {
-            if let Ok(run) = self.get_run_status(run_id).await {
+        for summary in self.list_run_summaries(0, limit).await? {
+            if let Ok(run) = self.get_run_status(summary.id).await {
                 runs.push(run);
             }
         }
 
+        Ok(runs)
+    }
+
+    /// A page of run summaries, most recent first, skipping `offset` runs.
+    /// Served from the run index (or the SQLite `runs` table); unindexed
+    /// runs are read from the first and last events of their log.
+    pub async fn list_run_summaries(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> ArkaiResult<Vec<RunSummary>> {
+        let run_ids = EventStore::list_runs().await?;
+        let mut summaries = EventStore::summaries(&run_ids).await?;
+
         // Sort by start time (most recent first)
-        runs.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        summaries.sort_by(|a, b| b.started_at.cmp(&a.started_at));
 
-        Ok(runs)
+        Ok(summaries.into_iter().skip(offset).take(limit).collect())
     }
 }
 
@@ -580,6 +1615,124 @@ impl Orchestrator {
 mod tests {
     use super::*;
 
+    fn step_with_input(input_from: InputSource) -> Step {
+        Step {
+            name: "step".to_string(),
+            adapter: AdapterType::Shell,
+            action: "cat".to_string(),
+            input_from,
+            retry_policy: crate::core::RetryPolicy::default(),
+            timeout_seconds: None,
+            continue_on_error: false,
+            artifact_name: None,
+            output_format: None,
+            output_schema: None,
+            clean_output: Default::default(),
+            enabled: true,
+            depends_on: Vec::new(),
+            compiled_schema: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_env_input() {
+        let orchestrator = Orchestrator::new();
+        let resolve = |env: &str, default: Option<&str>| {
+            let step = step_with_input(InputSource::Env {
+                env: env.to_string(),
+                default: default.map(String::from),
+            });
+            orchestrator.resolve_input("", &HashMap::new(), &step, &SafetyLimits::default())
+        };
+
+        std::env::set_var("ARKAI_TEST_ENV_INPUT_SET", "from env");
+        assert_eq!(
+            resolve("ARKAI_TEST_ENV_INPUT_SET", None).unwrap(),
+            "from env"
+        );
+        assert_eq!(
+            resolve("ARKAI_TEST_ENV_INPUT_SET", Some("fallback")).unwrap(),
+            "from env"
+        );
+
+        assert_eq!(
+            resolve("ARKAI_TEST_ENV_INPUT_UNSET", Some("fallback")).unwrap(),
+            "fallback"
+        );
+        let err = resolve("ARKAI_TEST_ENV_INPUT_UNSET", None).unwrap_err();
+        assert!(err.to_string().contains("ARKAI_TEST_ENV_INPUT_UNSET"));
+    }
+
+    #[test]
+    fn test_resolve_files_input() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("b.md"), "second note").unwrap();
+        std::fs::write(dir.path().join("a.md"), "first note").unwrap();
+
+        let orchestrator = Orchestrator::new();
+        let resolve = |pattern: &str, separator: Option<&str>| {
+            let step = step_with_input(InputSource::Files {
+                glob: dir.path().join(pattern).display().to_string(),
+                separator: separator.map(String::from),
+            });
+            orchestrator.resolve_input("", &HashMap::new(), &step, &SafetyLimits::default())
+        };
+
+        assert_eq!(resolve("*.md", None).unwrap(), "first note\n\nsecond note");
+        assert_eq!(
+            resolve("*.md", Some("\n---\n")).unwrap(),
+            "first note\n---\nsecond note"
+        );
+        assert!(resolve("*.txt", None)
+            .unwrap_err()
+            .to_string()
+            .contains("matched no files"));
+
+        // Every matched file goes through the denylist, before it's read
+        std::fs::write(dir.path().join("secrets.md"), [0xff, 0xfe]).unwrap();
+        let err = resolve("*.md", None).unwrap_err();
+        assert!(err.to_string().contains("denylist"), "{}", err);
+        assert!(err.to_string().contains("secrets.md"));
+        std::fs::remove_file(dir.path().join("secrets.md")).unwrap();
+
+        // And its size is checked without reading it
+        std::fs::write(dir.path().join("big.md"), [0xff; 64]).unwrap();
+        let limits = SafetyLimits {
+            max_input_bytes: 32,
+            ..SafetyLimits::default()
+        };
+        let step = step_with_input(InputSource::Files {
+            glob: dir.path().join("*.md").display().to_string(),
+            separator: None,
+        });
+        let err = orchestrator
+            .resolve_input("", &HashMap::new(), &step, &limits)
+            .unwrap_err();
+        assert!(err.to_string().contains("Maximum input bytes"), "{}", err);
+    }
+
+    #[test]
+    fn test_resolve_static_input() {
+        let orchestrator = Orchestrator::new();
+        let resolve = |value: serde_json::Value| {
+            orchestrator
+                .resolve_input(
+                    "",
+                    &HashMap::new(),
+                    &step_with_input(InputSource::Static { value }),
+                    &SafetyLimits::default(),
+                )
+                .unwrap()
+        };
+
+        assert_eq!(resolve(serde_json::json!("hello")), "hello");
+        assert_eq!(
+            resolve(serde_json::json!({"topic": "rust"})),
+            r#"{"topic":"rust"}"#
+        );
+        assert_eq!(resolve(serde_json::json!(42)), "42");
+    }
+
     #[test]
     fn test_orchestrator_creation() {
         let orchestrator = Orchestrator::new();
@@ -609,6 +1762,19 @@ mod tests {
         assert!(error.to_string().contains("exit code 7"));
     }
 
+    #[tokio::test]
+    async fn test_execute_shell_command_may_ignore_its_input() {
+        let orchestrator = Orchestrator::new();
+        // More than a pipe buffer, so the write outlives the command
+        let input = "x".repeat(1 << 20);
+        let output = orchestrator
+            .execute_shell_command("echo done", &input, Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert_eq!(output.content, "done\n");
+    }
+
     #[test]
     fn test_validate_step_action_rejects_denylisted_shell_command() {
         let orchestrator = Orchestrator::new();
@@ -619,6 +1785,14 @@ mod tests {
             input_from: InputSource::default(),
             retry_policy: crate::core::RetryPolicy::default(),
             timeout_seconds: Some(1),
+            continue_on_error: false,
+            artifact_name: None,
+            output_format: None,
+            output_schema: None,
+            clean_output: Default::default(),
+            enabled: true,
+            depends_on: Vec::new(),
+            compiled_schema: Default::default(),
         };
 
         let error = orchestrator
@@ -628,4 +1802,32 @@ mod tests {
         assert!(error.to_string().contains(".env"));
         assert!(error.to_string().contains("denylist"));
     }
+
+    #[tokio::test]
+    async fn test_store_in_library_requires_url_input() {
+        let orchestrator = Orchestrator::new();
+        let step = Step {
+            name: "source".to_string(),
+            adapter: AdapterType::Fabric,
+            action: ACTION_LIBRARY_STORE.to_string(),
+            input_from: InputSource::default(),
+            retry_policy: crate::core::RetryPolicy::default(),
+            timeout_seconds: None,
+            continue_on_error: false,
+            artifact_name: None,
+            output_format: None,
+            output_schema: None,
+            clean_output: Default::default(),
+            enabled: true,
+            depends_on: Vec::new(),
+            compiled_schema: Default::default(),
+        };
+
+        let error = orchestrator
+            .store_in_library("not a url", &step, "transcript")
+            .await
+            .unwrap_err();
+
+        assert!(error.to_string().contains("not a URL"));
+    }
 }
@@ -2,14 +2,28 @@
 //!
 //! Pipelines are defined in YAML and consist of ordered steps,
 //! each targeting an adapter (e.g., Fabric) with specific actions.
+//! A step waits for the step it takes input from and any it lists in
+//! `depends_on`; steps with nothing left to wait for run concurrently.
 
-use std::path::Path;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 use std::time::Duration;
 
-use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-use super::safety::SafetyLimits;
+use crate::domain::{ArtifactType, Event, EventType};
+use crate::error::{ArkaiError, Result};
+
+use super::clean::CleanOutput;
+use super::safety::{SafetyDefaults, SafetyLimits};
+
+/// Special action that deposits the step input into the library.
+///
+/// The pipeline input must be the source URL; the step input is written to the
+/// URL's content dir as `<artifact name>.md` (the step's `artifact_name`, or
+/// its name) and passed through unchanged.
+pub const ACTION_LIBRARY_STORE: &str = "__library_store__";
 
 /// A complete pipeline definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,30 +40,94 @@ pub struct Pipeline {
 
     /// Ordered list of steps to execute
     pub steps: Vec<Step>,
+
+    /// Most steps to run at once; steps whose dependencies have finished run
+    /// concurrently up to this limit (default: all of them)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_parallel: Option<usize>,
+
+    /// Ground extracted claims into evidence after a successful run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<EvidenceSpec>,
+}
+
+/// Post-run evidence extraction settings.
+///
+/// The `claims` step must output extractor JSON (`[{claim, quote, confidence}]`);
+/// quotes are grounded against the output of the `source` step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvidenceSpec {
+    /// Step whose output holds the extracted claims
+    pub claims: String,
+
+    /// Step whose output is the text quotes are grounded against
+    pub source: String,
 }
 
 impl Pipeline {
     /// Load a pipeline from a YAML file
     pub fn from_file(path: &Path) -> Result<Self> {
-        let content = std::fs::read_to_string(path)
-            .with_context(|| format!("Failed to read pipeline file: {}", path.display()))?;
+        Self::from_yaml(&read_pipeline_file(path)?)
+    }
 
-        Self::from_yaml(&content)
+    /// Load a pipeline from a YAML file, filling omitted safety limits from `defaults`
+    pub fn from_file_with_defaults(path: &Path, defaults: &SafetyDefaults) -> Result<Self> {
+        Self::from_yaml_with_defaults(&read_pipeline_file(path)?, defaults)
     }
 
     /// Parse a pipeline from YAML content
     pub fn from_yaml(content: &str) -> Result<Self> {
-        serde_yaml::from_str(content).context("Failed to parse pipeline YAML")
+        serde_yaml::from_str(content).map_err(ArkaiError::PipelineParse)
+    }
+
+    /// Parse a pipeline from YAML content, filling omitted safety limits from `defaults`
+    ///
+    /// Precedence per field: pipeline `safety_limits` > config defaults > `SafetyLimits::default`.
+    pub fn from_yaml_with_defaults(content: &str, defaults: &SafetyDefaults) -> Result<Self> {
+        let mut value: serde_yaml::Value =
+            serde_yaml::from_str(content).map_err(ArkaiError::PipelineParse)?;
+
+        let entries = defaults.entries();
+        if let (Some(root), false) = (value.as_mapping_mut(), entries.is_empty()) {
+            let limits = root
+                .entry("safety_limits".into())
+                .or_insert(serde_yaml::Value::Null);
+            if limits.is_null() {
+                *limits = serde_yaml::Mapping::new().into();
+            }
+            if let Some(limits) = limits.as_mapping_mut() {
+                for (key, default) in entries {
+                    if !limits.contains_key(key) {
+                        limits.insert(key.into(), default);
+                    }
+                }
+            }
+        }
+
+        serde_yaml::from_value(value).map_err(ArkaiError::PipelineParse)
     }
 
-    /// Validate the pipeline definition
+    /// Validate the pipeline definition, logging its [`Self::warnings`]
     pub fn validate(&self) -> Result<()> {
+        self.check_definition()?;
+        for warning in self.warnings() {
+            tracing::warn!(pipeline = %self.name, "{}", warning);
+        }
+        Ok(())
+    }
+
+    /// Errors that make the pipeline invalid
+    fn check_definition(&self) -> Result<()> {
         if self.name.is_empty() {
-            anyhow::bail!("Pipeline name cannot be empty");
+            return Err(ArkaiError::InvalidPipeline(
+                "Pipeline name cannot be empty".to_string(),
+            ));
         }
 
         if self.steps.is_empty() {
-            anyhow::bail!("Pipeline must have at least one step");
+            return Err(ArkaiError::InvalidPipeline(
+                "Pipeline must have at least one step".to_string(),
+            ));
         }
 
         // Validate step references
@@ -57,35 +135,288 @@ impl Pipeline {
 
         for (i, step) in self.steps.iter().enumerate() {
             if step.name.is_empty() {
-                anyhow::bail!("Step {} has an empty name", i);
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step {} has an empty name",
+                    i
+                )));
+            }
+
+            // Step names key artifacts and idempotency, so they must be unique
+            if step_names[..i].contains(&step.name.as_str()) {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Duplicate step name '{}' (step names must be unique)",
+                    step.name
+                )));
             }
 
-            // Check that previous_step references exist
-            if let InputSource::PreviousStep { ref previous_step } = step.input_from {
-                let step_index = step_names.iter().position(|&n| n == previous_step);
+            // Artifact names become file names in the run's artifacts dir
+            let file_name = step.artifact_file_name();
+            if file_name.is_empty() || file_name.contains(['/', '\\']) || file_name.starts_with('.')
+            {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step '{}' has an invalid artifact name '{}'",
+                    step.name, file_name
+                )));
+            }
+            if self.steps[..i]
+                .iter()
+                .any(|s| s.artifact_file_name() == file_name)
+            {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step '{}' reuses artifact name '{}'",
+                    step.name, file_name
+                )));
+            }
+
+            if !(0.0..=1.0).contains(&step.retry_policy.jitter_fraction) {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step '{}' has jitter_fraction {} (must be between 0 and 1)",
+                    step.name, step.retry_policy.jitter_fraction
+                )));
+            }
+
+            if let Err(e) = step.clean_output.cleaner() {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step '{}' has an invalid clean_output pattern: {}",
+                    step.name, e
+                )));
+            }
+
+            if let Err(e) = step.schema_validator() {
+                return Err(ArkaiError::InvalidPipeline(format!(
+                    "Step '{}' has an invalid output_schema: {}",
+                    step.name, e
+                )));
+            }
+
+            // Check that previous_step/artifact references name earlier steps
+            // (artifacts are keyed by the step that produced them)
+            let kind = match step.input_from {
+                InputSource::Artifact { .. } => "artifact",
+                _ => "step",
+            };
+            if let Some(target) = step.input_step() {
+                let step_index = step_names.iter().position(|&n| n == target);
                 match step_index {
                     Some(idx) if idx >= i => {
-                        anyhow::bail!(
-                            "Step '{}' references future step '{}' (forward references not allowed)",
-                            step.name,
-                            previous_step
-                        );
+                        return Err(ArkaiError::InvalidPipeline(format!(
+                            "Step '{}' references future {} '{}' (forward references not allowed)",
+                            step.name, kind, target
+                        )));
                     }
                     None => {
-                        anyhow::bail!(
-                            "Step '{}' references non-existent step '{}'",
-                            step.name,
-                            previous_step
-                        );
+                        return Err(ArkaiError::InvalidPipeline(format!(
+                            "Step '{}' references non-existent {} '{}'",
+                            step.name, kind, target
+                        )));
                     }
                     _ => {}
                 }
             }
+
+            // depends_on may name any step; cycles are rejected below
+            for dependency in &step.depends_on {
+                if !step_names.contains(&dependency.as_str()) {
+                    return Err(ArkaiError::InvalidPipeline(format!(
+                        "Step '{}' depends on non-existent step '{}'",
+                        step.name, dependency
+                    )));
+                }
+            }
+        }
+
+        if let Some(cycle) = self.dependency_cycle() {
+            return Err(ArkaiError::InvalidPipeline(format!(
+                "Dependency cycle: {}",
+                cycle.join(" -> ")
+            )));
+        }
+
+        if self.max_parallel == Some(0) {
+            return Err(ArkaiError::InvalidPipeline(
+                "max_parallel must be at least 1".to_string(),
+            ));
+        }
+
+        if let Some(ref evidence) = self.evidence {
+            for step in [&evidence.claims, &evidence.source] {
+                if !step_names.contains(&step.as_str()) {
+                    return Err(ArkaiError::InvalidPipeline(format!(
+                        "Evidence block references non-existent step '{}'",
+                        step
+                    )));
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// Validate the pipeline definition, treating warnings as errors
+    /// (`arkai run --strict`)
+    pub fn validate_strict(&self) -> Result<()> {
+        self.check_definition()?;
+        match self.warnings().as_slice() {
+            [] => Ok(()),
+            warnings => Err(ArkaiError::InvalidPipeline(warnings.join("; "))),
+        }
+    }
+
+    /// Problems that don't make the pipeline invalid but will likely fail a
+    /// run: steps taking input from a disabled step, and step timeouts that
+    /// can't all fit in the run timeout
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings: Vec<String> = self
+            .steps
+            .iter()
+            .filter(|step| step.enabled)
+            .filter_map(|step| {
+                let disabled = self.disabled_input(step)?;
+                Some(format!(
+                    "Step '{}' takes its input from disabled step '{}'",
+                    step.name, disabled
+                ))
+            })
+            .collect();
+
+        let worst_case = self.worst_case_runtime();
+        let run_timeout = Duration::from_secs(self.safety_limits.run_timeout_seconds);
+        if worst_case > run_timeout {
+            warnings.push(format!(
+                "Worst-case run time {}s (max_attempts × step timeout plus retry backoff, \
+                 along the longest chain of steps) exceeds the run timeout of {}s",
+                worst_case.as_secs(),
+                run_timeout.as_secs()
+            ));
+        }
+
+        warnings
+    }
+
+    /// Longest the enabled steps can take, with every attempt running until
+    /// its step timeout and every retry waiting out its full backoff. Steps
+    /// are scheduled as the orchestrator runs them: each set of ready steps
+    /// (up to `max_parallel`) runs together and lasts as long as its slowest
+    /// step, so independent steps overlap rather than add up.
+    pub fn worst_case_runtime(&self) -> Duration {
+        let mut pending: Vec<&Step> = self.steps.iter().collect();
+        let mut finished: HashSet<&str> = HashSet::new();
+        let mut total = Duration::ZERO;
+
+        while !pending.is_empty() {
+            let ready: Vec<&Step> = pending
+                .iter()
+                .copied()
+                .filter(|step| {
+                    step.dependencies().all(|dependency| {
+                        finished.contains(dependency) || self.get_step(dependency).is_none()
+                    })
+                })
+                .take(self.parallelism())
+                .collect();
+            // A dependency cycle, which check_definition reports
+            if ready.is_empty() {
+                break;
+            }
+            pending.retain(|step| !ready.iter().any(|r| r.name == step.name));
+
+            total += ready
+                .iter()
+                .filter(|step| step.enabled)
+                .map(|step| step.worst_case_time(&self.safety_limits))
+                .max()
+                .unwrap_or_default();
+            finished.extend(ready.iter().map(|step| step.name.as_str()));
+        }
+
+        total
+    }
+
+    /// Steps in a dependency cycle, as `[a, b, a]`, if there is one
+    fn dependency_cycle(&self) -> Option<Vec<&str>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+
+        fn visit<'a>(
+            pipeline: &'a Pipeline,
+            index: usize,
+            marks: &mut [Mark],
+            path: &mut Vec<&'a str>,
+        ) -> Option<Vec<&'a str>> {
+            let step = &pipeline.steps[index];
+            marks[index] = Mark::InProgress;
+            path.push(&step.name);
+            for dependency in step.dependencies() {
+                let Some(next) = pipeline.step_index(dependency) else {
+                    continue;
+                };
+                match marks[next] {
+                    Mark::InProgress => {
+                        let start = path.iter().position(|&name| name == dependency)?;
+                        let mut cycle = path[start..].to_vec();
+                        cycle.push(dependency);
+                        return Some(cycle);
+                    }
+                    Mark::Unvisited => {
+                        if let Some(cycle) = visit(pipeline, next, marks, path) {
+                            return Some(cycle);
+                        }
+                    }
+                    Mark::Done => {}
+                }
+            }
+            path.pop();
+            marks[index] = Mark::Done;
+            None
+        }
+
+        let mut marks = vec![Mark::Unvisited; self.steps.len()];
+        (0..self.steps.len()).find_map(|index| {
+            if marks[index] != Mark::Unvisited {
+                return None;
+            }
+            visit(self, index, &mut marks, &mut Vec::new())
+        })
+    }
+
+    /// How many steps may run at once
+    pub fn parallelism(&self) -> usize {
+        self.max_parallel.unwrap_or(self.steps.len()).max(1)
+    }
+
+    /// The disabled step `step` takes its input from, if any
+    pub fn disabled_input(&self, step: &Step) -> Option<&str> {
+        let input = self.get_step(step.input_step()?)?;
+        (!input.enabled).then_some(input.name.as_str())
+    }
+
+    /// Apply `arkai run --step-timeout/--run-timeout/--retry-budget` overrides.
+    ///
+    /// Overrides replace the pipeline's `safety_limits` in either direction
+    /// (tighter or looser). Steps with an explicit `timeout_seconds` keep it
+    /// unless `overrides.override_step_timeouts` is set.
+    pub fn with_timeout_overrides(mut self, overrides: &TimeoutOverrides) -> Self {
+        if let Some(seconds) = overrides.step_timeout_seconds {
+            self.safety_limits.step_timeout_seconds = seconds;
+            if overrides.override_step_timeouts {
+                for step in &mut self.steps {
+                    step.timeout_seconds = None;
+                }
+            }
+        }
+        if let Some(seconds) = overrides.run_timeout_seconds {
+            self.safety_limits.run_timeout_seconds = seconds;
+        }
+        if let Some(retries) = overrides.max_total_retries {
+            self.safety_limits.max_total_retries = Some(retries);
+        }
+        self
+    }
+
     /// Get a step by name
     pub fn get_step(&self, name: &str) -> Option<&Step> {
         self.steps.iter().find(|s| s.name == name)
@@ -95,6 +426,53 @@ impl Pipeline {
     pub fn step_index(&self, name: &str) -> Option<usize> {
         self.steps.iter().position(|s| s.name == name)
     }
+
+    /// Decide which steps a resume skips, given the run's replayed events
+    ///
+    /// A step is skipped when the log records it as completed; everything else
+    /// (never started, failed, or interrupted mid-step) runs again.
+    pub fn plan_resume(&self, events: &[Event]) -> Vec<(Step, StepPlan)> {
+        self.steps
+            .iter()
+            .map(|step| {
+                let completed = events.iter().any(|e| {
+                    e.event_type == EventType::StepCompleted
+                        && e.step_id.as_deref() == Some(step.name.as_str())
+                });
+                let plan = if completed {
+                    StepPlan::Skip
+                } else {
+                    StepPlan::Run
+                };
+                (step.clone(), plan)
+            })
+            .collect()
+    }
+}
+
+/// What a resume does with a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepPlan {
+    /// Already completed; reuse its stored artifact
+    Skip,
+    /// Not completed; execute it
+    Run,
+}
+
+/// Runtime timeout and retry budget overrides for a pipeline's safety limits
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimeoutOverrides {
+    /// Replaces `safety_limits.step_timeout_seconds`
+    pub step_timeout_seconds: Option<u64>,
+
+    /// Replaces `safety_limits.run_timeout_seconds`
+    pub run_timeout_seconds: Option<u64>,
+
+    /// Let the step timeout override win over per-step `timeout_seconds` too
+    pub override_step_timeouts: bool,
+
+    /// Replaces `safety_limits.max_total_retries`
+    pub max_total_retries: Option<u32>,
 }
 
 /// A single step in a pipeline
@@ -119,9 +497,101 @@ pub struct Step {
 
     /// Override timeout for this step (uses safety_limits.step_timeout_seconds if not set)
     pub timeout_seconds: Option<u64>,
+
+    /// Keep running later steps if this one fails (after retries); the run
+    /// then ends `PartiallyCompleted` instead of `Failed`
+    #[serde(default)]
+    pub continue_on_error: bool,
+
+    /// File name (without `.md`) for this step's stored artifact; defaults to
+    /// the step name. The artifact is still addressed by step name.
+    #[serde(default)]
+    pub artifact_name: Option<String>,
+
+    /// Format the step's output must be in; checked after each attempt, so an
+    /// output that doesn't match fails the attempt and is retried
+    #[serde(default)]
+    pub output_format: Option<OutputFormat>,
+
+    /// JSON Schema the step's (JSON) output must satisfy; checked like
+    /// `output_format`. Needs the `json-schema` feature.
+    #[serde(default)]
+    pub output_schema: Option<OutputSchema>,
+
+    /// Strip model chatter and code fences from the output before it's stored
+    /// and validated (see [`CleanOutput`])
+    #[serde(default)]
+    pub clean_output: CleanOutput,
+
+    /// Set to `false` to skip the step without deleting it; it's recorded as
+    /// `Skipped`, and steps taking their input from it fail
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Steps that must finish before this one starts, besides the one it
+    /// takes input from (e.g. a step reading files another step writes)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+
+    /// `output_schema`, compiled once by [`Self::schema_validator`] and
+    /// shared by clones of the step
+    #[serde(skip)]
+    pub(crate) compiled_schema: OnceLock<Arc<SchemaValidator>>,
 }
 
 impl Step {
+    /// The compiled `output_schema`, or `None` if the step doesn't declare one
+    pub fn schema_validator(&self) -> anyhow::Result<Option<Arc<SchemaValidator>>> {
+        let Some(ref schema) = self.output_schema else {
+            return Ok(None);
+        };
+        if let Some(validator) = self.compiled_schema.get() {
+            return Ok(Some(Arc::clone(validator)));
+        }
+        let validator = Arc::new(schema.compile()?);
+        Ok(Some(Arc::clone(
+            self.compiled_schema.get_or_init(|| validator),
+        )))
+    }
+
+    /// The step whose artifact this step takes as input, if any
+    pub fn input_step(&self) -> Option<&str> {
+        match self.input_from {
+            InputSource::PreviousStep { ref previous_step } => Some(previous_step),
+            InputSource::Artifact { ref artifact } => Some(artifact),
+            _ => None,
+        }
+    }
+
+    /// Steps that must finish before this one starts: its input step and
+    /// `depends_on`
+    pub fn dependencies(&self) -> impl Iterator<Item = &str> {
+        self.input_step()
+            .into_iter()
+            .chain(self.depends_on.iter().map(String::as_str))
+    }
+
+    /// Longest this step can take: every attempt running until the step
+    /// timeout, plus the backoff before each retry
+    pub fn worst_case_time(&self, limits: &SafetyLimits) -> Duration {
+        let attempts = self.retry_policy.max_attempts.max(1);
+        self.timeout(limits) * attempts + self.retry_policy.max_total_delay()
+    }
+
+    /// Name of the file this step's artifact is stored under
+    pub fn artifact_file_name(&self) -> &str {
+        self.artifact_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Whether the stored artifact can differ from the adapter's raw output
+    /// or be rejected after it: the step cleans its output or checks it
+    /// against a JSON format or schema
+    pub fn post_processes_output(&self) -> bool {
+        !matches!(self.clean_output, CleanOutput::Enabled(false))
+            || self.output_format == Some(OutputFormat::Json)
+            || self.output_schema.is_some()
+    }
+
     /// Get the effective timeout for this step
     pub fn timeout(&self, limits: &SafetyLimits) -> Duration {
         let seconds = self.timeout_seconds.unwrap_or(limits.step_timeout_seconds);
@@ -129,9 +599,135 @@ impl Step {
     }
 }
 
-/// Supported adapter types
+/// Declared format of a step's output
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Must parse as JSON
+    Json,
+    /// Markdown (not checked)
+    Markdown,
+    /// Plain text (not checked)
+    Text,
+}
+
+/// A declared format types the step's artifact
+impl From<OutputFormat> for ArtifactType {
+    fn from(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Json => Self::Json,
+            OutputFormat::Markdown => Self::Markdown,
+            OutputFormat::Text => Self::Text,
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Check that `output` is in this format
+    pub fn validate(&self, output: &str) -> anyhow::Result<()> {
+        match self {
+            Self::Json => serde_json::from_str::<serde_json::Value>(output)
+                .map(|_| ())
+                .map_err(|e| anyhow::anyhow!("Output is not valid JSON: {}", e)),
+            Self::Markdown | Self::Text => Ok(()),
+        }
+    }
+}
+
+/// JSON Schema for a step's output: a path to a schema file, or the schema inline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OutputSchema {
+    /// Path to a JSON schema file (relative to the working directory)
+    Path(PathBuf),
+    /// Schema written directly in the pipeline
+    Inline(serde_json::Value),
+}
+
+impl OutputSchema {
+    /// The schema document
+    pub fn load(&self) -> anyhow::Result<serde_json::Value> {
+        match self {
+            Self::Path(path) => {
+                let content = std::fs::read_to_string(path).map_err(|e| {
+                    anyhow::anyhow!("Failed to read output schema {}: {}", path.display(), e)
+                })?;
+                serde_json::from_str(&content).map_err(|e| {
+                    anyhow::anyhow!("Failed to parse output schema {}: {}", path.display(), e)
+                })
+            }
+            Self::Inline(schema) => Ok(schema.clone()),
+        }
+    }
+
+    /// Compile the schema
+    #[cfg(feature = "json-schema")]
+    pub fn compile(&self) -> anyhow::Result<SchemaValidator> {
+        let schema = self.load()?;
+        let validator = jsonschema::validator_for(&schema)
+            .map_err(|e| anyhow::anyhow!("Invalid output schema: {}", e))?;
+        Ok(SchemaValidator { validator })
+    }
+
+    /// Without the `json-schema` feature there's no validator, so a pipeline
+    /// whose steps declare a schema is rejected rather than run unchecked
+    #[cfg(not(feature = "json-schema"))]
+    pub fn compile(&self) -> anyhow::Result<SchemaValidator> {
+        anyhow::bail!("output_schema requires arkai to be built with the `json-schema` feature")
+    }
+}
+
+/// A compiled [`OutputSchema`]
+#[derive(Debug)]
+pub struct SchemaValidator {
+    #[cfg(feature = "json-schema")]
+    validator: jsonschema::Validator,
+}
+
+#[cfg(feature = "json-schema")]
+impl SchemaValidator {
+    /// Check that `output` is JSON matching the schema
+    pub fn validate(&self, output: &str) -> anyhow::Result<()> {
+        let instance: serde_json::Value = serde_json::from_str(output)
+            .map_err(|e| anyhow::anyhow!("Output is not valid JSON: {}", e))?;
+
+        let errors: Vec<String> = self
+            .validator
+            .iter_errors(&instance)
+            .map(|e| format!("{} (at '{}')", e, e.instance_path()))
+            .collect();
+        if !errors.is_empty() {
+            anyhow::bail!("Output does not match schema: {}", errors.join("; "));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "json-schema"))]
+impl SchemaValidator {
+    /// Unreachable: [`OutputSchema::compile`] never builds a validator
+    /// without the `json-schema` feature
+    pub fn validate(&self, _output: &str) -> anyhow::Result<()> {
+        anyhow::bail!("output_schema requires arkai to be built with the `json-schema` feature")
+    }
+}
+
+/// Read a pipeline file, distinguishing a missing file from other I/O errors
+fn read_pipeline_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => ArkaiError::PipelineNotFound {
+            path: path.to_path_buf(),
+        },
+        _ => ArkaiError::Other(
+            anyhow::Error::new(e)
+                .context(format!("Failed to read pipeline file: {}", path.display())),
+        ),
+    })
+}
+
+/// Supported adapter types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AdapterType {
     /// Fabric CLI/API
     Fabric,
@@ -140,6 +736,15 @@ pub enum AdapterType {
     Shell,
 }
 
+impl std::fmt::Display for AdapterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdapterType::Fabric => write!(f, "fabric"),
+            AdapterType::Shell => write!(f, "shell"),
+        }
+    }
+}
+
 impl Default for AdapterType {
     fn default() -> Self {
         Self::Fabric
@@ -152,7 +757,9 @@ impl Default for AdapterType {
 /// - Simple: `input_from: pipeline_input`
 /// - Previous step: `input_from: { previous_step: step_name }`
 /// - Artifact: `input_from: { artifact: artifact_name }`
-/// - Static: `input_from: { static: { key: value } }`
+/// - Static: `input_from: { static: "text" }` (strings are passed as-is, other values as JSON)
+/// - Environment: `input_from: { env: VAR_NAME, default: "fallback" }`
+/// - Files: `input_from: { glob: "notes/*.md", separator: "\n---\n" }`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum InputSource {
@@ -170,6 +777,21 @@ pub enum InputSource {
         #[serde(rename = "static")]
         value: serde_json::Value,
     },
+
+    /// Environment variable (errors if unset and no default is given)
+    Env {
+        env: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default: Option<String>,
+    },
+
+    /// Contents of every file matching a glob (relative to the working
+    /// directory), in path order, joined by `separator` (default: blank line)
+    Files {
+        glob: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        separator: Option<String>,
+    },
 }
 
 /// Marker for pipeline_input (deserializes from the string "pipeline_input")
@@ -203,8 +825,16 @@ pub struct RetryPolicy {
     /// Backoff multiplier (delay *= multiplier after each retry)
     #[serde(default = "default_backoff_multiplier")]
     pub backoff_multiplier: f64,
+
+    /// Randomize each delay by up to ± this fraction (e.g. 0.2 for ±20%) so
+    /// runs retrying the same backend spread out (default: 0, exact delays)
+    #[serde(default)]
+    pub jitter_fraction: f64,
 }
 
+fn default_enabled() -> bool {
+    true
+}
 fn default_max_attempts() -> u32 {
     3
 }
@@ -225,22 +855,47 @@ impl Default for RetryPolicy {
             initial_delay_ms: default_initial_delay(),
             max_delay_ms: default_max_delay(),
             backoff_multiplier: default_backoff_multiplier(),
+            jitter_fraction: 0.0,
         }
     }
 }
 
 impl RetryPolicy {
-    /// Calculate delay for a specific attempt (1-indexed)
+    /// Calculate delay for a specific attempt (1-indexed), with jitter
+    /// applied if `jitter_fraction` is set
     pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let delay = self.backoff_delay_ms(attempt);
+        if self.jitter_fraction <= 0.0 {
+            return Duration::from_millis(delay);
+        }
+
+        let factor = 1.0 + self.jitter_fraction * (fastrand::f64() * 2.0 - 1.0);
+        Duration::from_millis((delay as f64 * factor).round().max(0.0) as u64)
+    }
+
+    /// Exponential backoff delay in milliseconds, before jitter
+    fn backoff_delay_ms(&self, attempt: u32) -> u64 {
         if attempt <= 1 {
-            return Duration::from_millis(self.initial_delay_ms);
+            return self.initial_delay_ms;
         }
 
         let delay =
             self.initial_delay_ms as f64 * self.backoff_multiplier.powi((attempt - 1) as i32);
 
-        let capped = delay.min(self.max_delay_ms as f64) as u64;
-        Duration::from_millis(capped)
+        delay.min(self.max_delay_ms as f64) as u64
+    }
+
+    /// Longest the retries of one step can spend waiting, with every delay
+    /// at the top of its jitter range
+    pub fn max_total_delay(&self) -> Duration {
+        let jitter = 1.0 + self.jitter_fraction.max(0.0);
+        (1..self.max_attempts.max(1))
+            .map(|attempt| {
+                Duration::from_millis(
+                    (self.backoff_delay_ms(attempt) as f64 * jitter).round() as u64
+                )
+            })
+            .sum()
     }
 
     /// Check if we should retry based on attempt count
@@ -304,6 +959,344 @@ steps:
         assert!(pipeline.validate().is_err());
     }
 
+    #[test]
+    fn test_env_input_parsing() {
+        let yaml = r#"
+name: env
+description: Environment input
+steps:
+  - name: context
+    adapter: fabric
+    action: summarize
+    input_from:
+      env: ARKAI_CONTEXT
+  - name: dated
+    adapter: fabric
+    action: summarize
+    input_from:
+      env: ARKAI_DATE
+      default: today
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert!(pipeline.validate().is_ok());
+        assert!(matches!(
+            pipeline.steps[0].input_from,
+            InputSource::Env { ref env, default: None } if env == "ARKAI_CONTEXT"
+        ));
+        assert!(matches!(
+            pipeline.steps[1].input_from,
+            InputSource::Env { default: Some(ref d), .. } if d == "today"
+        ));
+    }
+
+    #[test]
+    fn test_artifact_references() {
+        let pipeline_with = |artifact: &str| {
+            Pipeline::from_yaml(&format!(
+                r#"
+name: artifacts
+description: Artifact references
+steps:
+  - name: first
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: second
+    adapter: fabric
+    action: analyze
+    input_from:
+      artifact: {}
+  - name: third
+    adapter: fabric
+    action: analyze
+    input_from: pipeline_input
+"#,
+                artifact
+            ))
+            .unwrap()
+        };
+
+        assert!(pipeline_with("first").validate().is_ok());
+
+        let err = pipeline_with("third").validate().unwrap_err();
+        assert!(err.to_string().contains("future artifact 'third'"));
+
+        let err = pipeline_with("frist").validate().unwrap_err();
+        assert!(err.to_string().contains("non-existent artifact 'frist'"));
+    }
+
+    #[test]
+    fn test_disabled_step_references_warn() {
+        let mut pipeline = Pipeline::from_yaml(TEST_PIPELINE_YAML).unwrap();
+        assert!(pipeline.steps.iter().all(|step| step.enabled));
+        assert!(pipeline.warnings().is_empty());
+
+        pipeline.steps[0].enabled = false;
+        // Still valid; referencing a disabled step is only a warning
+        assert!(pipeline.validate().is_ok());
+        assert_eq!(
+            pipeline.warnings(),
+            vec!["Step 'second' takes its input from disabled step 'first'"]
+        );
+        assert_eq!(pipeline.disabled_input(&pipeline.steps[1]), Some("first"));
+
+        // Nothing to warn about when the referencing step is disabled too
+        pipeline.steps[1].enabled = false;
+        assert!(pipeline.warnings().is_empty());
+
+        let parsed = Pipeline::from_yaml(
+            "name: t\ndescription: d\nsteps:\n  - name: a\n    adapter: shell\n    action: cat\n    enabled: false\n",
+        )
+        .unwrap();
+        assert!(!parsed.steps[0].enabled);
+    }
+
+    #[test]
+    fn test_retry_inflated_timeouts_exceed_run_timeout() {
+        let yaml = r#"
+name: slow
+description: Two steps, three attempts each
+safety_limits:
+  step_timeout_seconds: 60
+  run_timeout_seconds: 300
+steps:
+  - name: fetch
+    adapter: shell
+    action: cat
+  - name: summarize
+    adapter: fabric
+    action: summarize
+    input_from:
+      previous_step: fetch
+"#;
+        let mut pipeline = Pipeline::from_yaml(yaml).unwrap();
+        // 2 steps × (3 attempts × 60s + 1s and 2s of backoff)
+        assert_eq!(pipeline.worst_case_runtime(), Duration::from_secs(366));
+        assert!(pipeline.validate().is_ok());
+        assert_eq!(
+            pipeline.warnings(),
+            vec![
+                "Worst-case run time 366s (max_attempts × step timeout plus retry backoff, \
+                  along the longest chain of steps) exceeds the run timeout of 300s"
+            ]
+        );
+        let err = pipeline.validate_strict().unwrap_err();
+        assert!(err.to_string().contains("exceeds the run timeout of 300s"));
+
+        // Fits once retries are dropped
+        for step in &mut pipeline.steps {
+            step.retry_policy.max_attempts = 2;
+        }
+        assert!(pipeline.warnings().is_empty());
+        assert!(pipeline.validate_strict().is_ok());
+
+        // Disabled steps don't count
+        pipeline.steps[0].retry_policy.max_attempts = 5;
+        assert!(!pipeline.warnings().is_empty());
+        pipeline.steps[0].enabled = false;
+        pipeline.steps[1].input_from = InputSource::default();
+        assert_eq!(pipeline.worst_case_runtime(), Duration::from_secs(121));
+        assert!(pipeline.validate_strict().is_ok());
+    }
+
+    #[test]
+    fn test_independent_steps_overlap_in_worst_case_runtime() {
+        let yaml = r#"
+name: fan_out
+description: Two independent steps joined by a third
+safety_limits:
+  step_timeout_seconds: 60
+  run_timeout_seconds: 150
+steps:
+  - name: left
+    adapter: shell
+    action: cat
+    retry_policy:
+      max_attempts: 1
+  - name: right
+    adapter: shell
+    action: cat
+    retry_policy:
+      max_attempts: 2
+      initial_delay_ms: 5000
+  - name: join
+    adapter: shell
+    action: cat
+    depends_on: [left, right]
+    retry_policy:
+      max_attempts: 1
+"#;
+        let mut pipeline = Pipeline::from_yaml(yaml).unwrap();
+        // right (2 × 60s + 5s) and left run together, then join
+        assert_eq!(pipeline.worst_case_runtime(), Duration::from_secs(185));
+        assert_eq!(pipeline.warnings().len(), 1);
+
+        // One at a time, every step counts
+        pipeline.max_parallel = Some(1);
+        assert_eq!(pipeline.worst_case_runtime(), Duration::from_secs(245));
+
+        pipeline.max_parallel = None;
+        pipeline.safety_limits.run_timeout_seconds = 200;
+        assert!(pipeline.validate_strict().is_ok());
+    }
+
+    #[test]
+    fn test_dependency_cycle_rejected() {
+        let yaml = r#"
+name: cycle
+description: a waits for c, which waits for a
+max_parallel: 2
+steps:
+  - name: a
+    adapter: shell
+    action: cat
+    depends_on: [c]
+  - name: b
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: a
+  - name: c
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: b
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert_eq!(pipeline.parallelism(), 2);
+        let err = pipeline.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("Dependency cycle: a -> c -> b -> a"),
+            "{}",
+            err
+        );
+
+        let pipeline = Pipeline::from_yaml(&yaml.replace("[c]", "[missing]")).unwrap();
+        let err = pipeline.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("depends on non-existent step 'missing'"),
+            "{}",
+            err
+        );
+
+        // A forward dependency without a cycle is fine
+        let pipeline = Pipeline::from_yaml(&yaml.replace(
+            "    input_from:\n      previous_step: b\n",
+            "    input_from: pipeline_input\n",
+        ))
+        .unwrap();
+        pipeline.validate().unwrap();
+        assert_eq!(
+            pipeline.steps[0].dependencies().collect::<Vec<_>>(),
+            vec!["c"]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_step_names_rejected() {
+        let yaml = r#"
+name: duplicate
+description: Two steps share a name
+steps:
+  - name: summarize
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: summarize
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        let err = pipeline.validate().unwrap_err();
+        assert!(err.to_string().contains("Duplicate step name 'summarize'"));
+    }
+
+    #[test]
+    fn test_evidence_block_reference() {
+        let with_evidence = format!(
+            "{}evidence:\n  claims: second\n  source: first\n",
+            TEST_PIPELINE_YAML
+        );
+        let pipeline = Pipeline::from_yaml(&with_evidence).unwrap();
+        assert_eq!(pipeline.evidence.as_ref().unwrap().claims, "second");
+        assert!(pipeline.validate().is_ok());
+
+        let dangling = format!(
+            "{}evidence:\n  claims: missing\n  source: first\n",
+            TEST_PIPELINE_YAML
+        );
+        let pipeline = Pipeline::from_yaml(&dangling).unwrap();
+        assert!(pipeline.validate().is_err());
+
+        assert!(Pipeline::from_yaml(TEST_PIPELINE_YAML)
+            .unwrap()
+            .evidence
+            .is_none());
+    }
+
+    #[test]
+    fn test_timeout_overrides() {
+        let yaml = r#"
+name: timeouts
+description: Timeout overrides
+safety_limits:
+  step_timeout_seconds: 30
+  run_timeout_seconds: 600
+steps:
+  - name: default
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: explicit
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    timeout_seconds: 90
+"#;
+        let step_timeouts = |pipeline: &Pipeline| -> Vec<Duration> {
+            pipeline
+                .steps
+                .iter()
+                .map(|step| step.timeout(&pipeline.safety_limits))
+                .collect()
+        };
+
+        let overrides = TimeoutOverrides {
+            step_timeout_seconds: Some(5),
+            run_timeout_seconds: Some(7200),
+            override_step_timeouts: false,
+            max_total_retries: Some(4),
+        };
+        let pipeline = Pipeline::from_yaml(yaml)
+            .unwrap()
+            .with_timeout_overrides(&overrides);
+        assert_eq!(pipeline.safety_limits.run_timeout_seconds, 7200);
+        assert_eq!(pipeline.safety_limits.max_total_retries, Some(4));
+        assert_eq!(
+            step_timeouts(&pipeline),
+            vec![Duration::from_secs(5), Duration::from_secs(90)]
+        );
+
+        let forced = TimeoutOverrides {
+            override_step_timeouts: true,
+            ..overrides
+        };
+        let pipeline = Pipeline::from_yaml(yaml)
+            .unwrap()
+            .with_timeout_overrides(&forced);
+        assert_eq!(step_timeouts(&pipeline), vec![Duration::from_secs(5); 2]);
+
+        let untouched = Pipeline::from_yaml(yaml)
+            .unwrap()
+            .with_timeout_overrides(&TimeoutOverrides::default());
+        assert_eq!(untouched.safety_limits.step_timeout_seconds, 30);
+        assert_eq!(
+            step_timeouts(&untouched),
+            vec![Duration::from_secs(30), Duration::from_secs(90)]
+        );
+    }
+
     #[test]
     fn test_retry_policy_delays() {
         let policy = RetryPolicy {
@@ -320,6 +1313,85 @@ steps:
         assert_eq!(policy.delay_for_attempt(5), Duration::from_millis(10000)); // Capped
     }
 
+    #[test]
+    fn test_retry_policy_jitter() {
+        let exact = RetryPolicy {
+            initial_delay_ms: 1000,
+            backoff_multiplier: 2.0,
+            max_delay_ms: 10000,
+            ..Default::default()
+        };
+        let jittered = RetryPolicy {
+            jitter_fraction: 0.25,
+            ..exact.clone()
+        };
+
+        for _ in 0..100 {
+            // Without jitter every call gives the same delay
+            assert_eq!(exact.delay_for_attempt(2), Duration::from_millis(2000));
+
+            let delay = jittered.delay_for_attempt(2).as_millis();
+            assert!((1500..=2500).contains(&delay), "delay {}ms", delay);
+            let capped = jittered.delay_for_attempt(5).as_millis();
+            assert!((7500..=12500).contains(&capped), "delay {}ms", capped);
+        }
+
+        let yaml = r#"
+name: jitter
+description: Out-of-range jitter
+steps:
+  - name: a
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    retry_policy:
+      jitter_fraction: 1.5
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert!(pipeline.validate().is_err());
+    }
+
+    #[test]
+    fn test_safety_defaults_fill_omitted_fields() {
+        let defaults = SafetyDefaults {
+            max_steps: Some(5),
+            step_timeout_seconds: Some(42),
+            max_input_bytes: Some(1024),
+        };
+
+        // Pipeline sets max_steps explicitly; the rest come from config defaults
+        let pipeline = Pipeline::from_yaml_with_defaults(TEST_PIPELINE_YAML, &defaults).unwrap();
+        assert_eq!(pipeline.safety_limits.max_steps, 10);
+        assert_eq!(pipeline.safety_limits.step_timeout_seconds, 42);
+        assert_eq!(pipeline.safety_limits.max_input_bytes, 1024);
+        // Not covered by config: hardcoded default
+        assert_eq!(pipeline.safety_limits.run_timeout_seconds, 3600);
+    }
+
+    #[test]
+    fn test_safety_defaults_apply_without_safety_section() {
+        let yaml = r#"
+name: bare
+description: No safety section
+steps:
+  - name: only
+    adapter: fabric
+    action: summarize
+"#;
+        let defaults = SafetyDefaults {
+            max_steps: Some(3),
+            ..Default::default()
+        };
+
+        let pipeline = Pipeline::from_yaml_with_defaults(yaml, &defaults).unwrap();
+        assert_eq!(pipeline.safety_limits.max_steps, 3);
+        assert_eq!(pipeline.safety_limits.step_timeout_seconds, 300);
+
+        let unconfigured =
+            Pipeline::from_yaml_with_defaults(yaml, &SafetyDefaults::default()).unwrap();
+        assert_eq!(unconfigured.safety_limits.max_steps, 50);
+    }
+
     #[test]
     fn test_shell_pipeline_fixture_parsing() {
         let path = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -333,4 +1405,236 @@ steps:
         assert_eq!(pipeline.steps[0].action, "cat");
         assert!(pipeline.validate().is_ok());
     }
+
+    #[test]
+    fn test_artifact_name_validation() {
+        let yaml = r#"
+name: test
+description: Test pipeline
+steps:
+  - name: extract_wisdom
+    adapter: fabric
+    action: extract_wisdom
+    artifact_name: wisdom
+  - name: wisdom
+    adapter: fabric
+    action: summarize
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert_eq!(pipeline.steps[0].artifact_file_name(), "wisdom");
+        assert_eq!(pipeline.steps[1].artifact_file_name(), "wisdom");
+        assert!(pipeline.validate().is_err());
+
+        let mut pipeline = pipeline;
+        pipeline.steps[1].artifact_name = Some("summary".to_string());
+        assert!(pipeline.validate().is_ok());
+
+        pipeline.steps[1].artifact_name = Some("../summary".to_string());
+        assert!(pipeline.validate().is_err());
+    }
+
+    #[test]
+    fn test_output_format_validation() {
+        assert!(OutputFormat::Json.validate("{\"ok\": true}\n").is_ok());
+        assert!(OutputFormat::Json.validate("Here is your JSON").is_err());
+        assert!(OutputFormat::Markdown.validate("# Title").is_ok());
+        assert!(OutputFormat::Text.validate("anything").is_ok());
+
+        let yaml = r#"
+name: test
+description: Test pipeline
+steps:
+  - name: extract
+    adapter: fabric
+    action: extract_json
+    output_format: json
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert_eq!(pipeline.steps[0].output_format, Some(OutputFormat::Json));
+    }
+
+    #[test]
+    fn test_output_schema_parsing() {
+        let yaml = r#"
+name: test
+description: Test pipeline
+steps:
+  - name: from_file
+    adapter: fabric
+    action: extract_json
+    output_schema: schemas/claims.json
+  - name: inline
+    adapter: fabric
+    action: extract_json
+    output_schema:
+      type: object
+      required: [claims]
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert_eq!(
+            pipeline.steps[0].output_schema,
+            Some(OutputSchema::Path(PathBuf::from("schemas/claims.json")))
+        );
+        let inline = pipeline.steps[1].output_schema.as_ref().unwrap();
+        assert_eq!(inline.load().unwrap()["required"][0], "claims");
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn test_output_schema_validation() {
+        let schema = OutputSchema::Inline(serde_json::json!({
+            "type": "object",
+            "required": ["claims"],
+            "properties": { "claims": { "type": "array", "items": { "type": "string" } } }
+        }))
+        .compile()
+        .unwrap();
+
+        assert!(schema.validate(r#"{"claims": ["a", "b"]}"#).is_ok());
+
+        let err = schema.validate(r#"{"claims": [1]}"#).unwrap_err();
+        assert!(err.to_string().contains("/claims/0"));
+        assert!(schema.validate(r#"{"other": true}"#).is_err());
+        assert!(schema.validate("not json").is_err());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("schema.json");
+        std::fs::write(&path, r#"{"type": "array"}"#).unwrap();
+        let schema = OutputSchema::Path(path).compile().unwrap();
+        assert!(schema.validate("[1, 2]").is_ok());
+        assert!(schema.validate("{}").is_err());
+    }
+
+    #[cfg(feature = "json-schema")]
+    #[test]
+    fn test_output_schema_checked_once_in_validate() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("schema.json");
+        let yaml = format!(
+            r#"
+name: test
+description: Test pipeline
+steps:
+  - name: extract
+    adapter: fabric
+    action: extract_json
+    output_schema: {}
+"#,
+            path.display()
+        );
+        let pipeline = Pipeline::from_yaml(&yaml).unwrap();
+
+        // A missing or invalid schema makes the pipeline invalid
+        assert!(pipeline.validate().is_err());
+        std::fs::write(&path, r#"{"type": 12}"#).unwrap();
+        assert!(pipeline.validate().is_err());
+
+        // Once compiled, the validator is reused without rereading the file
+        std::fs::write(&path, r#"{"type": "array"}"#).unwrap();
+        pipeline.validate().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let step = pipeline.steps[0].clone();
+        let validator = step.schema_validator().unwrap().unwrap();
+        assert!(validator.validate("[1]").is_ok());
+        assert!(validator.validate("{}").is_err());
+    }
+
+    #[cfg(not(feature = "json-schema"))]
+    #[test]
+    fn test_output_schema_requires_feature() {
+        let schema = OutputSchema::Inline(serde_json::json!({ "type": "object" }));
+        assert!(schema.compile().is_err());
+
+        let yaml = r#"
+name: test
+description: Test pipeline
+steps:
+  - name: extract
+    adapter: fabric
+    action: extract_json
+    output_schema:
+      type: object
+"#;
+        let pipeline = Pipeline::from_yaml(yaml).unwrap();
+        assert!(pipeline.validate().is_err());
+    }
+
+    fn step_event(step: &str, event_type: EventType) -> Event {
+        let run_id = uuid::Uuid::nil();
+        Event::new(
+            run_id,
+            Some(step.to_string()),
+            event_type,
+            format!("{}:{}", run_id, step),
+            String::new(),
+            crate::domain::StepStatus::Pending,
+        )
+    }
+
+    fn plans(pipeline: &Pipeline, events: &[Event]) -> Vec<(String, StepPlan)> {
+        pipeline
+            .plan_resume(events)
+            .into_iter()
+            .map(|(step, plan)| (step.name, plan))
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_resume_fully_completed_skips_all() {
+        let pipeline = Pipeline::from_yaml(TEST_PIPELINE_YAML).unwrap();
+        let events = vec![
+            step_event("first", EventType::StepStarted),
+            step_event("first", EventType::StepCompleted),
+            step_event("second", EventType::StepStarted),
+            step_event("second", EventType::StepCompleted),
+        ];
+
+        assert_eq!(
+            plans(&pipeline, &events),
+            vec![
+                ("first".to_string(), StepPlan::Skip),
+                ("second".to_string(), StepPlan::Skip),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_resume_partially_completed() {
+        let pipeline = Pipeline::from_yaml(TEST_PIPELINE_YAML).unwrap();
+        let events = vec![
+            step_event("first", EventType::StepStarted),
+            step_event("first", EventType::StepCompleted),
+        ];
+
+        assert_eq!(
+            plans(&pipeline, &events),
+            vec![
+                ("first".to_string(), StepPlan::Skip),
+                ("second".to_string(), StepPlan::Run),
+            ]
+        );
+        assert!(pipeline
+            .plan_resume(&[])
+            .iter()
+            .all(|(_, plan)| *plan == StepPlan::Run));
+    }
+
+    #[test]
+    fn test_plan_resume_reruns_failed_step() {
+        let pipeline = Pipeline::from_yaml(TEST_PIPELINE_YAML).unwrap();
+        let events = vec![
+            step_event("first", EventType::StepStarted),
+            step_event("first", EventType::StepCompleted),
+            step_event("second", EventType::StepStarted),
+            step_event("second", EventType::StepFailed),
+        ];
+
+        assert_eq!(
+            plans(&pipeline, &events),
+            vec![
+                ("first".to_string(), StepPlan::Skip),
+                ("second".to_string(), StepPlan::Run),
+            ]
+        );
+    }
 }
@@ -0,0 +1,168 @@
+//! Token-bucket rate limiting for Fabric calls.
+//!
+//! Batches and library reprocessing can send more requests than the LLM
+//! provider behind Fabric allows, and each rejected call turns into a retry.
+//! The orchestrator takes a token from a [`RateLimiter`] before every
+//! `adapter: fabric` call, so calls are delayed to stay under the limit
+//! instead of failing. One limiter is shared by every run in the process.
+//!
+//! The limit comes from the `fabric:` section of `.arkai/config.yaml`:
+//!
+//! ```yaml
+//! fabric:
+//!   rate_limit:
+//!     requests_per_minute: 20
+//!     burst: 2
+//! ```
+//!
+//! `burst` (default 1) is how many calls may go out back to back after a
+//! quiet period; after that calls are spaced `60 / requests_per_minute`
+//! seconds apart.
+//!
+//! [`RateLimiter::state`] reports how often calls have been held back;
+//! `arkai serve` exposes it at `GET /healthz`.
+
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+/// Configured request rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Sustained calls per minute
+    pub requests_per_minute: u32,
+    /// Calls allowed back to back before spacing kicks in
+    #[serde(default = "default_burst")]
+    pub burst: u32,
+}
+
+fn default_burst() -> u32 {
+    1
+}
+
+impl RateLimit {
+    /// Tokens added per second
+    fn per_second(&self) -> f64 {
+        f64::from(self.requests_per_minute) / 60.0
+    }
+}
+
+/// Snapshot of a limiter, for reporting
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ThrottleState {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+    /// Tokens available now; negative while calls are queued
+    pub available: f64,
+    /// Calls that had to wait for a token
+    pub throttled_calls: u64,
+    /// Total time calls have waited
+    pub total_delay_ms: u64,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+    throttled_calls: u64,
+    total_delay: Duration,
+}
+
+/// Token bucket shared by concurrent callers
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    /// A limiter starting with a full bucket
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.burst.max(1)),
+                refilled_at: Instant::now(),
+                throttled_calls: 0,
+                total_delay: Duration::ZERO,
+            }),
+        }
+    }
+
+    /// The limiter for Fabric calls configured in `fabric.rate_limit`, if any.
+    /// Every caller gets the same limiter.
+    pub fn shared_fabric() -> Option<Arc<Self>> {
+        static SHARED: OnceLock<Option<Arc<RateLimiter>>> = OnceLock::new();
+        SHARED
+            .get_or_init(|| {
+                let limit = crate::config::config().ok()?.fabric_rate_limit?;
+                Some(Arc::new(Self::new(limit)))
+            })
+            .clone()
+    }
+
+    /// Add the tokens earned since the last refill
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let earned = now.duration_since(bucket.refilled_at).as_secs_f64() * self.limit.per_second();
+        bucket.tokens = (bucket.tokens + earned).min(f64::from(self.limit.burst.max(1)));
+        bucket.refilled_at = now;
+    }
+
+    /// Wait until a call may go out. Each caller reserves its token up
+    /// front, so concurrent callers are released in turn rather than all at
+    /// once. Returns how long the caller waited.
+    pub async fn acquire(&self) -> Duration {
+        let wait = {
+            let mut bucket = self.bucket.lock().unwrap();
+            self.refill(&mut bucket, Instant::now());
+            bucket.tokens -= 1.0;
+            if bucket.tokens >= 0.0 {
+                return Duration::ZERO;
+            }
+            let wait = Duration::from_secs_f64(-bucket.tokens / self.limit.per_second());
+            bucket.throttled_calls += 1;
+            bucket.total_delay += wait;
+            wait
+        };
+        tokio::time::sleep(wait).await;
+        wait
+    }
+
+    /// Current state of the bucket
+    pub fn state(&self) -> ThrottleState {
+        let mut bucket = self.bucket.lock().unwrap();
+        self.refill(&mut bucket, Instant::now());
+        ThrottleState {
+            requests_per_minute: self.limit.requests_per_minute,
+            burst: self.limit.burst,
+            available: bucket.tokens,
+            throttled_calls: bucket.throttled_calls,
+            total_delay_ms: bucket.total_delay.as_millis() as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_then_spaced_calls() {
+        // 1200/min: one token every 50ms
+        let limiter = RateLimiter::new(RateLimit {
+            requests_per_minute: 1200,
+            burst: 2,
+        });
+
+        assert_eq!(limiter.acquire().await, Duration::ZERO);
+        assert_eq!(limiter.acquire().await, Duration::ZERO);
+        let waited = limiter.acquire().await;
+        assert!(waited > Duration::from_millis(30), "waited {:?}", waited);
+
+        let state = limiter.state();
+        assert_eq!(state.throttled_calls, 1);
+        assert!(state.available < 1.0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Index of run summaries for fast listing.
+//!
+//! `~/.arkai/runs/index.jsonl` holds one [`RunSummary`] per line. The event
+//! store appends a line whenever a run ends, and the orchestrator appends
+//! one when a run is resumed; the last line for a run wins. Runs missing
+//! from the index (older runs, or runs that crashed) are summarized from
+//! their event logs and backfilled. `arkai runs --rebuild-index` rewrites
+//! the index from the run directories if it drifts.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+use crate::domain::RunSummary;
+use crate::error::Result;
+
+use super::event_store::EventStore;
+
+/// File name of the index inside the runs directory
+pub const INDEX_FILE: &str = "index.jsonl";
+
+/// The run index file
+#[derive(Debug, Clone)]
+pub struct RunIndex {
+    path: PathBuf,
+}
+
+impl RunIndex {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// The index in the runs directory
+    pub fn open() -> Result<Self> {
+        Ok(Self::new(EventStore::base_directory()?.join(INDEX_FILE)))
+    }
+
+    /// Get the path to the index file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record `summary`, replacing any earlier entry for the run
+    pub async fn upsert(&self, summary: &RunSummary) -> Result<()> {
+        self.append(std::slice::from_ref(summary)).await
+    }
+
+    async fn append(&self, summaries: &[RunSummary]) -> Result<()> {
+        if summaries.is_empty() {
+            return Ok(());
+        }
+        let lines = render(summaries)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .with_context(|| format!("Failed to open run index: {}", self.path.display()))?;
+        file.write_all(lines.as_bytes())
+            .await
+            .context("Failed to write run index")?;
+        file.flush().await.context("Failed to flush run index")?;
+        Ok(())
+    }
+
+    /// The latest summary of each indexed run. Unreadable lines are skipped.
+    pub async fn load(&self) -> Result<HashMap<Uuid, RunSummary>> {
+        let mut summaries = HashMap::new();
+        if !self.path.exists() {
+            return Ok(summaries);
+        }
+
+        let content = fs::read_to_string(&self.path)
+            .await
+            .with_context(|| format!("Failed to read run index: {}", self.path.display()))?;
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<RunSummary>(line) {
+                Ok(summary) => {
+                    summaries.insert(summary.id, summary);
+                }
+                Err(e) => tracing::warn!("Skipping unreadable run index line: {}", e),
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// Summaries of `run_ids`, from the index where present and otherwise
+    /// from each run's event log (added to the index for next time)
+    pub async fn summaries(&self, run_ids: &[Uuid]) -> Result<Vec<RunSummary>> {
+        let mut indexed = self.load().await?;
+        let mut summaries = Vec::with_capacity(run_ids.len());
+        let mut backfill = Vec::new();
+
+        for run_id in run_ids {
+            match indexed.remove(run_id) {
+                Some(summary) => summaries.push(summary),
+                None => {
+                    let store = EventStore::open(*run_id).await?;
+                    if let Ok(Some(summary)) = store.summary().await {
+                        backfill.push(summary.clone());
+                        summaries.push(summary);
+                    }
+                }
+            }
+        }
+
+        if let Err(e) = self.append(&backfill).await {
+            tracing::warn!("Failed to backfill run index: {}", e);
+        }
+        Ok(summaries)
+    }
+
+    /// Rewrite the index from every run directory. Returns the number of
+    /// runs indexed.
+    pub async fn rebuild(&self) -> Result<usize> {
+        let mut summaries = Vec::new();
+        for run_id in EventStore::list_runs().await? {
+            let store = EventStore::open(run_id).await?;
+            if let Ok(Some(summary)) = store.summary().await {
+                summaries.push(summary);
+            }
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        let tmp = self.path.with_extension("jsonl.tmp");
+        fs::write(&tmp, render(&summaries)?)
+            .await
+            .with_context(|| format!("Failed to write run index: {}", tmp.display()))?;
+        fs::rename(&tmp, &self.path)
+            .await
+            .with_context(|| format!("Failed to replace run index: {}", self.path.display()))?;
+        Ok(summaries.len())
+    }
+}
+
+/// Index lines for `summaries`
+fn render(summaries: &[RunSummary]) -> Result<String> {
+    let mut lines = String::new();
+    for summary in summaries {
+        lines.push_str(&serde_json::to_string(summary).context("Failed to serialize run")?);
+        lines.push('\n');
+    }
+    Ok(lines)
+}
@@ -7,12 +7,14 @@
 //! - Denylist patterns (to avoid processing secrets)
 
 use std::path::Path;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use glob::Pattern;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::config::{ResolvedConfig, ValueSource};
+
 /// Safety limits for pipeline execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SafetyLimits {
@@ -39,6 +41,21 @@ pub struct SafetyLimits {
     /// Glob patterns to reject (files matching these won't be processed)
     #[serde(default = "default_denylist")]
     pub denylist_patterns: Vec<String>,
+
+    /// Maximum cost in USD reported by adapters per run (default: unlimited).
+    /// Only adapters that report usage count towards it; the Fabric CLI
+    /// reports neither cost nor tokens, so Fabric steps spend nothing here.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_cost_usd: Option<f64>,
+
+    /// Maximum tokens reported by adapters per run (default: unlimited; see
+    /// `max_total_cost_usd` for which adapters report them)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_tokens: Option<u64>,
+
+    /// Maximum retries across all steps of a run (default: unlimited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_retries: Option<u32>,
 }
 
 fn default_max_steps() -> u32 {
@@ -76,6 +93,9 @@ impl Default for SafetyLimits {
             step_timeout_seconds: default_step_timeout(),
             run_timeout_seconds: default_run_timeout(),
             denylist_patterns: default_denylist(),
+            max_total_cost_usd: None,
+            max_total_tokens: None,
+            max_total_retries: None,
         }
     }
 }
@@ -99,25 +119,32 @@ impl SafetyLimits {
         input: &str,
         source_path: Option<&Path>,
     ) -> Result<(), SafetyViolation> {
-        // Check size
-        let size = input.len() as u64;
+        self.validate_input_size(input.len() as u64)?;
+        if let Some(path) = source_path {
+            self.validate_input_path(path)?;
+        }
+        Ok(())
+    }
+
+    /// Check an input's size in bytes against `max_input_bytes`
+    pub fn validate_input_size(&self, size: u64) -> Result<(), SafetyViolation> {
         if size > self.max_input_bytes {
             return Err(SafetyViolation::MaxInputBytes {
                 actual: size,
                 limit: self.max_input_bytes,
             });
         }
+        Ok(())
+    }
 
-        // Check denylist
-        if let Some(path) = source_path {
-            let path_str = path.to_string_lossy();
-            if self.is_denylisted(&path_str) {
-                return Err(SafetyViolation::DenylistMatch {
-                    path: path_str.to_string(),
-                });
-            }
+    /// Check an input file's path against the denylist
+    pub fn validate_input_path(&self, path: &Path) -> Result<(), SafetyViolation> {
+        let path_str = path.to_string_lossy();
+        if self.is_denylisted(&path_str) {
+            return Err(SafetyViolation::DenylistMatch {
+                path: path_str.to_string(),
+            });
         }
-
         Ok(())
     }
 
@@ -180,7 +207,99 @@ impl SafetyLimits {
             });
         }
 
-        Ok(())
+        // Check cost and token budgets
+        if let Some(limit) = self.max_total_cost_usd {
+            if tracker.total_cost_usd >= limit {
+                return Err(SafetyViolation::CostExceeded {
+                    actual: tracker.total_cost_usd,
+                    limit,
+                });
+            }
+        }
+        if let Some(limit) = self.max_total_tokens {
+            if tracker.total_tokens >= limit {
+                return Err(SafetyViolation::TokensExceeded {
+                    actual: tracker.total_tokens,
+                    limit,
+                });
+            }
+        }
+
+        self.check_retries(tracker)
+    }
+
+    /// Check the retries made so far against `max_total_retries`
+    pub fn check_retries(&self, tracker: &SafetyTracker) -> Result<(), SafetyViolation> {
+        match self.max_total_retries {
+            Some(limit) if tracker.total_retries > limit => {
+                Err(SafetyViolation::RetryBudgetExceeded {
+                    actual: tracker.total_retries,
+                    limit,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Time left before the run timeout, zero once it has passed
+    pub fn run_time_remaining(&self, tracker: &SafetyTracker) -> Duration {
+        Duration::from_secs(self.run_timeout_seconds).saturating_sub(tracker.started_at.elapsed())
+    }
+
+    /// The violation for a run stopped at the run timeout
+    pub fn run_timeout(&self, tracker: &SafetyTracker) -> SafetyViolation {
+        SafetyViolation::RunTimeout {
+            elapsed_seconds: tracker.started_at.elapsed().as_secs(),
+            limit_seconds: self.run_timeout_seconds,
+        }
+    }
+}
+
+/// Safety values configured outside the pipeline (the `safety:` section of config.yaml).
+///
+/// Fields are `None` unless explicitly set in the config file, so a pipeline only
+/// inherits values the user actually configured; anything else keeps the
+/// `SafetyLimits` defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SafetyDefaults {
+    /// From config `max_steps`
+    pub max_steps: Option<u32>,
+
+    /// From config `timeout_seconds` (applied per step)
+    pub step_timeout_seconds: Option<u64>,
+
+    /// From config `max_input_size_bytes`
+    pub max_input_bytes: Option<u64>,
+}
+
+impl SafetyDefaults {
+    /// Extract the explicitly configured safety values from a resolved config
+    pub fn from_config(config: &ResolvedConfig) -> Self {
+        let configured = |source: ValueSource| source == ValueSource::Config;
+        let sources = &config.sources;
+
+        Self {
+            max_steps: configured(sources.safety_max_steps).then_some(config.safety.max_steps),
+            step_timeout_seconds: configured(sources.safety_timeout_seconds)
+                .then_some(config.safety.timeout_seconds),
+            max_input_bytes: configured(sources.safety_max_input_size_bytes)
+                .then_some(config.safety.max_input_size_bytes as u64),
+        }
+    }
+
+    /// Values keyed by their `SafetyLimits` field name
+    pub(crate) fn entries(&self) -> Vec<(&'static str, serde_yaml::Value)> {
+        let mut entries = Vec::new();
+        if let Some(v) = self.max_steps {
+            entries.push(("max_steps", v.into()));
+        }
+        if let Some(v) = self.step_timeout_seconds {
+            entries.push(("step_timeout_seconds", v.into()));
+        }
+        if let Some(v) = self.max_input_bytes {
+            entries.push(("max_input_bytes", v.into()));
+        }
+        entries
     }
 }
 
@@ -196,6 +315,15 @@ pub struct SafetyTracker {
     /// Total output bytes produced
     pub output_bytes: u64,
 
+    /// Total cost in USD reported by adapters
+    pub total_cost_usd: f64,
+
+    /// Total tokens reported by adapters
+    pub total_tokens: u64,
+
+    /// Retries made across all steps
+    pub total_retries: u32,
+
     /// When the run started
     pub started_at: Instant,
 }
@@ -213,6 +341,9 @@ impl SafetyTracker {
             steps_executed: 0,
             input_bytes: 0,
             output_bytes: 0,
+            total_cost_usd: 0.0,
+            total_tokens: 0,
+            total_retries: 0,
             started_at: Instant::now(),
         }
     }
@@ -224,6 +355,12 @@ impl SafetyTracker {
         self.output_bytes += output_bytes;
     }
 
+    /// Record the tokens and cost an adapter call reported, if any
+    pub fn record_usage(&mut self, tokens: Option<u64>, cost_usd: Option<f64>) {
+        self.total_tokens += tokens.unwrap_or(0);
+        self.total_cost_usd += cost_usd.unwrap_or(0.0);
+    }
+
     /// Get elapsed time in seconds
     pub fn elapsed_seconds(&self) -> u64 {
         self.started_at.elapsed().as_secs()
@@ -254,10 +391,29 @@ pub enum SafetyViolation {
         limit_seconds: u64,
     },
 
+    #[error("Cost budget exceeded: ${actual:.4} >= ${limit:.4}")]
+    CostExceeded { actual: f64, limit: f64 },
+
+    #[error("Token budget exceeded: {actual} >= {limit}")]
+    TokensExceeded { actual: u64, limit: u64 },
+
+    #[error("Retry budget exceeded: {actual} > {limit}")]
+    RetryBudgetExceeded { actual: u32, limit: u32 },
+
     #[error("Path matches denylist pattern: {path}")]
     DenylistMatch { path: String },
 }
 
+impl SafetyViolation {
+    /// The violation for a step stopped at its `step_timeout`
+    pub fn step_timeout(step_timeout: Duration) -> Self {
+        SafetyViolation::StepTimeout {
+            elapsed_seconds: step_timeout.as_secs(),
+            limit_seconds: step_timeout.as_secs(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,4 +484,33 @@ mod tests {
         let result = limits.check(&tracker);
         assert!(matches!(result, Err(SafetyViolation::MaxSteps { .. })));
     }
+
+    #[test]
+    fn test_tracker_budgets() {
+        let limits = SafetyLimits {
+            max_total_cost_usd: Some(0.10),
+            max_total_tokens: Some(1000),
+            ..Default::default()
+        };
+
+        let mut tracker = SafetyTracker::new();
+        tracker.record_usage(Some(400), Some(0.04));
+        tracker.record_usage(None, None);
+        assert!(limits.check(&tracker).is_ok());
+
+        tracker.record_usage(Some(700), None);
+        let result = limits.check(&tracker);
+        assert!(matches!(
+            result,
+            Err(SafetyViolation::TokensExceeded {
+                actual: 1100,
+                limit: 1000
+            })
+        ));
+
+        let mut tracker = SafetyTracker::new();
+        tracker.record_usage(None, Some(0.25));
+        let result = limits.check(&tracker);
+        assert!(matches!(result, Err(SafetyViolation::CostExceeded { .. })));
+    }
 }
@@ -0,0 +1,159 @@
+//! Optional HMAC signing of event log lines for tamper-evidence.
+//!
+//! When `ARKAI_EVENT_HMAC_KEY` is set, every line appended to `events.jsonl`
+//! gets a matching line in the sidecar `events.sig`:
+//!
+//! ```text
+//! sig[n] = HMAC-SHA256(key, sig[n-1] || "\n" || line[n])    (sig[-1] = "")
+//! ```
+//!
+//! Chaining each signature to the previous one means edits, deletions, and
+//! reordering all break verification from the first altered line onward.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Environment variable holding the signing key
+pub const HMAC_KEY_ENV: &str = "ARKAI_EVENT_HMAC_KEY";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs and verifies chained event line signatures
+#[derive(Clone)]
+pub struct EventSigner {
+    key: Vec<u8>,
+}
+
+impl std::fmt::Debug for EventSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the key
+        f.debug_struct("EventSigner").finish_non_exhaustive()
+    }
+}
+
+impl EventSigner {
+    /// Create a signer from raw key bytes
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    /// Create a signer from `ARKAI_EVENT_HMAC_KEY`, if set and non-empty
+    pub fn from_env() -> Option<Self> {
+        std::env::var(HMAC_KEY_ENV)
+            .ok()
+            .filter(|key| !key.is_empty())
+            .map(Self::new)
+    }
+
+    /// Compute the signature for `line`, chained to the previous signature
+    pub fn sign(&self, previous: Option<&str>, line: &str) -> String {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(previous.unwrap_or("").as_bytes());
+        mac.update(b"\n");
+        mac.update(line.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Check a signature chain against event lines.
+    ///
+    /// Returns the 0-based indices of lines whose signature doesn't verify,
+    /// including trailing lines that have no signature at all.
+    pub fn verify_chain(&self, lines: &[String], signatures: &[String]) -> Vec<SignatureMismatch> {
+        let mut mismatches = Vec::new();
+
+        for (index, line) in lines.iter().enumerate() {
+            let previous = index
+                .checked_sub(1)
+                .and_then(|i| signatures.get(i))
+                .map(String::as_str);
+
+            match signatures.get(index) {
+                Some(stored) if *stored == self.sign(previous, line) => {}
+                Some(_) => mismatches.push(SignatureMismatch::Invalid { index }),
+                None => mismatches.push(SignatureMismatch::Missing { index }),
+            }
+        }
+
+        for index in lines.len()..signatures.len() {
+            mismatches.push(SignatureMismatch::Orphaned { index });
+        }
+
+        mismatches
+    }
+}
+
+/// A line that failed signature verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureMismatch {
+    /// Signature doesn't match the line content (or the chain before it)
+    Invalid { index: usize },
+
+    /// Event line has no corresponding signature
+    Missing { index: usize },
+
+    /// Signature with no corresponding event line (line deleted)
+    Orphaned { index: usize },
+}
+
+impl std::fmt::Display for SignatureMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Invalid { index } => write!(f, "event {} signature does not verify", index + 1),
+            Self::Missing { index } => write!(f, "event {} has no signature", index + 1),
+            Self::Orphaned { index } => {
+                write!(f, "signature {} has no matching event", index + 1)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed_chain(signer: &EventSigner, lines: &[String]) -> Vec<String> {
+        let mut signatures: Vec<String> = Vec::new();
+        for line in lines {
+            let sig = signer.sign(signatures.last().map(String::as_str), line);
+            signatures.push(sig);
+        }
+        signatures
+    }
+
+    #[test]
+    fn test_sign_is_deterministic_and_keyed() {
+        let a = EventSigner::new("key-a");
+        let b = EventSigner::new("key-b");
+
+        assert_eq!(a.sign(None, "line"), a.sign(None, "line"));
+        assert_ne!(a.sign(None, "line"), b.sign(None, "line"));
+        assert_ne!(a.sign(None, "line"), a.sign(Some("prev"), "line"));
+        assert_eq!(a.sign(None, "line").len(), 64);
+    }
+
+    #[test]
+    fn test_verify_chain_detects_modification_and_truncation() {
+        let signer = EventSigner::new("secret");
+        let lines: Vec<String> = (0..3).map(|i| format!("{{\"n\":{}}}", i)).collect();
+        let signatures = signed_chain(&signer, &lines);
+
+        assert!(signer.verify_chain(&lines, &signatures).is_empty());
+
+        let mut tampered = lines.clone();
+        tampered[1] = "{\"n\":42}".to_string();
+        assert_eq!(
+            signer.verify_chain(&tampered, &signatures),
+            vec![SignatureMismatch::Invalid { index: 1 }]
+        );
+
+        assert_eq!(
+            signer.verify_chain(&lines[..2], &signatures),
+            vec![SignatureMismatch::Orphaned { index: 2 }]
+        );
+        assert_eq!(
+            signer.verify_chain(&lines, &signatures[..2]),
+            vec![SignatureMismatch::Missing { index: 2 }]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Run snapshots for compacted event logs.
+//!
+//! `arkai compact` folds a finished run's `events.jsonl` into
+//! `snapshot.json`: the [`Run`] reconstructed from those events, how many
+//! there were, and the ID of the last one. The log then holds only events
+//! appended later (e.g. by a resume). Replaying loads the snapshot and
+//! applies the events after it.
+//!
+//! The snapshot is written (temp file + rename) before the log is replaced
+//! the same way, so a crash in between leaves the full log beside the new
+//! snapshot; replay then skips the log up to the snapshot's last event.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+use crate::domain::{Event, Run};
+use crate::error::Result;
+
+/// File name of the snapshot inside the run directory
+pub const SNAPSHOT_FILE: &str = "snapshot.json";
+
+/// The start of a run's log, folded into its reconstructed state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSnapshot {
+    /// Sequence number of the last event folded in (the count of events)
+    pub seq: usize,
+
+    /// ID of the last event folded in
+    pub last_event_id: Uuid,
+
+    /// The run reconstructed from the folded events
+    pub run: Run,
+
+    /// The folded `StepCompleted` events, which resume plans and
+    /// idempotency checks still need
+    #[serde(default)]
+    pub step_completions: Vec<Event>,
+}
+
+impl RunSnapshot {
+    /// Load the snapshot at `path`, if the run has one
+    pub async fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Failed to read snapshot: {}", path.display()))?;
+        let snapshot = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse snapshot: {}", path.display()))?;
+        Ok(Some(snapshot))
+    }
+
+    /// Write the snapshot to `path` atomically
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).context("Failed to serialize snapshot")?;
+        replace_file(path, json.as_bytes()).await
+    }
+
+    /// Drop the events this snapshot already holds from the front of a log.
+    /// They are only there if a compaction stopped before truncating it.
+    pub fn tail<T>(&self, events: Vec<T>, id: impl Fn(&T) -> Uuid) -> Vec<T> {
+        match events
+            .iter()
+            .position(|event| id(event) == self.last_event_id)
+        {
+            Some(last) => events.into_iter().skip(last + 1).collect(),
+            None => events,
+        }
+    }
+}
+
+/// Replace `path` with `content` via a temp file and rename, so readers see
+/// the old file or the new one and never a partial write
+pub async fn replace_file(path: &Path, content: &[u8]) -> Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp = path.with_file_name(temp_name);
+
+    let mut file = fs::File::create(&temp)
+        .await
+        .with_context(|| format!("Failed to create {}", temp.display()))?;
+    file.write_all(content)
+        .await
+        .with_context(|| format!("Failed to write {}", temp.display()))?;
+    file.sync_all()
+        .await
+        .with_context(|| format!("Failed to sync {}", temp.display()))?;
+    drop(file);
+
+    fs::rename(&temp, path)
+        .await
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(())
+}
@@ -0,0 +1,291 @@
+//! SQLite event log backend (`storage: sqlite`).
+//!
+//! All runs share `runs/events.db`. Events are rows keyed by
+//! `(run_id, seq)` holding the serialized event, with an index on
+//! idempotency keys so resume checks are a single lookup. The `runs` table
+//! holds each run's latest summary, so listing runs is one query rather than
+//! a read of every log.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use rusqlite::{params, Connection, OptionalExtension};
+use uuid::Uuid;
+
+use crate::domain::{Event, EventType, RunSummary};
+use crate::error::Result;
+
+use super::event_backend::{parse_event, summarize, EventStoreBackend};
+
+/// File name of the database inside the runs directory
+pub const DATABASE_FILE: &str = "events.db";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS events (
+    run_id TEXT NOT NULL,
+    seq INTEGER NOT NULL,
+    event_type TEXT NOT NULL,
+    idempotency_key TEXT NOT NULL,
+    event TEXT NOT NULL,
+    PRIMARY KEY (run_id, seq)
+);
+CREATE INDEX IF NOT EXISTS events_idempotency_key ON events (idempotency_key);
+CREATE TABLE IF NOT EXISTS runs (
+    run_id TEXT PRIMARY KEY,
+    summary TEXT NOT NULL
+);
+";
+
+/// Open `path`, creating the schema if needed
+fn connect(path: &Path) -> Result<Connection> {
+    let conn = Connection::open(path)
+        .with_context(|| format!("Failed to open event database: {}", path.display()))?;
+    conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA busy_timeout=5000;")
+        .context("Failed to configure event database")?;
+    conn.execute_batch(SCHEMA)
+        .context("Failed to create event database schema")?;
+    Ok(conn)
+}
+
+/// The name an event type is stored under (its serialized form)
+fn type_name(event_type: EventType) -> String {
+    serde_json::to_value(event_type)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+/// One run's events in the shared database
+pub struct SqliteBackend {
+    path: PathBuf,
+    run_id: Uuid,
+    conn: Mutex<Connection>,
+}
+
+impl SqliteBackend {
+    /// The log of `run_id` in the database at `path`
+    pub fn open(path: PathBuf, run_id: Uuid) -> Result<Self> {
+        let conn = connect(&path)?;
+        Ok(Self {
+            path,
+            run_id,
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Every summary in the `runs` table of the database at `path`
+    pub fn summaries(path: &Path) -> Result<HashMap<Uuid, RunSummary>> {
+        let conn = connect(path)?;
+        let mut stmt = conn
+            .prepare("SELECT summary FROM runs")
+            .context("Failed to list runs")?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .context("Failed to list runs")?;
+
+        let mut summaries = HashMap::new();
+        for row in rows {
+            let row = row.context("Failed to read run summary")?;
+            match serde_json::from_str::<RunSummary>(&row) {
+                Ok(summary) => {
+                    summaries.insert(summary.id, summary);
+                }
+                Err(e) => tracing::warn!("Skipping unreadable run summary: {}", e),
+            }
+        }
+        Ok(summaries)
+    }
+
+    /// The serialized event at one end of the log
+    fn end_line(&self, conn: &Connection, last: bool) -> Result<Option<String>> {
+        let order = if last { "DESC" } else { "ASC" };
+        Ok(conn
+            .query_row(
+                &format!(
+                    "SELECT event FROM events WHERE run_id = ?1 ORDER BY seq {} LIMIT 1",
+                    order
+                ),
+                [self.run_id.to_string()],
+                |row| row.get(0),
+            )
+            .optional()
+            .context("Failed to read event")?)
+    }
+
+    fn query_lines(&self, seen: usize) -> Result<Vec<(usize, String)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn
+            .prepare_cached(
+                "SELECT seq, event FROM events WHERE run_id = ?1 AND seq > ?2 ORDER BY seq",
+            )
+            .context("Failed to read events")?;
+        let rows = stmt
+            .query_map(params![self.run_id.to_string(), seen as i64], |row| {
+                Ok((row.get::<_, i64>(0)? as usize, row.get::<_, String>(1)?))
+            })
+            .context("Failed to read events")?;
+        Ok(rows
+            .collect::<rusqlite::Result<_>>()
+            .context("Failed to read events")?)
+    }
+}
+
+#[async_trait]
+impl EventStoreBackend for SqliteBackend {
+    fn location(&self) -> String {
+        format!("{}#{}", self.path.display(), self.run_id)
+    }
+
+    async fn append(&self, event: &Event, line: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO events (run_id, seq, event_type, idempotency_key, event)
+             SELECT ?1, COALESCE(MAX(seq), 0) + 1, ?2, ?3, ?4 FROM events WHERE run_id = ?1",
+            params![
+                self.run_id.to_string(),
+                type_name(event.event_type),
+                event.idempotency_key,
+                line
+            ],
+        )
+        .context("Failed to write event")?;
+        Ok(())
+    }
+
+    async fn lines(&self) -> Result<Vec<(usize, String)>> {
+        self.query_lines(0)
+    }
+
+    async fn lines_after(&self, seen: usize) -> Result<Vec<(usize, String)>> {
+        self.query_lines(seen)
+    }
+
+    async fn is_step_completed(&self, idempotency_key: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        Ok(conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM events
+                 WHERE idempotency_key = ?1 AND run_id = ?2 AND event_type = ?3)",
+                params![
+                    idempotency_key,
+                    self.run_id.to_string(),
+                    type_name(EventType::StepCompleted)
+                ],
+                |row| row.get(0),
+            )
+            .context("Failed to check step completion")?)
+    }
+
+    /// Reads the first and last rows, and the rest only when the last event
+    /// doesn't end the run
+    async fn summary(&self) -> Result<Option<RunSummary>> {
+        let (first, last) = {
+            let conn = self.conn.lock().unwrap();
+            (self.end_line(&conn, false)?, self.end_line(&conn, true)?)
+        };
+        let (Some(first), Some(last)) = (first, last) else {
+            return Ok(None);
+        };
+
+        summarize(&parse_event(&first)?, &parse_event(&last)?, || {
+            self.query_lines(0)?
+                .iter()
+                .map(|(_, line)| parse_event(line))
+                .collect()
+        })
+    }
+
+    async fn record_summary(&self, summary: &RunSummary) -> Result<()> {
+        let json = serde_json::to_string(summary).context("Failed to serialize run summary")?;
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO runs (run_id, summary) VALUES (?1, ?2)
+             ON CONFLICT(run_id) DO UPDATE SET summary = excluded.summary",
+            params![self.run_id.to_string(), json],
+        )
+        .context("Failed to record run summary")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Run, RunState, StepStatus};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn event(run_id: Uuid, event_type: EventType, key: &str) -> Event {
+        Event::new(
+            run_id,
+            None,
+            event_type,
+            key.to_string(),
+            "event".to_string(),
+            StepStatus::Running,
+        )
+    }
+
+    async fn append(backend: &SqliteBackend, event: &Event) {
+        let line = serde_json::to_string(event).unwrap();
+        backend.append(event, &line).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_runs_share_database_without_mixing() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(DATABASE_FILE);
+        let (a_id, b_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let a = SqliteBackend::open(path.clone(), a_id).unwrap();
+        let b = SqliteBackend::open(path.clone(), b_id).unwrap();
+        assert_eq!(a.summary().await.unwrap(), None);
+
+        append(
+            &a,
+            &event(a_id, EventType::RunStarted, "a:start").with_payload(json!({ "pipeline": "p" })),
+        )
+        .await;
+        append(&b, &event(b_id, EventType::RunStarted, "b:start")).await;
+        append(&a, &event(a_id, EventType::StepCompleted, "a:step")).await;
+        append(&b, &event(b_id, EventType::StepFailed, "b:step")).await;
+
+        let lines = a.lines().await.unwrap();
+        assert_eq!(
+            lines.iter().map(|(seq, _)| *seq).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(a.lines_after(1).await.unwrap(), lines[1..].to_vec());
+
+        assert!(a.is_step_completed("a:step").await.unwrap());
+        assert!(!a.is_step_completed("b:step").await.unwrap());
+        assert!(!b.is_step_completed("b:step").await.unwrap());
+
+        // Running: summarized from the whole log
+        let summary = a.summary().await.unwrap().unwrap();
+        assert_eq!(summary.pipeline, "p");
+        assert_eq!(summary.state, RunState::Running);
+
+        append(&a, &event(a_id, EventType::RunCompleted, "a:end")).await;
+        let events: Vec<Event> = a
+            .lines()
+            .await
+            .unwrap()
+            .iter()
+            .map(|(_, line)| parse_event(line).unwrap())
+            .collect();
+        let summary = a.summary().await.unwrap().unwrap();
+        assert_eq!(
+            summary,
+            RunSummary::from(&Run::from_events(&events).unwrap())
+        );
+
+        // Only recorded summaries are listed
+        a.record_summary(&summary).await.unwrap();
+        let listed = SqliteBackend::summaries(&path).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[&a_id].state, RunState::Completed);
+    }
+}
@@ -0,0 +1,82 @@
+//! Minimal `{{name}}` templates.
+//!
+//! Placeholders are replaced with values from a map; whitespace inside the
+//! braces is ignored. There are no conditionals or loops. A placeholder with
+//! no value is an error rather than an empty string, so a typo in a template
+//! shows up the first time it's rendered.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+/// Why a template couldn't be rendered
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TemplateError {
+    /// A placeholder doesn't name a known value
+    #[error("Unknown placeholder '{{{{{name}}}}}' in template (known: {known})")]
+    UnknownPlaceholder { name: String, known: String },
+
+    /// `{{` without a matching `}}`
+    #[error("Unclosed '{{{{' at byte {0} in template")]
+    Unclosed(usize),
+}
+
+/// Substitute every `{{name}}` in `template` with `vars[name]`
+pub fn render(template: &str, vars: &HashMap<&str, String>) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    let mut offset = 0;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or(TemplateError::Unclosed(offset + start))?;
+        let name = after[..end].trim();
+
+        match vars.get(name) {
+            Some(value) => out.push_str(value),
+            None => {
+                let mut known: Vec<&str> = vars.keys().copied().collect();
+                known.sort_unstable();
+                return Err(TemplateError::UnknownPlaceholder {
+                    name: name.to_string(),
+                    known: known.join(", "),
+                });
+            }
+        }
+
+        let consumed = start + 2 + end + 2;
+        rest = &rest[consumed..];
+        offset += consumed;
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_substitutes_and_rejects_unknown() {
+        let vars: HashMap<&str, String> = [("date", "2026-03-01"), ("title", "Walk")]
+            .into_iter()
+            .map(|(k, v)| (k, v.to_string()))
+            .collect();
+
+        assert_eq!(
+            render("# {{title}} ({{ date }})\n{{title}}", &vars).unwrap(),
+            "# Walk (2026-03-01)\nWalk"
+        );
+        assert_eq!(render("no placeholders", &vars).unwrap(), "no placeholders");
+
+        let err = render("{{date}} {{mood}}", &vars).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown placeholder '{{mood}}' in template (known: date, title)"
+        );
+        assert_eq!(render("ok {{date", &vars), Err(TemplateError::Unclosed(3)));
+    }
+}
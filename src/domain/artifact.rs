@@ -1,9 +1,13 @@
 //! Artifacts produced by pipeline steps.
 //!
 //! Artifacts are the outputs of steps that can be used as inputs to subsequent steps.
+//! Their metadata (size, hash, creation time) is also recorded on the step's
+//! `StepCompleted` event, so a reconstructed run knows it without reading
+//! artifact files.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// An artifact produced by a pipeline step
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,29 +24,56 @@ pub struct Artifact {
     /// When the artifact was created
     pub created_at: DateTime<Utc>,
 
-    /// Size in bytes (for tracking)
-    pub size_bytes: u64,
+    /// Size of the content in bytes
+    #[serde(alias = "size_bytes")]
+    pub byte_len: u64,
+
+    /// Hex SHA-256 of the content, for integrity checks
+    #[serde(default)]
+    pub content_sha256: String,
 }
 
 impl Artifact {
     /// Create a new artifact
     pub fn new(step_name: String, artifact_type: ArtifactType, content: String) -> Self {
-        let size_bytes = content.len() as u64;
+        let byte_len = content.len() as u64;
+        let content_sha256 = hex::encode(Sha256::digest(content.as_bytes()));
         Self {
             step_name,
             artifact_type,
             content,
             created_at: Utc::now(),
-            size_bytes,
+            byte_len,
+            content_sha256,
         }
     }
 
-    /// Create an artifact from step output
+    /// Create an artifact from step output, typed by its content
     pub fn from_output(step_name: String, output: String) -> Self {
-        Self::new(step_name, ArtifactType::StepOutput, output)
+        let artifact_type = ArtifactType::infer(&output);
+        Self::new(step_name, artifact_type, output)
+    }
+
+    /// Everything about the artifact but its content
+    pub fn metadata(&self) -> ArtifactMetadata {
+        ArtifactMetadata {
+            artifact_type: self.artifact_type,
+            created_at: self.created_at,
+            byte_len: self.byte_len,
+            content_sha256: self.content_sha256.clone(),
+        }
     }
 }
 
+/// Artifact metadata as recorded on a `StepCompleted` event
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactMetadata {
+    pub artifact_type: ArtifactType,
+    pub created_at: DateTime<Utc>,
+    pub byte_len: u64,
+    pub content_sha256: String,
+}
+
 /// Types of artifacts that can be produced
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -64,6 +95,55 @@ pub enum ArtifactType {
 
     /// Reference to external document (e.g., RAGFlow doc ID)
     DocumentReference,
+
+    /// A JSON object or array
+    Json,
+
+    /// Markdown with headers
+    Markdown,
+
+    /// Plain text
+    Text,
+}
+
+impl ArtifactType {
+    /// Guess the type of step output: JSON if it parses as an object or
+    /// array, Markdown if it has a `#` header, otherwise plain text
+    pub fn infer(content: &str) -> Self {
+        let trimmed = content.trim();
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+        {
+            return Self::Json;
+        }
+
+        let is_header = |line: &str| {
+            let hashes = line.len() - line.trim_start_matches('#').len();
+            (1..=6).contains(&hashes) && line[hashes..].starts_with(' ')
+        };
+        if content.lines().any(is_header) {
+            Self::Markdown
+        } else {
+            Self::Text
+        }
+    }
+}
+
+impl std::fmt::Display for ArtifactType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::StepOutput => "step_output",
+            Self::Transcript => "transcript",
+            Self::Wisdom => "wisdom",
+            Self::Summary => "summary",
+            Self::TaskList => "task_list",
+            Self::DocumentReference => "document_reference",
+            Self::Json => "json",
+            Self::Markdown => "markdown",
+            Self::Text => "text",
+        };
+        f.write_str(name)
+    }
 }
 
 impl Default for ArtifactType {
@@ -86,7 +166,63 @@ mod tests {
 
         assert_eq!(artifact.step_name, "summarize");
         assert_eq!(artifact.artifact_type, ArtifactType::Summary);
-        assert_eq!(artifact.size_bytes, 31);
+        assert_eq!(artifact.byte_len, 31);
+    }
+
+    #[test]
+    fn test_artifact_type_inference() {
+        assert_eq!(ArtifactType::infer("{\"ok\": true}\n"), ArtifactType::Json);
+        assert_eq!(ArtifactType::infer("  [1, 2, 3]"), ArtifactType::Json);
+        assert_eq!(
+            ArtifactType::infer("# Title\n\nBody text"),
+            ArtifactType::Markdown
+        );
+        assert_eq!(
+            ArtifactType::infer("Intro\n\n### Details\n- item"),
+            ArtifactType::Markdown
+        );
+        assert_eq!(ArtifactType::infer("Just some words."), ArtifactType::Text);
+        // Things that look like, but aren't, JSON or headers
+        assert_eq!(ArtifactType::infer("{not json}"), ArtifactType::Text);
+        assert_eq!(ArtifactType::infer("42"), ArtifactType::Text);
+        assert_eq!(ArtifactType::infer("#hashtag"), ArtifactType::Text);
+        assert_eq!(ArtifactType::infer("####### seven"), ArtifactType::Text);
+
+        assert_eq!(
+            Artifact::from_output("s".to_string(), "# Notes".to_string()).artifact_type,
+            ArtifactType::Markdown
+        );
+        assert_eq!(ArtifactType::TaskList.to_string(), "task_list");
+    }
+
+    #[test]
+    fn test_from_output_computes_size_and_hash() {
+        let artifact = Artifact::from_output("test".to_string(), "héllo".to_string());
+        assert_eq!(artifact.byte_len, 6);
+        assert_eq!(
+            artifact.content_sha256,
+            hex::encode(Sha256::digest("héllo".as_bytes()))
+        );
+        assert_eq!(
+            Artifact::from_output("t".to_string(), String::new()).content_sha256,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let json = serde_json::to_string(&artifact).unwrap();
+        let parsed: Artifact = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.metadata(), artifact.metadata());
+
+        // Artifacts serialized before the rename still load
+        let legacy: Artifact = serde_json::from_value(serde_json::json!({
+            "step_name": "old",
+            "artifact_type": "step_output",
+            "content": "abc",
+            "created_at": "2025-01-01T00:00:00Z",
+            "size_bytes": 3,
+        }))
+        .unwrap();
+        assert_eq!(legacy.byte_len, 3);
+        assert_eq!(legacy.content_sha256, "");
     }
 
     #[test]
@@ -0,0 +1,68 @@
+//! Environment snapshot recorded at the start of each run.
+//!
+//! A run's output depends on more than its pipeline and input: the Fabric
+//! version and the platform matter too. The orchestrator records a
+//! snapshot in the `RunStarted` payload under `environment` so a run can be
+//! reproduced (or a difference between two runs explained) later. Only
+//! non-secret values are captured: versions and paths, never environment
+//! variables or config contents.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Key of the snapshot in the `RunStarted` payload and `Run::metadata`
+pub const ENVIRONMENT_KEY: &str = "environment";
+
+/// Tool versions and platform a run executed with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentSnapshot {
+    /// Version of arkai that ran the pipeline
+    pub arkai_version: String,
+
+    /// Operating system (`std::env::consts::OS`)
+    pub os: String,
+
+    /// CPU architecture (`std::env::consts::ARCH`)
+    pub arch: String,
+
+    /// Resolved path of the Fabric binary, if the adapter runs one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fabric_binary: Option<String>,
+
+    /// Output of `fabric --version`, if it could be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fabric_version: Option<String>,
+}
+
+impl EnvironmentSnapshot {
+    /// The current process's environment; Fabric details are filled in by
+    /// the caller
+    pub fn current() -> Self {
+        Self {
+            arkai_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            fabric_binary: None,
+            fabric_version: None,
+        }
+    }
+}
+
+impl fmt::Display for EnvironmentSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unknown = "unknown";
+        writeln!(f, "arkai:          {}", self.arkai_version)?;
+        writeln!(f, "platform:       {}/{}", self.os, self.arch)?;
+        writeln!(
+            f,
+            "fabric binary:  {}",
+            self.fabric_binary.as_deref().unwrap_or(unknown)
+        )?;
+        write!(
+            f,
+            "fabric version: {}",
+            self.fabric_version.as_deref().unwrap_or(unknown)
+        )
+    }
+}
@@ -113,9 +113,19 @@ pub enum EventType {
     /// A run completed successfully
     RunCompleted,
 
+    /// A run finished, but `continue_on_error` steps failed along the way
+    RunPartiallyCompleted,
+
     /// A run failed
     RunFailed,
 
+    /// A run was cancelled before finishing (may be resumed)
+    RunCancelled,
+
+    /// The process executing a run died before it finished; recorded by
+    /// crash recovery (may be resumed)
+    RunInterrupted,
+
     /// A step has started execution
     StepStarted,
 
@@ -128,9 +138,15 @@ pub enum EventType {
     /// A step is being retried after failure
     StepRetrying,
 
+    /// A step was skipped without running (`enabled: false`)
+    StepSkipped,
+
     /// A safety limit was reached, halting execution
     SafetyLimitReached,
 
+    /// Claims from a pipeline's evidence block were grounded into evidence
+    EvidenceGrounded,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Voice Capture Events (Phase 1)
     // ─────────────────────────────────────────────────────────────────────────
@@ -150,6 +166,22 @@ pub enum EventType {
     VoiceProcessingFailed,
 }
 
+impl EventType {
+    /// Whether this event sets the run's final state (for now: a failed or
+    /// cancelled run may still be resumed)
+    pub fn ends_run(self) -> bool {
+        matches!(
+            self,
+            Self::RunCompleted
+                | Self::RunPartiallyCompleted
+                | Self::RunFailed
+                | Self::RunCancelled
+                | Self::RunInterrupted
+                | Self::SafetyLimitReached
+        )
+    }
+}
+
 /// Status of a step or run
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -195,6 +227,9 @@ pub enum VoiceQueueStatus {
 
     /// Processing failed
     Failed,
+
+    /// Not processed because the audio holds no speech
+    Skipped,
 }
 
 impl Default for VoiceQueueStatus {
@@ -210,6 +245,7 @@ impl std::fmt::Display for VoiceQueueStatus {
             Self::Processing => write!(f, "processing"),
             Self::Done => write!(f, "done"),
             Self::Failed => write!(f, "failed"),
+            Self::Skipped => write!(f, "skipped"),
         }
     }
 }
@@ -6,10 +6,12 @@
 //! - Artifact: Step outputs
 
 pub mod artifact;
+pub mod environment;
 pub mod events;
 pub mod run;
 
 // Re-export commonly used types
-pub use artifact::{Artifact, ArtifactType};
+pub use artifact::{Artifact, ArtifactMetadata, ArtifactType};
+pub use environment::EnvironmentSnapshot;
 pub use events::{Event, EventType, StepStatus, VoiceQueueStatus};
-pub use run::{Run, RunState};
+pub use run::{verify_events, AnomalyKind, EventAnomaly, Run, RunState, RunSummary};
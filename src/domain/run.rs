@@ -2,14 +2,16 @@
 //!
 //! A Run represents a single execution of a pipeline.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use uuid::Uuid;
 
-use super::artifact::Artifact;
+use super::artifact::{Artifact, ArtifactMetadata};
+use super::environment::{EnvironmentSnapshot, ENVIRONMENT_KEY};
 use super::events::{Event, EventType, StepStatus};
 
 /// A pipeline execution run
@@ -42,6 +44,11 @@ pub struct Run {
     /// Status of each step (step_name -> status)
     pub step_statuses: HashMap<String, StepStatus>,
 
+    /// Metadata of each completed step's artifact, from its `StepCompleted`
+    /// event (step_name -> metadata)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub artifact_metadata: HashMap<String, ArtifactMetadata>,
+
     /// Additional structured metadata associated with the run
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub metadata: HashMap<String, Value>,
@@ -60,6 +67,7 @@ impl Run {
             current_step: 0,
             artifacts: HashMap::new(),
             step_statuses: HashMap::new(),
+            artifact_metadata: HashMap::new(),
             metadata: HashMap::new(),
         }
     }
@@ -83,6 +91,7 @@ impl Run {
             current_step: 0,
             artifacts: HashMap::new(),
             step_statuses: HashMap::new(),
+            artifact_metadata: HashMap::new(),
             metadata: HashMap::new(),
         };
 
@@ -93,6 +102,24 @@ impl Run {
         Some(run)
     }
 
+    /// Reconstruct run state from a snapshot of its earlier events, if its
+    /// log was compacted, and the events after it
+    pub fn from_snapshot(snapshot: Option<Run>, events: &[Event]) -> Option<Self> {
+        let Some(mut run) = snapshot else {
+            return Self::from_events(events);
+        };
+        for event in events {
+            run.apply_event(event);
+        }
+        Some(run)
+    }
+
+    /// The environment snapshot recorded when the run started, if any
+    pub fn environment(&self) -> Option<EnvironmentSnapshot> {
+        let snapshot = self.metadata.get(ENVIRONMENT_KEY)?;
+        serde_json::from_value(snapshot.clone()).ok()
+    }
+
     /// Apply a single event to update run state
     pub fn apply_event(&mut self, event: &Event) {
         match event.event_type {
@@ -101,7 +128,14 @@ impl Run {
                 self.started_at = event.timestamp;
                 if let Some(Value::Object(metadata)) = &event.payload {
                     for (key, value) in metadata {
-                        self.metadata.insert(key.clone(), value.clone());
+                        match (key.as_str(), value.as_str()) {
+                            ("pipeline", Some(pipeline)) => {
+                                self.pipeline_name = pipeline.to_string()
+                            }
+                            _ => {
+                                self.metadata.insert(key.clone(), value.clone());
+                            }
+                        }
                     }
                 }
             }
@@ -109,12 +143,36 @@ impl Run {
                 self.state = RunState::Completed;
                 self.completed_at = Some(event.timestamp);
             }
+            EventType::RunPartiallyCompleted => {
+                let failed_steps = event
+                    .payload
+                    .as_ref()
+                    .and_then(|payload| payload.get("failed_steps"))
+                    .and_then(|steps| serde_json::from_value(steps.clone()).ok())
+                    .unwrap_or_default();
+                self.state = RunState::PartiallyCompleted { failed_steps };
+                self.completed_at = Some(event.timestamp);
+            }
             EventType::RunFailed => {
                 self.state = RunState::Failed {
                     error: event.error.clone().unwrap_or_default(),
                 };
                 self.completed_at = Some(event.timestamp);
             }
+            EventType::RunCancelled => {
+                self.state = RunState::Cancelled;
+                self.completed_at = Some(event.timestamp);
+            }
+            EventType::RunInterrupted => {
+                self.state = RunState::Interrupted;
+                self.completed_at = Some(event.timestamp);
+                // Steps that were in flight will run again on resume
+                for status in self.step_statuses.values_mut() {
+                    if *status == StepStatus::Running {
+                        *status = StepStatus::Pending;
+                    }
+                }
+            }
             EventType::StepStarted => {
                 if let Some(ref step_id) = event.step_id {
                     self.step_statuses
@@ -126,6 +184,14 @@ impl Run {
                     self.step_statuses
                         .insert(step_id.clone(), StepStatus::Completed);
                     self.current_step += 1;
+                    let metadata = event
+                        .payload
+                        .as_ref()
+                        .and_then(|payload| payload.get("artifact_metadata"))
+                        .and_then(|metadata| serde_json::from_value(metadata.clone()).ok());
+                    if let Some(metadata) = metadata {
+                        self.artifact_metadata.insert(step_id.clone(), metadata);
+                    }
                 }
             }
             EventType::StepFailed => {
@@ -140,6 +206,12 @@ impl Run {
                         .insert(step_id.clone(), StepStatus::Running);
                 }
             }
+            EventType::StepSkipped => {
+                if let Some(ref step_id) = event.step_id {
+                    self.step_statuses
+                        .insert(step_id.clone(), StepStatus::Skipped);
+                }
+            }
             EventType::SafetyLimitReached => {
                 self.state = RunState::SafetyLimitReached {
                     limit: event.error.clone().unwrap_or_default(),
@@ -147,6 +219,9 @@ impl Run {
                 self.completed_at = Some(event.timestamp);
             }
 
+            // Evidence is written alongside the run; state is unchanged
+            EventType::EvidenceGrounded => {}
+
             // Voice capture events don't affect Run state
             EventType::AudioDetected
             | EventType::VoiceQueued
@@ -177,6 +252,29 @@ impl Run {
     }
 }
 
+/// What `arkai runs` shows about a run, without its artifacts or step
+/// history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub id: Uuid,
+    pub pipeline: String,
+    pub state: RunState,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Run> for RunSummary {
+    fn from(run: &Run) -> Self {
+        Self {
+            id: run.id,
+            pipeline: run.pipeline_name.clone(),
+            state: run.state.clone(),
+            started_at: run.started_at,
+            completed_at: run.completed_at,
+        }
+    }
+}
+
 /// State of a pipeline run
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "status")]
@@ -190,11 +288,20 @@ pub enum RunState {
     /// Completed successfully
     Completed,
 
+    /// Finished, but the listed `continue_on_error` steps failed
+    PartiallyCompleted { failed_steps: Vec<String> },
+
     /// Failed with error
     Failed { error: String },
 
     /// Safety limit was reached
     SafetyLimitReached { limit: String },
+
+    /// Cancelled before finishing (can be resumed)
+    Cancelled,
+
+    /// The process running it died before it finished (can be resumed)
+    Interrupted,
 }
 
 impl Default for RunState {
@@ -203,6 +310,141 @@ impl Default for RunState {
     }
 }
 
+/// A consistency problem found while verifying an event sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventAnomaly {
+    /// Index of the offending event in the replayed sequence (0-based)
+    pub index: usize,
+
+    /// What was wrong
+    pub kind: AnomalyKind,
+}
+
+/// Kinds of event-sequence inconsistency
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// Event belongs to a different run than the first event
+    RunIdMismatch { expected: Uuid, actual: Uuid },
+
+    /// Timestamp is earlier than the previous event's
+    NonMonotonicTimestamp {
+        previous: DateTime<Utc>,
+        actual: DateTime<Utc>,
+    },
+
+    /// `StepCompleted` without a preceding `StepStarted` for the same key
+    CompletedWithoutStart { idempotency_key: String },
+
+    /// Completed steps exceed the number of steps in the pipeline
+    StepBeyondPipeline {
+        current_step: usize,
+        step_count: usize,
+    },
+
+    /// Event recorded after the run had already completed
+    EventAfterCompletion { event_type: EventType },
+}
+
+impl fmt::Display for AnomalyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RunIdMismatch { expected, actual } => {
+                write!(f, "run_id {} does not match run {}", actual, expected)
+            }
+            Self::NonMonotonicTimestamp { previous, actual } => write!(
+                f,
+                "timestamp {} is earlier than previous event ({})",
+                actual.to_rfc3339(),
+                previous.to_rfc3339()
+            ),
+            Self::CompletedWithoutStart { idempotency_key } => write!(
+                f,
+                "step_completed without preceding step_started for key {}",
+                idempotency_key
+            ),
+            Self::StepBeyondPipeline {
+                current_step,
+                step_count,
+            } => write!(
+                f,
+                "current_step {} exceeds pipeline length {}",
+                current_step, step_count
+            ),
+            Self::EventAfterCompletion { event_type } => {
+                write!(f, "{:?} recorded after run_completed", event_type)
+            }
+        }
+    }
+}
+
+/// Check an event sequence for internal consistency.
+///
+/// `step_count` enables the pipeline-length check when the pipeline is known.
+/// Failed, cancelled and interrupted runs may be resumed, so events after `RunFailed`,
+/// `SafetyLimitReached`, `RunCancelled` or `RunInterrupted` are allowed; only `RunCompleted`/`RunPartiallyCompleted` are treated as final.
+pub fn verify_events(events: &[Event], step_count: Option<usize>) -> Vec<EventAnomaly> {
+    let mut anomalies = Vec::new();
+    let Some(first) = events.first() else {
+        return anomalies;
+    };
+
+    let mut started_keys: HashSet<&str> = HashSet::new();
+    let mut previous_timestamp = first.timestamp;
+    let mut completed = false;
+    let mut current_step = 0usize;
+
+    for (index, event) in events.iter().enumerate() {
+        let mut flag = |kind| anomalies.push(EventAnomaly { index, kind });
+
+        if event.run_id != first.run_id {
+            flag(AnomalyKind::RunIdMismatch {
+                expected: first.run_id,
+                actual: event.run_id,
+            });
+        }
+
+        if event.timestamp < previous_timestamp {
+            flag(AnomalyKind::NonMonotonicTimestamp {
+                previous: previous_timestamp,
+                actual: event.timestamp,
+            });
+        }
+        previous_timestamp = previous_timestamp.max(event.timestamp);
+
+        if completed {
+            flag(AnomalyKind::EventAfterCompletion {
+                event_type: event.event_type,
+            });
+        }
+
+        match event.event_type {
+            EventType::StepStarted => {
+                started_keys.insert(event.idempotency_key.as_str());
+            }
+            EventType::StepCompleted => {
+                if !started_keys.contains(event.idempotency_key.as_str()) {
+                    flag(AnomalyKind::CompletedWithoutStart {
+                        idempotency_key: event.idempotency_key.clone(),
+                    });
+                }
+                if event.step_id.is_some() {
+                    current_step += 1;
+                    if let Some(step_count) = step_count.filter(|&n| current_step > n) {
+                        flag(AnomalyKind::StepBeyondPipeline {
+                            current_step,
+                            step_count,
+                        });
+                    }
+                }
+            }
+            EventType::RunCompleted | EventType::RunPartiallyCompleted => completed = true,
+            _ => {}
+        }
+    }
+
+    anomalies
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,4 +545,172 @@ mod tests {
 
         assert!(parsed.metadata.is_empty());
     }
+
+    fn event(run_id: Uuid, step: Option<&str>, event_type: EventType, key: &str) -> Event {
+        Event::new(
+            run_id,
+            step.map(String::from),
+            event_type,
+            key.to_string(),
+            String::new(),
+            StepStatus::Running,
+        )
+    }
+
+    #[test]
+    fn test_reconstruction_restores_artifact_metadata() {
+        let run_id = Uuid::new_v4();
+        let artifact = Artifact::from_output("a".to_string(), "output".to_string());
+        let events = vec![
+            event(run_id, None, EventType::RunStarted, "start"),
+            event(run_id, Some("a"), EventType::StepCompleted, "a:1")
+                .with_payload(json!({ "artifact_metadata": artifact.metadata() })),
+            // Logs from before metadata was recorded
+            event(run_id, Some("b"), EventType::StepCompleted, "b:1"),
+        ];
+
+        let run = Run::from_events(&events).unwrap();
+        assert_eq!(run.artifact_metadata["a"], artifact.metadata());
+        assert_eq!(run.artifact_metadata["a"].byte_len, 6);
+        assert!(!run.artifact_metadata.contains_key("b"));
+    }
+
+    fn consistent_sequence(run_id: Uuid) -> Vec<Event> {
+        vec![
+            event(run_id, None, EventType::RunStarted, "start"),
+            event(run_id, Some("a"), EventType::StepStarted, "a:1"),
+            event(run_id, Some("a"), EventType::StepCompleted, "a:1"),
+            event(run_id, None, EventType::RunCompleted, "complete"),
+        ]
+    }
+
+    #[test]
+    fn test_run_from_events_reconstructs_partial_completion() {
+        let run_id = Uuid::new_v4();
+        let mut events = consistent_sequence(run_id);
+        events[3] = event(run_id, None, EventType::RunPartiallyCompleted, "complete")
+            .with_payload(json!({ "failed_steps": ["optional"] }));
+
+        let run = Run::from_events(&events).unwrap();
+        assert_eq!(
+            run.state,
+            RunState::PartiallyCompleted {
+                failed_steps: vec!["optional".to_string()]
+            }
+        );
+        assert!(run.completed_at.is_some());
+
+        // Partial completion is final, like RunCompleted
+        events.push(event(run_id, Some("b"), EventType::StepStarted, "b:1"));
+        assert_eq!(verify_events(&events, None).len(), 1);
+    }
+
+    #[test]
+    fn test_verify_events_accepts_consistent_sequence() {
+        let events = consistent_sequence(Uuid::new_v4());
+        assert!(verify_events(&events, Some(1)).is_empty());
+    }
+
+    #[test]
+    fn test_verify_events_flags_completed_without_start() {
+        let run_id = Uuid::new_v4();
+        let mut events = consistent_sequence(run_id);
+        events.remove(1);
+
+        let anomalies = verify_events(&events, None);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].index, 1);
+        assert!(matches!(
+            anomalies[0].kind,
+            AnomalyKind::CompletedWithoutStart { .. }
+        ));
+    }
+
+    #[test]
+    fn test_verify_events_flags_step_beyond_pipeline() {
+        let run_id = Uuid::new_v4();
+        let events = vec![
+            event(run_id, None, EventType::RunStarted, "start"),
+            event(run_id, Some("a"), EventType::StepStarted, "a:1"),
+            event(run_id, Some("a"), EventType::StepCompleted, "a:1"),
+            event(run_id, Some("b"), EventType::StepStarted, "b:1"),
+            event(run_id, Some("b"), EventType::StepCompleted, "b:1"),
+        ];
+
+        let anomalies = verify_events(&events, Some(1));
+        assert_eq!(
+            anomalies,
+            vec![EventAnomaly {
+                index: 4,
+                kind: AnomalyKind::StepBeyondPipeline {
+                    current_step: 2,
+                    step_count: 1
+                }
+            }]
+        );
+        // Without a known pipeline length the check is skipped
+        assert!(verify_events(&events, None).is_empty());
+    }
+
+    #[test]
+    fn test_verify_events_flags_non_monotonic_timestamps() {
+        let run_id = Uuid::new_v4();
+        let mut events = consistent_sequence(run_id);
+        events[2].timestamp = events[0].timestamp - chrono::Duration::seconds(10);
+
+        let anomalies = verify_events(&events, None);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].index, 2);
+        assert!(matches!(
+            anomalies[0].kind,
+            AnomalyKind::NonMonotonicTimestamp { .. }
+        ));
+    }
+
+    #[test]
+    fn test_verify_events_flags_events_after_completion() {
+        let run_id = Uuid::new_v4();
+        let mut events = consistent_sequence(run_id);
+        events.push(event(run_id, Some("a"), EventType::StepStarted, "a:2"));
+
+        let anomalies = verify_events(&events, None);
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].index, 4);
+        assert_eq!(
+            anomalies[0].kind,
+            AnomalyKind::EventAfterCompletion {
+                event_type: EventType::StepStarted
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_events_allows_resume_after_failure() {
+        let run_id = Uuid::new_v4();
+        let events = vec![
+            event(run_id, None, EventType::RunStarted, "start"),
+            event(run_id, Some("a"), EventType::StepStarted, "a:1"),
+            event(run_id, Some("a"), EventType::StepFailed, "a:1"),
+            event(run_id, None, EventType::RunFailed, "complete"),
+            event(run_id, Some("a"), EventType::StepStarted, "a:1"),
+            event(run_id, Some("a"), EventType::StepCompleted, "a:1"),
+            event(run_id, None, EventType::RunCompleted, "complete"),
+        ];
+
+        assert!(verify_events(&events, Some(1)).is_empty());
+    }
+
+    #[test]
+    fn test_verify_events_flags_foreign_run_id() {
+        let run_id = Uuid::new_v4();
+        let mut events = consistent_sequence(run_id);
+        events[1].run_id = Uuid::new_v4();
+        events[2].run_id = events[1].run_id;
+
+        let anomalies = verify_events(&events, None);
+        assert_eq!(anomalies.len(), 2);
+        assert!(anomalies
+            .iter()
+            .all(|a| matches!(a.kind, AnomalyKind::RunIdMismatch { .. })));
+    }
 }
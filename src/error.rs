@@ -0,0 +1,99 @@
+//! Errors returned at the library boundary.
+//!
+//! The core public APIs (`Pipeline` loading, `Orchestrator`, `EventStore`)
+//! return [`ArkaiError`] so embedders can match on what went wrong. Internals
+//! still use `anyhow` for context; anything without a specific variant ends up
+//! in [`ArkaiError::Other`].
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::core::SafetyViolation;
+
+/// Result type for the core public APIs
+pub type Result<T, E = ArkaiError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+pub enum ArkaiError {
+    /// The pipeline file doesn't exist
+    #[error("Pipeline file not found: {}", path.display())]
+    PipelineNotFound { path: PathBuf },
+
+    /// The pipeline file isn't valid YAML for a pipeline
+    #[error("Failed to parse pipeline YAML")]
+    PipelineParse(#[source] serde_yaml::Error),
+
+    /// The pipeline parsed but failed validation
+    #[error("{0}")]
+    InvalidPipeline(String),
+
+    /// No run matches the given ID or prefix
+    #[error("No run found matching '{0}'")]
+    RunNotFound(String),
+
+    /// A run ID prefix matches more than one run
+    #[error("Run ID prefix '{prefix}' is ambiguous ({count} runs match)")]
+    AmbiguousRunId { prefix: String, count: usize },
+
+    /// A step named in a request doesn't exist or has no output
+    #[error("{0}")]
+    StepNotFound(String),
+
+    /// A safety limit rejected the run's input or execution
+    #[error(transparent)]
+    Safety(#[from] SafetyViolation),
+
+    /// Anything else (I/O, serialization, adapters)
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ArkaiError {
+    /// Recover a specific variant if one is wrapped in the `anyhow` chain
+    fn from(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<ArkaiError>() {
+            Ok(arkai) => return arkai,
+            Err(error) => error,
+        };
+        match error.downcast_ref::<SafetyViolation>() {
+            Some(violation) => Self::Safety(violation.clone()),
+            None => Self::Other(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for ArkaiError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Other(error.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_anyhow_recovers_specific_variants() {
+        let safety: anyhow::Error = SafetyViolation::MaxSteps {
+            actual: 2,
+            limit: 1,
+        }
+        .into();
+        assert!(matches!(
+            ArkaiError::from(safety.context("step failed")),
+            ArkaiError::Safety(SafetyViolation::MaxSteps { .. })
+        ));
+
+        let not_found: anyhow::Error = ArkaiError::RunNotFound("abc".to_string()).into();
+        assert!(matches!(
+            ArkaiError::from(not_found),
+            ArkaiError::RunNotFound(id) if id == "abc"
+        ));
+
+        assert!(matches!(
+            ArkaiError::from(anyhow::anyhow!("boom")),
+            ArkaiError::Other(_)
+        ));
+    }
+}
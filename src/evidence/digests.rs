@@ -0,0 +1,233 @@
+//! Cache of artifact digests, keyed on file size and modification time.
+//!
+//! `evidence reindex` records each artifact's digest in `metadata.json` and
+//! `evidence validate` compares against it, so running one after the other
+//! would hash every artifact twice. A [`DigestCache`] shared by a command
+//! hashes each file once. With `evidence.persist_digests: true` in config
+//! it's also kept in `~/.arkai/digest_cache.json` between commands, where
+//! `reindex` reuses an entry until the file's size or mtime changes.
+//! `validate` never trusts a persisted entry, since an edit can keep both,
+//! and hashes the file again.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use super::spans::compute_hash;
+
+/// Hashes file content into a digest string
+pub type DigestFn = Arc<dyn Fn(&[u8]) -> String + Send + Sync>;
+
+/// A file's digest and the metadata it was computed at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CachedDigest {
+    len: u64,
+    modified: SystemTime,
+    digest: String,
+    /// Hashed by this process rather than loaded from the cache file
+    #[serde(skip)]
+    hashed_here: bool,
+}
+
+/// Digests of files hashed so far
+pub struct DigestCache {
+    entries: Mutex<HashMap<PathBuf, CachedDigest>>,
+    /// Where the cache is persisted, if anywhere
+    path: Option<PathBuf>,
+    hash: DigestFn,
+}
+
+impl Default for DigestCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DigestCache {
+    /// An empty in-memory cache using [`compute_hash`]
+    pub fn new() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            path: None,
+            hash: Arc::new(compute_hash),
+        }
+    }
+
+    /// The cache persisted at `path`; a missing or unreadable file starts
+    /// empty
+    pub async fn open(path: PathBuf) -> Self {
+        let entries = tokio::fs::read_to_string(&path)
+            .await
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            entries: Mutex::new(entries),
+            path: Some(path),
+            ..Self::new()
+        }
+    }
+
+    /// The cache for a command: persisted in arkai home when
+    /// `evidence.persist_digests` is set, in memory otherwise
+    pub async fn open_default() -> Result<Self> {
+        if !crate::config::config()?.persist_digests {
+            return Ok(Self::new());
+        }
+        Ok(Self::open(crate::config::digest_cache_path()?).await)
+    }
+
+    /// Hash with `hash` instead of [`compute_hash`]
+    pub fn with_hash(mut self, hash: impl Fn(&[u8]) -> String + Send + Sync + 'static) -> Self {
+        self.hash = Arc::new(hash);
+        self
+    }
+
+    /// Digest of the file at `path`, hashing it only if it's new to the
+    /// cache or has changed size or mtime since it was hashed
+    pub async fn digest(&self, path: &Path) -> Result<String> {
+        self.lookup(path, true).await
+    }
+
+    /// Digest of the file at `path` for validation: reuses only a digest
+    /// this process computed from the unchanged file, and hashes anything
+    /// loaded from the cache file again
+    pub async fn verified_digest(&self, path: &Path) -> Result<String> {
+        self.lookup(path, false).await
+    }
+
+    async fn lookup(&self, path: &Path, trust_persisted: bool) -> Result<String> {
+        let metadata = tokio::fs::metadata(path)
+            .await
+            .with_context(|| format!("Failed to read metadata: {}", path.display()))?;
+        let len = metadata.len();
+        let modified = metadata.modified()?;
+
+        if let Some(cached) = self.entries.lock().unwrap().get(path) {
+            let fresh = cached.len == len && cached.modified == modified;
+            if fresh && (trust_persisted || cached.hashed_here) {
+                return Ok(cached.digest.clone());
+            }
+        }
+
+        let content = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("Failed to read: {}", path.display()))?;
+        let hash = self.hash.clone();
+        let digest = tokio::task::spawn_blocking(move || hash(&content))
+            .await
+            .context("Hashing task panicked")?;
+        self.entries.lock().unwrap().insert(
+            path.to_path_buf(),
+            CachedDigest {
+                len,
+                modified,
+                digest: digest.clone(),
+                hashed_here: true,
+            },
+        );
+        Ok(digest)
+    }
+
+    /// Write the cache back to its file, dropping entries for files that no
+    /// longer exist (a no-op for in-memory caches)
+    pub async fn save(&self) -> Result<()> {
+        let Some(ref path) = self.path else {
+            return Ok(());
+        };
+        let paths: Vec<PathBuf> = self.entries.lock().unwrap().keys().cloned().collect();
+        for cached in paths {
+            if !tokio::fs::try_exists(&cached).await.unwrap_or(false) {
+                self.entries.lock().unwrap().remove(&cached);
+            }
+        }
+
+        let content = serde_json::to_string(&*self.entries.lock().unwrap())?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, content)
+            .await
+            .with_context(|| format!("Failed to write digest cache: {}", tmp.display()))?;
+        tokio::fs::rename(&tmp, path)
+            .await
+            .with_context(|| format!("Failed to replace digest cache: {}", path.display()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_rehashes_only_changed_files_and_persists() {
+        let temp = TempDir::new().unwrap();
+        let artifact = temp.path().join("transcript.md");
+        std::fs::write(&artifact, "first").unwrap();
+
+        let hashes = Arc::new(AtomicUsize::new(0));
+        let counting = |hashes: Arc<AtomicUsize>| {
+            move |bytes: &[u8]| {
+                hashes.fetch_add(1, Ordering::SeqCst);
+                compute_hash(bytes)
+            }
+        };
+
+        let cache_path = temp.path().join("digest_cache.json");
+        let cache = DigestCache::open(cache_path.clone())
+            .await
+            .with_hash(counting(hashes.clone()));
+        let first = cache.digest(&artifact).await.unwrap();
+        assert_eq!(first, compute_hash(b"first"));
+        assert_eq!(cache.digest(&artifact).await.unwrap(), first);
+        assert_eq!(cache.verified_digest(&artifact).await.unwrap(), first);
+        assert_eq!(hashes.load(Ordering::SeqCst), 1);
+
+        // A different size invalidates the entry
+        std::fs::write(&artifact, "second!").unwrap();
+        assert_eq!(
+            cache.digest(&artifact).await.unwrap(),
+            compute_hash(b"second!")
+        );
+        assert_eq!(hashes.load(Ordering::SeqCst), 2);
+
+        // Reopened from disk, the entry is reused by reindex but validation
+        // hashes the file again
+        cache.save().await.unwrap();
+        let reopened = DigestCache::open(cache_path)
+            .await
+            .with_hash(counting(hashes.clone()));
+        reopened.digest(&artifact).await.unwrap();
+        assert_eq!(hashes.load(Ordering::SeqCst), 2);
+        reopened.verified_digest(&artifact).await.unwrap();
+        assert_eq!(hashes.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_save_drops_missing_files() {
+        let temp = TempDir::new().unwrap();
+        let kept = temp.path().join("kept.md");
+        let removed = temp.path().join("removed.md");
+        std::fs::write(&kept, "kept").unwrap();
+        std::fs::write(&removed, "removed").unwrap();
+
+        let cache_path = temp.path().join("digest_cache.json");
+        let cache = DigestCache::open(cache_path.clone()).await;
+        cache.digest(&kept).await.unwrap();
+        cache.digest(&removed).await.unwrap();
+        std::fs::remove_file(&removed).unwrap();
+        cache.save().await.unwrap();
+
+        let saved: HashMap<PathBuf, serde_json::Value> =
+            serde_json::from_str(&std::fs::read_to_string(cache_path).unwrap()).unwrap();
+        assert_eq!(saved.keys().collect::<Vec<_>>(), vec![&kept]);
+    }
+}
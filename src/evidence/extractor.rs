@@ -0,0 +1,177 @@
+//! External claim extractors
+//!
+//! An extractor is a configured command (or Fabric pattern) that reads a
+//! transcript on stdin and prints the claims it found as JSON:
+//!
+//! ```json
+//! [{"claim": "...", "quote": "verbatim text", "confidence": 0.8}]
+//! ```
+//!
+//! Claims may also name the `source_artifact` they were taken from (e.g. a
+//! `wisdom.md` bullet whose quote lives in the transcript).
+//!
+//! The `{"claims": [...]}` shape written by `extract_claims` is accepted too,
+//! as is output wrapped in a Markdown code fence. Quotes are grounded against
+//! the transcript by the caller; extractors only propose claims.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::adapters::{Adapter, FabricAdapter};
+use crate::config::ExtractorConfig;
+
+/// Default timeout for one extraction run
+const DEFAULT_TIMEOUT_SECONDS: u64 = 300;
+
+/// A claim proposed by an extractor
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExtractedClaim {
+    /// The claim being made
+    pub claim: String,
+    /// Verbatim quote supporting the claim
+    pub quote: String,
+    /// Extractor's confidence in the claim (0.0-1.0)
+    #[serde(default = "default_confidence")]
+    pub confidence: f64,
+    /// Artifact the claim was taken from, if not the grounding transcript
+    #[serde(default)]
+    pub source_artifact: Option<String>,
+}
+
+fn default_confidence() -> f64 {
+    0.5
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ExtractorOutput {
+    List(Vec<ExtractedClaim>),
+    Wrapped { claims: Vec<ExtractedClaim> },
+}
+
+/// Parse an extractor's stdout into claims
+pub fn parse_extractor_output(stdout: &str) -> Result<Vec<ExtractedClaim>> {
+    let trimmed = stdout.trim();
+
+    // LLM-backed extractors often wrap JSON in a ```json fence
+    let json = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|rest| rest.trim_end().strip_suffix("```"))
+        .unwrap_or(trimmed);
+
+    let output: ExtractorOutput = serde_json::from_str(json.trim())
+        .context("Extractor output is not a JSON array of {claim, quote, confidence}")?;
+
+    Ok(match output {
+        ExtractorOutput::List(claims) | ExtractorOutput::Wrapped { claims } => claims,
+    })
+}
+
+/// Run a configured extractor over a transcript
+pub async fn run_extractor(
+    name: &str,
+    extractor: &ExtractorConfig,
+    transcript: &str,
+) -> Result<Vec<ExtractedClaim>> {
+    let timeout = Duration::from_secs(extractor.timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_SECONDS));
+
+    let stdout = match (&extractor.pattern, extractor.command.split_first()) {
+        (Some(pattern), _) => {
+            FabricAdapter::new()
+                .execute(pattern, transcript, timeout)
+                .await?
+                .content
+        }
+        (None, Some((program, args))) => {
+            run_command(name, program, args, transcript, timeout).await?
+        }
+        (None, None) => anyhow::bail!(
+            "Extractor '{}' has neither a command nor a pattern configured",
+            name
+        ),
+    };
+
+    parse_extractor_output(&stdout)
+        .with_context(|| format!("Failed to parse output of extractor '{}'", name))
+}
+
+async fn run_command(
+    name: &str,
+    program: &str,
+    args: &[String],
+    input: &str,
+    timeout: Duration,
+) -> Result<String> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .with_context(|| format!("Failed to spawn extractor '{}' ({})", name, program))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // An extractor may exit without reading stdin; that's not an error
+        let _ = stdin.write_all(input.as_bytes()).await;
+    }
+
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .with_context(|| format!("Extractor '{}' timed out after {:?}", name, timeout))?
+        .with_context(|| format!("Failed to wait for extractor '{}'", name))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Extractor '{}' failed with exit code {}: {}",
+            name,
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    String::from_utf8(output.stdout).context("Extractor output is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_extractor_output_shapes() {
+        let list = r#"[{"claim": "c", "quote": "q", "confidence": 0.9}]"#;
+        let wrapped = r#"{"claims": [{"claim": "c", "quote": "q"}]}"#;
+        let fenced = "```json\n[{\"claim\": \"c\", \"quote\": \"q\"}]\n```";
+
+        assert_eq!(parse_extractor_output(list).unwrap()[0].confidence, 0.9);
+        assert_eq!(parse_extractor_output(wrapped).unwrap()[0].confidence, 0.5);
+        assert_eq!(parse_extractor_output(fenced).unwrap()[0].quote, "q");
+        assert!(parse_extractor_output("not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_extractor_command() {
+        let extractor = ExtractorConfig {
+            command: vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                r#"cat >/dev/null; echo '[{"claim":"c","quote":"q"}]'"#.to_string(),
+            ],
+            ..Default::default()
+        };
+
+        let claims = run_extractor("fake", &extractor, "transcript")
+            .await
+            .unwrap();
+        assert_eq!(claims.len(), 1);
+
+        let missing = ExtractorConfig::default();
+        assert!(run_extractor("empty", &missing, "t").await.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! Grounding of extracted claims against a transcript
+//!
+//! Turns a proposed claim into an `Evidence` record: resolved (exact span, or
+//! a unique normalized match mapped back to raw bytes), ambiguous
+//! (first of several exact matches), or honestly unresolved.
+
+use super::extractor::ExtractedClaim;
+use super::spans::{
+    compute_evidence_id, compute_hash, compute_slice_hash, extract_anchor_text,
+    find_nearest_timestamp, find_quote_with, MatchMethod, MatchOptions, MatchStatus, ANCHOR_WINDOW,
+};
+use super::types::{Evidence, ResolutionMethod, Span};
+
+/// Ground one claim's quote in `transcript`, producing its evidence record.
+///
+/// `transcript_artifact` is the artifact file name recorded in the span
+/// (relative to the content directory). `options` picks the fallbacks tried
+/// when the quote has no exact match.
+pub fn ground_claim(
+    content_id: &str,
+    transcript: &str,
+    transcript_artifact: &str,
+    claim: &ExtractedClaim,
+    extractor: &str,
+    ts: &str,
+    options: &MatchOptions,
+) -> Evidence {
+    let quote_sha256 = compute_hash(claim.quote.as_bytes());
+    let match_result = find_quote_with(transcript, &claim.quote, options);
+
+    let evidence = match match_result.status() {
+        MatchStatus::Resolved => {
+            let (start, end) = match_result.selected_match().unwrap();
+            let slice_sha256 = compute_slice_hash(transcript.as_bytes(), start, end);
+            let anchor = extract_anchor_text(transcript, start, end, ANCHOR_WINDOW);
+            let video_ts = find_nearest_timestamp(transcript, start);
+            let id = compute_evidence_id(content_id, extractor, &quote_sha256, Some((start, end)));
+
+            Evidence::new_resolved(
+                id,
+                content_id.to_string(),
+                claim.claim.clone(),
+                claim.quote.clone(),
+                quote_sha256,
+                Span {
+                    artifact: transcript_artifact.to_string(),
+                    utf8_byte_offset: [start, end],
+                    slice_sha256,
+                    anchor_text: Some(anchor),
+                    video_timestamp: video_ts,
+                },
+                claim.confidence,
+                extractor.to_string(),
+                ts.to_string(),
+            )
+            .with_resolution_method(match match_result.method {
+                MatchMethod::Exact => ResolutionMethod::Exact,
+                MatchMethod::UnicodeNormalized(_) => ResolutionMethod::UnicodeNormalized,
+                MatchMethod::PunctuationNormalized => ResolutionMethod::PunctuationNormalized,
+                MatchMethod::WhitespaceNormalized => ResolutionMethod::Normalized,
+            })
+        }
+        MatchStatus::Ambiguous => {
+            let (start, end) = match_result.selected_match().unwrap();
+            let (match_count, _) = match_result.match_info();
+            let slice_sha256 = compute_slice_hash(transcript.as_bytes(), start, end);
+            let anchor = extract_anchor_text(transcript, start, end, ANCHOR_WINDOW);
+            let video_ts = find_nearest_timestamp(transcript, start);
+            let id = compute_evidence_id(content_id, extractor, &quote_sha256, Some((start, end)));
+
+            Evidence::new_ambiguous(
+                id,
+                content_id.to_string(),
+                claim.claim.clone(),
+                claim.quote.clone(),
+                quote_sha256,
+                Span {
+                    artifact: transcript_artifact.to_string(),
+                    utf8_byte_offset: [start, end],
+                    slice_sha256,
+                    anchor_text: Some(anchor),
+                    video_timestamp: video_ts,
+                },
+                match_count,
+                claim.confidence,
+                extractor.to_string(),
+                ts.to_string(),
+            )
+        }
+        MatchStatus::Unresolved => {
+            let id = compute_evidence_id(content_id, extractor, &quote_sha256, None);
+
+            Evidence::new_unresolved(
+                id,
+                content_id.to_string(),
+                claim.claim.clone(),
+                claim.quote.clone(),
+                quote_sha256,
+                match_result.normalized_hint,
+                claim.confidence,
+                extractor.to_string(),
+                ts.to_string(),
+            )
+        }
+    };
+    evidence.with_source_artifact(
+        claim
+            .source_artifact
+            .clone()
+            .filter(|source| source != transcript_artifact),
+    )
+}
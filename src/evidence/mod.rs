@@ -6,8 +6,10 @@
 //!
 //! # Design Principles
 //!
-//! - **Honest unresolved**: Never generate wrong spans. If no exact match, record unresolved.
-//! - **Append-only**: Evidence is stored in JSONL format, never modified.
+//! - **Honest unresolved**: Never generate wrong spans. If no exact (or uniquely
+//!   mappable normalized) match, record unresolved.
+//! - **Append-only**: Evidence is stored in JSONL format, never modified in
+//!   place. Deletes are tombstone events until an explicit compaction.
 //! - **Hash verification**: Each span includes slice_sha256 for drift detection.
 //! - **Deterministic IDs**: Same input always produces same evidence ID.
 //!
@@ -34,15 +36,25 @@
 //! };
 //! ```
 
+pub mod digests;
+pub mod extractor;
+pub mod grounding;
 pub mod spans;
+pub mod store;
 pub mod types;
 
 pub use spans::{
     compute_evidence_id, compute_hash, compute_slice_hash, extract_anchor_text, find_exact_matches,
-    find_nearest_timestamp, find_quote, offset_to_line_col, LineCol, MatchResult, MatchStatus,
+    find_nearest_timestamp, find_quote, find_quote_with, offset_to_line_col, LineCol, MatchMethod,
+    MatchOptions, MatchResult, MatchStatus, UnicodeForm, ANCHOR_WINDOW,
 };
 
+pub use digests::DigestCache;
+pub use extractor::{parse_extractor_output, run_extractor, ExtractedClaim};
+pub use grounding::ground_claim;
+pub use store::{EvidenceRange, EvidenceStore};
+
 pub use types::{
     EntitiesFile, Entity, EntityMention, Evidence, EvidenceEvent, Resolution, ResolutionMethod,
-    Span, Status, UnresolvedReason,
+    Span, Status, UnresolvedReason, VerifyResult,
 };
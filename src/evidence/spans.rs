@@ -5,12 +5,17 @@
 //!
 //! # Design Decisions (V1)
 //!
-//! - **Exact match only**: We only generate spans for exact byte matches
+//! - **Exact match first**: Spans come from exact byte matches whenever one exists
+//! - **Mapped fallbacks only**: A normalized (NFC, smart quotes, whitespace)
+//!   match yields a span only if it is unique and maps back to whole raw
+//!   characters; otherwise it's a hint
 //! - **Honest unresolved**: If no match found, we record status=unresolved
-//! - **No normalization mapping**: Normalized search is hint-only, no offset conversion
 //! - **UTF-8 byte offsets**: All offsets are byte indices into raw file bytes
 
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use unicode_normalization::char::canonical_combining_class;
+use unicode_normalization::UnicodeNormalization;
 
 /// Result of searching for a quote in transcript
 #[derive(Debug, Clone)]
@@ -19,6 +24,73 @@ pub struct MatchResult {
     pub matches: Vec<(usize, usize)>,
     /// Whether a normalized match was found (hint for unresolved reason)
     pub normalized_hint: bool,
+    /// How `matches` were found
+    pub method: MatchMethod,
+}
+
+/// How a quote's matches were found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMethod {
+    /// Byte-for-byte match
+    Exact,
+    /// Match after Unicode normalization, mapped back to raw offsets
+    UnicodeNormalized(UnicodeForm),
+    /// Match after also treating curly quotes and dashes as their ASCII
+    /// equivalents, mapped back to raw offsets
+    PunctuationNormalized,
+    /// Match after also collapsing whitespace runs, mapped back to raw offsets
+    WhitespaceNormalized,
+}
+
+/// Unicode normalization form used for matching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnicodeForm {
+    /// Canonical composition: `e` + combining accent matches `é`
+    Nfc,
+    /// Compatibility composition: additionally folds ligatures, full-width
+    /// forms, etc.
+    Nfkc,
+}
+
+impl UnicodeForm {
+    fn normalize(&self, text: &str) -> String {
+        match self {
+            Self::Nfc => text.nfc().collect(),
+            Self::Nfkc => text.nfkc().collect(),
+        }
+    }
+}
+
+/// Options for [`find_quote_with`]; set from `evidence.matching` in config:
+///
+/// ```yaml
+/// evidence:
+///   matching:
+///     unicode: nfc        # or nfkc, or null to disable
+///     punctuation: true   # curly quotes/dashes match straight ones
+///     whitespace: true    # line breaks/runs of spaces match a single space
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MatchOptions {
+    /// Normalization tried when there's no exact match (`None` disables it)
+    pub unicode: Option<UnicodeForm>,
+    /// Treat curly quotes and dashes as `'`, `"` and `-`
+    pub punctuation: bool,
+    /// Let any run of whitespace match any other as a last fallback
+    pub whitespace: bool,
+}
+
+impl Default for MatchOptions {
+    /// NFC fallback on: canonically equivalent text is the same text
+    fn default() -> Self {
+        Self {
+            unicode: Some(UnicodeForm::Nfc),
+            punctuation: false,
+            whitespace: true,
+        }
+    }
 }
 
 impl MatchResult {
@@ -63,6 +135,51 @@ impl MatchStatus {
     }
 }
 
+/// ASCII stand-in for typographic quotes and dashes
+fn fold_punctuation(c: char) -> Option<char> {
+    match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => Some('\''),
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' => Some('"'),
+        '\u{2010}' | '\u{2011}' | '\u{2012}' | '\u{2013}' | '\u{2014}' | '\u{2015}'
+        | '\u{2212}' => Some('-'),
+        _ => None,
+    }
+}
+
+/// The rewrite applied to transcript and quote for one normalized tier
+#[derive(Debug, Clone, Copy)]
+struct Fold {
+    unicode: Option<UnicodeForm>,
+    punctuation: bool,
+    whitespace: bool,
+}
+
+impl Fold {
+    /// Fold a quote; transcripts go through [`MappedText::new`], which folds
+    /// the same way segment by segment
+    fn apply_to_quote(&self, quote: &str) -> String {
+        let folded = self.apply(quote);
+        if self.whitespace {
+            normalize_whitespace(&folded)
+        } else {
+            folded
+        }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        let text = match self.unicode {
+            Some(form) => form.normalize(text),
+            None => text.to_string(),
+        };
+        if !self.punctuation {
+            return text;
+        }
+        text.chars()
+            .map(|c| fold_punctuation(c).unwrap_or(c))
+            .collect()
+    }
+}
+
 /// Find all exact matches of quote bytes in transcript bytes
 ///
 /// Returns all (start, end) byte offset pairs where the quote appears.
@@ -110,9 +227,98 @@ fn normalize_whitespace(text: &str) -> String {
     text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
+/// Text rewritten for matching, with each byte traced to the raw segment it
+/// came from
+struct MappedText {
+    text: String,
+    /// Raw byte range of the segment that produced each byte of `text`
+    raw: Vec<(usize, usize)>,
+}
+
+impl MappedText {
+    /// Fold `raw` one segment at a time (a starter plus its combining marks,
+    /// or a whitespace run when folding whitespace), so every folded byte has a
+    /// known raw origin
+    fn new(raw: &str, fold: Fold) -> Self {
+        let mut mapped = Self {
+            text: String::with_capacity(raw.len()),
+            raw: Vec::with_capacity(raw.len()),
+        };
+
+        let is_space = |c: char| fold.whitespace && c.is_whitespace();
+        let mut start = 0;
+        let mut in_space = false;
+        for (i, c) in raw.char_indices() {
+            let space = is_space(c);
+            let boundary = if in_space {
+                !space
+            } else {
+                space || canonical_combining_class(c) == 0
+            };
+            if i > start && boundary {
+                mapped.push(raw, start, i, fold, in_space);
+                start = i;
+            }
+            in_space = space;
+        }
+        if start < raw.len() {
+            mapped.push(raw, start, raw.len(), fold, in_space);
+        }
+        mapped
+    }
+
+    fn push(&mut self, raw: &str, start: usize, end: usize, fold: Fold, space: bool) {
+        let folded = if space {
+            " ".to_string()
+        } else {
+            fold.apply(&raw[start..end])
+        };
+        self.raw
+            .extend(std::iter::repeat_n((start, end), folded.len()));
+        self.text.push_str(&folded);
+    }
+
+    /// Raw span for a match over `text[start..end]`, or `None` if the match
+    /// starts or ends inside a segment (e.g. half of a composed character)
+    fn to_raw(&self, start: usize, end: usize) -> Option<(usize, usize)> {
+        if start >= end || end > self.raw.len() {
+            return None;
+        }
+        let starts_segment = start == 0 || self.raw[start - 1] != self.raw[start];
+        let ends_segment = end == self.raw.len() || self.raw[end] != self.raw[end - 1];
+        (starts_segment && ends_segment).then(|| (self.raw[start].0, self.raw[end - 1].1))
+    }
+}
+
+/// Outcome of a normalized search
+enum NormalizedMatch {
+    /// No normalized match
+    None,
+    /// Exactly one match, mapped to this raw span
+    Unique((usize, usize)),
+    /// Matches exist but can't be turned into a single raw span
+    HintOnly,
+}
+
+fn find_folded_match(transcript: &str, quote: &str, fold: Fold) -> NormalizedMatch {
+    let mapped = MappedText::new(transcript, fold);
+    let quote = fold.apply_to_quote(quote);
+
+    let matches = find_exact_matches(mapped.text.as_bytes(), quote.as_bytes());
+    match matches.as_slice() {
+        [] => NormalizedMatch::None,
+        [(start, end)] => match mapped.to_raw(*start, *end) {
+            Some(span) => NormalizedMatch::Unique(span),
+            None => NormalizedMatch::HintOnly,
+        },
+        _ => NormalizedMatch::HintOnly,
+    }
+}
+
 /// Find quote in transcript with full match result
 ///
-/// This is the main entry point for span resolution.
+/// This is the main entry point for span resolution, using the default
+/// [`MatchOptions`].
 ///
 /// # Arguments
 /// * `transcript` - The full transcript as string
@@ -121,17 +327,61 @@ fn normalize_whitespace(text: &str) -> String {
 /// # Returns
 /// * `MatchResult` with all matches and normalized hint
 pub fn find_quote(transcript: &str, quote: &str) -> MatchResult {
+    find_quote_with(transcript, quote, &MatchOptions::default())
+}
+
+/// Find quote in transcript, trying exact matching first and then the
+/// fallbacks enabled in `options`
+pub fn find_quote_with(transcript: &str, quote: &str, options: &MatchOptions) -> MatchResult {
     let matches = find_exact_matches(transcript.as_bytes(), quote.as_bytes());
+    if !matches.is_empty() {
+        return MatchResult {
+            matches,
+            normalized_hint: false,
+            method: MatchMethod::Exact,
+        };
+    }
 
-    let normalized_hint = if matches.is_empty() {
-        has_normalized_match(transcript, quote)
-    } else {
-        false
+    // Fallback tiers, least invasive first, each including the folds before
+    // it; the first unique mapped match wins
+    let mut fold = Fold {
+        unicode: None,
+        punctuation: false,
+        whitespace: false,
     };
+    let mut tiers = Vec::new();
+    if let Some(form) = options.unicode {
+        fold.unicode = Some(form);
+        tiers.push((fold, MatchMethod::UnicodeNormalized(form)));
+    }
+    if options.punctuation {
+        fold.punctuation = true;
+        tiers.push((fold, MatchMethod::PunctuationNormalized));
+    }
+    if options.whitespace {
+        fold.whitespace = true;
+        tiers.push((fold, MatchMethod::WhitespaceNormalized));
+    }
+
+    let mut normalized_hint = false;
+    for (fold, method) in tiers {
+        match find_folded_match(transcript, quote, fold) {
+            NormalizedMatch::Unique(span) => {
+                return MatchResult {
+                    matches: vec![span],
+                    normalized_hint: false,
+                    method,
+                }
+            }
+            NormalizedMatch::HintOnly => normalized_hint = true,
+            NormalizedMatch::None => {}
+        }
+    }
 
     MatchResult {
         matches,
-        normalized_hint,
+        normalized_hint: normalized_hint || has_normalized_match(transcript, quote),
+        method: MatchMethod::Exact,
     }
 }
 
@@ -163,15 +413,18 @@ pub fn compute_slice_hash(transcript: &[u8], start: usize, end: usize) -> String
     compute_hash(slice)
 }
 
+/// Default total characters of context stored as a span's anchor text
+pub const ANCHOR_WINDOW: usize = 80;
+
 /// Extract anchor text around a span
 ///
-/// Returns ~80 characters of context around the span.
+/// Returns ~`window` characters of context around the span.
 ///
 /// # Arguments
 /// * `transcript` - The full transcript as string
 /// * `start` - Start byte offset of span
 /// * `end` - End byte offset of span
-/// * `window` - Total characters of context (default 80)
+/// * `window` - Total characters of context (default [`ANCHOR_WINDOW`])
 ///
 /// # Returns
 /// * String with context around the span
@@ -358,20 +611,164 @@ mod tests {
         let result = MatchResult {
             matches: vec![(0, 5)],
             normalized_hint: false,
+            method: MatchMethod::Exact,
         };
         assert_eq!(result.status(), MatchStatus::Resolved);
 
         let result = MatchResult {
             matches: vec![(0, 5), (10, 15)],
             normalized_hint: false,
+            method: MatchMethod::Exact,
         };
         assert_eq!(result.status(), MatchStatus::Ambiguous);
 
         let result = MatchResult {
             matches: vec![],
             normalized_hint: true,
+            method: MatchMethod::Exact,
+        };
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+    }
+
+    #[test]
+    fn test_find_quote_nfc_maps_to_raw_span() {
+        // Precomposed quote against a decomposed transcript
+        let transcript = "Le cafe\u{301} est ouvert. Ici.";
+        let result = find_quote(transcript, "caf\u{e9} est");
+        assert_eq!(result.status(), MatchStatus::Resolved);
+        assert_eq!(
+            result.method,
+            MatchMethod::UnicodeNormalized(UnicodeForm::Nfc)
+        );
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "cafe\u{301} est");
+
+        // And the other way round
+        let transcript = "Le caf\u{e9} est ouvert.";
+        let result = find_quote(transcript, "cafe\u{301}");
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "caf\u{e9}");
+
+        // Disabled: unresolved
+        let options = MatchOptions {
+            unicode: None,
+            ..Default::default()
+        };
+        let result = find_quote_with(transcript, "cafe\u{301}", &options);
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+    }
+
+    #[test]
+    fn test_find_quote_normalized_never_splits_a_character() {
+        // NFKC turns the ligature into "fi"; a quote ending on the "f" would
+        // need half of a raw character, so it's only a hint
+        let transcript = "the \u{FB01}rst step";
+        let nfkc = MatchOptions {
+            unicode: Some(UnicodeForm::Nfkc),
+            ..Default::default()
         };
+        let result = find_quote_with(transcript, "the f", &nfkc);
         assert_eq!(result.status(), MatchStatus::Unresolved);
+        assert!(result.normalized_hint);
+    }
+
+    #[test]
+    fn test_find_quote_nfc_ambiguous_is_hint_only() {
+        let transcript = "cafe\u{301} and cafe\u{301}";
+        let result = find_quote(transcript, "caf\u{e9}");
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+        assert!(result.normalized_hint);
+    }
+
+    #[test]
+    fn test_find_quote_nfkc() {
+        let transcript = "the \u{FB01}rst step";
+        let nfkc = MatchOptions {
+            unicode: Some(UnicodeForm::Nfkc),
+            ..Default::default()
+        };
+        let result = find_quote_with(transcript, "first step", &nfkc);
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "\u{FB01}rst step");
+
+        // NFC leaves the ligature alone
+        assert_eq!(
+            find_quote(transcript, "first step").status(),
+            MatchStatus::Unresolved
+        );
+    }
+
+    #[test]
+    fn test_find_quote_punctuation_fallback() {
+        let punctuation = MatchOptions {
+            punctuation: true,
+            ..Default::default()
+        };
+
+        // Curly quotes in the transcript, straight in the quote
+        let transcript = "She said \u{201C}it\u{2019}s fine\u{201D} and left.";
+        let result = find_quote_with(transcript, "\"it's fine\"", &punctuation);
+        assert_eq!(result.status(), MatchStatus::Resolved);
+        assert_eq!(result.method, MatchMethod::PunctuationNormalized);
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "\u{201C}it\u{2019}s fine\u{201D}");
+
+        // Em and en dashes against a hyphen
+        let transcript = "pages 10\u{2013}12 \u{2014} roughly";
+        let result = find_quote_with(transcript, "10-12 - roughly", &punctuation);
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "10\u{2013}12 \u{2014} roughly");
+
+        // Off by default: only a hint
+        let result = find_quote(transcript, "10-12");
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+    }
+
+    #[test]
+    fn test_find_quote_punctuation_ambiguous_is_hint_only() {
+        let punctuation = MatchOptions {
+            punctuation: true,
+            ..Default::default()
+        };
+        let transcript = "it\u{2019}s here and it\u{2019}s there";
+        let result = find_quote_with(transcript, "it's", &punctuation);
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+        assert!(result.normalized_hint);
+    }
+
+    #[test]
+    fn test_find_quote_whitespace_maps_to_raw_span() {
+        // The transcript wraps the quote across a line break
+        let transcript = "[00:01] we should\n   ship it today. Then rest.";
+        let result = find_quote(transcript, "should ship it  today.");
+        assert_eq!(result.status(), MatchStatus::Resolved);
+        assert_eq!(result.method, MatchMethod::WhitespaceNormalized);
+        let (start, end) = result.selected_match().unwrap();
+        assert_eq!(&transcript[start..end], "should\n   ship it today.");
+
+        // Combined with NFC: both differences at once
+        let transcript = "un cafe\u{301}\n\tnoir";
+        let (start, end) = find_quote(transcript, "caf\u{e9} noir")
+            .selected_match()
+            .unwrap();
+        assert_eq!(&transcript[start..end], "cafe\u{301}\n\tnoir");
+
+        // Disabled: the old hint-only behaviour
+        let options = MatchOptions {
+            whitespace: false,
+            ..Default::default()
+        };
+        let result = find_quote_with("a\n b", "a b", &options);
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+        assert!(result.normalized_hint);
+    }
+
+    #[test]
+    fn test_find_quote_whitespace_ambiguous_is_hint_only() {
+        let transcript = "ship\nit, then ship  it";
+        let result = find_quote(transcript, "ship it");
+        assert_eq!(result.status(), MatchStatus::Unresolved);
+        assert!(result.normalized_hint);
     }
 
     #[test]
@@ -445,9 +842,17 @@ mod tests {
     fn test_normalized_hint() {
         let transcript = "Hello   world  with   extra   spaces";
         let quote = "world with extra";
-        let result = find_quote(transcript, quote);
+        let hint_only = MatchOptions {
+            whitespace: false,
+            ..Default::default()
+        };
+        let result = find_quote_with(transcript, quote, &hint_only);
         assert!(result.matches.is_empty());
         assert!(result.normalized_hint);
+
+        // By default a unique whitespace-normalized match resolves
+        let result = find_quote(transcript, quote);
+        assert_eq!(result.selected_match(), Some((8, 27)));
     }
 
     #[test]
@@ -476,6 +881,7 @@ mod tests {
                 anchor_text: Some("Context with\nnewline".to_string()),
                 video_timestamp: None,
             }),
+            source_artifact: None,
             confidence: 0.9,
             extractor: "test".to_string(),
             ts: "2026-01-12T00:00:00Z".to_string(),
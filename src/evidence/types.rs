@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::spans::{compute_evidence_id, compute_slice_hash};
+
 /// Resolution status for a quote match
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -22,6 +24,15 @@ pub enum Status {
 pub enum ResolutionMethod {
     /// Exact byte match found
     Exact,
+    /// Unique match after Unicode normalization (NFC/NFKC); the span covers
+    /// the raw bytes, which differ from the quote's
+    UnicodeNormalized,
+    /// Unique match after treating curly quotes and dashes as ASCII; the span
+    /// covers the raw bytes
+    PunctuationNormalized,
+    /// Unique match after normalization that also collapses whitespace; the
+    /// span covers the raw bytes
+    Normalized,
     /// No match found
     None,
     /// Normalized match found but no span generated (hint only)
@@ -91,6 +102,10 @@ pub struct Evidence {
     /// Span in artifact (present if resolved/ambiguous)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub span: Option<Span>,
+    /// Artifact the claim was taken from, when it differs from the grounding
+    /// artifact in `span` (e.g. a wisdom.md bullet grounded in source.md)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_artifact: Option<String>,
     /// Confidence score from extractor
     pub confidence: f64,
     /// Name of the extraction pattern
@@ -143,6 +158,34 @@ pub struct EntitiesFile {
     pub entities: Vec<Entity>,
 }
 
+/// Outcome of checking a span against the current artifact bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyResult {
+    /// Slice hash still matches
+    Valid,
+    /// Slice is in bounds but its hash changed
+    Stale,
+    /// Byte range no longer fits the artifact
+    OutOfBounds,
+}
+
+impl Span {
+    /// Verify this span against the artifact's current bytes
+    pub fn verify(&self, artifact_bytes: &[u8]) -> VerifyResult {
+        let [start, end] = self.utf8_byte_offset;
+
+        if start > end || end > artifact_bytes.len() {
+            return VerifyResult::OutOfBounds;
+        }
+
+        if compute_slice_hash(artifact_bytes, start, end) == self.slice_sha256 {
+            VerifyResult::Valid
+        } else {
+            VerifyResult::Stale
+        }
+    }
+}
+
 /// Evidence-related events for events.jsonl
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -163,6 +206,20 @@ pub enum EvidenceEvent {
         stale_count: usize,
         unresolved_count: usize,
     },
+    /// Evidence was marked stale because its artifact was regenerated
+    EvidenceInvalidated {
+        content_id: String,
+        artifact: String,
+        evidence_ids: Vec<String>,
+        reason: String,
+    },
+    /// Evidence was logically deleted; readers skip it from now on
+    EvidenceDeleted {
+        content_id: String,
+        evidence_id: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
 }
 
 impl Evidence {
@@ -192,6 +249,7 @@ impl Evidence {
                 reason: None,
             },
             span: Some(span),
+            source_artifact: None,
             confidence,
             extractor,
             ts,
@@ -225,6 +283,7 @@ impl Evidence {
                 reason: Some(UnresolvedReason::MultipleMatches),
             },
             span: Some(span),
+            source_artifact: None,
             confidence,
             extractor,
             ts,
@@ -266,9 +325,144 @@ impl Evidence {
                 reason: Some(reason),
             },
             span: None,
+            source_artifact: None,
             confidence,
             extractor,
             ts,
         }
     }
+
+    /// Create an evidence entry citing where `entity` is mentioned, with the
+    /// entity name as the claim. Status, resolution and span are copied from
+    /// `mention`, and the ID is computed as for a grounded claim, so entity
+    /// citations go through the same evidence.jsonl validation.
+    pub fn from_entity_mention(
+        entity: &Entity,
+        mention: &EntityMention,
+        content_id: &str,
+        extractor: &str,
+        ts: &str,
+    ) -> Self {
+        let offsets = match mention.status {
+            Status::Unresolved => None,
+            Status::Resolved | Status::Ambiguous => mention.span.as_ref().map(|span| {
+                let [start, end] = span.utf8_byte_offset;
+                (start, end)
+            }),
+        };
+
+        Self {
+            id: compute_evidence_id(content_id, extractor, &mention.quote_sha256, offsets),
+            content_id: content_id.to_string(),
+            claim: entity.name.clone(),
+            quote: mention.quote.clone(),
+            quote_sha256: mention.quote_sha256.clone(),
+            status: mention.status,
+            resolution: mention.resolution.clone(),
+            span: mention.span.clone(),
+            source_artifact: None,
+            confidence: entity.confidence,
+            extractor: extractor.to_string(),
+            ts: ts.to_string(),
+        }
+    }
+
+    /// Record how the span was found, for matches that weren't exact
+    pub fn with_resolution_method(mut self, method: ResolutionMethod) -> Self {
+        self.resolution.method = method;
+        self
+    }
+
+    /// Record the artifact the claim was taken from
+    pub fn with_source_artifact(mut self, artifact: Option<String>) -> Self {
+        self.source_artifact = artifact;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span_over(text: &str, start: usize, end: usize) -> Span {
+        Span {
+            artifact: "transcript.txt".to_string(),
+            utf8_byte_offset: [start, end],
+            slice_sha256: compute_slice_hash(text.as_bytes(), start, end),
+            anchor_text: None,
+            video_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_span_verify_valid() {
+        let span = span_over("hello world", 6, 11);
+        assert_eq!(span.verify(b"hello world"), VerifyResult::Valid);
+    }
+
+    #[test]
+    fn test_span_verify_stale() {
+        let span = span_over("hello world", 6, 11);
+        assert_eq!(span.verify(b"hello there"), VerifyResult::Stale);
+    }
+
+    #[test]
+    fn test_span_verify_out_of_bounds() {
+        let span = span_over("hello world", 6, 11);
+        assert_eq!(span.verify(b"hello"), VerifyResult::OutOfBounds);
+
+        let mut inverted = span.clone();
+        inverted.utf8_byte_offset = [8, 2];
+        assert_eq!(inverted.verify(b"hello world"), VerifyResult::OutOfBounds);
+    }
+
+    #[test]
+    fn test_evidence_from_resolved_entity_mention() {
+        let transcript = "We met Ada Lovelace at the museum.";
+        let quote = "Ada Lovelace";
+        let quote_sha256 = crate::evidence::compute_hash(quote.as_bytes());
+        let mention = EntityMention {
+            quote: quote.to_string(),
+            quote_sha256: quote_sha256.clone(),
+            status: Status::Resolved,
+            resolution: Resolution {
+                method: ResolutionMethod::Exact,
+                match_count: 1,
+                match_rank: 1,
+                reason: None,
+            },
+            span: Some(span_over(transcript, 7, 19)),
+        };
+        let entity = Entity {
+            name: "Ada Lovelace".to_string(),
+            entity_type: "person".to_string(),
+            confidence: 0.8,
+            mentions: vec![mention.clone()],
+        };
+
+        let evidence = Evidence::from_entity_mention(
+            &entity,
+            &mention,
+            "abcdef0123456789",
+            "extract_entities",
+            "2026-01-01T00:00:00Z",
+        );
+
+        assert_eq!(
+            evidence.id,
+            compute_evidence_id(
+                "abcdef0123456789",
+                "extract_entities",
+                &quote_sha256,
+                Some((7, 19))
+            )
+        );
+        assert_eq!(evidence.claim, "Ada Lovelace");
+        assert_eq!(evidence.status, Status::Resolved);
+        assert_eq!(evidence.confidence, 0.8);
+        assert_eq!(
+            evidence.span.unwrap().verify(transcript.as_bytes()),
+            VerifyResult::Valid
+        );
+    }
 }
@@ -0,0 +1,420 @@
+//! Batch transcription.
+//!
+//! Spawning the Whisper CLI per memo reloads the model every time, which
+//! dominates the runtime for short clips. When a backlog is pending and the
+//! backend can stay resident (whisper.cpp's `whisper-server`), a
+//! [`BatchTranscriber`] loads the model once and sends every file through the
+//! same session. Other backends fall back to one process per file, as does
+//! the rest of a batch whose server dies partway through. A file that fails
+//! is recorded and the batch moves on.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use tokio::process::{Child, Command};
+
+use super::transcriber::{
+    cpp_model_path, find_on_path, parse_openai_output, run_whisper, TranscribeOptions,
+    TranscriptCache, TranscriptResult, WhisperBinary, WhisperFlavor,
+};
+
+/// whisper.cpp's resident server, looked for next to the CLI, then on PATH
+const SERVER_BINARY: &str = "whisper-server";
+
+/// How long to wait for the server to load its model
+const SERVER_STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A Whisper backend that transcribes files one after another
+#[async_trait]
+pub trait TranscriptionSession: Send {
+    /// Short description for progress output
+    fn describe(&self) -> String;
+
+    /// Transcribe one file
+    async fn transcribe(
+        &mut self,
+        audio_path: &Path,
+        options: &TranscribeOptions,
+    ) -> Result<TranscriptResult>;
+
+    /// Whether the session can take more files (a server that exited can't)
+    fn is_alive(&mut self) -> bool {
+        true
+    }
+}
+
+/// Runs the Whisper CLI once per file
+pub struct PerFileSession;
+
+#[async_trait]
+impl TranscriptionSession for PerFileSession {
+    fn describe(&self) -> String {
+        "one Whisper process per file".to_string()
+    }
+
+    async fn transcribe(
+        &mut self,
+        audio_path: &Path,
+        options: &TranscribeOptions,
+    ) -> Result<TranscriptResult> {
+        run_whisper(audio_path, options).await
+    }
+}
+
+/// A `whisper-server` process holding the model in memory. The process is
+/// killed when the session is dropped.
+pub struct WhisperServerSession {
+    child: Child,
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl WhisperServerSession {
+    /// Start `server_binary` for `options.model` on a free local port and
+    /// wait until it accepts requests. The server converts uploads with
+    /// ffmpeg (`--convert`), since it only reads WAV itself and memos are
+    /// m4a.
+    pub async fn start(
+        server_binary: &Path,
+        options: &TranscribeOptions,
+        extra_args: &[String],
+    ) -> Result<Self> {
+        let port = free_port()?;
+        let mut child = Command::new(server_binary)
+            .arg("-m")
+            .arg(cpp_model_path(&options.model))
+            .args(["-l", &options.language])
+            .args(["--host", "127.0.0.1", "--port", &port.to_string()])
+            .arg("--convert")
+            .args(extra_args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .with_context(|| format!("Failed to start {}", server_binary.display()))?;
+
+        let base_url = format!("http://127.0.0.1:{}", port);
+        let client = reqwest::Client::new();
+        let deadline = tokio::time::Instant::now() + SERVER_STARTUP_TIMEOUT;
+        loop {
+            if let Some(status) = child.try_wait()? {
+                anyhow::bail!("whisper-server exited during startup ({})", status);
+            }
+            if client.get(&base_url).send().await.is_ok() {
+                break;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "whisper-server did not start within {}s",
+                    SERVER_STARTUP_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+
+        Ok(Self {
+            child,
+            base_url,
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl TranscriptionSession for WhisperServerSession {
+    fn describe(&self) -> String {
+        format!(
+            "shared whisper-server session (pid {})",
+            self.child.id().unwrap_or(0)
+        )
+    }
+
+    async fn transcribe(
+        &mut self,
+        audio_path: &Path,
+        options: &TranscribeOptions,
+    ) -> Result<TranscriptResult> {
+        let bytes = tokio::fs::read(audio_path)
+            .await
+            .with_context(|| format!("Failed to read {}", audio_path.display()))?;
+        let file_name = audio_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+
+        let form = reqwest::multipart::Form::new()
+            .part(
+                "file",
+                reqwest::multipart::Part::bytes(bytes).file_name(file_name),
+            )
+            .text("response_format", "verbose_json")
+            .text("language", options.language.clone());
+
+        let response = self
+            .client
+            .post(format!("{}/inference", self.base_url))
+            .multipart(form)
+            .send()
+            .await
+            .context("Failed to reach whisper-server")?;
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            anyhow::bail!("whisper-server error {}: {}", status, body);
+        }
+
+        parse_openai_output(&body)
+    }
+
+    fn is_alive(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+}
+
+/// Transcribes a run of files through one backend session, serving cached
+/// transcripts where possible
+pub struct BatchTranscriber {
+    session: Box<dyn TranscriptionSession>,
+    options: TranscribeOptions,
+    cache: Option<TranscriptCache>,
+    failures: Vec<(PathBuf, String)>,
+}
+
+impl BatchTranscriber {
+    /// Transcriber over `session`, using the default cache unless
+    /// `options.use_cache` is off
+    pub fn new(session: Box<dyn TranscriptionSession>, options: TranscribeOptions) -> Result<Self> {
+        let cache = if options.use_cache {
+            let cache = TranscriptCache::open_default()?;
+            Some(match WhisperBinary::from_config() {
+                Ok(whisper) => cache.for_engine(&whisper),
+                Err(_) => cache,
+            })
+        } else {
+            None
+        };
+        Ok(Self {
+            session,
+            options,
+            cache,
+            failures: Vec::new(),
+        })
+    }
+
+    /// Replace the transcript cache (`None` disables it)
+    pub fn with_cache(mut self, cache: Option<TranscriptCache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Transcriber for `pending` files. With more than one pending and a
+    /// whisper.cpp backend that ships `whisper-server`, the model is loaded
+    /// once; otherwise (or if the server fails to start) each file spawns the
+    /// CLI.
+    pub async fn for_backlog(pending: usize, options: TranscribeOptions) -> Result<Self> {
+        let session: Box<dyn TranscriptionSession> = match Self::server_binary(pending) {
+            Some((server, extra_args)) => {
+                match WhisperServerSession::start(&server, &options, &extra_args).await {
+                    Ok(session) => Box::new(session),
+                    Err(e) => {
+                        tracing::warn!("Falling back to per-file transcription: {:#}", e);
+                        Box::new(PerFileSession)
+                    }
+                }
+            }
+            None => Box::new(PerFileSession),
+        };
+        Self::new(session, options)
+    }
+
+    /// `whisper-server` to use for a backlog of `pending`, if any
+    fn server_binary(pending: usize) -> Option<(PathBuf, Vec<String>)> {
+        if pending < 2 {
+            return None;
+        }
+        let whisper = WhisperBinary::from_config().ok()?;
+        if whisper.flavor != WhisperFlavor::Cpp {
+            return None;
+        }
+        let sibling = whisper
+            .path
+            .parent()
+            .map(|dir| dir.join(SERVER_BINARY))
+            .filter(|path| path.is_file());
+        sibling
+            .or_else(|| find_on_path(SERVER_BINARY, std::env::var_os("PATH").as_deref()))
+            .map(|server| (server, whisper.extra_args))
+    }
+
+    /// What the session is, for progress output
+    pub fn describe(&self) -> String {
+        self.session.describe()
+    }
+
+    /// Files that failed so far, with their errors
+    pub fn failures(&self) -> &[(PathBuf, String)] {
+        &self.failures
+    }
+
+    /// Transcribe one file through the shared session. A failure is recorded
+    /// in [`Self::failures`]; if it took the session down, later files go
+    /// through one process each.
+    pub async fn transcribe(&mut self, audio_path: &Path) -> Result<TranscriptResult> {
+        let result = self.transcribe_in_session(audio_path).await;
+        if let Err(ref e) = result {
+            self.failures
+                .push((audio_path.to_path_buf(), format!("{:#}", e)));
+            if !self.session.is_alive() {
+                tracing::warn!(
+                    "{} stopped; transcribing the rest of the batch one file at a time",
+                    self.session.describe()
+                );
+                self.session = Box::new(PerFileSession);
+            }
+        }
+        result
+    }
+
+    async fn transcribe_in_session(&mut self, audio_path: &Path) -> Result<TranscriptResult> {
+        let Self {
+            session,
+            options,
+            cache,
+            ..
+        } = self;
+
+        let Some(cache) = cache else {
+            return session.transcribe(audio_path, options).await;
+        };
+        let (result, cached) = cache
+            .get_or_transcribe(audio_path, options, || {
+                session.transcribe(audio_path, options)
+            })
+            .await?;
+        if cached {
+            tracing::debug!("Transcript for {} served from cache", audio_path.display());
+        }
+        Ok(result)
+    }
+}
+
+/// An unused local port for the server to bind
+fn free_port() -> Result<u16> {
+    let listener =
+        std::net::TcpListener::bind("127.0.0.1:0").context("Failed to find a free port")?;
+    Ok(listener.local_addr()?.port())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
+
+    /// Records which session instance handled each file
+    struct MockSession {
+        id: usize,
+        calls: Arc<Mutex<Vec<(usize, PathBuf)>>>,
+    }
+
+    #[async_trait]
+    impl TranscriptionSession for MockSession {
+        fn describe(&self) -> String {
+            format!("mock {}", self.id)
+        }
+
+        async fn transcribe(
+            &mut self,
+            audio_path: &Path,
+            _options: &TranscribeOptions,
+        ) -> Result<TranscriptResult> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((self.id, audio_path.to_path_buf()));
+            if audio_path.ends_with("corrupt.m4a") {
+                anyhow::bail!("unreadable audio");
+            }
+            Ok(TranscriptResult {
+                text: format!("transcript of {}", audio_path.display()),
+                language: "en".to_string(),
+                duration_seconds: 1.0,
+                segments: Vec::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_uses_one_session_for_all_files() {
+        let dir = TempDir::new().unwrap();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let session = MockSession {
+            id: 7,
+            calls: Arc::clone(&calls),
+        };
+        let mut batch = BatchTranscriber::new(Box::new(session), TranscribeOptions::new("base"))
+            .unwrap()
+            .with_cache(Some(TranscriptCache::new(dir.path().join("cache"))));
+
+        let files: Vec<PathBuf> = (0..3)
+            .map(|i| {
+                let path = dir.path().join(format!("memo{}.m4a", i));
+                std::fs::write(&path, format!("audio {}", i)).unwrap();
+                path
+            })
+            .collect();
+        for file in &files {
+            let result = batch.transcribe(file).await.unwrap();
+            assert!(result.text.ends_with(&format!("{}", file.display())));
+        }
+
+        let handled = calls.lock().unwrap().clone();
+        assert_eq!(handled.len(), 3);
+        assert!(handled.iter().all(|(id, _)| *id == 7));
+        assert_eq!(
+            handled
+                .into_iter()
+                .map(|(_, path)| path)
+                .collect::<Vec<_>>(),
+            files
+        );
+
+        // Cached files don't reach the session again
+        batch.transcribe(&files[0]).await.unwrap();
+        assert_eq!(calls.lock().unwrap().len(), 3);
+        assert_eq!(batch.describe(), "mock 7");
+        assert!(batch.failures().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_file_is_recorded_and_batch_continues() {
+        let dir = TempDir::new().unwrap();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let session = MockSession {
+            id: 1,
+            calls: Arc::clone(&calls),
+        };
+        let mut batch = BatchTranscriber::new(Box::new(session), TranscribeOptions::new("base"))
+            .unwrap()
+            .with_cache(None);
+
+        let files: Vec<PathBuf> = ["first.m4a", "corrupt.m4a", "last.m4a"]
+            .iter()
+            .map(|name| dir.path().join(name))
+            .collect();
+        let mut results = Vec::new();
+        for file in &files {
+            results.push(batch.transcribe(file).await.is_ok());
+        }
+
+        assert_eq!(results, vec![true, false, true]);
+        assert_eq!(calls.lock().unwrap().len(), 3);
+        assert_eq!(batch.failures().len(), 1);
+        assert_eq!(batch.failures()[0].0, files[1]);
+        assert!(batch.failures()[0].1.contains("unreadable audio"));
+    }
+}
@@ -0,0 +1,298 @@
+//! Delivering processed memos to one or more destinations.
+//!
+//! `voice process --route both` sends the transcript to Clawdbot and the raw
+//! audio to Telegram. Each [`Destination`] is tried independently and the
+//! item is marked done when the [`RequirePolicy`] is met: every destination
+//! (`all`) or at least one (`any`). Each destination's result is recorded on
+//! the queue item, and a done item whose delivery was partial keeps the
+//! failing destinations in its error.
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use super::queue::{DeliveryStatus, QueueItem, VoiceQueue};
+use super::transcriber::TranscriptResult;
+
+/// Somewhere a processed memo can be sent
+#[async_trait]
+pub trait Destination: Send + Sync {
+    /// Name shown in progress output and the queue (`telegram`, `clawdbot`)
+    fn name(&self) -> &str;
+
+    /// Deliver `item`, returning a receipt (e.g. a message ID)
+    async fn deliver(&self, item: &QueueItem, transcript: &TranscriptResult) -> Result<String>;
+}
+
+/// Which destinations must succeed for an item to count as done
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum RequirePolicy {
+    /// Every destination
+    #[default]
+    All,
+    /// At least one destination
+    Any,
+}
+
+/// Per-destination results of delivering one item
+#[derive(Debug, Default)]
+pub struct DeliveryOutcome {
+    /// Destination name and its receipt or error, in delivery order
+    pub results: Vec<(String, Result<String, String>)>,
+}
+
+impl DeliveryOutcome {
+    /// Whether the outcome meets `policy`
+    pub fn satisfies(&self, policy: RequirePolicy) -> bool {
+        match policy {
+            RequirePolicy::All => self.results.iter().all(|(_, r)| r.is_ok()),
+            RequirePolicy::Any => self.results.iter().any(|(_, r)| r.is_ok()),
+        }
+    }
+
+    /// Failed destinations as `name: error`, joined with `; `
+    pub fn failures(&self) -> Option<String> {
+        let failures: Vec<String> = self
+            .results
+            .iter()
+            .filter_map(|(name, r)| r.as_ref().err().map(|e| format!("{}: {}", name, e)))
+            .collect();
+        (!failures.is_empty()).then(|| failures.join("; "))
+    }
+}
+
+/// Deliver `item` to every destination, continuing past failures.
+///
+/// Destinations `item` already records as sent (a retry after a partial
+/// failure) aren't sent to again; their earlier receipt is reused.
+pub async fn deliver_all(
+    destinations: &[&dyn Destination],
+    item: &QueueItem,
+    transcript: &TranscriptResult,
+) -> DeliveryOutcome {
+    let mut outcome = DeliveryOutcome::default();
+    for destination in destinations {
+        let result = match item.deliveries.get(destination.name()) {
+            Some(DeliveryStatus::Sent(receipt)) => Ok(receipt.clone()),
+            _ => destination
+                .deliver(item, transcript)
+                .await
+                .map_err(|e| e.to_string()),
+        };
+        outcome
+            .results
+            .push((destination.name().to_string(), result));
+    }
+    outcome
+}
+
+/// Record each destination's result, then mark `item_id` done or failed
+/// according to `policy`. Returns whether it was marked done.
+pub async fn record_outcome(
+    queue: &VoiceQueue,
+    item_id: &str,
+    outcome: &DeliveryOutcome,
+    policy: RequirePolicy,
+) -> Result<bool> {
+    for (destination, result) in &outcome.results {
+        let status = match result {
+            Ok(receipt) => DeliveryStatus::Sent(receipt.clone()),
+            Err(error) => DeliveryStatus::Failed(error.clone()),
+        };
+        queue.record_delivery(item_id, destination, &status).await?;
+    }
+
+    let failures = outcome.failures();
+    if !outcome.satisfies(policy) {
+        let error = failures.unwrap_or_else(|| "No destinations".to_string());
+        queue.mark_failed(item_id, &error).await?;
+        return Ok(false);
+    }
+
+    match failures {
+        Some(partial) => queue.mark_partially_done(item_id, &partial).await?,
+        None => queue.mark_done(item_id).await?,
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::VoiceQueueStatus;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    struct MockDestination {
+        name: &'static str,
+        fail: bool,
+        calls: AtomicUsize,
+    }
+
+    impl MockDestination {
+        fn new(name: &'static str, fail: bool) -> Self {
+            Self {
+                name,
+                fail,
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Destination for MockDestination {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        async fn deliver(
+            &self,
+            item: &QueueItem,
+            _transcript: &TranscriptResult,
+        ) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail {
+                anyhow::bail!("{} unreachable", self.name);
+            }
+            Ok(format!("{}-{}", self.name, &item.id[..4]))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_route_both_require_policies() {
+        let temp = TempDir::new().unwrap();
+        let queue = VoiceQueue::new(temp.path().join("queue.jsonl"));
+        let transcript = TranscriptResult {
+            text: "hello".to_string(),
+            language: "en".to_string(),
+            duration_seconds: 2.0,
+            segments: Vec::new(),
+        };
+
+        let write_memo = |name: &str| {
+            let path = temp.path().join(name);
+            std::fs::write(&path, name).unwrap();
+            path
+        };
+        let paths = [
+            write_memo("a.m4a"),
+            write_memo("b.m4a"),
+            write_memo("c.m4a"),
+        ];
+        let mut items = Vec::new();
+        for path in &paths {
+            let id = queue
+                .enqueue(path, 5, Utc::now())
+                .await
+                .unwrap()
+                .id()
+                .to_string();
+            items.push(queue.get(&id).await.unwrap().unwrap());
+        }
+
+        let clawdbot = MockDestination::new("clawdbot", false);
+        let telegram_ok = MockDestination::new("telegram", false);
+        let telegram_down = MockDestination::new("telegram", true);
+
+        // Both succeed
+        let outcome = deliver_all(&[&clawdbot, &telegram_ok], &items[0], &transcript).await;
+        assert!(outcome.failures().is_none());
+        assert!(
+            record_outcome(&queue, &items[0].id, &outcome, RequirePolicy::All)
+                .await
+                .unwrap()
+        );
+        let item = queue.get(&items[0].id).await.unwrap().unwrap();
+        assert_eq!(item.status, VoiceQueueStatus::Done);
+        assert_eq!(item.error, None);
+
+        // One fails, require any: done, with the failure kept
+        let outcome = deliver_all(&[&clawdbot, &telegram_down], &items[1], &transcript).await;
+        assert!(
+            record_outcome(&queue, &items[1].id, &outcome, RequirePolicy::Any)
+                .await
+                .unwrap()
+        );
+        let item = queue.get(&items[1].id).await.unwrap().unwrap();
+        assert_eq!(item.status, VoiceQueueStatus::Done);
+        assert_eq!(
+            item.error.as_deref(),
+            Some("telegram: telegram unreachable")
+        );
+        assert!(matches!(
+            item.deliveries["clawdbot"],
+            DeliveryStatus::Sent(_)
+        ));
+        assert!(matches!(
+            item.deliveries["telegram"],
+            DeliveryStatus::Failed(_)
+        ));
+
+        // One fails, require all: failed
+        let outcome = deliver_all(&[&clawdbot, &telegram_down], &items[2], &transcript).await;
+        assert!(
+            !record_outcome(&queue, &items[2].id, &outcome, RequirePolicy::All)
+                .await
+                .unwrap()
+        );
+        let item = queue.get(&items[2].id).await.unwrap().unwrap();
+        assert_eq!(item.status, VoiceQueueStatus::Failed);
+        assert_eq!(
+            item.error.as_deref(),
+            Some("telegram: telegram unreachable")
+        );
+
+        // A failing destination doesn't stop the others
+        assert_eq!(clawdbot.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(telegram_down.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_skips_destinations_already_sent() {
+        let temp = TempDir::new().unwrap();
+        let queue = VoiceQueue::new(temp.path().join("queue.jsonl"));
+        let transcript = TranscriptResult {
+            text: "hello".to_string(),
+            language: "en".to_string(),
+            duration_seconds: 2.0,
+            segments: Vec::new(),
+        };
+        let path = temp.path().join("a.m4a");
+        std::fs::write(&path, "a").unwrap();
+        let id = queue
+            .enqueue(&path, 5, Utc::now())
+            .await
+            .unwrap()
+            .id()
+            .to_string();
+        let item = queue.get(&id).await.unwrap().unwrap();
+
+        // First attempt: clawdbot and obsidian succeed, telegram fails
+        let clawdbot = MockDestination::new("clawdbot", false);
+        let obsidian = MockDestination::new("obsidian", false);
+        let telegram_down = MockDestination::new("telegram", true);
+        let outcome =
+            deliver_all(&[&clawdbot, &obsidian, &telegram_down], &item, &transcript).await;
+        assert!(!record_outcome(&queue, &id, &outcome, RequirePolicy::All)
+            .await
+            .unwrap());
+
+        // Retry: only telegram is sent to, and the earlier receipts are kept
+        let item = queue.get(&id).await.unwrap().unwrap();
+        let telegram_ok = MockDestination::new("telegram", false);
+        let outcome = deliver_all(&[&clawdbot, &obsidian, &telegram_ok], &item, &transcript).await;
+        assert!(record_outcome(&queue, &id, &outcome, RequirePolicy::All)
+            .await
+            .unwrap());
+        assert_eq!(clawdbot.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(obsidian.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(telegram_ok.calls.load(Ordering::SeqCst), 1);
+
+        let item = queue.get(&id).await.unwrap().unwrap();
+        assert_eq!(item.status, VoiceQueueStatus::Done);
+        assert_eq!(
+            item.deliveries["clawdbot"],
+            DeliveryStatus::Sent(format!("clawdbot-{}", &id[..4]))
+        );
+    }
+}
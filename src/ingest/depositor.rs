@@ -0,0 +1,405 @@
+//! Depositor: transcripts into an Obsidian vault.
+//!
+//! Each done item with a stored transcript becomes one markdown note with
+//! YAML frontmatter (date, duration, source file) and the transcript (after
+//! its summary, if one was made) as its body, with speaker labels when the
+//! memo was diarized. Notes are named by memo date and title, and an existing
+//! note is left alone unless the export is forced, so re-running an export
+//! only adds new memos.
+//!
+//! A [`NoteFormat`] (`voice_export` in config) swaps in a `{{...}}` note
+//! template, a file name pattern and a subfolder to match a vault's
+//! conventions.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::queue::QueueItem;
+use super::transcriber::TranscriptResult;
+use crate::core::template;
+use crate::domain::VoiceQueueStatus;
+
+/// What an export wrote
+#[derive(Debug, Default)]
+pub struct ExportReport {
+    /// Notes written
+    pub written: Vec<PathBuf>,
+    /// Notes that already existed
+    pub existing: Vec<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct NoteFrontmatter<'a> {
+    date: String,
+    duration_seconds: u64,
+    source: &'a str,
+    voice_id: &'a str,
+    language: &'a str,
+}
+
+/// Items that can be exported: done, transcribed, detected at or after
+/// `since`
+pub fn exportable<'a>(
+    items: impl IntoIterator<Item = &'a QueueItem>,
+    since: Option<DateTime<Utc>>,
+) -> Vec<&'a QueueItem> {
+    let mut items: Vec<&QueueItem> = items
+        .into_iter()
+        .filter(|item| item.status == VoiceQueueStatus::Done && item.transcript.is_some())
+        .filter(|item| since.is_none_or(|since| item.data.detected_at >= since))
+        .collect();
+    items.sort_by_key(|item| item.data.detected_at);
+    items
+}
+
+/// Placeholders available to note templates and file name patterns
+pub const NOTE_PLACEHOLDERS: &[&str] = &[
+    "date",
+    "time",
+    "datetime",
+    "duration",
+    "transcript",
+    "filename",
+    "title",
+    "tags",
+    "id",
+    "language",
+    "summary",
+];
+
+/// Default file name pattern: `YYYY-MM-DD HHMM <title>`
+const DEFAULT_FILENAME: &str = "{{date}} {{time}} {{title}}";
+
+/// How notes are named, formatted and placed in the vault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteFormat {
+    /// Note template; `None` writes YAML frontmatter plus the transcript
+    pub template: Option<String>,
+    /// File name pattern, without `.md`
+    pub filename: String,
+    /// Folder inside the vault
+    pub subfolder: Option<PathBuf>,
+    /// Values for `{{tags}}`
+    pub tags: Vec<String>,
+}
+
+impl Default for NoteFormat {
+    fn default() -> Self {
+        Self {
+            template: None,
+            filename: DEFAULT_FILENAME.to_string(),
+            subfolder: None,
+            tags: Vec::new(),
+        }
+    }
+}
+
+impl NoteFormat {
+    /// Format from `voice_export` config, reading the template file
+    pub fn from_config(config: &crate::config::VoiceExportConfig) -> Result<Self> {
+        let template = match &config.template {
+            Some(path) => Some(
+                std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read note template {}", path))?,
+            ),
+            None => None,
+        };
+        Ok(Self {
+            template,
+            filename: config
+                .filename
+                .clone()
+                .unwrap_or_else(|| DEFAULT_FILENAME.to_string()),
+            subfolder: config.subfolder.as_ref().map(PathBuf::from),
+            tags: config.tags.clone(),
+        })
+    }
+
+    fn vars(
+        &self,
+        item: &QueueItem,
+        transcript: &TranscriptResult,
+    ) -> HashMap<&'static str, String> {
+        let at = item.data.detected_at;
+        let title = Path::new(&item.data.file_name)
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let values = [
+            at.format("%Y-%m-%d").to_string(),
+            at.format("%H:%M").to_string(),
+            at.to_rfc3339(),
+            (transcript.duration_seconds.round() as u64).to_string(),
+            transcript.labeled_text().trim().to_string(),
+            item.data.file_name.clone(),
+            title,
+            format!("[{}]", self.tags.join(", ")),
+            item.id.clone(),
+            transcript.language.clone(),
+            item.summary.clone().unwrap_or_default(),
+        ];
+        NOTE_PLACEHOLDERS.iter().copied().zip(values).collect()
+    }
+
+    /// Note file name for `item`, with characters Obsidian can't link
+    /// stripped
+    pub fn file_name(&self, item: &QueueItem, transcript: &TranscriptResult) -> Result<String> {
+        let name = template::render(&self.filename, &self.vars(item, transcript))
+            .context("Invalid note file name pattern")?;
+        let name: String = name
+            .chars()
+            .filter(|c| {
+                !matches!(
+                    c,
+                    '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']'
+                )
+            })
+            .collect();
+        Ok(format!("{}.md", name.trim()))
+    }
+
+    /// Where `item`'s note goes in `vault`
+    pub fn note_path(
+        &self,
+        vault: &Path,
+        item: &QueueItem,
+        transcript: &TranscriptResult,
+    ) -> Result<PathBuf> {
+        let dir = match &self.subfolder {
+            Some(subfolder) => vault.join(subfolder),
+            None => vault.to_path_buf(),
+        };
+        Ok(dir.join(self.file_name(item, transcript)?))
+    }
+
+    /// Markdown note for `item`
+    pub fn render(&self, item: &QueueItem, transcript: &TranscriptResult) -> Result<String> {
+        if let Some(note_template) = &self.template {
+            return template::render(note_template, &self.vars(item, transcript))
+                .context("Invalid note template");
+        }
+
+        let frontmatter = NoteFrontmatter {
+            date: item.data.detected_at.to_rfc3339(),
+            duration_seconds: transcript.duration_seconds.round() as u64,
+            source: &item.data.file_name,
+            voice_id: &item.id,
+            language: &transcript.language,
+        };
+        let yaml = serde_yaml::to_string(&frontmatter).context("Failed to render frontmatter")?;
+        let body = match &item.summary {
+            Some(summary) => format!(
+                "## Summary\n\n{}\n\n## Transcript\n\n{}",
+                summary.trim(),
+                transcript.labeled_text().trim()
+            ),
+            None => transcript.labeled_text().trim().to_string(),
+        };
+        Ok(format!(
+            "---\n{}---\n\n{}\n",
+            yaml.trim_start_matches("---\n"),
+            body
+        ))
+    }
+}
+
+/// Write a note for each item into `vault` (under the format's subfolder),
+/// skipping notes that exist unless `overwrite` is set
+pub async fn export_to_vault(
+    items: &[&QueueItem],
+    vault: &Path,
+    format: &NoteFormat,
+    overwrite: bool,
+) -> Result<ExportReport> {
+    let dir = match &format.subfolder {
+        Some(subfolder) => vault.join(subfolder),
+        None => vault.to_path_buf(),
+    };
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let mut report = ExportReport::default();
+    for item in items {
+        let Some(transcript) = &item.transcript else {
+            continue;
+        };
+        let path = format.note_path(vault, item, transcript)?;
+        if path.exists() && !overwrite {
+            report.existing.push(path);
+            continue;
+        }
+
+        tokio::fs::write(&path, format.render(item, transcript)?)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        report.written.push(path);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingest::VoiceQueue;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_export_two_items_idempotently() {
+        let temp = TempDir::new().unwrap();
+        let queue = VoiceQueue::new(temp.path().join("queue.jsonl"));
+
+        let mut ids = Vec::new();
+        for (name, at, text) in [
+            (
+                "Morning walk.m4a",
+                "2026-03-01T08:15:00Z",
+                "Remember the milk.",
+            ),
+            (
+                "Idea: pipeline?.m4a",
+                "2026-03-02T21:40:00Z",
+                "Cache the embeddings.",
+            ),
+            ("Pending.m4a", "2026-03-03T10:00:00Z", ""),
+        ] {
+            let path = temp.path().join(name);
+            std::fs::write(&path, name).unwrap();
+            let at = DateTime::parse_from_rfc3339(at)
+                .unwrap()
+                .with_timezone(&Utc);
+            let id = queue.enqueue(&path, 4, at).await.unwrap().id().to_string();
+            if !text.is_empty() {
+                let transcript = TranscriptResult {
+                    text: text.to_string(),
+                    language: "en".to_string(),
+                    duration_seconds: 41.6,
+                    segments: Vec::new(),
+                };
+                queue.record_transcript(&id, &transcript).await.unwrap();
+                queue.mark_done(&id).await.unwrap();
+            }
+            ids.push(id);
+        }
+
+        let items = queue.replay().await.unwrap();
+        let vault = temp.path().join("vault");
+        let selected = exportable(items.values(), None);
+        assert_eq!(selected.len(), 2);
+
+        let report = export_to_vault(&selected, &vault, &NoteFormat::default(), false)
+            .await
+            .unwrap();
+        assert_eq!(report.written.len(), 2);
+        assert!(report.existing.is_empty());
+
+        let note = std::fs::read_to_string(vault.join("2026-03-01 0815 Morning walk.md")).unwrap();
+        assert!(note.starts_with("---\ndate: 2026-03-01T08:15:00+00:00\n"));
+        assert!(note.contains("duration_seconds: 42\n"));
+        assert!(note.contains("source: Morning walk.m4a\n"));
+        assert!(note.contains(&format!("voice_id: {}\n", ids[0])));
+        assert!(note.ends_with("---\n\nRemember the milk.\n"));
+        assert!(vault.join("2026-03-02 2140 Idea pipeline.md").exists());
+
+        // Re-export writes nothing and leaves edited notes alone
+        std::fs::write(&report.written[0], "edited").unwrap();
+        let report = export_to_vault(&selected, &vault, &NoteFormat::default(), false)
+            .await
+            .unwrap();
+        assert!(report.written.is_empty());
+        assert_eq!(report.existing.len(), 2);
+        assert_eq!(
+            std::fs::read_to_string(&report.existing[0]).unwrap(),
+            "edited"
+        );
+
+        // Unless forced
+        let report = export_to_vault(&selected, &vault, &NoteFormat::default(), true)
+            .await
+            .unwrap();
+        assert_eq!(report.written.len(), 2);
+        assert!(report.existing.is_empty());
+        assert!(std::fs::read_to_string(&report.written[0])
+            .unwrap()
+            .ends_with("Remember the milk.\n"));
+
+        // --since filters by memo date
+        let since = DateTime::parse_from_rfc3339("2026-03-02T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let selected = exportable(items.values(), Some(since));
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].id, ids[1]);
+    }
+
+    fn item_with_transcript() -> (QueueItem, TranscriptResult) {
+        let item = QueueItem {
+            id: "ab12cd34ef56".to_string(),
+            status: VoiceQueueStatus::Done,
+            data: crate::ingest::queue::QueueItemData {
+                file_path: PathBuf::from("/memos/Standup notes.m4a"),
+                file_name: "Standup notes.m4a".to_string(),
+                file_size: 10,
+                detected_at: DateTime::parse_from_rfc3339("2026-03-04T09:05:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc),
+                duration_seconds: Some(61.0),
+            },
+            started_at: None,
+            completed_at: None,
+            error: None,
+            skip_reason: None,
+            retry_count: 0,
+            deliveries: HashMap::new(),
+            transcript: None,
+            transcript_hash: None,
+            summary: None,
+        };
+        let transcript = TranscriptResult {
+            text: " Ship the export. ".to_string(),
+            language: "en".to_string(),
+            duration_seconds: 61.4,
+            segments: Vec::new(),
+        };
+        (item, transcript)
+    }
+
+    #[test]
+    fn test_custom_template_and_filename() {
+        let (item, transcript) = item_with_transcript();
+        let format = NoteFormat {
+            template: Some(
+                "---\ncreated: {{date}}\ntags: {{tags}}\n---\n# {{title}}\n\n{{transcript}}\n\n_{{duration}}s from {{filename}}_\n"
+                    .to_string(),
+            ),
+            filename: "{{date}}-voice-{{id}}".to_string(),
+            subfolder: Some(PathBuf::from("Inbox/Voice")),
+            tags: vec!["voice".to_string(), "work".to_string()],
+        };
+
+        assert_eq!(
+            format.render(&item, &transcript).unwrap(),
+            "---\ncreated: 2026-03-04\ntags: [voice, work]\n---\n# Standup notes\n\nShip the export.\n\n_61s from Standup notes.m4a_\n"
+        );
+        assert_eq!(
+            format.file_name(&item, &transcript).unwrap(),
+            "2026-03-04-voice-ab12cd34ef56.md"
+        );
+
+        let typo = NoteFormat {
+            template: Some("{{date}} {{transcirpt}}".to_string()),
+            ..format
+        };
+        let err = format!("{:#}", typo.render(&item, &transcript).unwrap_err());
+        assert!(
+            err.contains("Unknown placeholder '{{transcirpt}}'"),
+            "{}",
+            err
+        );
+    }
+}
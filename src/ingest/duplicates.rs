@@ -0,0 +1,119 @@
+//! Probable-duplicate detection for voice memos.
+//!
+//! A memo recorded twice, or re-synced with a tiny difference, gets a new
+//! content hash but says the same thing. Each transcript is stored with a
+//! hash of its normalized text (lowercased, punctuation dropped, whitespace
+//! collapsed); a new transcript whose hash matches an already-processed item
+//! is a probable duplicate. By default this only warns.
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::queue::QueueItem;
+use crate::domain::VoiceQueueStatus;
+
+/// What to do with a probable duplicate (`transcriber.duplicates` in config)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePolicy {
+    /// Print a warning and process it anyway
+    #[default]
+    Warn,
+    /// Mark it skipped instead of delivering it
+    Skip,
+    /// Don't check
+    Off,
+}
+
+/// Hash of `text` ignoring case, punctuation and whitespace differences
+/// (first 12 hex characters of a SHA256, like item IDs), or `None` when
+/// nothing is left to compare (silent or unintelligible memos all normalize
+/// to the empty string, so they'd all look like duplicates of each other)
+pub fn normalized_text_hash(text: &str) -> Option<String> {
+    let normalized: String = text
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if normalized.is_empty() {
+        return None;
+    }
+
+    let digest = Sha256::digest(normalized.as_bytes());
+    Some(format!("{:x}", digest)[..12].to_string())
+}
+
+/// The already-processed item (other than `id`) whose transcript has
+/// `text_hash`, if any
+pub fn find_duplicate<'a>(
+    items: impl IntoIterator<Item = &'a QueueItem>,
+    id: &str,
+    text_hash: &str,
+) -> Option<&'a QueueItem> {
+    items
+        .into_iter()
+        .filter(|item| item.id != id && item.status == VoiceQueueStatus::Done)
+        .filter(|item| item.transcript_hash.as_deref() == Some(text_hash))
+        .min_by_key(|item| item.data.detected_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingest::{TranscriptResult, VoiceQueue};
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_near_identical_transcripts_are_flagged() {
+        let temp = TempDir::new().unwrap();
+        let queue = VoiceQueue::new(temp.path().join("queue.jsonl"));
+
+        let mut ids = Vec::new();
+        for (name, text) in [
+            ("first.m4a", "Call the plumber about the kitchen sink."),
+            ("resynced.m4a", "call the plumber, about the  kitchen sink"),
+            ("other.m4a", "Call the electrician about the kitchen light."),
+        ] {
+            let path = temp.path().join(name);
+            std::fs::write(&path, name).unwrap();
+            let id = queue
+                .enqueue(&path, 5, Utc::now())
+                .await
+                .unwrap()
+                .id()
+                .to_string();
+            let transcript = TranscriptResult {
+                text: text.to_string(),
+                ..TranscriptResult::default()
+            };
+            queue.record_transcript(&id, &transcript).await.unwrap();
+            ids.push(id);
+        }
+        queue.mark_done(&ids[0]).await.unwrap();
+
+        let items = queue.replay().await.unwrap();
+        let hash = |i: usize| items[&ids[i]].transcript_hash.clone().unwrap();
+        assert_eq!(hash(0), hash(1));
+        assert_ne!(hash(0), hash(2));
+
+        let duplicate = find_duplicate(items.values(), &ids[1], &hash(1)).unwrap();
+        assert_eq!(duplicate.id, ids[0]);
+        assert!(find_duplicate(items.values(), &ids[2], &hash(2)).is_none());
+        // An item isn't a duplicate of itself
+        assert!(find_duplicate(items.values(), &ids[0], &hash(0)).is_none());
+    }
+
+    #[test]
+    fn test_empty_text_has_no_hash() {
+        assert_eq!(normalized_text_hash(""), None);
+        assert_eq!(normalized_text_hash("  ...  \n -- "), None);
+        assert!(normalized_text_hash("Hello.").is_some());
+    }
+}
@@ -0,0 +1,189 @@
+//! Watcher heartbeat.
+//!
+//! A watcher running as a background service can die without anyone
+//! noticing. While it runs, the watcher loop rewrites
+//! `~/.arkai/watcher.heartbeat` with the current time every few seconds;
+//! `arkai voice status` reads it back and reports how long ago the watcher
+//! was last seen.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+
+/// A heartbeat older than this means the watcher has stopped
+pub const STALE_AFTER: Duration = Duration::from_secs(60);
+
+/// How often the watcher rewrites the heartbeat file
+pub const BEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The heartbeat file
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    path: PathBuf,
+    interval: Duration,
+    last_beat: Option<Instant>,
+}
+
+impl Heartbeat {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            interval: BEAT_INTERVAL,
+            last_beat: None,
+        }
+    }
+
+    /// Beat at most once per `interval` instead of [`BEAT_INTERVAL`]
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// `watcher.heartbeat` in arkai home
+    pub fn default_path() -> Result<PathBuf> {
+        Ok(crate::config::arkai_home()?.join("watcher.heartbeat"))
+    }
+
+    /// Record that the watcher is alive now, unless it already did within
+    /// the interval. Failures are logged, not returned: a missed beat
+    /// shouldn't stop the watcher.
+    pub async fn beat(&mut self) {
+        if self
+            .last_beat
+            .is_some_and(|last| last.elapsed() < self.interval)
+        {
+            return;
+        }
+        self.last_beat = Some(Instant::now());
+        if let Err(e) = tokio::fs::write(&self.path, Utc::now().to_rfc3339()).await {
+            tracing::warn!("Failed to write heartbeat {}: {}", self.path.display(), e);
+        }
+    }
+
+    /// When the watcher last beat, if it ever has
+    pub async fn last_seen(&self) -> Option<DateTime<Utc>> {
+        let contents = tokio::fs::read_to_string(&self.path).await.ok()?;
+        DateTime::parse_from_rfc3339(contents.trim())
+            .ok()
+            .map(|dt| dt.with_timezone(&Utc))
+    }
+}
+
+/// Whether the watcher is running, judged from its heartbeat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherLiveness {
+    /// No heartbeat file
+    NeverSeen,
+    /// Beat within [`STALE_AFTER`]
+    Alive { ago: Duration },
+    /// Last beat longer ago than [`STALE_AFTER`]
+    Stopped { ago: Duration },
+}
+
+impl WatcherLiveness {
+    /// Judge `last_seen` as of `now`
+    pub fn from_last_seen(last_seen: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Self {
+        let Some(last_seen) = last_seen else {
+            return Self::NeverSeen;
+        };
+        // A heartbeat from the future (clock skew) counts as just now
+        let ago = (now - last_seen).to_std().unwrap_or_default();
+        if ago > STALE_AFTER {
+            Self::Stopped { ago }
+        } else {
+            Self::Alive { ago }
+        }
+    }
+}
+
+impl std::fmt::Display for WatcherLiveness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NeverSeen => write!(f, "watcher has not run"),
+            Self::Alive { ago } => write!(f, "watcher last seen {} ago", format_ago(*ago)),
+            Self::Stopped { ago } => write!(
+                f,
+                "watcher appears stopped (last seen {} ago)",
+                format_ago(*ago)
+            ),
+        }
+    }
+}
+
+/// `3s`, `5m`, `2h`, `4d`
+fn format_ago(ago: Duration) -> String {
+    let secs = ago.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_liveness_from_heartbeat_age() {
+        let now = Utc::now();
+        let seen = |secs: i64| Some(now - chrono::Duration::seconds(secs));
+
+        let alive = WatcherLiveness::from_last_seen(seen(3), now);
+        assert_eq!(
+            alive,
+            WatcherLiveness::Alive {
+                ago: Duration::from_secs(3)
+            }
+        );
+        assert_eq!(alive.to_string(), "watcher last seen 3s ago");
+
+        let stopped = WatcherLiveness::from_last_seen(seen(2 * 3600 + 120), now);
+        assert!(matches!(stopped, WatcherLiveness::Stopped { .. }));
+        assert_eq!(
+            stopped.to_string(),
+            "watcher appears stopped (last seen 2h ago)"
+        );
+
+        // Right at the threshold is still alive; just past it is not
+        assert!(matches!(
+            WatcherLiveness::from_last_seen(seen(60), now),
+            WatcherLiveness::Alive { .. }
+        ));
+        assert!(matches!(
+            WatcherLiveness::from_last_seen(seen(61), now),
+            WatcherLiveness::Stopped { .. }
+        ));
+        assert_eq!(
+            WatcherLiveness::from_last_seen(None, now).to_string(),
+            "watcher has not run"
+        );
+        assert_eq!(
+            WatcherLiveness::from_last_seen(seen(-5), now),
+            WatcherLiveness::Alive {
+                ago: Duration::ZERO
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_beats_at_most_once_per_interval() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("watcher.heartbeat");
+        let mut heartbeat = Heartbeat::new(path.clone()).with_interval(Duration::from_millis(300));
+
+        heartbeat.beat().await;
+        let first = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        heartbeat.beat().await;
+        assert!(!path.exists(), "beat again within the interval");
+
+        tokio::time::sleep(Duration::from_millis(350)).await;
+        heartbeat.beat().await;
+        let second = std::fs::read_to_string(&path).unwrap();
+        assert_ne!(first, second);
+    }
+}
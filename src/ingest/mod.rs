@@ -5,8 +5,11 @@
 //!
 //! 1. **Watcher**: Monitors Voice Memos directory for new .m4a files
 //! 2. **Queue**: JSONL-based queue for idempotent processing
-//! 3. (Phase 2) Transcriber: Whisper transcription
-//! 4. (Phase 3) Depositor: Write to Obsidian vault
+//! 3. (Phase 2) Transcriber: Whisper transcription, batched through one
+//!    session when the backend supports it; silent memos are skipped and
+//!    probable duplicates flagged
+//! 4. **Depositor**: Transcripts (optionally summarized by a Fabric pattern)
+//!    exported as notes into an Obsidian vault
 //!
 //! # Architecture
 //!
@@ -16,11 +19,35 @@
 //!                   events.jsonl
 //! ```
 
+pub mod batch;
+pub mod delivery;
+pub mod depositor;
+pub mod duplicates;
+pub mod heartbeat;
+pub mod notify;
 pub mod queue;
+pub mod service;
+pub mod silence;
+pub mod summarize;
 pub mod transcriber;
 pub mod watcher;
 
 // Re-export key types
-pub use queue::{QueueItem, VoiceQueue, VoiceQueueError};
-pub use transcriber::{transcribe, TranscriptResult};
-pub use watcher::{AudioFileEvent, VoiceMemoWatcher, WatcherConfig};
+pub use batch::{BatchTranscriber, PerFileSession, TranscriptionSession, WhisperServerSession};
+pub use delivery::{deliver_all, record_outcome, DeliveryOutcome, Destination, RequirePolicy};
+pub use depositor::{export_to_vault, exportable, ExportReport, NoteFormat};
+pub use duplicates::{find_duplicate, normalized_text_hash, DuplicatePolicy};
+pub use heartbeat::{Heartbeat, WatcherLiveness};
+pub use notify::{
+    NotificationConfig, Notifications, Notifier, NotifyEvent, NotifyKind, SystemNotifier,
+};
+pub use queue::{
+    DeliveryStatus, NormalizeTarget, QueueItem, QueueStats, VoiceQueue, VoiceQueueError,
+};
+pub use silence::{check_silence, SilenceThresholds};
+pub use summarize::{summarize_item, summary_pipeline, DEFAULT_SUMMARY_PATTERN};
+pub use transcriber::{
+    transcribe, transcribe_with_options, TranscribeOptions, TranscriptCache, TranscriptResult,
+    WhisperBinary, WhisperFlavor,
+};
+pub use watcher::{tool_on_path, AudioFileEvent, VoiceMemoWatcher, WatcherConfig};
@@ -0,0 +1,232 @@
+//! Desktop notifications for voice capture.
+//!
+//! The watcher (new memo queued) and the process loop (memo delivered or
+//! failed) can raise an OS notification so a background watcher doesn't need
+//! a terminal. Notifications are off unless enabled with `--notify` or
+//! `notifications.enabled` in config, and are best-effort: a failure to show
+//! one is logged and never stops processing.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+/// Kinds of event that can raise a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyKind {
+    /// A new memo was queued by the watcher
+    Queued,
+    /// A memo was delivered
+    Delivered,
+    /// A memo failed to transcribe or deliver
+    Failed,
+}
+
+/// Which events notify (`notifications` in config)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    /// Show notifications at all
+    pub enabled: bool,
+    /// Events that notify when enabled
+    pub events: Vec<NotifyKind>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            events: vec![
+                NotifyKind::Queued,
+                NotifyKind::Delivered,
+                NotifyKind::Failed,
+            ],
+        }
+    }
+}
+
+impl NotificationConfig {
+    /// Whether an event of `kind` should notify
+    pub fn should_notify(&self, kind: NotifyKind) -> bool {
+        self.enabled && self.events.contains(&kind)
+    }
+}
+
+/// Something worth telling the user about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotifyEvent<'a> {
+    Queued { file_name: &'a str },
+    Delivered { file_name: &'a str, route: &'a str },
+    Failed { file_name: &'a str, error: &'a str },
+}
+
+impl NotifyEvent<'_> {
+    pub fn kind(&self) -> NotifyKind {
+        match self {
+            Self::Queued { .. } => NotifyKind::Queued,
+            Self::Delivered { .. } => NotifyKind::Delivered,
+            Self::Failed { .. } => NotifyKind::Failed,
+        }
+    }
+
+    /// Notification title and body
+    pub fn message(&self) -> (String, String) {
+        match self {
+            Self::Queued { file_name } => ("Voice memo queued".to_string(), file_name.to_string()),
+            Self::Delivered { file_name, route } => (
+                format!("Voice memo sent to {}", route),
+                file_name.to_string(),
+            ),
+            Self::Failed { file_name, error } => (
+                "Voice memo failed".to_string(),
+                format!("{}: {}", file_name, error),
+            ),
+        }
+    }
+}
+
+/// Shows a notification
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn show(&self, title: &str, body: &str) -> Result<()>;
+}
+
+/// The OS notification center: `osascript` on macOS, `notify-send` elsewhere
+pub struct SystemNotifier;
+
+#[async_trait]
+impl Notifier for SystemNotifier {
+    async fn show(&self, title: &str, body: &str) -> Result<()> {
+        let mut command = if cfg!(target_os = "macos") {
+            let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+            let mut command = tokio::process::Command::new("osascript");
+            command.arg("-e").arg(format!(
+                "display notification {} with title {}",
+                quote(body),
+                quote(title)
+            ));
+            command
+        } else {
+            let mut command = tokio::process::Command::new("notify-send");
+            command.arg(title).arg(body);
+            command
+        };
+
+        let output = command
+            .output()
+            .await
+            .context("Failed to run notification command")?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Notification command failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Sends events allowed by the config to a [`Notifier`]
+pub struct Notifications {
+    config: NotificationConfig,
+    notifier: Box<dyn Notifier>,
+}
+
+impl Notifications {
+    pub fn new(config: NotificationConfig, notifier: Box<dyn Notifier>) -> Self {
+        Self { config, notifier }
+    }
+
+    /// Notifications through the OS, enabled by config or `force`
+    /// (`--notify`)
+    pub fn system(config: &NotificationConfig, force: bool) -> Self {
+        let mut config = config.clone();
+        config.enabled |= force;
+        Self::new(config, Box::new(SystemNotifier))
+    }
+
+    /// Notify about `event` if configured to; failures are only logged
+    pub async fn send(&self, event: NotifyEvent<'_>) {
+        if !self.config.should_notify(event.kind()) {
+            return;
+        }
+        let (title, body) = event.message();
+        if let Err(e) = self.notifier.show(&title, &body).await {
+            tracing::warn!("Notification failed: {:#}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        shown: Arc<Mutex<Vec<(String, String)>>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl Notifier for Recorder {
+        async fn show(&self, title: &str, body: &str) -> Result<()> {
+            self.shown
+                .lock()
+                .unwrap()
+                .push((title.to_string(), body.to_string()));
+            if self.fail {
+                anyhow::bail!("no notification daemon");
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notifications_follow_config() {
+        let queued = NotifyEvent::Queued {
+            file_name: "Walk.m4a",
+        };
+        let failed = NotifyEvent::Failed {
+            file_name: "Walk.m4a",
+            error: "timeout",
+        };
+
+        // Off by default
+        let recorder = Recorder::default();
+        let notifications =
+            Notifications::new(NotificationConfig::default(), Box::new(recorder.clone()));
+        notifications.send(queued.clone()).await;
+        assert!(recorder.shown.lock().unwrap().is_empty());
+
+        // Enabled for failures only
+        let config = NotificationConfig {
+            enabled: true,
+            events: vec![NotifyKind::Failed],
+        };
+        let notifications = Notifications::new(config.clone(), Box::new(recorder.clone()));
+        notifications.send(queued.clone()).await;
+        notifications.send(failed.clone()).await;
+        assert_eq!(
+            *recorder.shown.lock().unwrap(),
+            vec![(
+                "Voice memo failed".to_string(),
+                "Walk.m4a: timeout".to_string()
+            )]
+        );
+
+        // A failing notifier doesn't propagate
+        let broken = Recorder {
+            fail: true,
+            ..Recorder::default()
+        };
+        Notifications::new(config, Box::new(broken.clone()))
+            .send(failed)
+            .await;
+        assert_eq!(broken.shown.lock().unwrap().len(), 1);
+
+        // --notify enables the configured events
+        let forced = Notifications::system(&NotificationConfig::default(), true);
+        assert!(forced.config.should_notify(NotifyKind::Delivered));
+    }
+}
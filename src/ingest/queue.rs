@@ -7,13 +7,15 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 use tokio::fs::{self, File, OpenOptions};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 
+use super::duplicates::normalized_text_hash;
+use super::transcriber::TranscriptResult;
 use crate::domain::VoiceQueueStatus;
 
 /// Errors that can occur with the voice queue
@@ -73,6 +75,38 @@ pub enum QueueEventType {
 
     /// Reset for retry
     ResetForRetry,
+
+    /// Skipped as non-speech
+    Skipped,
+
+    /// Delivery to one destination succeeded or failed
+    Delivered,
+
+    /// Transcript produced (stored for export)
+    Transcribed,
+
+    /// Transcript summarized by a pipeline run
+    Summarized,
+}
+
+/// Outcome of delivering an item to one destination
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "state", content = "detail")]
+pub enum DeliveryStatus {
+    /// Delivered, with the destination's receipt (e.g. message ID)
+    Sent(String),
+
+    /// Delivery failed with this error
+    Failed(String),
+}
+
+impl std::fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sent(receipt) => write!(f, "sent ({})", receipt),
+            Self::Failed(error) => write!(f, "failed ({})", error),
+        }
+    }
 }
 
 /// Metadata for a queued audio file
@@ -113,11 +147,27 @@ pub struct QueueItem {
     /// When processing completed (if applicable)
     pub completed_at: Option<DateTime<Utc>>,
 
-    /// Error message (if failed)
+    /// Error message (if failed, or which destinations failed when a
+    /// delivery only partially succeeded)
     pub error: Option<String>,
 
+    /// Why the item was skipped (if skipped)
+    pub skip_reason: Option<String>,
+
     /// Number of retry attempts
     pub retry_count: u32,
+
+    /// Latest delivery status per destination (`telegram`, `clawdbot`)
+    pub deliveries: HashMap<String, DeliveryStatus>,
+
+    /// Transcript, if the item was transcribed locally
+    pub transcript: Option<TranscriptResult>,
+
+    /// Normalized-text hash of the transcript, for duplicate detection
+    pub transcript_hash: Option<String>,
+
+    /// Summary of the transcript, if one was produced
+    pub summary: Option<String>,
 }
 
 /// JSONL-based voice queue
@@ -204,7 +254,12 @@ impl VoiceQueue {
                                 started_at: None,
                                 completed_at: None,
                                 error: None,
+                                skip_reason: None,
                                 retry_count: 0,
+                                deliveries: HashMap::new(),
+                                transcript: None,
+                                transcript_hash: None,
+                                summary: None,
                             },
                         );
                     }
@@ -220,6 +275,12 @@ impl VoiceQueue {
                 if let Some(item) = items.get_mut(&event.item_id) {
                     item.status = VoiceQueueStatus::Done;
                     item.completed_at = Some(event.timestamp);
+                    item.error = event
+                        .data
+                        .as_ref()
+                        .and_then(|data| data.get("partial_failure"))
+                        .and_then(|error| error.as_str())
+                        .map(str::to_string);
                 }
             }
             QueueEventType::Failed => {
@@ -233,6 +294,57 @@ impl VoiceQueue {
                     }
                 }
             }
+            QueueEventType::Skipped => {
+                if let Some(item) = items.get_mut(&event.item_id) {
+                    item.status = VoiceQueueStatus::Skipped;
+                    item.completed_at = Some(event.timestamp);
+                    item.skip_reason = event
+                        .data
+                        .as_ref()
+                        .and_then(|data| data.get("reason"))
+                        .and_then(|reason| reason.as_str())
+                        .map(str::to_string);
+                }
+            }
+            QueueEventType::Delivered => {
+                let Some(item) = items.get_mut(&event.item_id) else {
+                    return;
+                };
+                let Some(data) = event.data else {
+                    return;
+                };
+                let destination = data.get("destination").and_then(|d| d.as_str());
+                let status = data
+                    .get("status")
+                    .cloned()
+                    .and_then(|s| serde_json::from_value::<DeliveryStatus>(s).ok());
+                if let (Some(destination), Some(status)) = (destination, status) {
+                    item.deliveries.insert(destination.to_string(), status);
+                }
+            }
+            QueueEventType::Transcribed => {
+                if let Some(item) = items.get_mut(&event.item_id) {
+                    if let Some(data) = event.data {
+                        item.transcript_hash = data
+                            .get("text_hash")
+                            .and_then(|v| v.as_str())
+                            .map(String::from);
+                        if let Ok(transcript) = serde_json::from_value(data) {
+                            item.transcript = Some(transcript);
+                        }
+                    }
+                }
+            }
+            QueueEventType::Summarized => {
+                if let Some(item) = items.get_mut(&event.item_id) {
+                    item.summary = event
+                        .data
+                        .as_ref()
+                        .and_then(|data| data.get("summary"))
+                        .and_then(|summary| summary.as_str())
+                        .map(str::to_string);
+                }
+            }
             QueueEventType::ResetForRetry => {
                 if let Some(item) = items.get_mut(&event.item_id) {
                     item.status = VoiceQueueStatus::Pending;
@@ -259,7 +371,7 @@ impl VoiceQueue {
         let items = self.replay().await?;
         if let Some(existing) = items.get(&hash) {
             match existing.status {
-                VoiceQueueStatus::Done => {
+                VoiceQueueStatus::Done | VoiceQueueStatus::Skipped => {
                     return Ok(EnqueueResult::AlreadyProcessed(hash));
                 }
                 VoiceQueueStatus::Failed => {
@@ -359,6 +471,83 @@ impl VoiceQueue {
         Ok(())
     }
 
+    /// Store an item's transcript, with its normalized-text hash
+    pub async fn record_transcript(
+        &self,
+        id: &str,
+        transcript: &TranscriptResult,
+    ) -> Result<(), VoiceQueueError> {
+        let mut data = serde_json::to_value(transcript)?;
+        if let Some(hash) = normalized_text_hash(&transcript.text) {
+            data["text_hash"] = hash.into();
+        }
+        let event = QueueEvent {
+            timestamp: Utc::now(),
+            item_id: id.to_string(),
+            event_type: QueueEventType::Transcribed,
+            data: Some(data),
+        };
+        self.append_event(&event).await?;
+
+        Ok(())
+    }
+
+    /// Store an item's summary and the run that produced it
+    pub async fn record_summary(
+        &self,
+        id: &str,
+        summary: &str,
+        run_id: uuid::Uuid,
+    ) -> Result<(), VoiceQueueError> {
+        let event = QueueEvent {
+            timestamp: Utc::now(),
+            item_id: id.to_string(),
+            event_type: QueueEventType::Summarized,
+            data: Some(serde_json::json!({ "summary": summary, "run_id": run_id })),
+        };
+        self.append_event(&event).await?;
+
+        Ok(())
+    }
+
+    /// Record the outcome of delivering an item to `destination`
+    pub async fn record_delivery(
+        &self,
+        id: &str,
+        destination: &str,
+        status: &DeliveryStatus,
+    ) -> Result<(), VoiceQueueError> {
+        let event = QueueEvent {
+            timestamp: Utc::now(),
+            item_id: id.to_string(),
+            event_type: QueueEventType::Delivered,
+            data: Some(serde_json::json!({
+                "destination": destination,
+                "status": status,
+            })),
+        };
+        self.append_event(&event).await?;
+
+        Ok(())
+    }
+
+    /// Mark an item as done although some destinations failed
+    pub async fn mark_partially_done(
+        &self,
+        id: &str,
+        failures: &str,
+    ) -> Result<(), VoiceQueueError> {
+        let event = QueueEvent {
+            timestamp: Utc::now(),
+            item_id: id.to_string(),
+            event_type: QueueEventType::Completed,
+            data: Some(serde_json::json!({ "partial_failure": failures })),
+        };
+        self.append_event(&event).await?;
+
+        Ok(())
+    }
+
     /// Mark an item as failed
     pub async fn mark_failed(&self, id: &str, error: &str) -> Result<(), VoiceQueueError> {
         let event = QueueEvent {
@@ -372,18 +561,26 @@ impl VoiceQueue {
         Ok(())
     }
 
+    /// Mark an item as skipped (non-speech audio) with the reason
+    pub async fn mark_skipped(&self, id: &str, reason: &str) -> Result<(), VoiceQueueError> {
+        let event = QueueEvent {
+            timestamp: Utc::now(),
+            item_id: id.to_string(),
+            event_type: QueueEventType::Skipped,
+            data: Some(serde_json::json!({ "reason": reason })),
+        };
+        self.append_event(&event).await?;
+
+        Ok(())
+    }
+
     /// Get queue status summary
     pub async fn status(&self) -> Result<QueueStatus, VoiceQueueError> {
         let items = self.replay().await?;
 
         let mut status = QueueStatus::default();
         for item in items.values() {
-            match item.status {
-                VoiceQueueStatus::Pending => status.pending += 1,
-                VoiceQueueStatus::Processing => status.processing += 1,
-                VoiceQueueStatus::Done => status.done += 1,
-                VoiceQueueStatus::Failed => status.failed += 1,
-            }
+            status.count(item.status);
         }
 
         // Get recent items (last 5)
@@ -394,6 +591,39 @@ impl VoiceQueue {
         Ok(status)
     }
 
+    /// Capture volume of items detected at or after `since` (all items if
+    /// `None`), counting days from `since` (or the first capture) to today
+    pub async fn stats(&self, since: Option<DateTime<Utc>>) -> Result<QueueStats, VoiceQueueError> {
+        let items = self.replay().await?;
+        let mut stats = QueueStats::default();
+        let mut capture_days = std::collections::BTreeSet::new();
+
+        for item in items.values() {
+            if since.is_some_and(|since| item.data.detected_at < since) {
+                continue;
+            }
+            stats.by_status.count(item.status);
+            stats.total_duration_seconds += item.data.duration_seconds.unwrap_or(0.0) as f64;
+            stats.total_bytes += item.data.file_size;
+            capture_days.insert(item.data.detected_at.date_naive());
+        }
+
+        let today = Utc::now().date_naive();
+        let Some(first) = since
+            .map(|since| since.date_naive())
+            .or_else(|| capture_days.first().copied())
+        else {
+            return Ok(stats);
+        };
+        stats.days = first.iter_days().take_while(|day| *day <= today).count();
+        stats.empty_days = first
+            .iter_days()
+            .take_while(|day| *day <= today)
+            .filter(|day| !capture_days.contains(day))
+            .collect();
+        Ok(stats)
+    }
+
     /// Get a specific item by ID
     pub async fn get(&self, id: &str) -> Result<Option<QueueItem>, VoiceQueueError> {
         let items = self.replay().await?;
@@ -441,13 +671,54 @@ pub struct QueueStatus {
     pub processing: usize,
     pub done: usize,
     pub failed: usize,
+    pub skipped: usize,
     pub recent: Vec<QueueItem>,
 }
 
 impl QueueStatus {
     /// Total items in queue
     pub fn total(&self) -> usize {
-        self.pending + self.processing + self.done + self.failed
+        self.pending + self.processing + self.done + self.failed + self.skipped
+    }
+
+    fn count(&mut self, status: VoiceQueueStatus) {
+        match status {
+            VoiceQueueStatus::Pending => self.pending += 1,
+            VoiceQueueStatus::Processing => self.processing += 1,
+            VoiceQueueStatus::Done => self.done += 1,
+            VoiceQueueStatus::Failed => self.failed += 1,
+            VoiceQueueStatus::Skipped => self.skipped += 1,
+        }
+    }
+}
+
+/// Capture volume over a period (see [`VoiceQueue::stats`])
+#[derive(Debug, Clone, Default)]
+pub struct QueueStats {
+    /// Counts by status (`recent` is left empty)
+    pub by_status: QueueStatus,
+    /// Sum of known audio durations
+    pub total_duration_seconds: f64,
+    /// Sum of file sizes
+    pub total_bytes: u64,
+    /// Calendar days in the period, today included
+    pub days: usize,
+    /// Days in the period with no captures
+    pub empty_days: Vec<NaiveDate>,
+}
+
+impl QueueStats {
+    /// Items in the period
+    pub fn total(&self) -> usize {
+        self.by_status.total()
+    }
+
+    /// Items per day over the period
+    pub fn average_per_day(&self) -> f64 {
+        if self.days == 0 {
+            return 0.0;
+        }
+        self.total() as f64 / self.days as f64
     }
 }
 
@@ -493,17 +764,77 @@ pub async fn probe_duration(path: &Path) -> Option<f32> {
     String::from_utf8_lossy(&output.stdout).trim().parse().ok()
 }
 
-/// Normalize audio file if needed (.qta → .m4a)
+/// What `.qta` files are converted to (`normalize_target` in config)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NormalizeTarget {
+    /// AAC in .m4a: playable, for the Telegram route
+    #[default]
+    M4a,
+    /// 16 kHz mono PCM WAV, what Whisper decodes to anyway
+    Wav16k,
+    /// Keep the .qta as is
+    None,
+}
+
+impl NormalizeTarget {
+    /// Extension of converted files
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            Self::M4a => Some("m4a"),
+            Self::Wav16k => Some("wav"),
+            Self::None => None,
+        }
+    }
+
+    /// Check that memos captured with this target can go out on `route`.
+    /// Routes sending raw audio to Telegram need a playable file, and a
+    /// 16 kHz WAV isn't one.
+    pub fn check_route(self, route: &str) -> Result<()> {
+        if self == Self::Wav16k && matches!(route, "telegram" | "both") {
+            anyhow::bail!(
+                "normalize_target: wav16k can't be used with the '{}' route, which sends raw audio to Telegram; use m4a (the default)",
+                route
+            );
+        }
+        Ok(())
+    }
+
+    /// ffmpeg arguments converting `input` to `output`
+    pub fn ffmpeg_args(self, input: &Path, output: &Path) -> Vec<std::ffi::OsString> {
+        let codec: &[&str] = match self {
+            Self::M4a => &["-c:a", "aac", "-b:a", "128k"],
+            Self::Wav16k => &["-ar", "16000", "-ac", "1", "-c:a", "pcm_s16le"],
+            Self::None => &[],
+        };
+        let mut args: Vec<std::ffi::OsString> = vec!["-i".into(), input.into()];
+        args.extend(codec.iter().map(Into::into));
+        args.push("-y".into()); // Overwrite output
+        args.push(output.into());
+        args
+    }
+}
+
+/// Normalize audio file if needed (.qta → the configured
+/// [`NormalizeTarget`], .m4a by default)
 /// Returns the path to use for hashing/processing.
 /// For .m4a files, returns the original path unchanged.
-/// For .qta files, converts to .m4a and caches in voice_cache directory.
+/// For .qta files, converts and caches in voice_cache directory.
 ///
 /// Security: ffmpeg args are hardcoded, no user input in command construction.
 pub async fn normalize_audio(input: &Path) -> Result<PathBuf> {
+    normalize_audio_to(input, crate::config::config()?.normalize_target).await
+}
+
+/// [`normalize_audio`] with an explicit target
+pub async fn normalize_audio_to(input: &Path, target: NormalizeTarget) -> Result<PathBuf> {
     // If not .qta, return original path unchanged
     if input.extension().map(|e| e != "qta").unwrap_or(true) {
         return Ok(input.to_path_buf());
     }
+    let Some(extension) = target.extension() else {
+        return Ok(input.to_path_buf());
+    };
 
     // Get cache directory
     let cache_dir = crate::config::voice_cache_dir()?;
@@ -511,7 +842,7 @@ pub async fn normalize_audio(input: &Path) -> Result<PathBuf> {
 
     // Compute hash of input file to create cache filename
     let hash = compute_file_hash(input).await?;
-    let output = cache_dir.join(format!("{}.m4a", hash));
+    let output = cache_dir.join(format!("{}.{}", hash, extension));
 
     // If already cached, return cached path
     if output.exists() {
@@ -519,19 +850,10 @@ pub async fn normalize_audio(input: &Path) -> Result<PathBuf> {
         return Ok(output);
     }
 
-    // Convert .qta → .m4a using ffmpeg with hardcoded args (security)
-    tracing::info!("Normalizing .qta → .m4a: {}", input.display());
+    // Convert using ffmpeg with hardcoded args (security)
+    tracing::info!("Normalizing .qta → .{}: {}", extension, input.display());
     let status = tokio::process::Command::new("ffmpeg")
-        .args([
-            "-i",
-            input.to_str().unwrap_or(""),
-            "-c:a",
-            "aac",
-            "-b:a",
-            "128k",
-            "-y", // Overwrite output
-        ])
-        .arg(&output)
+        .args(target.ffmpeg_args(input, &output))
         .stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
         .status()
@@ -648,4 +970,187 @@ mod tests {
         assert_eq!(item.status, VoiceQueueStatus::Pending);
         assert_eq!(item.retry_count, 1);
     }
+
+    #[tokio::test]
+    async fn test_delivery_status_per_destination() {
+        let (queue, temp) = create_test_queue().await;
+
+        let audio_path = temp.path().join("test.m4a");
+        tokio::fs::write(&audio_path, b"fake audio content")
+            .await
+            .unwrap();
+        let id = queue
+            .enqueue(&audio_path, 18, Utc::now())
+            .await
+            .unwrap()
+            .id()
+            .to_string();
+
+        queue
+            .record_delivery(&id, "telegram", &DeliveryStatus::Failed("timeout".into()))
+            .await
+            .unwrap();
+        queue
+            .record_delivery(&id, "clawdbot", &DeliveryStatus::Sent("ok".into()))
+            .await
+            .unwrap();
+        // A later attempt replaces the earlier status for that destination
+        queue
+            .record_delivery(&id, "telegram", &DeliveryStatus::Sent("4711".into()))
+            .await
+            .unwrap();
+
+        let item = queue.get(&id).await.unwrap().unwrap();
+        assert_eq!(item.deliveries.len(), 2);
+        assert_eq!(
+            item.deliveries["telegram"],
+            DeliveryStatus::Sent("4711".to_string())
+        );
+        assert_eq!(
+            item.deliveries["clawdbot"],
+            DeliveryStatus::Sent("ok".to_string())
+        );
+
+        // Survives a fresh replay from disk
+        let reopened = VoiceQueue::new(temp.path().join("test_queue.jsonl"));
+        let item = reopened.get(&id).await.unwrap().unwrap();
+        assert_eq!(item.deliveries["telegram"].to_string(), "sent (4711)");
+    }
+
+    #[tokio::test]
+    async fn test_stats_over_seeded_queue() {
+        let (queue, _temp) = create_test_queue().await;
+        let now = Utc::now();
+        let day = chrono::Duration::days(1);
+
+        // Seed items directly so durations don't depend on ffprobe
+        for (id, days_ago, size, duration) in [
+            ("old", 40, 5_000, Some(600.0)),
+            ("a", 3, 1_000, Some(30.0)),
+            ("b", 3, 2_000, Some(90.5)),
+            ("c", 1, 4_000, None),
+        ] {
+            let data = QueueItemData {
+                file_path: PathBuf::from(format!("/memos/{}.m4a", id)),
+                file_name: format!("{}.m4a", id),
+                file_size: size,
+                detected_at: now - day * days_ago,
+                duration_seconds: duration,
+            };
+            queue
+                .append_event(&QueueEvent {
+                    timestamp: now,
+                    item_id: id.to_string(),
+                    event_type: QueueEventType::Enqueued,
+                    data: Some(serde_json::to_value(&data).unwrap()),
+                })
+                .await
+                .unwrap();
+        }
+        queue.mark_processing("a").await.unwrap();
+        queue.mark_done("a").await.unwrap();
+        queue.mark_skipped("c", "silent").await.unwrap();
+
+        let stats = queue.stats(Some(now - day * 4)).await.unwrap();
+        assert_eq!(stats.total(), 3);
+        assert_eq!(stats.total_duration_seconds, 120.5);
+        assert_eq!(stats.total_bytes, 7_000);
+        assert_eq!(stats.by_status.done, 1);
+        assert_eq!(stats.by_status.pending, 1);
+        assert_eq!(stats.by_status.skipped, 1);
+        assert_eq!(stats.days, 5);
+        assert_eq!(stats.average_per_day(), 0.6);
+        // 4 days ago, 2 days ago and today have no captures
+        assert_eq!(
+            stats.empty_days,
+            vec![
+                (now - day * 4).date_naive(),
+                (now - day * 2).date_naive(),
+                now.date_naive(),
+            ]
+        );
+
+        // Without a window, everything from the first capture counts
+        let all = queue.stats(None).await.unwrap();
+        assert_eq!(all.total(), 4);
+        assert_eq!(all.total_duration_seconds, 720.5);
+        assert_eq!(all.days, 41);
+    }
+
+    #[test]
+    fn test_normalize_target_ffmpeg_args() {
+        let args = |target: NormalizeTarget| -> Vec<String> {
+            target
+                .ffmpeg_args(Path::new("/memos/a.qta"), Path::new("/cache/ab12.out"))
+                .into_iter()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect()
+        };
+
+        assert_eq!(
+            args(NormalizeTarget::M4a),
+            [
+                "-i",
+                "/memos/a.qta",
+                "-c:a",
+                "aac",
+                "-b:a",
+                "128k",
+                "-y",
+                "/cache/ab12.out"
+            ]
+        );
+        assert_eq!(
+            args(NormalizeTarget::Wav16k),
+            [
+                "-i",
+                "/memos/a.qta",
+                "-ar",
+                "16000",
+                "-ac",
+                "1",
+                "-c:a",
+                "pcm_s16le",
+                "-y",
+                "/cache/ab12.out"
+            ]
+        );
+        assert_eq!(NormalizeTarget::M4a.extension(), Some("m4a"));
+        assert_eq!(NormalizeTarget::Wav16k.extension(), Some("wav"));
+        assert_eq!(NormalizeTarget::None.extension(), None);
+
+        assert!(NormalizeTarget::Wav16k.check_route("clawdbot").is_ok());
+        assert!(NormalizeTarget::Wav16k.check_route("telegram").is_err());
+        assert!(NormalizeTarget::Wav16k.check_route("both").is_err());
+        assert!(NormalizeTarget::M4a.check_route("telegram").is_ok());
+
+        let parsed: Vec<NormalizeTarget> = serde_yaml::from_str("[m4a, wav16k, none]").unwrap();
+        assert_eq!(
+            parsed,
+            [
+                NormalizeTarget::M4a,
+                NormalizeTarget::Wav16k,
+                NormalizeTarget::None
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_normalize_none_keeps_qta() {
+        let path = Path::new("/memos/a.qta");
+        assert_eq!(
+            normalize_audio_to(path, NormalizeTarget::None)
+                .await
+                .unwrap(),
+            path
+        );
+        // Non-.qta files are never converted
+        let m4a = Path::new("/memos/b.m4a");
+        assert_eq!(
+            normalize_audio_to(m4a, NormalizeTarget::Wav16k)
+                .await
+                .unwrap(),
+            m4a
+        );
+    }
 }
@@ -0,0 +1,395 @@
+//! Login services for the capture pipeline.
+//!
+//! `arkai voice install-service` writes a launchd agent (macOS) or a systemd
+//! user unit (Linux) for each of `arkai voice watch` and `arkai voice
+//! process`, so memos are queued and delivered without a terminal open. The
+//! services run the current `arkai` binary with the environment it needs
+//! (tokens, `ARKAI_HOME`, `PATH` for Whisper and ffmpeg) copied from the
+//! installing shell.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Environment variables copied into the service, when set
+pub const SERVICE_ENV: &[&str] = &[
+    "PATH",
+    "ARKAI_HOME",
+    "ARKAI_LIBRARY",
+    "ARKAI_FABRIC_BIN",
+    "WHISPER_PATH",
+    "TELEGRAM_BOT_TOKEN",
+    "TELEGRAM_CHAT_ID",
+    "CLAWDBOT_TOKEN",
+    "CLAWDBOT_ENDPOINT",
+];
+
+/// Service manager to generate for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServicePlatform {
+    /// macOS launchd agent (`~/Library/LaunchAgents`)
+    Launchd,
+    /// systemd user unit (`~/.config/systemd/user`)
+    Systemd,
+}
+
+impl ServicePlatform {
+    /// The service manager of the OS this was built for
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Launchd
+        } else {
+            Self::Systemd
+        }
+    }
+}
+
+/// One long-running `arkai` command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceSpec {
+    /// Short name (`voice-watch`); the launchd label is `com.arkai.<name>`
+    pub name: String,
+    pub description: String,
+    pub binary: PathBuf,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+    /// Where stdout and stderr are appended (launchd only; systemd uses the journal)
+    pub log_path: PathBuf,
+}
+
+impl ServiceSpec {
+    /// Label used by launchd
+    pub fn label(&self) -> String {
+        format!("com.arkai.{}", self.name)
+    }
+
+    /// The service file's contents for `platform`
+    pub fn render(&self, platform: ServicePlatform) -> String {
+        match platform {
+            ServicePlatform::Launchd => self.render_plist(),
+            ServicePlatform::Systemd => self.render_unit(),
+        }
+    }
+
+    /// Where the service file is installed, under `home`
+    pub fn install_path(&self, platform: ServicePlatform, home: &Path) -> PathBuf {
+        match platform {
+            ServicePlatform::Launchd => home
+                .join("Library/LaunchAgents")
+                .join(format!("{}.plist", self.label())),
+            ServicePlatform::Systemd => home
+                .join(".config/systemd/user")
+                .join(format!("arkai-{}.service", self.name)),
+        }
+    }
+
+    fn render_plist(&self) -> String {
+        let string = |s: &str| format!("<string>{}</string>", xml_escape(s));
+        let mut out = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+             <plist version=\"1.0\">\n<dict>\n",
+        );
+        out.push_str(&format!(
+            "  <key>Label</key>\n  {}\n",
+            string(&self.label())
+        ));
+        out.push_str("  <key>ProgramArguments</key>\n  <array>\n");
+        out.push_str(&format!(
+            "    {}\n",
+            string(&self.binary.display().to_string())
+        ));
+        for arg in &self.args {
+            out.push_str(&format!("    {}\n", string(arg)));
+        }
+        out.push_str("  </array>\n");
+        if !self.env.is_empty() {
+            out.push_str("  <key>EnvironmentVariables</key>\n  <dict>\n");
+            for (key, value) in &self.env {
+                out.push_str(&format!(
+                    "    <key>{}</key>\n    {}\n",
+                    xml_escape(key),
+                    string(value)
+                ));
+            }
+            out.push_str("  </dict>\n");
+        }
+        let log = self.log_path.display().to_string();
+        out.push_str("  <key>RunAtLoad</key>\n  <true/>\n");
+        out.push_str("  <key>KeepAlive</key>\n  <true/>\n");
+        out.push_str(&format!(
+            "  <key>StandardOutPath</key>\n  {}\n",
+            string(&log)
+        ));
+        out.push_str(&format!(
+            "  <key>StandardErrorPath</key>\n  {}\n",
+            string(&log)
+        ));
+        out.push_str("</dict>\n</plist>\n");
+        out
+    }
+
+    fn render_unit(&self) -> String {
+        let exec: Vec<String> = std::iter::once(self.binary.display().to_string())
+            .chain(self.args.iter().cloned())
+            .map(|arg| systemd_exec_arg(&arg))
+            .collect();
+        let mut out = format!(
+            "[Unit]\nDescription={}\nAfter=network-online.target\n\n[Service]\nExecStart={}\n",
+            self.description,
+            exec.join(" ")
+        );
+        for (key, value) in &self.env {
+            out.push_str(&format!(
+                "Environment={}\n",
+                systemd_quote(&format!("{}={}", key, value))
+            ));
+        }
+        out.push_str("Restart=on-failure\nRestartSec=10\n\n[Install]\nWantedBy=default.target\n");
+        out
+    }
+}
+
+/// The watch and process services. `route` is passed to `voice process`.
+pub fn voice_services(
+    binary: &Path,
+    route: &str,
+    log_dir: &Path,
+    env: Vec<(String, String)>,
+) -> Vec<ServiceSpec> {
+    let spec = |name: &str, description: &str, args: &[&str]| ServiceSpec {
+        name: name.to_string(),
+        description: description.to_string(),
+        binary: binary.to_path_buf(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        env: env.clone(),
+        log_path: log_dir.join(format!("{}.log", name)),
+    };
+    vec![
+        spec(
+            "voice-watch",
+            "arkai voice memo watcher",
+            &["voice", "watch"],
+        ),
+        spec(
+            "voice-process",
+            "arkai voice memo processor",
+            &["voice", "process", "--route", route],
+        ),
+    ]
+}
+
+/// [`SERVICE_ENV`] variables that `lookup` finds, in that order
+pub fn service_env(lookup: impl Fn(&str) -> Option<String>) -> Vec<(String, String)> {
+    SERVICE_ENV
+        .iter()
+        .filter_map(|key| lookup(key).map(|value| (key.to_string(), value)))
+        .collect()
+}
+
+/// Write the service files and load them with launchctl / systemctl.
+/// Returns the files written.
+pub async fn install(
+    services: &[ServiceSpec],
+    platform: ServicePlatform,
+    home: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for service in services {
+        let path = service.install_path(platform, home);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        if let Some(parent) = service.log_path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        write_private(&path, &service.render(platform))
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written.push(path);
+    }
+
+    match platform {
+        ServicePlatform::Launchd => {
+            for path in &written {
+                // Reload if already installed
+                let _ = run("launchctl", &["unload", &path.display().to_string()]).await;
+                run("launchctl", &["load", "-w", &path.display().to_string()]).await?;
+            }
+        }
+        ServicePlatform::Systemd => {
+            run("systemctl", &["--user", "daemon-reload"]).await?;
+            for service in services {
+                let unit = format!("arkai-{}.service", service.name);
+                run("systemctl", &["--user", "enable", "--now", &unit]).await?;
+            }
+        }
+    }
+    Ok(written)
+}
+
+/// Stop the services and remove their files. Returns the files removed.
+pub async fn uninstall(
+    services: &[ServiceSpec],
+    platform: ServicePlatform,
+    home: &Path,
+) -> Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    for service in services {
+        let path = service.install_path(platform, home);
+        if !path.exists() {
+            continue;
+        }
+        // Stopping is best-effort: the service may never have been loaded
+        let _ = match platform {
+            ServicePlatform::Launchd => {
+                run("launchctl", &["unload", "-w", &path.display().to_string()]).await
+            }
+            ServicePlatform::Systemd => {
+                let unit = format!("arkai-{}.service", service.name);
+                run("systemctl", &["--user", "disable", "--now", &unit]).await
+            }
+        };
+        tokio::fs::remove_file(&path)
+            .await
+            .with_context(|| format!("Failed to remove {}", path.display()))?;
+        removed.push(path);
+    }
+    if platform == ServicePlatform::Systemd && !removed.is_empty() {
+        let _ = run("systemctl", &["--user", "daemon-reload"]).await;
+    }
+    Ok(removed)
+}
+
+/// Write `content` to `path` readable only by the owner, since the
+/// environment may hold tokens. A new file is created 0600 and an existing
+/// one is restricted before anything is written to it.
+async fn write_private(path: &Path, content: &str) -> std::io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut options = tokio::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(path).await?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .await?;
+    }
+    file.write_all(content.as_bytes()).await?;
+    file.flush().await
+}
+
+async fn run(program: &str, args: &[&str]) -> Result<()> {
+    let output = tokio::process::Command::new(program)
+        .args(args)
+        .output()
+        .await
+        .with_context(|| format!("Failed to run {}", program))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "{} {} failed: {}",
+            program,
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Quote for systemd's command line and `Environment=` syntax when needed,
+/// escaping `%` so it isn't expanded as a specifier
+fn systemd_quote(s: &str) -> String {
+    let s = s.replace('%', "%%");
+    if !s.is_empty() && !s.contains(|c: char| c.is_whitespace() || matches!(c, '"' | '\\' | '\'')) {
+        return s;
+    }
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// [`systemd_quote`] for an `ExecStart=` argument, also escaping `$`, which
+/// systemd expands as a variable on command lines (but not in `Environment=`)
+fn systemd_exec_arg(s: &str) -> String {
+    systemd_quote(&s.replace('$', "$$"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn services() -> Vec<ServiceSpec> {
+        let env = service_env(|key| match key {
+            "PATH" => Some("/opt/homebrew/bin:/usr/bin".to_string()),
+            "CLAWDBOT_TOKEN" => Some("s3cret&co".to_string()),
+            _ => None,
+        });
+        voice_services(
+            Path::new("/Users/me/.cargo/bin/arkai"),
+            "clawdbot",
+            Path::new("/Users/me/.arkai/logs"),
+            env,
+        )
+    }
+
+    #[test]
+    fn test_launchd_plist() {
+        let services = services();
+        let process = &services[1];
+        let plist = process.render(ServicePlatform::Launchd);
+
+        assert!(plist.contains("<key>Label</key>\n  <string>com.arkai.voice-process</string>"));
+        assert!(plist.contains(
+            "  <array>\n    <string>/Users/me/.cargo/bin/arkai</string>\n    <string>voice</string>\n    <string>process</string>\n    <string>--route</string>\n    <string>clawdbot</string>\n  </array>"
+        ));
+        assert!(plist.contains(
+            "    <key>PATH</key>\n    <string>/opt/homebrew/bin:/usr/bin</string>\n    <key>CLAWDBOT_TOKEN</key>\n    <string>s3cret&amp;co</string>"
+        ));
+        assert!(!plist.contains("TELEGRAM_BOT_TOKEN"));
+        assert!(plist.contains("<string>/Users/me/.arkai/logs/voice-process.log</string>"));
+        assert_eq!(
+            process.install_path(ServicePlatform::Launchd, Path::new("/Users/me")),
+            PathBuf::from("/Users/me/Library/LaunchAgents/com.arkai.voice-process.plist")
+        );
+    }
+
+    #[test]
+    fn test_systemd_unit() {
+        let services = services();
+        let watch = services[0].render(ServicePlatform::Systemd);
+        assert!(watch.contains("\nExecStart=/Users/me/.cargo/bin/arkai voice watch\n"));
+
+        let mut process = services[1].clone();
+        process.binary = PathBuf::from("/home/me/my tools/arkai");
+        let unit = process.render(ServicePlatform::Systemd);
+        assert!(unit
+            .contains("\nExecStart=\"/home/me/my tools/arkai\" voice process --route clawdbot\n"));
+        assert!(unit.contains("\nEnvironment=PATH=/opt/homebrew/bin:/usr/bin\n"));
+        assert!(unit.contains("\nEnvironment=CLAWDBOT_TOKEN=s3cret&co\n"));
+        assert!(unit.contains("\nWantedBy=default.target\n"));
+
+        // Specifiers and variables are kept literal
+        process.binary = PathBuf::from("/home/me/100%/arkai");
+        process.args = vec!["--tag".to_string(), "$HOME".to_string()];
+        process.env = vec![("TOKEN".to_string(), "a%b$c".to_string())];
+        let unit = process.render(ServicePlatform::Systemd);
+        assert!(unit.contains("\nExecStart=/home/me/100%%/arkai --tag $$HOME\n"));
+        assert!(unit.contains("\nEnvironment=TOKEN=a%%b$c\n"));
+        assert_eq!(
+            process.install_path(ServicePlatform::Systemd, Path::new("/home/me")),
+            PathBuf::from("/home/me/.config/systemd/user/arkai-voice-process.service")
+        );
+    }
+}
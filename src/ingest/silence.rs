@@ -0,0 +1,171 @@
+//! Silence detection for voice memos.
+//!
+//! Accidental taps produce memos that are a second long or nothing but room
+//! noise. Before transcribing, `ffmpeg -af volumedetect` measures the mean
+//! volume; memos that are too short or too quiet are marked `Skipped` instead
+//! of being sent to Whisper.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// When a memo counts as non-speech (`transcriber.silence` in config)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct SilenceThresholds {
+    /// Check memos at all before transcribing
+    pub enabled: bool,
+    /// Memos shorter than this are skipped
+    pub min_duration_seconds: f32,
+    /// Memos whose mean volume is at or below this (dBFS) are skipped
+    pub max_mean_volume_db: f64,
+}
+
+impl Default for SilenceThresholds {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_duration_seconds: 1.0,
+            max_mean_volume_db: -50.0,
+        }
+    }
+}
+
+/// Measured properties of a memo
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AudioLevels {
+    pub duration_seconds: Option<f32>,
+    pub mean_volume_db: Option<f64>,
+}
+
+impl SilenceThresholds {
+    /// Why a memo with `levels` should be skipped, if it should. Unknown
+    /// measurements never cause a skip.
+    pub fn skip_reason(&self, levels: &AudioLevels) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        if let Some(duration) = levels.duration_seconds {
+            if duration < self.min_duration_seconds {
+                return Some(format!(
+                    "Too short ({:.1}s < {:.1}s)",
+                    duration, self.min_duration_seconds
+                ));
+            }
+        }
+        if let Some(volume) = levels.mean_volume_db {
+            if volume <= self.max_mean_volume_db {
+                return Some(format!(
+                    "Silent (mean volume {:.1} dB <= {:.1} dB)",
+                    volume, self.max_mean_volume_db
+                ));
+            }
+        }
+        None
+    }
+}
+
+/// Mean volume from `volumedetect` output (`mean_volume: -23.4 dB`)
+pub fn parse_mean_volume(stderr: &str) -> Option<f64> {
+    stderr.lines().find_map(|line| {
+        let (_, rest) = line.split_once("mean_volume:")?;
+        rest.trim().trim_end_matches("dB").trim().parse().ok()
+    })
+}
+
+/// Mean volume of `path` via ffmpeg, or `None` if it couldn't be measured
+pub async fn measure_mean_volume(path: &Path) -> Option<f64> {
+    let output = tokio::process::Command::new("ffmpeg")
+        .args(["-hide_banner", "-nostats", "-i"])
+        .arg(path)
+        .args(["-af", "volumedetect", "-vn", "-f", "null", "-"])
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_mean_volume(&String::from_utf8_lossy(&output.stderr))
+}
+
+/// Why `path` should be skipped, measuring it with ffmpeg. `duration` is the
+/// ffprobe duration recorded at enqueue time.
+pub async fn check_silence(
+    path: &Path,
+    duration: Option<f32>,
+    thresholds: &SilenceThresholds,
+) -> Option<String> {
+    if !thresholds.enabled {
+        return None;
+    }
+    let levels = AudioLevels {
+        duration_seconds: duration,
+        mean_volume_db: measure_mean_volume(path).await,
+    };
+    thresholds.skip_reason(&levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SILENT_STDERR: &str = "\
+Input #0, mov,mp4,m4a,3gp,3g2,mj2, from 'tap.m4a':
+[Parsed_volumedetect_0 @ 0x600] n_samples: 96000
+[Parsed_volumedetect_0 @ 0x600] mean_volume: -91.0 dB
+[Parsed_volumedetect_0 @ 0x600] max_volume: -91.0 dB
+";
+
+    const SPEECH_STDERR: &str = "\
+[Parsed_volumedetect_0 @ 0x600] n_samples: 2880000
+[Parsed_volumedetect_0 @ 0x600] mean_volume: -24.7 dB
+[Parsed_volumedetect_0 @ 0x600] max_volume: -3.1 dB
+";
+
+    #[test]
+    fn test_silent_memo_is_skipped_and_speech_proceeds() {
+        let thresholds = SilenceThresholds::default();
+
+        let silent = AudioLevels {
+            duration_seconds: Some(12.0),
+            mean_volume_db: parse_mean_volume(SILENT_STDERR),
+        };
+        assert_eq!(silent.mean_volume_db, Some(-91.0));
+        let reason = thresholds.skip_reason(&silent).unwrap();
+        assert!(reason.starts_with("Silent"), "{}", reason);
+
+        let speech = AudioLevels {
+            duration_seconds: Some(12.0),
+            mean_volume_db: parse_mean_volume(SPEECH_STDERR),
+        };
+        assert_eq!(thresholds.skip_reason(&speech), None);
+
+        let tap = AudioLevels {
+            duration_seconds: Some(0.4),
+            ..speech
+        };
+        assert!(thresholds
+            .skip_reason(&tap)
+            .unwrap()
+            .starts_with("Too short"));
+
+        // Digital silence; unmeasurable audio is never skipped
+        assert_eq!(
+            parse_mean_volume("mean_volume: -inf dB"),
+            Some(f64::NEG_INFINITY)
+        );
+        assert_eq!(thresholds.skip_reason(&AudioLevels::default()), None);
+
+        // Thresholds are configurable
+        let strict = SilenceThresholds {
+            max_mean_volume_db: -20.0,
+            ..SilenceThresholds::default()
+        };
+        assert!(strict.skip_reason(&speech).is_some());
+        let disabled = SilenceThresholds {
+            enabled: false,
+            ..SilenceThresholds::default()
+        };
+        assert_eq!(disabled.skip_reason(&silent), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Summaries of voice transcripts.
+//!
+//! A transcript is summarized by running a one-step pipeline (a Fabric
+//! pattern, `summarize` by default) through the [`Orchestrator`], so the
+//! summary is an artifact of a normal run with its own event log. The result
+//! is stored on the queue item and included in exported notes.
+
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+use super::queue::{QueueItem, VoiceQueue};
+use crate::core::{Orchestrator, Pipeline};
+use crate::domain::RunState;
+
+/// Fabric pattern used when none is configured
+pub const DEFAULT_SUMMARY_PATTERN: &str = "summarize";
+
+/// Name of the step whose output is the summary
+const SUMMARY_STEP: &str = "summary";
+
+/// One-step pipeline running `pattern` over its input
+pub fn summary_pipeline(pattern: &str) -> Result<Pipeline> {
+    serde_json::from_value(serde_json::json!({
+        "name": "voice_summary",
+        "description": format!("Summarize a voice transcript with '{}'", pattern),
+        "steps": [{
+            "name": SUMMARY_STEP,
+            "adapter": "fabric",
+            "action": pattern,
+            "input_from": "pipeline_input",
+        }],
+    }))
+    .context("Failed to build summary pipeline")
+}
+
+/// Summarize `item`'s transcript with `pattern` and record the summary on
+/// the queue. Returns the summary and the run that produced it.
+pub async fn summarize_item(
+    orchestrator: &Orchestrator,
+    queue: &VoiceQueue,
+    item: &QueueItem,
+    pattern: &str,
+) -> Result<(String, Uuid)> {
+    let transcript = item
+        .transcript
+        .as_ref()
+        .with_context(|| format!("Item {} has no transcript to summarize", item.id))?;
+
+    let pipeline = summary_pipeline(pattern)?;
+    let run = orchestrator
+        .run_pipeline(&pipeline, transcript.labeled_text())
+        .await?;
+    if run.state != RunState::Completed {
+        anyhow::bail!(
+            "Summary run {} ended {:?} (see `arkai status {}`)",
+            run.id,
+            run.state,
+            run.id
+        );
+    }
+
+    let summary = orchestrator
+        .load_run_output(run.id, Some(SUMMARY_STEP))
+        .await?
+        .trim()
+        .to_string();
+    queue.record_summary(&item.id, &summary, run.id).await?;
+    Ok((summary, run.id))
+}
@@ -1,26 +1,91 @@
 //! Whisper transcription backend.
 //!
-//! Shells out to local whisper binary for transcription.
+//! Shells out to a local Whisper CLI for transcription: OpenAI `whisper`,
+//! `faster-whisper`, whisperX, or whisper.cpp, set with `transcriber.binary`
+//! or found on PATH, with options mapped to each one's flags. Timed segments
+//! are kept, with speaker labels when the backend diarizes (whisperX). Results are cached
+//! under `$ARKAI_HOME/transcript_cache/`, keyed on the audio content hash plus
+//! the options that affect output, so reprocessing the same memo doesn't
+//! re-run Whisper.
 
-use std::path::Path;
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tokio::process::Command;
 
+use super::queue::compute_file_hash;
+
 /// Result of transcription
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct TranscriptResult {
     pub text: String,
     pub language: String,
     pub duration_seconds: f64,
+    /// Timed segments, when the backend reports them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// A timed stretch of a transcript
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    /// Speaker label from diarization (`SPEAKER_00`); `None` without it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speaker: Option<String>,
+}
+
+impl TranscriptResult {
+    /// Whether any segment carries a speaker label
+    pub fn has_speakers(&self) -> bool {
+        self.segments.iter().any(|s| s.speaker.is_some())
+    }
+
+    /// The transcript with a `SPEAKER: ` prefix each time the speaker
+    /// changes, one turn per paragraph. Plain text without diarization.
+    pub fn labeled_text(&self) -> String {
+        if !self.has_speakers() {
+            return self.text.clone();
+        }
+
+        let mut turns: Vec<(Option<&str>, String)> = Vec::new();
+        for segment in &self.segments {
+            let text = segment.text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let speaker = segment.speaker.as_deref();
+            match turns.last_mut() {
+                Some((current, turn)) if *current == speaker => {
+                    turn.push(' ');
+                    turn.push_str(text);
+                }
+                _ => turns.push((speaker, text.to_string())),
+            }
+        }
+        turns
+            .into_iter()
+            .map(|(speaker, turn)| match speaker {
+                Some(speaker) => format!("{}: {}", speaker, turn),
+                None => turn,
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
 }
 
-/// Whisper output JSON structure
+/// Whisper output JSON structure (OpenAI, faster-whisper, whisperX)
 #[derive(Debug, Deserialize)]
 struct WhisperOutput {
-    text: String,
+    /// Absent in whisperX output, which only has segments
+    #[serde(default)]
+    text: Option<String>,
     #[serde(default)]
     language: String,
     #[serde(default)]
@@ -29,59 +94,790 @@ struct WhisperOutput {
 
 #[derive(Debug, Deserialize)]
 struct WhisperSegment {
+    #[serde(default)]
+    start: f64,
     #[serde(default)]
     end: f64,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    speaker: Option<String>,
+}
+
+/// How to transcribe a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscribeOptions {
+    /// Whisper model name (e.g. `base`, `small`)
+    pub model: String,
+
+    /// Spoken language passed to Whisper
+    pub language: String,
+
+    /// Serve and store results in the transcript cache
+    pub use_cache: bool,
+}
+
+impl TranscribeOptions {
+    /// Options for `model` with the default language, cache enabled
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            language: "en".to_string(),
+            use_cache: true,
+        }
+    }
+}
+
+/// Transcripts stored by audio hash + options + the engine that made them
+#[derive(Debug, Clone)]
+pub struct TranscriptCache {
+    dir: PathBuf,
+    /// Binary, flavor and extra args of the Whisper engine, so switching
+    /// engines or flags doesn't serve another engine's transcripts
+    engine: String,
+}
+
+impl TranscriptCache {
+    /// Cache stored in `dir`
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            engine: String::new(),
+        }
+    }
+
+    /// Key entries on `whisper` as well, for results it produces
+    pub fn for_engine(mut self, whisper: &WhisperBinary) -> Self {
+        let mut engine = format!("{}\0{:?}", whisper.path.display(), whisper.flavor);
+        for arg in &whisper.extra_args {
+            engine.push('\0');
+            engine.push_str(arg);
+        }
+        self.engine = engine;
+        self
+    }
+
+    /// Cache at `$ARKAI_HOME/transcript_cache/`
+    pub fn open_default() -> Result<Self> {
+        Ok(Self::new(crate::config::transcript_cache_dir()?))
+    }
+
+    /// Get the cache directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cache key for an audio hash transcribed with `options` by this
+    /// cache's engine
+    pub fn key(&self, audio_hash: &str, options: &TranscribeOptions) -> String {
+        let mut hasher = Sha256::new();
+        for part in [audio_hash, &options.model, &options.language, &self.engine] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// Cached result for `key`. Unreadable entries count as misses.
+    pub async fn get(&self, key: &str) -> Option<TranscriptResult> {
+        let content = tokio::fs::read_to_string(self.entry_path(key)).await.ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Store a result under `key`
+    pub async fn put(&self, key: &str, result: &TranscriptResult) -> Result<()> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+
+        // Write-then-rename so a concurrent reader never sees a partial entry
+        let path = self.entry_path(key);
+        let tmp_path = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp_path, serde_json::to_string(result)?)
+            .await
+            .with_context(|| format!("Failed to write {}", tmp_path.display()))?;
+        tokio::fs::rename(&tmp_path, &path)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Delete every cached transcript, returning how many were removed
+    pub async fn clear(&self) -> Result<usize> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e).context("Failed to read transcript cache"),
+        };
+
+        let mut removed = 0;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                tokio::fs::remove_file(&path)
+                    .await
+                    .with_context(|| format!("Failed to remove {}", path.display()))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Serve `audio_path` from the cache, or run `transcribe` and cache its
+    /// result. Returns the result and whether it came from the cache; a
+    /// result that can't be cached is still returned.
+    pub async fn get_or_transcribe<F, Fut>(
+        &self,
+        audio_path: &Path,
+        options: &TranscribeOptions,
+        transcribe: F,
+    ) -> Result<(TranscriptResult, bool)>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<TranscriptResult>>,
+    {
+        let audio_hash = compute_file_hash(audio_path)
+            .await
+            .with_context(|| format!("Failed to hash {}", audio_path.display()))?;
+        let key = self.key(&audio_hash, options);
+
+        if let Some(cached) = self.get(&key).await {
+            return Ok((cached, true));
+        }
+
+        let result = transcribe().await?;
+        if let Err(e) = self.put(&key, &result).await {
+            tracing::warn!(
+                "Failed to cache transcript for {}: {:#}",
+                audio_path.display(),
+                e
+            );
+        }
+        Ok((result, false))
+    }
 }
 
-/// Transcribe audio using local Whisper binary
+/// Transcribe audio using local Whisper binary, with default options
 pub async fn transcribe(audio_path: &Path, model: &str) -> Result<TranscriptResult> {
-    let whisper_path =
-        std::env::var("WHISPER_PATH").unwrap_or_else(|_| "/opt/homebrew/bin/whisper".to_string());
+    transcribe_with_options(audio_path, &TranscribeOptions::new(model)).await
+}
+
+/// Transcribe audio, serving from the transcript cache unless
+/// `options.use_cache` is off
+pub async fn transcribe_with_options(
+    audio_path: &Path,
+    options: &TranscribeOptions,
+) -> Result<TranscriptResult> {
+    if !options.use_cache {
+        return run_whisper(audio_path, options).await;
+    }
+
+    let cache = TranscriptCache::open_default()?.for_engine(&WhisperBinary::from_config()?);
+    let (result, cached) = cache
+        .get_or_transcribe(audio_path, options, || run_whisper(audio_path, options))
+        .await?;
+    if cached {
+        tracing::debug!("Transcript for {} served from cache", audio_path.display());
+    }
+    Ok(result)
+}
+
+/// Binaries probed on PATH when `transcriber.binary` is unset, in order
+const AUTODETECT_CANDIDATES: &[&str] = &[
+    "whisper",
+    "faster-whisper",
+    "whisper-ctranslate2",
+    "whisper-cli",
+    "whisper-cpp",
+    "whisperx",
+];
+
+/// Flag dialect of a Whisper CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WhisperFlavor {
+    /// OpenAI's Python `whisper`
+    Openai,
+    /// `faster-whisper` / `whisper-ctranslate2`, which mirror OpenAI's flags
+    Faster,
+    /// whisper.cpp (`whisper-cli`, `whisper-cpp`, `main`)
+    Cpp,
+    /// whisperX, OpenAI's flags plus `--diarize` for speaker labels
+    Whisperx,
+}
+
+impl WhisperFlavor {
+    /// Guess the dialect from a binary's file name
+    pub fn from_binary(binary: &Path) -> Self {
+        let name = binary
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if name.contains("whisperx") {
+            Self::Whisperx
+        } else if name.contains("faster") || name.contains("ctranslate2") {
+            Self::Faster
+        } else if name.contains("cpp") || name.contains("whisper-cli") || name == "main" {
+            Self::Cpp
+        } else {
+            Self::Openai
+        }
+    }
+}
+
+/// A resolved Whisper binary and how to talk to it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhisperBinary {
+    pub path: PathBuf,
+    pub flavor: WhisperFlavor,
+    pub extra_args: Vec<String>,
+}
+
+impl WhisperBinary {
+    /// Resolve the binary from config (or `WHISPER_PATH`), else autodetect
+    pub fn from_config() -> Result<Self> {
+        let config = &crate::config::config()?.transcriber;
+        Self::resolve(config, std::env::var_os("PATH"))
+    }
+
+    /// Resolve against an explicit config and PATH value
+    pub fn resolve(
+        config: &crate::config::TranscriberConfig,
+        path_var: Option<std::ffi::OsString>,
+    ) -> Result<Self> {
+        let build = |path: PathBuf| Self {
+            flavor: config
+                .flavor
+                .unwrap_or_else(|| WhisperFlavor::from_binary(&path)),
+            extra_args: config.extra_args.clone(),
+            path,
+        };
+
+        if let Some(binary) = config.binary.as_deref() {
+            let path = Path::new(binary);
+            if path.components().count() > 1 {
+                return Ok(build(path.to_path_buf()));
+            }
+            return find_on_path(binary, path_var.as_deref())
+                .map(build)
+                .with_context(|| {
+                    format!(
+                        "Whisper binary '{}' not found on PATH. Set `transcriber.binary` to its full path",
+                        binary
+                    )
+                });
+        }
+
+        AUTODETECT_CANDIDATES
+            .iter()
+            .find_map(|candidate| find_on_path(candidate, path_var.as_deref()))
+            .map(build)
+            .with_context(|| {
+                format!(
+                    "No Whisper binary found. Checked {} on PATH. Install one, or set \
+                     `transcriber.binary` in config.yaml (or WHISPER_PATH)",
+                    AUTODETECT_CANDIDATES.join(", ")
+                )
+            })
+    }
+
+    /// Arguments to transcribe `audio_path` into `out_dir` as JSON
+    pub fn args(
+        &self,
+        audio_path: &Path,
+        out_dir: &Path,
+        options: &TranscribeOptions,
+    ) -> Vec<std::ffi::OsString> {
+        let mut args: Vec<std::ffi::OsString> = match self.flavor {
+            WhisperFlavor::Openai | WhisperFlavor::Faster | WhisperFlavor::Whisperx => vec![
+                audio_path.into(),
+                "--model".into(),
+                (&options.model).into(),
+                "--output_dir".into(),
+                out_dir.into(),
+                "--output_format".into(),
+                "json".into(),
+                "--language".into(),
+                (&options.language).into(),
+            ],
+            // whisper.cpp takes a model file; a bare name means the repo's
+            // `models/ggml-<name>.bin`
+            WhisperFlavor::Cpp => vec![
+                "-m".into(),
+                cpp_model_path(&options.model).into(),
+                "-f".into(),
+                audio_path.into(),
+                "-l".into(),
+                (&options.language).into(),
+                "-oj".into(),
+                "-of".into(),
+                out_dir.join(audio_stem(audio_path)).into(),
+            ],
+        };
+        if self.flavor == WhisperFlavor::Whisperx {
+            args.push("--diarize".into());
+        }
+        args.extend(self.extra_args.iter().map(Into::into));
+        args
+    }
+
+    /// Where the JSON output for `audio_path` lands in `out_dir`
+    pub fn output_path(&self, audio_path: &Path, out_dir: &Path) -> PathBuf {
+        out_dir.join(format!("{}.json", audio_stem(audio_path)))
+    }
+
+    /// Parse this flavor's JSON output
+    pub fn parse_output(&self, json: &str) -> Result<TranscriptResult> {
+        match self.flavor {
+            WhisperFlavor::Openai | WhisperFlavor::Faster | WhisperFlavor::Whisperx => {
+                parse_openai_output(json)
+            }
+            WhisperFlavor::Cpp => {
+                let whisper: CppOutput =
+                    serde_json::from_str(json).context("Failed to parse whisper.cpp JSON")?;
+                let text: String = whisper
+                    .transcription
+                    .iter()
+                    .map(|s| s.text.as_str())
+                    .collect();
+                let segments = whisper
+                    .transcription
+                    .into_iter()
+                    .map(|s| TranscriptSegment {
+                        start: s.offsets.from as f64 / 1000.0,
+                        end: s.offsets.to as f64 / 1000.0,
+                        text: s.text.trim().to_string(),
+                        speaker: None,
+                    })
+                    .collect();
+                Ok(build_result(text, whisper.result.language, segments))
+            }
+        }
+    }
+}
+
+/// Parse OpenAI-style JSON (also what `whisper-server` returns for
+/// `verbose_json`)
+pub(super) fn parse_openai_output(json: &str) -> Result<TranscriptResult> {
+    let whisper: WhisperOutput =
+        serde_json::from_str(json).context("Failed to parse whisper JSON")?;
+    let text = whisper.text.unwrap_or_else(|| {
+        whisper
+            .segments
+            .iter()
+            .map(|s| s.text.trim())
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+    let segments = whisper
+        .segments
+        .into_iter()
+        .map(|s| TranscriptSegment {
+            start: s.start,
+            end: s.end,
+            text: s.text.trim().to_string(),
+            speaker: s.speaker,
+        })
+        .collect();
+    Ok(build_result(text, whisper.language, segments))
+}
+
+fn build_result(
+    text: String,
+    language: String,
+    segments: Vec<TranscriptSegment>,
+) -> TranscriptResult {
+    TranscriptResult {
+        text: text.trim().to_string(),
+        language: if language.is_empty() {
+            "en".to_string()
+        } else {
+            language
+        },
+        duration_seconds: segments
+            .last()
+            .map(|s: &TranscriptSegment| s.end)
+            .unwrap_or(0.0),
+        segments,
+    }
+}
+
+/// whisper.cpp `-oj` output
+#[derive(Debug, Deserialize)]
+struct CppOutput {
+    #[serde(default)]
+    result: CppResult,
+    #[serde(default)]
+    transcription: Vec<CppSegment>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CppResult {
+    #[serde(default)]
+    language: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CppSegment {
+    text: String,
+    #[serde(default)]
+    offsets: CppOffsets,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CppOffsets {
+    /// Milliseconds
+    #[serde(default)]
+    from: u64,
+    /// Milliseconds
+    #[serde(default)]
+    to: u64,
+}
+
+fn audio_stem(audio_path: &Path) -> String {
+    audio_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+pub(super) fn cpp_model_path(model: &str) -> PathBuf {
+    if model.ends_with(".bin") || Path::new(model).components().count() > 1 {
+        PathBuf::from(model)
+    } else {
+        Path::new("models").join(format!("ggml-{}.bin", model))
+    }
+}
+
+pub(super) fn find_on_path(name: &str, path_var: Option<&std::ffi::OsStr>) -> Option<PathBuf> {
+    std::env::split_paths(path_var?)
+        .map(|dir| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Run the Whisper binary on one file
+pub(super) async fn run_whisper(
+    audio_path: &Path,
+    options: &TranscribeOptions,
+) -> Result<TranscriptResult> {
+    let whisper = WhisperBinary::from_config()?;
 
     // Create temp dir for output
     let temp_dir = tempfile::tempdir().context("Failed to create temp dir")?;
 
-    let output = Command::new(&whisper_path)
-        .arg(audio_path)
-        .arg("--model")
-        .arg(model)
-        .arg("--output_dir")
-        .arg(temp_dir.path())
-        .arg("--output_format")
-        .arg("json")
-        .arg("--language")
-        .arg("en") // Default to English, can be made configurable
+    let output = Command::new(&whisper.path)
+        .args(whisper.args(audio_path, temp_dir.path(), options))
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .output()
         .await
-        .context("Failed to run whisper")?;
+        .with_context(|| format!("Failed to run {}", whisper.path.display()))?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         anyhow::bail!("Whisper failed: {}", stderr);
     }
 
-    // Find and parse JSON output
-    let stem = audio_path.file_stem().unwrap_or_default().to_string_lossy();
-    let json_path = temp_dir.path().join(format!("{}.json", stem));
-
+    let json_path = whisper.output_path(audio_path, temp_dir.path());
     let json_content = tokio::fs::read_to_string(&json_path)
         .await
         .context("Failed to read whisper output")?;
 
-    let whisper: WhisperOutput =
-        serde_json::from_str(&json_content).context("Failed to parse whisper JSON")?;
+    whisper.parse_output(&json_content)
+}
 
-    let duration = whisper.segments.last().map(|s| s.end).unwrap_or(0.0);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
 
-    Ok(TranscriptResult {
-        text: whisper.text.trim().to_string(),
-        language: if whisper.language.is_empty() {
-            "en".to_string()
-        } else {
-            whisper.language
-        },
-        duration_seconds: duration,
-    })
+    fn result(text: &str) -> TranscriptResult {
+        TranscriptResult {
+            text: text.to_string(),
+            language: "en".to_string(),
+            duration_seconds: 3.0,
+            segments: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_second_transcription_is_served_from_cache() {
+        let dir = TempDir::new().unwrap();
+        let audio = dir.path().join("memo.m4a");
+        std::fs::write(&audio, b"fake audio").unwrap();
+        let cache = TranscriptCache::new(dir.path().join("cache"));
+        let runs = AtomicUsize::new(0);
+        let backend = || async {
+            runs.fetch_add(1, Ordering::SeqCst);
+            Ok(result("hello"))
+        };
+
+        let base = TranscribeOptions::new("base");
+        let (first, cached) = cache
+            .get_or_transcribe(&audio, &base, backend)
+            .await
+            .unwrap();
+        assert!(!cached);
+        let (second, cached) = cache
+            .get_or_transcribe(&audio, &base, backend)
+            .await
+            .unwrap();
+        assert!(cached);
+        assert_eq!(first, second);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        // A different model is a different entry
+        let small = TranscribeOptions::new("small");
+        let (_, cached) = cache
+            .get_or_transcribe(&audio, &small, backend)
+            .await
+            .unwrap();
+        assert!(!cached);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        // So is different audio
+        std::fs::write(&audio, b"other audio").unwrap();
+        let (_, cached) = cache
+            .get_or_transcribe(&audio, &base, backend)
+            .await
+            .unwrap();
+        assert!(!cached);
+
+        assert_eq!(cache.clear().await.unwrap(), 3);
+        let (_, cached) = cache
+            .get_or_transcribe(&audio, &base, backend)
+            .await
+            .unwrap();
+        assert!(!cached);
+    }
+
+    #[tokio::test]
+    async fn test_engine_is_part_of_the_cache_key() {
+        let dir = TempDir::new().unwrap();
+        let audio = dir.path().join("memo.m4a");
+        std::fs::write(&audio, b"fake audio").unwrap();
+        let options = TranscribeOptions::new("base");
+        let openai = binary(WhisperFlavor::Openai);
+        let backend = || async { Ok(result("hello")) };
+
+        let cache = TranscriptCache::new(dir.path().join("cache")).for_engine(&openai);
+        cache
+            .get_or_transcribe(&audio, &options, backend)
+            .await
+            .unwrap();
+        let (_, cached) = cache
+            .get_or_transcribe(&audio, &options, backend)
+            .await
+            .unwrap();
+        assert!(cached);
+
+        // Another flavor, binary or extra args is a miss
+        let mut cpp = openai.clone();
+        cpp.flavor = WhisperFlavor::Cpp;
+        let mut other_binary = openai.clone();
+        other_binary.path = PathBuf::from("/opt/whisper/bin/whisper");
+        let mut other_args = openai.clone();
+        other_args.extra_args.push("--fp16".to_string());
+        for engine in [cpp, other_binary, other_args] {
+            let cache = TranscriptCache::new(dir.path().join("cache")).for_engine(&engine);
+            let (_, cached) = cache
+                .get_or_transcribe(&audio, &options, backend)
+                .await
+                .unwrap();
+            assert!(!cached, "{:?}", engine);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_write_failure_keeps_the_transcript() {
+        let dir = TempDir::new().unwrap();
+        let audio = dir.path().join("memo.m4a");
+        std::fs::write(&audio, b"fake audio").unwrap();
+        // The cache directory can't be created under a file
+        let blocker = dir.path().join("not_a_dir");
+        std::fs::write(&blocker, b"").unwrap();
+        let cache = TranscriptCache::new(blocker.join("cache"));
+
+        let (transcript, cached) = cache
+            .get_or_transcribe(&audio, &TranscribeOptions::new("base"), || async {
+                Ok(result("hello"))
+            })
+            .await
+            .unwrap();
+        assert_eq!(transcript.text, "hello");
+        assert!(!cached);
+    }
+
+    #[tokio::test]
+    async fn test_failed_transcription_is_not_cached() {
+        let dir = TempDir::new().unwrap();
+        let audio = dir.path().join("memo.m4a");
+        std::fs::write(&audio, b"fake audio").unwrap();
+        let cache = TranscriptCache::new(dir.path().join("cache"));
+        let options = TranscribeOptions::new("base");
+
+        let failed = cache
+            .get_or_transcribe(&audio, &options, || async { anyhow::bail!("whisper died") })
+            .await;
+        assert!(failed.is_err());
+        assert_eq!(cache.clear().await.unwrap(), 0);
+    }
+
+    fn binary(flavor: WhisperFlavor) -> WhisperBinary {
+        WhisperBinary {
+            path: PathBuf::from("whisper"),
+            flavor,
+            extra_args: vec!["--threads".to_string(), "4".to_string()],
+        }
+    }
+
+    fn args(whisper: &WhisperBinary) -> Vec<String> {
+        whisper
+            .args(
+                Path::new("/memos/a.m4a"),
+                Path::new("/tmp/out"),
+                &TranscribeOptions::new("base"),
+            )
+            .into_iter()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_options_map_to_each_flavor() {
+        let openai = vec![
+            "/memos/a.m4a",
+            "--model",
+            "base",
+            "--output_dir",
+            "/tmp/out",
+            "--output_format",
+            "json",
+            "--language",
+            "en",
+            "--threads",
+            "4",
+        ];
+        assert_eq!(args(&binary(WhisperFlavor::Openai)), openai);
+        assert_eq!(args(&binary(WhisperFlavor::Faster)), openai);
+        assert_eq!(
+            args(&binary(WhisperFlavor::Cpp)),
+            vec![
+                "-m",
+                "models/ggml-base.bin",
+                "-f",
+                "/memos/a.m4a",
+                "-l",
+                "en",
+                "-oj",
+                "-of",
+                "/tmp/out/a",
+                "--threads",
+                "4",
+            ]
+        );
+        assert_eq!(
+            binary(WhisperFlavor::Cpp).output_path(Path::new("/memos/a.m4a"), Path::new("/o")),
+            PathBuf::from("/o/a.json")
+        );
+
+        assert_eq!(
+            WhisperFlavor::from_binary(Path::new("/opt/homebrew/bin/whisper")),
+            WhisperFlavor::Openai
+        );
+        assert_eq!(
+            WhisperFlavor::from_binary(Path::new("whisper-ctranslate2")),
+            WhisperFlavor::Faster
+        );
+        assert_eq!(
+            WhisperFlavor::from_binary(Path::new("/usr/local/bin/whisper-cli")),
+            WhisperFlavor::Cpp
+        );
+    }
+
+    #[test]
+    fn test_parse_cpp_output() {
+        let json = r#"{"result": {"language": "de"}, "transcription": [
+            {"offsets": {"from": 0, "to": 1500}, "text": " Hallo"},
+            {"offsets": {"from": 1500, "to": 4200}, "text": " Welt"}
+        ]}"#;
+        let result = binary(WhisperFlavor::Cpp).parse_output(json).unwrap();
+        assert_eq!(result.text, "Hallo Welt");
+        assert_eq!(result.language, "de");
+        assert_eq!(result.duration_seconds, 4.2);
+        assert_eq!(result.segments[1].start, 1.5);
+        assert!(!result.has_speakers());
+        assert_eq!(result.labeled_text(), "Hallo Welt");
+    }
+
+    #[test]
+    fn test_parse_diarized_whisperx_output() {
+        let json = r#"{"language": "en", "segments": [
+            {"start": 0.0, "end": 2.1, "text": " Did you book the venue?", "speaker": "SPEAKER_00"},
+            {"start": 2.3, "end": 4.0, "text": " Yes, for Friday.", "speaker": "SPEAKER_01"},
+            {"start": 4.0, "end": 5.5, "text": " The deposit is paid.", "speaker": "SPEAKER_01"},
+            {"start": 5.9, "end": 6.4, "text": " Great."}
+        ]}"#;
+        let result = binary(WhisperFlavor::Whisperx).parse_output(json).unwrap();
+        assert_eq!(
+            result.text,
+            "Did you book the venue? Yes, for Friday. The deposit is paid. Great."
+        );
+        assert_eq!(result.duration_seconds, 6.4);
+        assert_eq!(result.segments.len(), 4);
+        assert_eq!(result.segments[0].speaker.as_deref(), Some("SPEAKER_00"));
+        assert_eq!(result.segments[3].speaker, None);
+        assert_eq!(
+            result.labeled_text(),
+            "SPEAKER_00: Did you book the venue?\n\nSPEAKER_01: Yes, for Friday. The deposit is paid.\n\nGreat."
+        );
+
+        // whisperX asks for diarization; a plain OpenAI parse has no speakers
+        assert!(args(&binary(WhisperFlavor::Whisperx)).contains(&"--diarize".to_string()));
+        assert_eq!(
+            WhisperFlavor::from_binary(Path::new("/usr/bin/whisperx")),
+            WhisperFlavor::Whisperx
+        );
+        let plain = binary(WhisperFlavor::Openai)
+            .parse_output(r#"{"text": " Hi", "segments": [{"start": 0, "end": 1, "text": " Hi"}]}"#)
+            .unwrap();
+        assert!(!plain.has_speakers());
+    }
+
+    #[test]
+    fn test_resolve_autodetects_or_reports_not_found() {
+        use crate::config::TranscriberConfig;
+
+        let dir = TempDir::new().unwrap();
+        let config = TranscriberConfig::default();
+
+        let err = WhisperBinary::resolve(&config, Some(dir.path().into())).unwrap_err();
+        assert!(err.to_string().contains("No Whisper binary found"));
+        assert!(err.to_string().contains("transcriber.binary"));
+
+        std::fs::write(dir.path().join("whisper-cli"), "").unwrap();
+        let found = WhisperBinary::resolve(&config, Some(dir.path().into())).unwrap();
+        assert_eq!(found.path, dir.path().join("whisper-cli"));
+        assert_eq!(found.flavor, WhisperFlavor::Cpp);
+
+        // Configured name must exist; configured flavor wins over the name
+        let config = TranscriberConfig {
+            binary: Some("whisper-cli".to_string()),
+            flavor: Some(WhisperFlavor::Openai),
+            ..TranscriberConfig::default()
+        };
+        let found = WhisperBinary::resolve(&config, Some(dir.path().into())).unwrap();
+        assert_eq!(found.flavor, WhisperFlavor::Openai);
+        let config = TranscriberConfig {
+            binary: Some("missing-whisper".to_string()),
+            ..config
+        };
+        assert!(WhisperBinary::resolve(&config, Some(dir.path().into())).is_err());
+    }
 }
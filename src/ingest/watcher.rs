@@ -12,9 +12,16 @@
 //! - ffprobe validation for .qta files (pre-normalize)
 //!
 //! If normalization or validation fails, files are deferred (not errored)
-//! and will be retried on the next stability window.
+//! and will be retried on the next stability window. A missing `ffprobe` or
+//! `ffmpeg` is not a deferral: it is reported once with an install hint, and
+//! `.qta` files that become stable meanwhile are set aside. The watcher looks
+//! for the tools again every minute and, once they're installed, processes
+//! the files it set aside.
+//!
+//! While watching, the loop rewrites the heartbeat file (when configured)
+//! every few seconds so `arkai voice status` can tell a live watcher from a dead one.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Minimum age before processing (hardening against iCloud sync)
 /// Files modified in the last 30 seconds are considered potentially unstable.
@@ -27,6 +34,9 @@ const MAX_DEFERRALS: u32 = 20;
 /// Maximum time a file can be pending before being marked stuck (30 minutes)
 /// Prevents files from staying in pending state forever.
 const MAX_PENDING_SECS: u64 = 30 * 60;
+
+/// How often the watch loop looks for missing ffmpeg/ffprobe again
+const TOOL_RECHECK_SECS: u64 = 60;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -39,6 +49,7 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::sync::mpsc;
 
+use super::heartbeat::Heartbeat;
 use super::queue::{compute_file_hash, normalize_audio, EnqueueResult, VoiceQueue};
 
 /// Errors that can occur with the watcher
@@ -68,6 +79,19 @@ pub struct WatcherConfig {
 
     /// File extensions to watch
     pub extensions: Vec<String>,
+
+    /// Heartbeat file rewritten every few seconds while watching
+    #[serde(default)]
+    pub heartbeat_path: Option<PathBuf>,
+}
+
+impl WatcherConfig {
+    /// Whether `.qta` files (which need ffmpeg) are watched
+    fn watches_qta(&self) -> bool {
+        self.extensions
+            .iter()
+            .any(|ext| ext.eq_ignore_ascii_case("qta"))
+    }
 }
 
 impl Default for WatcherConfig {
@@ -76,6 +100,7 @@ impl Default for WatcherConfig {
             watch_path: Self::default_voice_memos_path(),
             stability_delay_secs: 10, // Bumped from 5 for iPhone sync stability
             extensions: vec!["m4a".to_string(), "qta".to_string()], // Added .qta for iPhone sync
+            heartbeat_path: None,
         }
     }
 }
@@ -139,15 +164,29 @@ impl VoiceMemoWatcher {
     /// Scan the directory once and enqueue any existing files
     /// Returns the number of new files queued
     pub async fn scan_once(&self, queue: &VoiceQueue) -> Result<ScanResult> {
+        self.scan_once_with_tools(queue, &MediaTools::detect(tool_on_path))
+            .await
+    }
+
+    async fn scan_once_with_tools(
+        &self,
+        queue: &VoiceQueue,
+        tools: &MediaTools,
+    ) -> Result<ScanResult> {
         self.config
             .validate()
             .map_err(|e| anyhow::anyhow!("{}", e))?;
 
-        // Phase 1.6: Check ffprobe availability upfront (fail fast, not silent failures)
-        check_ffprobe_available().await?;
-
         let mut result = ScanResult::default();
 
+        // Phase 1.6: Check ffmpeg/ffprobe upfront (report once, not silent deferral)
+        if self.config.watches_qta() {
+            result.media_tools_missing = tools.diagnostic();
+            if let Some(diagnostic) = &result.media_tools_missing {
+                tracing::warn!("{}", diagnostic);
+            }
+        }
+
         let mut entries = tokio::fs::read_dir(&self.config.watch_path).await?;
 
         while let Some(entry) = entries.next_entry().await? {
@@ -188,11 +227,23 @@ impl VoiceMemoWatcher {
 
             // Pre-validate with ffprobe for .qta files
             if is_qta_file(&path) {
-                if !validate_audio_readable(&path).await {
-                    tracing::info!("Deferred (ffprobe failed): {}", path.display());
-                    result.deferred += 1;
+                if !tools.available() {
+                    result.missing_tools += 1;
                     continue;
                 }
+                match validate_audio_readable(&path).await {
+                    AudioCheck::Readable => {}
+                    AudioCheck::NotReadable => {
+                        tracing::info!("Deferred (ffprobe failed): {}", path.display());
+                        result.deferred += 1;
+                        continue;
+                    }
+                    AudioCheck::ToolMissing => {
+                        tracing::warn!("{}", MediaTools::missing(&["ffprobe"]).hint());
+                        result.missing_tools += 1;
+                        continue;
+                    }
+                }
             }
 
             // Normalize .qta → .m4a if needed (before hashing/enqueueing)
@@ -307,6 +358,10 @@ pub struct ScanResult {
     pub reset_for_retry: usize,
     pub deferred: usize,
     pub errors: usize,
+    /// `.qta` files left alone because ffmpeg/ffprobe is missing
+    pub missing_tools: usize,
+    /// Install hint, when watched files need tools that aren't installed
+    pub media_tools_missing: Option<String>,
 }
 
 impl ScanResult {
@@ -324,7 +379,6 @@ impl ScanResult {
 ///
 /// Future improvements (noted, not implemented):
 /// - TODO: Make timing (3 checks, 2s) configurable
-/// - TODO: ffprobe check only when first .qta encountered (not at startup)
 /// - TODO: Log spam prevention (once per file per N minutes)
 #[derive(Debug, Clone)]
 struct FileStabilityState {
@@ -428,8 +482,18 @@ async fn run_watcher(
     event_tx: mpsc::Sender<AudioFileEvent>,
     stop_rx: &mut mpsc::Receiver<()>,
 ) -> Result<()> {
-    // Phase 1.6: Check ffprobe availability at startup (fail fast, not infinite defer)
-    check_ffprobe_available().await?;
+    // Phase 1.6: Check ffmpeg/ffprobe at startup (report once, not infinite defer).
+    // Only .qta files need them; without them they're set aside until a
+    // re-check finds the tools.
+    let mut tools = MediaTools::detect(tool_on_path);
+    let mut waiting_for_tools = WaitingForTools::new(Duration::from_secs(TOOL_RECHECK_SECS));
+    if config.watches_qta() {
+        if let Some(diagnostic) = tools.diagnostic() {
+            tracing::error!("{}", diagnostic);
+        }
+    }
+
+    let mut heartbeat = config.heartbeat_path.clone().map(Heartbeat::new);
 
     // Track files being stabilized with enhanced state
     let mut pending: HashMap<PathBuf, FileStabilityState> = HashMap::new();
@@ -463,6 +527,10 @@ async fn run_watcher(
             break;
         }
 
+        if let Some(heartbeat) = &mut heartbeat {
+            heartbeat.beat().await;
+        }
+
         // Check for file events (non-blocking with timeout)
         match rx.recv_timeout(Duration::from_millis(500)) {
             Ok(Ok(events)) => {
@@ -512,6 +580,15 @@ async fn run_watcher(
             }
         }
 
+        // Once ffmpeg/ffprobe are installed, set-aside .qta files go back
+        // through the stability checks
+        for path in waiting_for_tools.recheck(&mut tools, tool_on_path) {
+            if let Ok(metadata) = std::fs::metadata(&path) {
+                let mtime = metadata.modified().unwrap_or(std::time::SystemTime::now());
+                pending.insert(path, FileStabilityState::new(metadata.len(), mtime));
+            }
+        }
+
         // Phase 1.6 liveness guard: Remove stuck files (too many deferrals or too long pending)
         let stuck_files: Vec<PathBuf> = pending
             .iter()
@@ -559,16 +636,35 @@ async fn run_watcher(
             // Pre-normalize validation: verify file is readable with ffprobe
             // If this fails, the file is likely still syncing despite passing stability checks
             if is_qta_file(&path) {
-                if !validate_audio_readable(&path).await {
-                    tracing::info!(
-                        "Deferred (ffprobe failed, still syncing?): {}",
-                        path.display()
-                    );
-                    // Reset for retry - don't remove from pending
-                    if let Some(state) = pending.get_mut(&path) {
-                        state.reset_for_retry();
+                let check = if tools.available() {
+                    validate_audio_readable(&path).await
+                } else {
+                    AudioCheck::ToolMissing
+                };
+                match check {
+                    AudioCheck::Readable => {}
+                    AudioCheck::NotReadable => {
+                        tracing::info!(
+                            "Deferred (ffprobe failed, still syncing?): {}",
+                            path.display()
+                        );
+                        // Reset for retry - don't remove from pending
+                        if let Some(state) = pending.get_mut(&path) {
+                            state.reset_for_retry();
+                        }
+                        continue;
+                    }
+                    AudioCheck::ToolMissing => {
+                        // Retrying can't help until the tools are installed
+                        if tools.available() {
+                            tools = MediaTools::missing(&["ffprobe"]);
+                            tracing::error!("{}", tools.hint());
+                        }
+                        tracing::warn!("Waiting for ffmpeg to be installed: {}", path.display());
+                        pending.remove(&path);
+                        waiting_for_tools.add(path);
+                        continue;
                     }
-                    continue;
                 }
             }
 
@@ -654,26 +750,112 @@ fn is_qta_file(path: &Path) -> bool {
         .unwrap_or(false)
 }
 
-/// Check if ffprobe is available (Phase 1.6 hardening)
-/// Called at startup to fail fast instead of infinite defer loop
-async fn check_ffprobe_available() -> Result<()> {
-    match tokio::process::Command::new("ffprobe")
-        .arg("-version")
-        .output()
-        .await
-    {
-        Ok(out) if out.status.success() => Ok(()),
-        Ok(_) => anyhow::bail!("ffprobe found but returned error. Verify ffmpeg installation."),
-        Err(e) => anyhow::bail!(
-            "ffprobe not found: {}. Install ffmpeg to process .qta files: brew install ffmpeg",
-            e
-        ),
+/// Tools needed for .qta files: ffprobe validates, ffmpeg converts
+const MEDIA_TOOLS: [&str; 2] = ["ffprobe", "ffmpeg"];
+
+/// Which of [`MEDIA_TOOLS`] are missing (Phase 1.6 hardening)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct MediaTools {
+    missing: Vec<&'static str>,
+}
+
+impl MediaTools {
+    /// Check each tool with `available` (normally a PATH lookup)
+    fn detect(available: impl Fn(&str) -> bool) -> Self {
+        Self {
+            missing: MEDIA_TOOLS
+                .into_iter()
+                .filter(|tool| !available(tool))
+                .collect(),
+        }
+    }
+
+    fn missing(tools: &[&'static str]) -> Self {
+        Self {
+            missing: tools.to_vec(),
+        }
+    }
+
+    fn available(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// What's missing and how to install it
+    fn hint(&self) -> String {
+        format!(
+            "{} not found on PATH. .qta voice memos can't be validated or converted until ffmpeg is installed (macOS: brew install ffmpeg, Debian/Ubuntu: apt install ffmpeg).",
+            self.missing.join(" and ")
+        )
+    }
+
+    /// [`hint`](Self::hint), if anything is missing
+    fn diagnostic(&self) -> Option<String> {
+        (!self.available()).then(|| self.hint())
+    }
+}
+
+/// `.qta` files that became stable while ffmpeg/ffprobe were missing
+struct WaitingForTools {
+    paths: HashSet<PathBuf>,
+    interval: Duration,
+    last_check: Instant,
+}
+
+impl WaitingForTools {
+    fn new(interval: Duration) -> Self {
+        Self {
+            paths: HashSet::new(),
+            interval,
+            last_check: Instant::now(),
+        }
+    }
+
+    fn add(&mut self, path: PathBuf) {
+        self.paths.insert(path);
+    }
+
+    /// While `tools` are missing, look for them again (with `available`)
+    /// at most once per interval. Once they're all found, returns the files
+    /// set aside so they can be processed.
+    fn recheck(
+        &mut self,
+        tools: &mut MediaTools,
+        available: impl Fn(&str) -> bool,
+    ) -> Vec<PathBuf> {
+        if tools.available() || self.last_check.elapsed() < self.interval {
+            return Vec::new();
+        }
+        self.last_check = Instant::now();
+
+        *tools = MediaTools::detect(available);
+        if !tools.available() {
+            return Vec::new();
+        }
+        tracing::info!(
+            "ffmpeg and ffprobe found; processing {} waiting .qta file(s)",
+            self.paths.len()
+        );
+        self.paths.drain().collect()
     }
 }
 
+/// Whether `name` is an executable on PATH
+pub fn tool_on_path(name: &str) -> bool {
+    super::transcriber::find_on_path(name, std::env::var_os("PATH").as_deref()).is_some()
+}
+
+/// Outcome of probing a file with ffprobe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AudioCheck {
+    Readable,
+    /// ffprobe ran but couldn't read a duration (likely still syncing)
+    NotReadable,
+    /// ffprobe couldn't be run at all
+    ToolMissing,
+}
+
 /// Validate that an audio file is readable using ffprobe
-/// Returns false if ffprobe fails (file likely still syncing)
-async fn validate_audio_readable(path: &Path) -> bool {
+async fn validate_audio_readable(path: &Path) -> AudioCheck {
     let output = tokio::process::Command::new("ffprobe")
         .args([
             "-v",
@@ -691,9 +873,14 @@ async fn validate_audio_readable(path: &Path) -> bool {
         Ok(out) => {
             // ffprobe succeeded - check if we got a valid duration
             let duration_str = String::from_utf8_lossy(&out.stdout);
-            duration_str.trim().parse::<f32>().is_ok()
+            if duration_str.trim().parse::<f32>().is_ok() {
+                AudioCheck::Readable
+            } else {
+                AudioCheck::NotReadable
+            }
         }
-        Err(_) => false,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => AudioCheck::ToolMissing,
+        Err(_) => AudioCheck::NotReadable,
     }
 }
 
@@ -733,6 +920,7 @@ mod tests {
             watch_path: temp.path().to_path_buf(),
             stability_delay_secs: 1,
             extensions: vec!["m4a".to_string()],
+            heartbeat_path: None,
         };
         let watcher = VoiceMemoWatcher::with_config(config);
 
@@ -780,6 +968,7 @@ mod tests {
             watch_path: temp.path().to_path_buf(),
             stability_delay_secs: 1,
             extensions: vec!["m4a".to_string()],
+            heartbeat_path: None,
         };
         let watcher = VoiceMemoWatcher::with_config(config);
 
@@ -804,4 +993,107 @@ mod tests {
             "Files should show as already queued"
         );
     }
+
+    #[tokio::test]
+    async fn test_watch_loop_updates_heartbeat() {
+        use crate::ingest::WatcherLiveness;
+
+        let temp = TempDir::new().unwrap();
+        let heartbeat_path = temp.path().join("watcher.heartbeat");
+        let config = WatcherConfig {
+            watch_path: temp.path().to_path_buf(),
+            stability_delay_secs: 1,
+            extensions: vec!["m4a".to_string()],
+            heartbeat_path: Some(heartbeat_path.clone()),
+        };
+        let watcher = VoiceMemoWatcher::with_config(config);
+        let queue = Arc::new(VoiceQueue::new(temp.path().join("queue.jsonl")));
+        let heartbeat = Heartbeat::new(heartbeat_path);
+        assert!(heartbeat.last_seen().await.is_none());
+
+        let (_events, handle) = watcher.watch(queue).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        let first = heartbeat.last_seen().await.expect("heartbeat written");
+        // Not rewritten on every loop iteration (every 500ms)
+        tokio::time::sleep(Duration::from_millis(1200)).await;
+        let second = heartbeat.last_seen().await.unwrap();
+        handle.stop().await.unwrap();
+
+        assert_eq!(second, first);
+        assert!(matches!(
+            WatcherLiveness::from_last_seen(Some(second), Utc::now()),
+            WatcherLiveness::Alive { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_ffprobe_is_reported_not_deferred() {
+        use filetime::{set_file_mtime, FileTime};
+
+        let temp = TempDir::new().unwrap();
+        let memo = temp.path().join("memo.qta");
+        tokio::fs::write(&memo, b"qta audio").await.unwrap();
+        let old = std::time::SystemTime::now() - Duration::from_secs(120);
+        set_file_mtime(&memo, FileTime::from_system_time(old)).unwrap();
+
+        let watcher = VoiceMemoWatcher::with_config(WatcherConfig {
+            watch_path: temp.path().to_path_buf(),
+            stability_delay_secs: 1,
+            extensions: vec!["qta".to_string()],
+            heartbeat_path: None,
+        });
+        let queue = VoiceQueue::new(temp.path().join("queue.jsonl"));
+
+        let tools = MediaTools::detect(|tool| tool != "ffprobe");
+        assert_eq!(tools.missing, vec!["ffprobe"]);
+        let result = watcher.scan_once_with_tools(&queue, &tools).await.unwrap();
+
+        assert_eq!(result.deferred, 0, "missing ffprobe isn't a sync delay");
+        assert_eq!(result.missing_tools, 1);
+        let diagnostic = result.media_tools_missing.unwrap();
+        assert!(
+            diagnostic.starts_with("ffprobe not found on PATH"),
+            "{}",
+            diagnostic
+        );
+        assert!(diagnostic.contains("brew install ffmpeg"));
+        assert_eq!(queue.status().await.unwrap().total(), 0);
+
+        // Both present: nothing to report
+        assert_eq!(MediaTools::detect(|_| true).diagnostic(), None);
+        assert!(MediaTools::detect(|_| false)
+            .hint()
+            .starts_with("ffprobe and ffmpeg not found"));
+    }
+
+    #[test]
+    fn test_qta_files_wait_until_tools_are_installed() {
+        let mut tools = MediaTools::detect(|_| false);
+        let mut waiting = WaitingForTools::new(Duration::ZERO);
+        waiting.add(PathBuf::from("/memos/a.qta"));
+        waiting.add(PathBuf::from("/memos/b.qta"));
+
+        // Still missing: the files stay set aside
+        assert!(waiting
+            .recheck(&mut tools, |tool| tool == "ffmpeg")
+            .is_empty());
+        assert_eq!(tools.missing, vec!["ffprobe"]);
+
+        // Installed: every waiting file comes back, once
+        let mut ready = waiting.recheck(&mut tools, |_| true);
+        ready.sort();
+        assert_eq!(
+            ready,
+            vec![PathBuf::from("/memos/a.qta"), PathBuf::from("/memos/b.qta")]
+        );
+        assert!(tools.available());
+        assert!(waiting.recheck(&mut tools, |_| true).is_empty());
+
+        // Not re-checked more often than the interval
+        let mut tools = MediaTools::detect(|_| false);
+        let mut waiting = WaitingForTools::new(Duration::from_secs(3600));
+        waiting.add(PathBuf::from("/memos/c.qta"));
+        assert!(waiting.recheck(&mut tools, |_| true).is_empty());
+        assert!(!tools.available());
+    }
 }
@@ -15,7 +15,9 @@
 //! - `adapters`: External system integrations (Fabric)
 //! - `core`: Orchestration logic (EventStore, Pipeline, Safety)
 //! - `domain`: Data structures (Event, Run, Artifact)
+//! - `error`: `ArkaiError`, returned by the core public APIs
 //! - `cli`: Command-line interface
+//! - `telemetry`: OpenTelemetry span export (`otel` feature)
 //!
 //! # Usage
 //!
@@ -35,14 +37,18 @@ pub mod cli;
 pub mod config;
 pub mod core;
 pub mod domain;
+pub mod error;
 pub mod evidence;
 pub mod ingest;
 pub mod library;
 pub mod store;
+#[cfg(feature = "otel")]
+pub mod telemetry;
 
 // Re-export main types at crate root for convenience
 pub use core::Orchestrator;
 pub use domain::{Event, EventType, Run, RunState};
+pub use error::ArkaiError;
 pub use evidence::{Evidence, MatchResult, MatchStatus, Span, Status as EvidenceStatus};
 pub use library::{Catalog, CatalogItem, ContentId, ContentType, LibraryContent};
 
@@ -2,7 +2,7 @@
 //!
 //! Simple JSON-based index that can be searched and filtered.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -43,13 +43,16 @@ impl Catalog {
 
     /// Load the catalog from disk
     pub async fn load() -> Result<Self> {
-        let path = Self::catalog_path()?;
+        Self::load_from(&Self::catalog_path()?).await
+    }
 
+    /// Load a catalog from a specific path
+    pub async fn load_from(path: &Path) -> Result<Self> {
         if !path.exists() {
             return Ok(Self::new());
         }
 
-        let content = fs::read_to_string(&path)
+        let content = fs::read_to_string(path)
             .await
             .with_context(|| format!("Failed to read catalog: {}", path.display()))?;
 
@@ -58,15 +61,18 @@ impl Catalog {
 
     /// Save the catalog to disk
     pub async fn save(&self) -> Result<()> {
-        let path = Self::catalog_path()?;
+        self.save_to(&Self::catalog_path()?).await
+    }
 
+    /// Save the catalog to a specific path
+    pub async fn save_to(&self, path: &Path) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent).await?;
         }
 
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(&path, content)
+        fs::write(path, content)
             .await
             .with_context(|| format!("Failed to write catalog: {}", path.display()))?;
 
@@ -89,6 +95,76 @@ impl Catalog {
         self.items.iter().find(|i| &i.id == id)
     }
 
+    /// Resolve an item by full ID or unique ID prefix
+    pub fn resolve(&self, prefix: &str) -> Result<&CatalogItem> {
+        let matches: Vec<&CatalogItem> = self
+            .items
+            .iter()
+            .filter(|i| i.id.as_str().starts_with(prefix))
+            .collect();
+
+        match matches.as_slice() {
+            [item] => Ok(item),
+            [] => anyhow::bail!("Content not found: {}", prefix),
+            _ => anyhow::bail!(
+                "Content ID prefix '{}' is ambiguous ({} matches)",
+                prefix,
+                matches.len()
+            ),
+        }
+    }
+
+    /// Link two items as related (bidirectional, idempotent)
+    pub fn link(&mut self, a: &ContentId, b: &ContentId) -> Result<()> {
+        if a == b {
+            anyhow::bail!("Cannot link content {} to itself", a);
+        }
+        for id in [a, b] {
+            if self.get(id).is_none() {
+                anyhow::bail!("Content not found: {}", id);
+            }
+        }
+
+        for (from, to) in [(a, b), (b, a)] {
+            if let Some(item) = self.items.iter_mut().find(|i| &i.id == from) {
+                if !item.related.contains(to) {
+                    item.related.push(to.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove a relationship between two items; returns whether a link existed
+    pub fn unlink(&mut self, a: &ContentId, b: &ContentId) -> bool {
+        let mut removed = false;
+
+        for (from, to) in [(a, b), (b, a)] {
+            if let Some(item) = self.items.iter_mut().find(|i| &i.id == from) {
+                let before = item.related.len();
+                item.related.retain(|id| id != to);
+                removed |= item.related.len() != before;
+            }
+        }
+
+        removed
+    }
+
+    /// Get the cataloged items related to an item
+    pub fn related(&self, id: &ContentId) -> Vec<&CatalogItem> {
+        self.get(id)
+            .map(|item| item.related.iter().filter_map(|r| self.get(r)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Find an item by the SHA256 of its imported source
+    pub fn find_by_content_hash(&self, sha256: &str) -> Option<&CatalogItem> {
+        self.items
+            .iter()
+            .find(|i| i.content_sha256.as_deref() == Some(sha256))
+    }
+
     /// Remove an item by ID
     pub fn remove(&mut self, id: &ContentId) -> Option<CatalogItem> {
         if let Some(pos) = self.items.iter().position(|i| &i.id == id) {
@@ -174,6 +250,14 @@ pub struct CatalogItem {
 
     /// Run ID that produced this content (for traceability)
     pub run_id: Option<String>,
+
+    /// Related content (e.g. a video and its companion article)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<ContentId>,
+
+    /// SHA256 of the imported source file (set by `arkai library import`, used for dedup)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_sha256: Option<String>,
 }
 
 impl CatalogItem {
@@ -195,6 +279,8 @@ impl CatalogItem {
             tags: Vec::new(),
             artifacts: Vec::new(),
             run_id: None,
+            related: Vec::new(),
+            content_sha256: None,
         }
     }
 
@@ -323,4 +409,66 @@ mod tests {
         assert!(removed.is_some());
         assert_eq!(catalog.len(), 0);
     }
+
+    fn linked_pair() -> (Catalog, ContentId, ContentId) {
+        let mut catalog = Catalog::new();
+        let video = CatalogItem::new(
+            "https://youtube.com/watch?v=v1",
+            "Talk",
+            ContentType::YouTube,
+        );
+        let article = CatalogItem::new("https://example.com/talk", "Talk notes", ContentType::Web);
+        let (a, b) = (video.id.clone(), article.id.clone());
+        catalog.add(video);
+        catalog.add(article);
+        catalog.link(&a, &b).unwrap();
+        (catalog, a, b)
+    }
+
+    #[test]
+    fn test_catalog_link_is_bidirectional_and_idempotent() {
+        let (mut catalog, a, b) = linked_pair();
+        catalog.link(&b, &a).unwrap();
+
+        assert_eq!(catalog.get(&a).unwrap().related, vec![b.clone()]);
+        assert_eq!(catalog.get(&b).unwrap().related, vec![a.clone()]);
+        assert_eq!(catalog.related(&a)[0].id, b);
+
+        assert!(catalog.link(&a, &a).is_err());
+        assert!(catalog
+            .link(&a, &ContentId::from_url("https://missing.example"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_catalog_unlink() {
+        let (mut catalog, a, b) = linked_pair();
+
+        assert!(catalog.unlink(&b, &a));
+        assert!(catalog.get(&a).unwrap().related.is_empty());
+        assert!(catalog.get(&b).unwrap().related.is_empty());
+        assert!(!catalog.unlink(&a, &b));
+    }
+
+    #[tokio::test]
+    async fn test_catalog_links_survive_round_trip() {
+        let (catalog, a, b) = linked_pair();
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("catalog.json");
+
+        catalog.save_to(&path).await.unwrap();
+        let loaded = Catalog::load_from(&path).await.unwrap();
+
+        assert_eq!(loaded.get(&a).unwrap().related, vec![b.clone()]);
+        assert_eq!(loaded.get(&b).unwrap().related, vec![a]);
+    }
+
+    #[test]
+    fn test_catalog_resolve_prefix() {
+        let (catalog, a, _) = linked_pair();
+
+        assert_eq!(catalog.resolve(&a.as_str()[..6]).unwrap().id, a);
+        assert!(catalog.resolve("zzzz").is_err());
+        assert!(catalog.resolve("").is_err()); // ambiguous
+    }
 }
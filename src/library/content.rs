@@ -3,7 +3,7 @@
 //! Manages the storage and retrieval of processed content artifacts.
 //! Content is organized by type (youtube, articles, etc.) with content ID subdirectories.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -29,6 +29,21 @@ fn sanitize_filename(name: &str) -> String {
         .collect()
 }
 
+/// Fallback title from a URL: its last path segment, or the host
+fn title_from_url(url: &str) -> String {
+    let without_query = url.split(['?', '#']).next().unwrap_or(url);
+    let path = without_query
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(without_query);
+
+    path.split('/')
+        .rev()
+        .find(|segment| !segment.is_empty())
+        .unwrap_or("Untitled")
+        .to_string()
+}
+
 /// Extract video ID from YouTube URL
 fn extract_video_id_from_url(url: &str) -> Option<String> {
     let url_lower = url.to_lowercase();
@@ -49,7 +64,11 @@ fn extract_video_id_from_url(url: &str) -> Option<String> {
     }
 }
 
-/// Content identifier (SHA256(url)[0:16])
+/// Domain separator for content-based IDs, so they never coincide with a
+/// URL-based ID for the same bytes
+const CONTENT_ID_DOMAIN: &[u8] = b"arkai:content\0";
+
+/// Content identifier (SHA256(url)[0:16], or a content hash for URL-less items)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ContentId(String);
 
@@ -58,6 +77,23 @@ impl ContentId {
     pub fn from_url(url: &str) -> Self {
         let mut hasher = Sha256::new();
         hasher.update(url.as_bytes());
+        Self::from_digest(hasher)
+    }
+
+    /// Create a content ID from the content itself (for pasted text or local
+    /// files with no URL). Line endings and trailing whitespace are normalized
+    /// so trivially different copies of the same text share an ID.
+    pub fn from_content(bytes: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(bytes);
+        let normalized: Vec<&str> = text.trim().lines().map(str::trim_end).collect();
+
+        let mut hasher = Sha256::new();
+        hasher.update(CONTENT_ID_DOMAIN);
+        hasher.update(normalized.join("\n").as_bytes());
+        Self::from_digest(hasher)
+    }
+
+    fn from_digest(hasher: Sha256) -> Self {
         let result = hasher.finalize();
 
         // Take first 8 bytes (16 hex chars)
@@ -91,6 +127,18 @@ pub enum ContentType {
     Other,
 }
 
+impl ContentType {
+    /// Detect the content type from a source URL
+    pub fn detect(url: &str) -> Self {
+        let url_lower = url.to_lowercase();
+        if url_lower.contains("youtube.com") || url_lower.contains("youtu.be") {
+            ContentType::YouTube
+        } else {
+            ContentType::Web
+        }
+    }
+}
+
 impl std::fmt::Display for ContentType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -114,6 +162,18 @@ impl std::str::FromStr for ContentType {
     }
 }
 
+/// How a content ID was derived
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdSource {
+    /// Hash of the source URL (default for web/youtube)
+    #[default]
+    Url,
+
+    /// Hash of the normalized content
+    Content,
+}
+
 /// Library content with storage operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LibraryContent {
@@ -135,6 +195,10 @@ pub struct LibraryContent {
     /// User-provided tags
     #[serde(default)]
     pub tags: Vec<String>,
+
+    /// Scheme used to derive `id`
+    #[serde(default)]
+    pub id_source: IdSource,
 }
 
 impl LibraryContent {
@@ -152,6 +216,22 @@ impl LibraryContent {
             content_type,
             processed_at: Utc::now(),
             tags: Vec::new(),
+            id_source: IdSource::Url,
+        }
+    }
+
+    /// Create library content identified by its content rather than its URL.
+    /// `url` is kept for provenance (e.g. a `file://` path) but not hashed.
+    pub fn from_content(
+        url: impl Into<String>,
+        title: impl Into<String>,
+        content_type: ContentType,
+        content: &[u8],
+    ) -> Self {
+        Self {
+            id: ContentId::from_content(content),
+            id_source: IdSource::Content,
+            ..Self::new(url, title, content_type)
         }
     }
 
@@ -189,12 +269,16 @@ impl LibraryContent {
         content_type: ContentType,
     ) -> Result<Option<PathBuf>> {
         let type_dir = config::content_type_dir(content_type)?;
+        Self::find_in_type_dir(&type_dir, id).await
+    }
 
+    /// Find content directory by content ID within a specific content-type directory
+    async fn find_in_type_dir(type_dir: &Path, id: &ContentId) -> Result<Option<PathBuf>> {
         if !type_dir.exists() {
             return Ok(None);
         }
 
-        let mut entries = fs::read_dir(&type_dir).await?;
+        let mut entries = fs::read_dir(type_dir).await?;
         let id_str = id.as_str();
 
         while let Some(entry) = entries.next_entry().await? {
@@ -313,14 +397,17 @@ impl LibraryContent {
 
     /// List all artifacts for this content
     pub async fn list_artifacts(&self) -> Result<Vec<String>> {
-        let dir = self.content_dir()?;
+        Self::list_artifacts_in(&self.content_dir()?).await
+    }
 
+    /// List the artifacts in a content directory, sorted by name
+    pub async fn list_artifacts_in(dir: &Path) -> Result<Vec<String>> {
         if !dir.exists() {
             return Ok(Vec::new());
         }
 
         let mut artifacts = Vec::new();
-        let mut entries = fs::read_dir(&dir).await?;
+        let mut entries = fs::read_dir(dir).await?;
 
         while let Some(entry) = entries.next_entry().await? {
             if let Some(name) = entry.file_name().to_str() {
@@ -330,6 +417,7 @@ impl LibraryContent {
             }
         }
 
+        artifacts.sort();
         Ok(artifacts)
     }
 
@@ -358,27 +446,79 @@ impl LibraryContent {
         Ok(legacy_path.exists())
     }
 
+    /// Deposit an artifact for `url` into the library mid-pipeline.
+    ///
+    /// Reuses the existing content dir for the URL if there is one; otherwise
+    /// creates it (titled from the URL) along with its metadata.json.
+    pub async fn deposit_artifact(url: &str, name: &str, content: &str) -> Result<PathBuf> {
+        let content_type = ContentType::detect(url);
+        let type_dir = config::content_type_dir(content_type)?;
+        Self::deposit_artifact_in(&type_dir, url, name, content).await
+    }
+
+    /// `deposit_artifact` against an explicit content-type directory
+    pub async fn deposit_artifact_in(
+        type_dir: &Path,
+        url: &str,
+        name: &str,
+        content: &str,
+    ) -> Result<PathBuf> {
+        // The name becomes a file inside the content dir; keep it there
+        if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+            anyhow::bail!("Invalid artifact name: {:?}", name);
+        }
+
+        let item = Self::new(url, title_from_url(url), ContentType::detect(url));
+
+        let dir = match Self::find_in_type_dir(type_dir, &item.id).await? {
+            Some(dir) => dir,
+            None => {
+                let dir = type_dir.join(item.folder_name());
+                fs::create_dir_all(&dir).await.with_context(|| {
+                    format!("Failed to create content directory: {}", dir.display())
+                })?;
+                let metadata = serde_json::to_string_pretty(&item)?;
+                fs::write(dir.join("metadata.json"), metadata)
+                    .await
+                    .with_context(|| format!("Failed to write metadata in {}", dir.display()))?;
+                dir
+            }
+        };
+
+        let path = dir.join(format!("{}.md", name));
+        fs::write(&path, content)
+            .await
+            .with_context(|| format!("Failed to write artifact: {}", path.display()))?;
+
+        Ok(path)
+    }
+
     /// Copy artifacts from a run to the library
     pub async fn copy_from_run(&self, run_id: uuid::Uuid) -> Result<Vec<String>> {
-        let run_artifacts_dir = crate::config::runs_dir()?
-            .join(run_id.to_string())
-            .join("artifacts");
+        let run_dir = crate::config::runs_dir()?.join(run_id.to_string());
+        let run_artifacts_dir = run_dir.join("artifacts");
+
+        // Evidence grounded by a pipeline `evidence:` block travels with the artifacts
+        let run_evidence = run_dir.join("evidence.jsonl");
+        if run_evidence.exists() {
+            let dir = self.ensure_dir().await?;
+            fs::copy(&run_evidence, dir.join("evidence.jsonl"))
+                .await
+                .context("Failed to copy run evidence into library")?;
+        }
 
         if !run_artifacts_dir.exists() {
             return Ok(Vec::new());
         }
 
+        // Resolves object references as well as inline artifacts
+        let store = crate::core::EventStore::open(run_id).await?;
         let mut copied = Vec::new();
-        let mut entries = fs::read_dir(&run_artifacts_dir).await?;
 
-        while let Some(entry) = entries.next_entry().await? {
-            if let Some(name) = entry.file_name().to_str() {
-                if name.ends_with(".md") {
-                    let artifact_name = name.trim_end_matches(".md");
-                    let content = fs::read_to_string(entry.path()).await?;
-                    self.store_artifact(artifact_name, &content).await?;
-                    copied.push(artifact_name.to_string());
-                }
+        for artifact_name in store.list_artifacts().await? {
+            if let Some(content) = store.load_artifact(&artifact_name).await? {
+                self.store_artifact(&artifact_name, &content).await?;
+                copied.push(artifact_name);
             }
         }
 
@@ -401,6 +541,38 @@ mod tests {
         assert_eq!(id1.as_str().len(), 16); // 8 bytes = 16 hex chars
     }
 
+    #[test]
+    fn test_content_id_from_content() {
+        let id1 = ContentId::from_content(b"# Notes\r\nsame text  \r\n");
+        let id2 = ContentId::from_content(b"# Notes\nsame text\n");
+        let id3 = ContentId::from_content(b"# Notes\ndifferent text\n");
+
+        assert_eq!(id1, id2);
+        assert_ne!(id1, id3);
+        assert_eq!(id1.as_str().len(), 16);
+    }
+
+    #[test]
+    fn test_content_and_url_ids_do_not_collide() {
+        let text = "https://example.com/post";
+        assert_ne!(
+            ContentId::from_content(text.as_bytes()),
+            ContentId::from_url(text)
+        );
+
+        let content =
+            LibraryContent::from_content("file:///notes/a.md", "A", ContentType::Other, b"body");
+        assert_eq!(content.id_source, IdSource::Content);
+        assert_ne!(content.id, ContentId::from_url("file:///notes/a.md"));
+    }
+
+    #[test]
+    fn test_id_source_defaults_to_url_for_old_metadata() {
+        let json = r#"{"id":"0123456789abcdef","title":"T","url":"https://x.y","content_type":"web","processed_at":"2026-01-01T00:00:00Z"}"#;
+        let content: LibraryContent = serde_json::from_str(json).unwrap();
+        assert_eq!(content.id_source, IdSource::Url);
+    }
+
     #[test]
     fn test_content_type_from_str() {
         assert_eq!(
@@ -425,4 +597,64 @@ mod tests {
         assert_eq!(content.url, "https://youtube.com/watch?v=abc");
         assert_eq!(content.content_type, ContentType::YouTube);
     }
+
+    #[test]
+    fn test_content_type_detect() {
+        assert_eq!(
+            ContentType::detect("https://youtu.be/abc"),
+            ContentType::YouTube
+        );
+        assert_eq!(
+            ContentType::detect("https://example.com/post"),
+            ContentType::Web
+        );
+    }
+
+    #[test]
+    fn test_title_from_url() {
+        assert_eq!(
+            title_from_url("https://example.com/blog/my-post?x=1"),
+            "my-post"
+        );
+        assert_eq!(title_from_url("https://example.com/"), "example.com");
+    }
+
+    #[tokio::test]
+    async fn test_deposit_artifact_creates_content_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let url = "https://example.com/articles/deep-work";
+
+        let path = LibraryContent::deposit_artifact_in(temp.path(), url, "source", "# Deep Work\n")
+            .await
+            .unwrap();
+
+        let dir = path.parent().unwrap();
+        assert!(dir.starts_with(temp.path()));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Deep Work\n");
+
+        let metadata: LibraryContent =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("metadata.json")).unwrap())
+                .unwrap();
+        assert_eq!(metadata.url, url);
+        assert_eq!(metadata.id, ContentId::from_url(url));
+
+        // A second deposit reuses the same directory
+        let second = LibraryContent::deposit_artifact_in(temp.path(), url, "summary", "short")
+            .await
+            .unwrap();
+        assert_eq!(second.parent().unwrap(), dir);
+    }
+
+    #[tokio::test]
+    async fn test_deposit_artifact_rejects_path_names() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let url = "https://example.com/articles/deep-work";
+
+        for name in ["../escape", "..", "nested/name", "nested\\name", ""] {
+            let result = LibraryContent::deposit_artifact_in(temp.path(), url, name, "x").await;
+            assert!(result.is_err(), "{:?} should be rejected", name);
+        }
+        assert!(!temp.path().parent().unwrap().join("escape.md").exists());
+        assert_eq!(std::fs::read_dir(temp.path()).unwrap().count(), 0);
+    }
 }
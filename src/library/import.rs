@@ -0,0 +1,240 @@
+//! Bulk import of existing markdown/text files into the library.
+//!
+//! Each file becomes a content dir holding `source.md` + `metadata.json` and a
+//! catalog entry. Files are deduplicated by content hash, so re-running an
+//! import over the same directory is a no-op.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use super::catalog::{Catalog, CatalogItem};
+use super::content::{ContentId, ContentType, LibraryContent};
+
+/// File extensions picked up by an import
+const IMPORT_EXTENSIONS: &[&str] = &["md", "markdown", "txt"];
+
+/// Options for a directory import
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    /// Content type for all imported files
+    pub content_type: ContentType,
+
+    /// Tags applied to every imported item
+    pub tags: Vec<String>,
+}
+
+/// Outcome of a directory import
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// Newly imported items
+    pub imported: Vec<(PathBuf, ContentId)>,
+
+    /// Files skipped because identical content was already cataloged
+    pub skipped: Vec<(PathBuf, ContentId)>,
+}
+
+/// Import every markdown/text file under `source_dir` into `type_dir`, recording
+/// each in `catalog`. The caller is responsible for saving the catalog.
+pub async fn import_directory(
+    source_dir: &Path,
+    type_dir: &Path,
+    catalog: &mut Catalog,
+    options: &ImportOptions,
+) -> Result<ImportReport> {
+    let mut report = ImportReport::default();
+
+    for path in collect_files(source_dir)? {
+        let content = fs::read_to_string(&path)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let sha256 = hex::encode(Sha256::digest(content.as_bytes()));
+
+        if let Some(existing) = catalog.find_by_content_hash(&sha256) {
+            report.skipped.push((path, existing.id.clone()));
+            continue;
+        }
+
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        let url = format!("file://{}", canonical.display());
+        let title = title_for(&path, &content);
+
+        // Local files have no real URL, so identify them by content
+        let mut item =
+            LibraryContent::from_content(&url, &title, options.content_type, content.as_bytes());
+        item.tags = options.tags.clone();
+
+        let dir = type_dir.join(item.folder_name());
+        fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("Failed to create content directory: {}", dir.display()))?;
+        fs::write(dir.join("source.md"), &content)
+            .await
+            .with_context(|| format!("Failed to write source.md in {}", dir.display()))?;
+        fs::write(
+            dir.join("metadata.json"),
+            serde_json::to_string_pretty(&item)?,
+        )
+        .await
+        .with_context(|| format!("Failed to write metadata in {}", dir.display()))?;
+
+        let mut entry = CatalogItem::new(&url, &title, options.content_type)
+            .with_tags(options.tags.clone())
+            .with_artifact("source");
+        entry.id = item.id.clone();
+        entry.content_sha256 = Some(sha256);
+        catalog.add(entry);
+
+        report.imported.push((path, item.id));
+    }
+
+    Ok(report)
+}
+
+/// Recursively collect importable files, sorted for deterministic imports
+fn collect_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let entries = std::fs::read_dir(&current)
+            .with_context(|| format!("Failed to read directory: {}", current.display()))?;
+
+        for entry in entries {
+            let path = entry?.path();
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.'));
+            if hidden {
+                continue;
+            }
+
+            if path.is_dir() {
+                pending.push(path);
+            } else if path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| IMPORT_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+            {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// Title from the first markdown heading, falling back to the file stem
+fn title_for(path: &Path, content: &str) -> String {
+    content
+        .lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix('#'))
+        .map(|heading| heading.trim_start_matches('#').trim().to_string())
+        .filter(|heading| !heading.is_empty())
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("Untitled")
+                .to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn options() -> ImportOptions {
+        ImportOptions {
+            content_type: ContentType::Web,
+            tags: vec!["imported".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_title_for_prefers_heading() {
+        let path = Path::new("/notes/some-file.md");
+        assert_eq!(title_for(path, "intro\n## Real Title\nbody"), "Real Title");
+        assert_eq!(title_for(path, "no heading here"), "some-file");
+    }
+
+    #[tokio::test]
+    async fn test_import_directory_creates_catalog_entries() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        std::fs::write(source.path().join("a.md"), "# First Talk\nhello").unwrap();
+        std::fs::create_dir(source.path().join("nested")).unwrap();
+        std::fs::write(source.path().join("nested/b.md"), "second transcript").unwrap();
+        std::fs::write(source.path().join("ignored.json"), "{}").unwrap();
+
+        let mut catalog = Catalog::new();
+        let report = import_directory(source.path(), library.path(), &mut catalog, &options())
+            .await
+            .unwrap();
+
+        assert_eq!(report.imported.len(), 2);
+        assert!(report.skipped.is_empty());
+        assert_eq!(catalog.len(), 2);
+
+        let first = catalog.search("First Talk");
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].content_type, ContentType::Web);
+        assert_eq!(first[0].artifacts, vec!["source".to_string()]);
+        assert!(first[0].tags.contains(&"imported".to_string()));
+        assert!(first[0].url.starts_with("file://"));
+
+        assert!(catalog.items.iter().any(|i| i.title == "b"));
+
+        // Content dirs hold source.md + metadata.json
+        let dirs: Vec<_> = std::fs::read_dir(library.path()).unwrap().collect();
+        assert_eq!(dirs.len(), 2);
+        for dir in dirs {
+            let dir = dir.unwrap().path();
+            assert!(dir.join("source.md").exists());
+            assert!(dir.join("metadata.json").exists());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_directory_uses_content_ids() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        std::fs::write(source.path().join("a.md"), "# Title\nbody").unwrap();
+
+        let mut catalog = Catalog::new();
+        let report = import_directory(source.path(), library.path(), &mut catalog, &options())
+            .await
+            .unwrap();
+
+        let id = &report.imported[0].1;
+        assert_eq!(*id, ContentId::from_content(b"# Title\nbody"));
+        assert!(catalog.get(id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_import_directory_is_idempotent() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        std::fs::write(source.path().join("a.md"), "same content").unwrap();
+
+        let mut catalog = Catalog::new();
+        import_directory(source.path(), library.path(), &mut catalog, &options())
+            .await
+            .unwrap();
+
+        // Same content under another name is deduplicated by hash
+        std::fs::write(source.path().join("copy.md"), "same content").unwrap();
+        let report = import_directory(source.path(), library.path(), &mut catalog, &options())
+            .await
+            .unwrap();
+
+        assert!(report.imported.is_empty());
+        assert_eq!(report.skipped.len(), 2);
+        assert_eq!(catalog.len(), 1);
+    }
+}
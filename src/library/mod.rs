@@ -18,6 +18,8 @@
 
 pub mod catalog;
 pub mod content;
+pub mod import;
 
 pub use catalog::{Catalog, CatalogItem};
-pub use content::{ContentId, ContentType, LibraryContent};
+pub use content::{ContentId, ContentType, IdSource, LibraryContent};
+pub use import::{import_directory, ImportOptions, ImportReport};
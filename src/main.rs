@@ -2,19 +2,74 @@
 
 use anyhow::Result;
 use clap::Parser;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 
-use arkai::cli::Cli;
+use arkai::cli::{exit_code, Cli};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize tracing
+    // Usage errors get their own code; --help and --version still exit 0
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(error) => {
+            let _ = error.print();
+            std::process::exit(if error.use_stderr() {
+                exit_code::USAGE
+            } else {
+                exit_code::SUCCESS
+            });
+        }
+    };
+
+    // Initialize tracing (--quiet silences logs unless RUST_LOG asks for them;
+    // exported spans are filtered separately, so --quiet doesn't stop them)
+    let default_level = if cli.quiet { "off" } else { "info" };
+    let filter =
+        |default| EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default));
     tracing_subscriber::registry()
-        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
-        .with(tracing_subscriber::fmt::layer().with_target(false))
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_target(false)
+                .with_filter(filter(default_level)),
+        )
+        .with(otel_layer()?.map(|layer| layer.with_filter(filter("info"))))
         .init();
 
-    // Parse and execute CLI
-    let cli = Cli::parse();
-    cli.execute().await
+    // Execute CLI, flushing exported spans before exiting with its code
+    let result = cli.execute().await;
+    #[cfg(feature = "otel")]
+    arkai::telemetry::shutdown();
+
+    match result {
+        Ok(exit_code::SUCCESS) => Ok(()),
+        Ok(code) => std::process::exit(code),
+        Err(error) => {
+            // Same rendering as returning the error from main, with a specific code
+            eprintln!("Error: {:?}", error);
+            std::process::exit(exit_code::for_error(&error));
+        }
+    }
+}
+
+/// The OTLP export layer, when `ARKAI_OTLP_ENDPOINT` is set
+#[cfg(feature = "otel")]
+fn otel_layer<S>() -> Result<Option<impl Layer<S>>>
+where
+    S: tracing::Subscriber + for<'span> tracing_subscriber::registry::LookupSpan<'span>,
+{
+    match arkai::telemetry::otlp_endpoint() {
+        Some(endpoint) => Ok(Some(arkai::telemetry::layer(&endpoint)?)),
+        None => Ok(None),
+    }
+}
+
+/// Without the `otel` feature there is nothing to export to
+#[cfg(not(feature = "otel"))]
+fn otel_layer() -> Result<Option<tracing_subscriber::layer::Identity>> {
+    if std::env::var_os("ARKAI_OTLP_ENDPOINT").is_some() {
+        eprintln!(
+            "Warning: ARKAI_OTLP_ENDPOINT is set, but arkai was built without the otel feature"
+        );
+    }
+    Ok(None)
 }
@@ -230,6 +230,12 @@ pub fn import_library_metadata(store: &Store, library_path: &Path) -> Result<Imp
     Ok(stats)
 }
 
+/// Import one library content directory (holding a metadata.json) into the
+/// store, whatever its content type.
+pub fn import_library_dir(store: &Store, dir: &Path) -> Result<()> {
+    import_single_library_dir(store, dir, &dir.join("metadata.json"))
+}
+
 /// Import a single library directory into the store.
 fn import_single_library_dir(store: &Store, dir: &Path, metadata_path: &Path) -> Result<()> {
     let raw = fs::read_to_string(metadata_path)
@@ -0,0 +1,101 @@
+//! OpenTelemetry export for tracing spans (`otel` feature).
+//!
+//! When `ARKAI_OTLP_ENDPOINT` is set, `arkai` adds a layer that exports its
+//! spans (runs, steps and the `#[instrument]`ed calls inside them) over
+//! OTLP/HTTP, so a run shows up as one trace in Jaeger, Tempo or any other
+//! OTLP collector:
+//!
+//! ```bash
+//! ARKAI_OTLP_ENDPOINT=http://localhost:4318 arkai run hello -i input.txt
+//! ```
+//!
+//! Spans are sent to `<endpoint>/v1/traces`, like the base URL in
+//! `OTEL_EXPORTER_OTLP_ENDPOINT`. The standard `OTEL_EXPORTER_OTLP_*`
+//! variables (headers, timeout, a signal-specific endpoint) and
+//! `OTEL_SERVICE_NAME` are honoured; the service name defaults to `arkai`.
+
+use anyhow::Context;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{self as sdktrace, Tracer};
+use opentelemetry_sdk::{runtime, Resource};
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::error::Result;
+
+/// Environment variable holding the OTLP collector URL
+pub const OTLP_ENDPOINT_ENV: &str = "ARKAI_OTLP_ENDPOINT";
+
+/// Service name reported when `OTEL_SERVICE_NAME` isn't set
+pub const DEFAULT_SERVICE_NAME: &str = "arkai";
+
+/// The URL spans are posted to for a collector at `endpoint`
+pub fn traces_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim().trim_end_matches('/');
+    if endpoint.ends_with("/v1/traces") {
+        endpoint.to_string()
+    } else {
+        format!("{}/v1/traces", endpoint)
+    }
+}
+
+/// The configured collector URL, if export is turned on
+pub fn otlp_endpoint() -> Option<String> {
+    std::env::var(OTLP_ENDPOINT_ENV)
+        .ok()
+        .filter(|endpoint| !endpoint.trim().is_empty())
+}
+
+/// A layer exporting spans to the collector at `endpoint`, batched in the
+/// background. Must be called inside a Tokio runtime; call [`shutdown`]
+/// before exiting so the last batch is sent.
+pub fn layer<S>(endpoint: &str) -> Result<OpenTelemetryLayer<S, Tracer>>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let service_name =
+        std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| DEFAULT_SERVICE_NAME.to_string());
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .http()
+                .with_endpoint(traces_url(endpoint)),
+        )
+        .with_trace_config(
+            sdktrace::config()
+                .with_resource(Resource::new([KeyValue::new("service.name", service_name)])),
+        )
+        .install_batch(runtime::Tokio)
+        .with_context(|| format!("Failed to set up OTLP export to {}", endpoint))?;
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Send any spans still queued and stop exporting
+pub fn shutdown() {
+    opentelemetry::global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_traces_url_appends_signal_path_once() {
+        assert_eq!(
+            traces_url("http://localhost:4318"),
+            "http://localhost:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_url("http://localhost:4318/"),
+            "http://localhost:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_url("https://tempo.example/otlp/v1/traces"),
+            "https://tempo.example/otlp/v1/traces"
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Artifact Deduplication Integration Tests
+//!
+//! Tests that runs producing identical output share one content-addressed
+//! object.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::RunState;
+
+const PIPELINE_YAML: &str = r#"
+name: artifact_dedup_test
+description: Echo the input so equal inputs give equal artifacts
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+"#;
+
+#[tokio::test]
+async fn test_identical_runs_share_one_object() {
+    let home = common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let orchestrator = Orchestrator::new();
+    let mut run_ids = Vec::new();
+    for _ in 0..2 {
+        let run = orchestrator
+            .run_pipeline(&pipeline, "the same transcript".to_string())
+            .await
+            .unwrap();
+        assert_eq!(run.state, RunState::Completed);
+        run_ids.push(run.id);
+    }
+    assert_ne!(run_ids[0], run_ids[1]);
+
+    // One object, referenced from both run directories
+    let objects: Vec<_> = std::fs::read_dir(home.join("objects"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(objects.len(), 1);
+
+    for run_id in &run_ids {
+        let store = EventStore::open(*run_id).await.unwrap();
+        assert!(store.artifacts_dir().join("echo.ref").exists());
+        assert!(!store.artifacts_dir().join("echo.md").exists());
+        assert_eq!(
+            orchestrator
+                .load_run_output(*run_id, Some("echo"))
+                .await
+                .unwrap(),
+            "the same transcript"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Artifact Name Integration Tests
+//!
+//! Tests for storing a step's artifact under a custom `artifact_name`.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use arkai::library::ContentType;
+
+const PIPELINE_YAML: &str = r#"
+name: artifact_name_test
+description: A step whose artifact is named independently of the step
+steps:
+  - name: extract_wisdom
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+    artifact_name: wisdom
+  - name: shout
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: extract_wisdom
+"#;
+
+const LIBRARY_PIPELINE_YAML: &str = r#"
+name: artifact_name_library_test
+description: Deposit a step input into the library under a custom name
+steps:
+  - name: store_wisdom
+    adapter: fabric
+    action: __library_store__
+    input_from: pipeline_input
+    artifact_name: wisdom
+"#;
+
+#[tokio::test]
+async fn test_custom_artifact_name_is_stored_and_addressable_by_step() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let orchestrator = Orchestrator::new();
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    // Stored under the custom name, not the step name
+    let store = EventStore::open(run.id).await.unwrap();
+    let artifacts_dir = store.artifacts_dir();
+    assert!(artifacts_dir.join("wisdom.ref").exists());
+    assert!(!artifacts_dir.join("extract_wisdom.ref").exists());
+    assert!(artifacts_dir.join("shout.ref").exists());
+
+    // Still keyed and loadable by step name
+    assert_eq!(run.artifacts["extract_wisdom"].content, "hello");
+    assert_eq!(run.artifacts["shout"].content, "HELLO");
+    let output = orchestrator
+        .load_run_output(run.id, Some("extract_wisdom"))
+        .await
+        .unwrap();
+    assert_eq!(output, "hello");
+}
+
+#[tokio::test]
+async fn test_library_store_deposits_under_artifact_name() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(LIBRARY_PIPELINE_YAML).unwrap();
+    let url = "https://example.com/articles/artifact-name";
+    let run = Orchestrator::new()
+        .run_pipeline(&pipeline, url.to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let type_dir = arkai::config::content_type_dir(ContentType::detect(url)).unwrap();
+    let content_dir = std::fs::read_dir(type_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|dir| dir.join("wisdom.md").exists())
+        .expect("no content dir with wisdom.md");
+    assert_eq!(
+        std::fs::read_to_string(content_dir.join("wisdom.md")).unwrap(),
+        url
+    );
+    assert!(!content_dir.join("store_wisdom.md").exists());
+}
@@ -0,0 +1,105 @@
+//! Batch Run Integration Tests
+//!
+//! Tests for running one pipeline over a directory of input files.
+
+mod common;
+
+use std::path::Path;
+
+use arkai::cli::batch::{collect_inputs, run_batch, BatchManifest, EntryState};
+use arkai::core::Pipeline;
+use tempfile::TempDir;
+
+const PIPELINE_YAML: &str = r#"
+name: batch_test
+description: Echo each input back, failing on inputs containing FAIL
+
+steps:
+  - name: echo
+    adapter: shell
+    action: grep -v FAIL
+    input_from: pipeline_input
+    retry_policy:
+      max_attempts: 1
+"#;
+
+fn event_count(run_id: uuid::Uuid) -> usize {
+    let events = arkai::config::runs_dir()
+        .unwrap()
+        .join(run_id.to_string())
+        .join("events.jsonl");
+    std::fs::read_to_string(events).unwrap().lines().count()
+}
+
+fn write_inputs(dir: &Path, second: &str) {
+    std::fs::write(dir.join("one.md"), "first transcript").unwrap();
+    std::fs::write(dir.join("two.md"), second).unwrap();
+    std::fs::write(dir.join("skip.txt"), "not matched").unwrap();
+}
+
+#[tokio::test]
+async fn test_batch_runs_each_file() {
+    common::init_home();
+
+    let inputs = TempDir::new().unwrap();
+    write_inputs(inputs.path(), "second transcript");
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let files = collect_inputs(inputs.path(), "*.md").unwrap();
+    let manifest = run_batch(&pipeline, BatchManifest::new("batch_test", files), 2)
+        .await
+        .unwrap();
+
+    assert_eq!(manifest.entries.len(), 2);
+    assert!(manifest.entries.iter().all(|e| e.is_completed()));
+    assert!(manifest.entries[0].path.ends_with("one.md"));
+    assert!(manifest.entries[1].path.ends_with("two.md"));
+
+    // Each file gets its own run
+    let run_ids: Vec<_> = manifest.entries.iter().map(|e| e.run_id.unwrap()).collect();
+    assert_ne!(run_ids[0], run_ids[1]);
+    for run_id in run_ids {
+        assert!(event_count(run_id) > 0);
+    }
+
+    // Progress is persisted alongside the runs
+    let saved = BatchManifest::load(manifest.id).unwrap();
+    assert!(saved.entries.iter().all(|e| e.is_completed()));
+}
+
+#[tokio::test]
+async fn test_resume_batch_skips_completed_files() {
+    common::init_home();
+
+    let inputs = TempDir::new().unwrap();
+    write_inputs(inputs.path(), "FAIL");
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let files = collect_inputs(inputs.path(), "*.md").unwrap();
+    let first = run_batch(&pipeline, BatchManifest::new("batch_test", files), 1)
+        .await
+        .unwrap();
+
+    assert!(first.entries[0].is_completed());
+    assert!(matches!(first.entries[1].state, EntryState::Failed { .. }));
+    let completed_run = first.entries[0].run_id.unwrap();
+    let failed_run = first.entries[1].run_id.unwrap();
+    let completed_events = event_count(completed_run);
+
+    // Simulate the batch being killed while the second file was running
+    let mut interrupted = BatchManifest::load(first.id).unwrap();
+    interrupted.entries[1].state = EntryState::Running;
+    interrupted.save().unwrap();
+
+    std::fs::write(inputs.path().join("two.md"), "second transcript").unwrap();
+    let resumed = run_batch(&pipeline, BatchManifest::load(first.id).unwrap(), 1)
+        .await
+        .unwrap();
+
+    assert!(resumed.entries.iter().all(|e| e.is_completed()));
+
+    // The completed file was skipped; the other resumed under its original run
+    assert_eq!(resumed.entries[0].run_id, Some(completed_run));
+    assert_eq!(event_count(completed_run), completed_events);
+    assert_eq!(resumed.entries[1].run_id, Some(failed_run));
+}
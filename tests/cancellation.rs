@@ -0,0 +1,88 @@
+//! Cancellation Integration Tests
+//!
+//! Tests for cancelling a run programmatically with a `CancellationToken`.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState};
+use tokio_util::sync::CancellationToken;
+
+const PIPELINE_YAML: &str = r#"
+name: cancel_test
+description: A fast step, a slow step, then another fast step
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: slow
+    adapter: shell
+    action: sleep 10; cat
+    input_from:
+      previous_step: first
+  - name: last
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: slow
+"#;
+
+#[tokio::test]
+async fn test_cancel_mid_run_stops_further_steps() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let token = CancellationToken::new();
+    let orchestrator = Orchestrator::new().with_cancellation(token.clone());
+
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        token.cancel();
+    });
+
+    let started = Instant::now();
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    canceller.await.unwrap();
+
+    // The in-flight slow step was interrupted rather than waited out
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(run.state, RunState::Cancelled);
+    assert!(run.artifacts.contains_key("first"));
+    assert!(!run.artifacts.contains_key("slow"));
+    assert!(!run.step_statuses.contains_key("last"));
+
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    assert_eq!(events.last().unwrap().event_type, EventType::RunCancelled);
+    assert!(!events.iter().any(|e| e.step_id.as_deref() == Some("last")));
+
+    // Replayed state matches
+    let status = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(status.state, RunState::Cancelled);
+}
+
+#[tokio::test]
+async fn test_cancel_before_start_runs_nothing() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let token = CancellationToken::new();
+    token.cancel();
+    let run = Orchestrator::new()
+        .with_cancellation(token)
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Cancelled);
+    assert!(run.artifacts.is_empty());
+}
@@ -0,0 +1,142 @@
+//! Circuit Breaker Integration Tests
+//!
+//! Tests that repeated Fabric failures open the circuit, that steps then
+//! fail fast without calling Fabric, and that the circuit closes again once
+//! Fabric recovers.
+
+mod common;
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput, CircuitBreaker, CircuitState};
+use arkai::core::{AdapterType, Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use async_trait::async_trait;
+
+/// Stands in for Fabric, failing while `down` is set
+struct FlakyFabric {
+    down: Arc<AtomicBool>,
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Adapter for FlakyFabric {
+    fn name(&self) -> &str {
+        "flaky-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.down.load(Ordering::SeqCst) {
+            anyhow::bail!("provider unavailable");
+        }
+        Ok(AdapterOutput::new(input.to_string()))
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: circuit_breaker_test
+description: One Fabric step with retries
+steps:
+  - name: summarize
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    retry_policy:
+      max_attempts: 5
+      initial_delay_ms: 10
+      max_delay_ms: 10
+"#;
+
+/// An orchestrator whose Fabric is down, with a breaker that opens after
+/// three failures and cools down after 300ms
+fn flaky_orchestrator() -> (
+    Orchestrator,
+    Arc<AtomicBool>,
+    Arc<AtomicUsize>,
+    Arc<CircuitBreaker>,
+) {
+    common::init_home();
+
+    let down = Arc::new(AtomicBool::new(true));
+    let calls = Arc::new(AtomicUsize::new(0));
+    let breaker = Arc::new(CircuitBreaker::new("fabric", 3, Duration::from_millis(300)));
+    let orchestrator = Orchestrator::new()
+        .with_fabric_adapter(FlakyFabric {
+            down: down.clone(),
+            calls: calls.clone(),
+        })
+        .with_circuit_breaker(AdapterType::Fabric, Some(breaker.clone()));
+    (orchestrator, down, calls, breaker)
+}
+
+#[tokio::test]
+async fn test_repeated_failures_trip_breaker() {
+    let (orchestrator, _down, calls, _breaker) = flaky_orchestrator();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+
+    // Three failed attempts open the circuit; the remaining retries are skipped
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(run.state, RunState::Failed { .. }));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        orchestrator.circuit_state(AdapterType::Fabric),
+        Some(CircuitState::Open)
+    );
+}
+
+#[tokio::test]
+async fn test_open_breaker_fails_fast() {
+    let (orchestrator, _down, calls, _breaker) = flaky_orchestrator();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    // While open, the next run fails fast without calling Fabric
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    match run.state {
+        RunState::Failed { ref error } => assert!(error.contains("Circuit open"), "{}", error),
+        ref state => panic!("Expected Failed, got {:?}", state),
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_breaker_closes_after_cooldown() {
+    let (orchestrator, down, calls, breaker) = flaky_orchestrator();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    // After the cooldown a trial call goes through and closes the circuit
+    down.store(false, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+}
@@ -0,0 +1,68 @@
+//! Shared setup for the integration tests
+
+// Each test binary uses only some of these
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::OnceLock;
+
+use tempfile::TempDir;
+
+/// A one-step pipeline that echoes its input back
+pub const ECHO_PIPELINE: &str = r#"
+name: echo
+description: Echo the input back
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+"#;
+
+/// Point ARKAI_HOME at a temp dir shared by every test in this binary.
+///
+/// Config reads ARKAI_HOME once, so this runs before any test touches it
+/// and every test in the binary sees the same home.
+pub fn init_home() -> &'static Path {
+    static HOME: OnceLock<PathBuf> = OnceLock::new();
+    HOME.get_or_init(|| {
+        let home = TempDir::new().unwrap().keep();
+        std::env::set_var("ARKAI_HOME", &home);
+        home
+    })
+}
+
+/// A temp project dir with each `(name, yaml)` written to pipelines/<name>.yaml
+pub fn project_with_pipelines(pipelines: &[(&str, &str)]) -> TempDir {
+    let project = TempDir::new().unwrap();
+    let dir = project.path().join("pipelines");
+    std::fs::create_dir_all(&dir).unwrap();
+    for (name, yaml) in pipelines {
+        std::fs::write(dir.join(format!("{}.yaml", name)), yaml).unwrap();
+    }
+    project
+}
+
+/// Run the `arkai` binary in `project`, with its home under the project
+pub fn run_arkai(project: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_arkai"))
+        .args(args)
+        .current_dir(project)
+        .env("ARKAI_HOME", project.join(".arkai-home"))
+        .env_remove("RUST_LOG")
+        .output()
+        .expect("failed to run arkai")
+}
+
+/// Stdout of a successful run, parsed as one JSON document
+pub fn stdout_json(output: &Output) -> serde_json::Value {
+    assert!(output.status.success(), "{:?}", output);
+    serde_json::from_slice(&output.stdout).expect("stdout is one JSON document")
+}
+
+/// Stdout of a successful run
+pub fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
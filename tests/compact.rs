@@ -0,0 +1,56 @@
+//! Event Log Compaction Integration Tests
+//!
+//! Runs the `arkai` binary, compacts the runs one at a time and with
+//! `--all`, and checks that compacted runs still read back the same.
+
+mod common;
+
+use common::{project_with_pipelines, run_arkai, stdout, stdout_json, ECHO_PIPELINE};
+
+#[test]
+fn test_compacted_runs_read_back_the_same() {
+    let project = project_with_pipelines(&[("echo", ECHO_PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "hello").unwrap();
+    let runs_dir = project.path().join(".arkai-home").join("runs");
+
+    for _ in 0..2 {
+        let run = run_arkai(project.path(), &["run", "echo", "-i", "input.txt"]);
+        assert!(run.status.success(), "{:?}", run);
+    }
+    let before = stdout_json(&run_arkai(project.path(), &["--json", "runs"]));
+    let run_ids: Vec<String> = before
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|run| run["id"].as_str().unwrap().to_string())
+        .collect();
+    let statuses: Vec<_> = run_ids
+        .iter()
+        .map(|id| stdout_json(&run_arkai(project.path(), &["--json", "status", id])))
+        .collect();
+
+    let compacted = stdout(&run_arkai(project.path(), &["compact", &run_ids[0]]));
+    assert!(compacted.contains("events into snapshot"), "{}", compacted);
+    let run_dir = runs_dir.join(&run_ids[0]);
+    assert!(run_dir.join("snapshot.json").exists());
+    assert_eq!(
+        std::fs::read_to_string(run_dir.join("events.jsonl")).unwrap(),
+        ""
+    );
+
+    // The already-compacted run has nothing left to fold in
+    let all = stdout(&run_arkai(project.path(), &["compact", "--all"]));
+    assert!(all.contains("1 runs compacted, 0 skipped"), "{}", all);
+    assert!(runs_dir.join(&run_ids[1]).join("snapshot.json").exists());
+
+    for (id, status) in run_ids.iter().zip(&statuses) {
+        let after = stdout_json(&run_arkai(project.path(), &["--json", "status", id]));
+        assert_eq!(&after, status);
+        stdout(&run_arkai(project.path(), &["verify", id]));
+    }
+    let after = stdout_json(&run_arkai(
+        project.path(),
+        &["--json", "runs", "--rebuild-index"],
+    ));
+    assert_eq!(after, before);
+}
@@ -0,0 +1,74 @@
+//! Continue-on-error Integration Tests
+//!
+//! Tests for optional steps whose failure leaves a run `PartiallyCompleted`.
+
+mod common;
+
+use arkai::core::{Orchestrator, Pipeline};
+use arkai::domain::RunState;
+
+const PIPELINE_YAML: &str = r#"
+name: partial_test
+description: One optional step fails, the rest succeed
+
+steps:
+  - name: fetch
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+
+  - name: optional
+    adapter: shell
+    action: "false"
+    input_from:
+      previous_step: fetch
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 1
+
+  - name: summary
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: fetch
+"#;
+
+#[tokio::test]
+async fn test_failed_optional_step_ends_partially_completed() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    pipeline.validate().unwrap();
+
+    let run = orchestrator
+        .run_pipeline(&pipeline, "usable output".to_string())
+        .await
+        .unwrap();
+
+    let expected = RunState::PartiallyCompleted {
+        failed_steps: vec!["optional".to_string()],
+    };
+    assert_eq!(run.state, expected);
+    assert_eq!(run.artifacts["summary"].content.trim(), "USABLE OUTPUT");
+    assert!(!run.artifacts.contains_key("optional"));
+
+    // Reconstructed from the event log
+    let status = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(status.state, expected);
+}
+
+#[tokio::test]
+async fn test_failed_required_step_fails_run() {
+    common::init_home();
+
+    // Without continue_on_error the same failure fails the run
+    let orchestrator = Orchestrator::new();
+    let strict =
+        Pipeline::from_yaml(&PIPELINE_YAML.replace("continue_on_error: true", "")).unwrap();
+    let run = orchestrator
+        .run_pipeline(&strict, "usable output".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(run.state, RunState::Failed { .. }));
+}
@@ -0,0 +1,109 @@
+//! Cost Budget Integration Tests
+//!
+//! Tests that a run stops at `safety_limits.max_total_cost_usd` once the
+//! cost reported by its adapter calls reaches the budget.
+
+mod common;
+
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use async_trait::async_trait;
+
+/// Stands in for Fabric, reporting a fixed cost and token count per call
+struct BilledFabric;
+
+#[async_trait]
+impl Adapter for BilledFabric {
+    fn name(&self) -> &str {
+        "billed-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        Ok(AdapterOutput {
+            content: input.to_string(),
+            tokens_used: Some(500),
+            cost_usd: Some(0.40),
+        })
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: cost_budget_test
+description: Three Fabric steps under a budget that covers two
+safety_limits:
+  max_total_cost_usd: 0.75
+steps:
+  - name: first
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: second
+    adapter: fabric
+    action: summarize
+    input_from:
+      previous_step: first
+  - name: third
+    adapter: fabric
+    action: summarize
+    input_from:
+      previous_step: second
+"#;
+
+#[tokio::test]
+async fn test_run_stops_when_cost_budget_is_spent() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(BilledFabric)
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    match run.state {
+        RunState::SafetyLimitReached { ref limit } => {
+            assert!(limit.contains("Cost budget exceeded"), "limit: {}", limit)
+        }
+        ref state => panic!("Expected SafetyLimitReached, got {:?}", state),
+    }
+    assert!(run.artifacts.contains_key("second"));
+    assert!(!run.artifacts.contains_key("third"));
+}
+
+#[tokio::test]
+async fn test_resume_counts_what_finished_steps_spent() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let orchestrator = Orchestrator::new().with_fabric_adapter(BilledFabric);
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(run.state, RunState::SafetyLimitReached { .. }));
+
+    // The budget is still spent, so the resumed run doesn't reach the third step
+    let resumed = orchestrator
+        .resume_run(run.id, &pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    match resumed.state {
+        RunState::SafetyLimitReached { ref limit } => {
+            assert!(limit.contains("Cost budget exceeded"), "limit: {}", limit)
+        }
+        ref state => panic!("Expected SafetyLimitReached, got {:?}", state),
+    }
+    assert!(!resumed.artifacts.contains_key("third"));
+}
@@ -0,0 +1,97 @@
+//! Disabled Step Integration Tests
+//!
+//! Tests for skipping steps with `enabled: false`.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState, StepStatus};
+
+const SKIPPED_YAML: &str = r#"
+name: disabled_step_test
+description: A disabled step between two enabled ones
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: draft
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: first
+    enabled: false
+  - name: last
+    adapter: shell
+    action: rev
+    input_from:
+      previous_step: first
+"#;
+
+const REFERENCED_YAML: &str = r#"
+name: disabled_reference_test
+description: A step taking input from a disabled step
+steps:
+  - name: draft
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+    enabled: false
+  - name: polish
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: draft
+"#;
+
+#[tokio::test]
+async fn test_disabled_step_is_skipped() {
+    common::init_home();
+    let orchestrator = Orchestrator::new();
+
+    // Skipped entirely: no artifact, recorded as Skipped
+    let pipeline = Pipeline::from_yaml(SKIPPED_YAML).unwrap();
+    let run = orchestrator
+        .run_pipeline(&pipeline, "abc".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+    assert!(!run.artifacts.contains_key("draft"));
+    assert_eq!(run.artifacts["last"].content, "cba");
+
+    let replayed = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(replayed.step_statuses["draft"], StepStatus::Skipped);
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    assert!(events.iter().any(|e| {
+        e.event_type == EventType::StepSkipped && e.step_id.as_deref() == Some("draft")
+    }));
+    assert!(!events.iter().any(|e| {
+        e.event_type == EventType::StepStarted && e.step_id.as_deref() == Some("draft")
+    }));
+}
+
+#[tokio::test]
+async fn test_reference_to_disabled_step_fails() {
+    common::init_home();
+    let orchestrator = Orchestrator::new();
+
+    // A step that needs the disabled step's output fails clearly
+    let pipeline = Pipeline::from_yaml(REFERENCED_YAML).unwrap();
+    let run = orchestrator
+        .run_pipeline(&pipeline, "abc".to_string())
+        .await
+        .unwrap();
+    match run.state {
+        RunState::Failed { error } => assert!(
+            error.contains("Step 'polish' takes its input from step 'draft', which is disabled"),
+            "{}",
+            error
+        ),
+        state => panic!("expected failure, got {:?}", state),
+    }
+}
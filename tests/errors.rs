@@ -0,0 +1,86 @@
+//! Library Error Integration Tests
+//!
+//! Tests that the core public APIs return matchable `ArkaiError` variants.
+
+mod common;
+
+use std::path::Path;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline, SafetyViolation};
+use arkai::ArkaiError;
+use uuid::Uuid;
+
+const PIPELINE_YAML: &str = r#"
+name: errors_test
+description: One shell step with a tiny input limit
+safety_limits:
+  max_input_bytes: 16
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+"#;
+
+#[tokio::test]
+async fn test_pipeline_loading_errors() {
+    common::init_home();
+
+    let missing = Pipeline::from_file(Path::new("pipelines/does-not-exist.yaml"));
+    assert!(matches!(missing, Err(ArkaiError::PipelineNotFound { .. })));
+    assert!(matches!(
+        Pipeline::from_yaml("steps: [unterminated"),
+        Err(ArkaiError::PipelineParse(_))
+    ));
+    let duplicate = Pipeline::from_yaml(&PIPELINE_YAML.replace(
+        "steps:\n",
+        "steps:\n  - name: echo\n    adapter: shell\n    action: cat\n    input_from: pipeline_input\n",
+    ))
+    .unwrap();
+    assert!(matches!(
+        duplicate.validate(),
+        Err(ArkaiError::InvalidPipeline(msg)) if msg.contains("Duplicate step name")
+    ));
+}
+
+#[tokio::test]
+async fn test_safety_limit_error() {
+    common::init_home();
+
+    // Safety limits surface as their own variant
+    let orchestrator = Orchestrator::new();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let too_big = orchestrator
+        .run_pipeline(&pipeline, "x".repeat(64))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        too_big,
+        ArkaiError::Safety(SafetyViolation::MaxInputBytes { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_unknown_run_and_step_errors() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    assert!(matches!(
+        orchestrator.get_run_status(Uuid::new_v4()).await,
+        Err(ArkaiError::RunNotFound(_))
+    ));
+    assert!(matches!(
+        EventStore::resolve_run_id("zzzz").await,
+        Err(ArkaiError::RunNotFound(_))
+    ));
+
+    let run = orchestrator
+        .run_pipeline(&pipeline, "small".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(
+        orchestrator.load_run_output(run.id, Some("nope")).await,
+        Err(ArkaiError::StepNotFound(_))
+    ));
+}
@@ -0,0 +1,97 @@
+//! Exit Code Integration Tests
+//!
+//! Runs the `arkai` binary and checks that distinct failures map to distinct
+//! exit codes.
+
+mod common;
+
+use std::path::Path;
+
+use arkai::cli::exit_code;
+use arkai::domain::RunState;
+use common::project_with_pipelines;
+
+const FAILING_PIPELINE: &str = r#"
+name: failing
+description: A single step that always fails
+steps:
+  - name: fail
+    adapter: shell
+    action: "false"
+    input_from: pipeline_input
+    retry_policy:
+      max_attempts: 1
+"#;
+
+const LIMITED_PIPELINE: &str = r#"
+name: limited
+description: Two steps with room for only one
+safety_limits:
+  max_steps: 1
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: second
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: first
+"#;
+
+fn run_arkai(project: &Path, args: &[&str]) -> i32 {
+    common::run_arkai(project, args)
+        .status
+        .code()
+        .expect("arkai was killed by a signal")
+}
+
+#[test]
+fn test_exit_codes_distinguish_failures() {
+    let project =
+        project_with_pipelines(&[("failing", FAILING_PIPELINE), ("limited", LIMITED_PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "some input").unwrap();
+    std::fs::write(project.path().join("empty.txt"), "  \n").unwrap();
+
+    let failed = run_arkai(project.path(), &["run", "failing", "-i", "input.txt"]);
+    let limited = run_arkai(project.path(), &["run", "limited", "-i", "input.txt"]);
+
+    assert_eq!(failed, exit_code::RUN_FAILED);
+    assert_eq!(limited, exit_code::SAFETY_LIMIT);
+    assert_ne!(failed, limited);
+
+    assert_eq!(
+        run_arkai(project.path(), &["run", "missing", "-i", "input.txt"]),
+        exit_code::PIPELINE_NOT_FOUND
+    );
+    assert_eq!(
+        run_arkai(project.path(), &["run", "failing", "-i", "empty.txt"]),
+        exit_code::INVALID_INPUT
+    );
+
+    // A typo isn't mistaken for a failed run
+    assert_eq!(
+        run_arkai(project.path(), &["run", "failing", "--no-such-flag"]),
+        exit_code::USAGE
+    );
+    assert_eq!(run_arkai(project.path(), &["--help"]), exit_code::SUCCESS);
+}
+
+#[test]
+fn test_unfinished_run_does_not_exit_successfully() {
+    for state in [RunState::Running, RunState::Paused, RunState::Interrupted] {
+        assert_eq!(
+            exit_code::for_state(&state),
+            exit_code::ERROR,
+            "{:?}",
+            state
+        );
+    }
+    assert_eq!(
+        exit_code::for_state(&RunState::PartiallyCompleted {
+            failed_steps: vec!["optional".to_string()]
+        }),
+        exit_code::SUCCESS
+    );
+}
@@ -0,0 +1,109 @@
+//! Status Follow Integration Tests
+//!
+//! Tests for `arkai status --follow` tailing a run's event log.
+
+mod common;
+
+use std::time::Duration;
+
+use arkai::cli::follow::follow_run;
+use arkai::core::EventStore;
+use arkai::domain::{Event, EventType, RunState, StepStatus};
+use uuid::Uuid;
+
+fn event(run_id: Uuid, step: Option<&str>, event_type: EventType, summary: &str) -> Event {
+    let status = match event_type {
+        EventType::RunCompleted | EventType::StepCompleted => StepStatus::Completed,
+        _ => StepStatus::Running,
+    };
+    Event::new(
+        run_id,
+        step.map(str::to_string),
+        event_type,
+        format!("{}:{}", run_id, summary),
+        summary.to_string(),
+        status,
+    )
+}
+
+#[tokio::test]
+async fn test_follow_prints_appended_events_until_finished() {
+    common::init_home();
+
+    let run_id = Uuid::new_v4();
+    let store = EventStore::open(run_id).await.unwrap();
+    store
+        .append(&event(run_id, None, EventType::RunStarted, "Run started"))
+        .await
+        .unwrap();
+
+    let follower = tokio::spawn(async move {
+        let store = EventStore::open(run_id).await.unwrap();
+        let mut out = Vec::new();
+        let run = follow_run(&store, &mut out, Duration::from_millis(20))
+            .await
+            .unwrap();
+        (run, String::from_utf8(out).unwrap())
+    });
+
+    for (step, event_type, summary) in [
+        (Some("summarize"), EventType::StepStarted, "Step started"),
+        (
+            Some("summarize"),
+            EventType::StepCompleted,
+            "Step completed",
+        ),
+        (None, EventType::RunCompleted, "Run completed"),
+    ] {
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        store
+            .append(&event(run_id, step, event_type, summary))
+            .await
+            .unwrap();
+    }
+
+    let (run, output) = tokio::time::timeout(Duration::from_secs(5), follower)
+        .await
+        .expect("follow should stop at the terminal event")
+        .unwrap();
+
+    assert_eq!(run.state, RunState::Completed);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 5, "unexpected output:\n{}", output);
+    assert!(lines[0].contains("RunStarted - Run started"));
+    assert!(lines[1].contains("StepStarted [summarize] - Step started"));
+    assert!(lines[2].contains("StepCompleted [summarize] - Step completed"));
+    assert!(lines[3].contains("RunCompleted - Run completed"));
+    assert_eq!(lines[4], "Final state: Completed");
+}
+
+#[tokio::test]
+async fn test_follow_finished_run_prints_final_state() {
+    common::init_home();
+
+    let run_id = Uuid::new_v4();
+    let store = EventStore::open(run_id).await.unwrap();
+    store
+        .append(&event(run_id, None, EventType::RunStarted, "Run started"))
+        .await
+        .unwrap();
+    store
+        .append(&event(
+            run_id,
+            None,
+            EventType::RunCompleted,
+            "Run completed",
+        ))
+        .await
+        .unwrap();
+
+    let mut out = Vec::new();
+    follow_run(&store, &mut out, Duration::from_millis(20))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        format!("Run {} already finished\nFinal state: Completed\n", run_id)
+    );
+}
@@ -0,0 +1,93 @@
+//! Interrupted Run Integration Tests
+//!
+//! Tests for detecting runs whose process died mid-step and marking them
+//! interrupted.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator};
+use arkai::domain::{Event, EventType, RunState, StepStatus};
+use uuid::Uuid;
+
+/// A run whose log stops at a `StepStarted`, as if the process crashed
+async fn crashed_run() -> Uuid {
+    let run_id = Uuid::new_v4();
+    let store = EventStore::open(run_id).await.unwrap();
+    let event = |event_type, step: Option<&str>| {
+        Event::new(
+            run_id,
+            step.map(String::from),
+            event_type,
+            format!("{}:{:?}", run_id, event_type),
+            String::new(),
+            StepStatus::Running,
+        )
+    };
+    store
+        .append(
+            &event(EventType::RunStarted, None)
+                .with_payload(serde_json::json!({ "pipeline": "crashy" })),
+        )
+        .await
+        .unwrap();
+    store
+        .append(&event(EventType::StepStarted, Some("summarize")))
+        .await
+        .unwrap();
+    run_id
+}
+
+#[tokio::test]
+async fn test_truncated_run_is_detected_as_interrupted() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let crashed = crashed_run().await;
+    let still_running = crashed_run().await;
+
+    // Before recovery the crashed step looks like it's running forever
+    let run = orchestrator.get_run_status(crashed).await.unwrap();
+    assert_eq!(run.state, RunState::Running);
+    assert_eq!(run.step_statuses["summarize"], StepStatus::Running);
+
+    // A run whose lock is held is live in another process and left alone
+    let live_store = EventStore::open(still_running).await.unwrap();
+    let lock = live_store.lock().unwrap();
+    assert!(live_store.is_locked());
+    assert!(!EventStore::open(crashed).await.unwrap().is_locked());
+    assert_eq!(
+        orchestrator.recover_interrupted_runs().await.unwrap(),
+        vec![crashed]
+    );
+
+    let run = orchestrator.get_run_status(crashed).await.unwrap();
+    assert_eq!(run.state, RunState::Interrupted);
+    assert_eq!(run.step_statuses["summarize"], StepStatus::Pending);
+    let events = EventStore::open(crashed)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let last = events.last().unwrap();
+    assert_eq!(last.event_type, EventType::RunInterrupted);
+    assert_eq!(last.payload.as_ref().unwrap()["step"], "summarize");
+
+    // Listed as interrupted; recovery doesn't mark a run twice
+    let listed = orchestrator.list_run_summaries(0, 10).await.unwrap();
+    let summary = listed.iter().find(|s| s.id == crashed).unwrap();
+    assert_eq!(summary.state, RunState::Interrupted);
+    assert!(orchestrator
+        .recover_interrupted_runs()
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Once the other process is gone, its run is interrupted too
+    drop(lock);
+    assert!(!live_store.is_locked());
+    assert_eq!(
+        orchestrator.recover_interrupted_runs().await.unwrap(),
+        vec![still_running]
+    );
+}
@@ -0,0 +1,38 @@
+//! JSON Output Integration Tests
+//!
+//! Runs the `arkai` binary with the global `--json` flag and checks that
+//! `runs` and `status` print a single parseable JSON document.
+
+mod common;
+
+use common::{project_with_pipelines, run_arkai, stdout_json, ECHO_PIPELINE};
+
+#[test]
+fn test_runs_and_status_print_json() {
+    let project = project_with_pipelines(&[("echo", ECHO_PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "hello").unwrap();
+
+    let run = run_arkai(project.path(), &["run", "echo", "-i", "input.txt"]);
+    assert!(run.status.success());
+
+    let runs = stdout_json(&run_arkai(project.path(), &["--json", "runs"]));
+    let runs = runs.as_array().unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0]["pipeline"], "echo");
+    assert_eq!(runs[0]["state"], "completed");
+    assert!(runs[0]["started_at"].is_string());
+    let run_id = runs[0]["id"].as_str().unwrap();
+
+    // The flag is global, so it also works after the subcommand
+    let status = stdout_json(&run_arkai(project.path(), &["status", run_id, "--json"]));
+    assert_eq!(status["id"], run_id);
+    assert_eq!(status["pipeline_name"], "echo");
+    assert_eq!(status["state_label"], "completed");
+    assert_eq!(status["finished"], true);
+    assert_eq!(status["steps"][0]["name"], "echo");
+    assert_eq!(status["step_statuses"]["echo"], "completed");
+
+    // Without the flag the table is unchanged
+    let table = run_arkai(project.path(), &["runs"]);
+    assert!(String::from_utf8_lossy(&table.stdout).starts_with("RUN ID"));
+}
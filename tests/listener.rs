@@ -0,0 +1,68 @@
+//! Event Listener Integration Tests
+//!
+//! Tests for observing a run's events in real time via
+//! `Orchestrator::run_pipeline_with_listener`.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState};
+
+const PIPELINE_YAML: &str = r#"
+name: listener_test
+description: Two shell steps
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: second
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: first
+"#;
+
+#[tokio::test]
+async fn test_listener_receives_every_event_in_order() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let sink = Arc::clone(&received);
+    let run = Orchestrator::new()
+        .run_pipeline_with_listener(&pipeline, "hello".to_string(), move |event| {
+            sink.lock().unwrap().push(event.clone());
+        })
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let received = received.lock().unwrap().clone();
+    let types: Vec<EventType> = received.iter().map(|e| e.event_type).collect();
+    assert_eq!(
+        types,
+        vec![
+            EventType::RunStarted,
+            EventType::StepStarted,
+            EventType::StepCompleted,
+            EventType::StepStarted,
+            EventType::StepCompleted,
+            EventType::RunCompleted,
+        ]
+    );
+
+    // Exactly what was written to the event log
+    let logged = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let logged_ids: Vec<_> = logged.iter().map(|e| e.id).collect();
+    let received_ids: Vec<_> = received.iter().map(|e| e.id).collect();
+    assert_eq!(received_ids, logged_ids);
+}
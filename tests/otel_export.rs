@@ -0,0 +1,79 @@
+//! OpenTelemetry Export Integration Tests
+//!
+//! Runs the `arkai` binary with `ARKAI_OTLP_ENDPOINT` pointing at a stub
+//! collector and checks that the run's spans are exported over OTLP/HTTP.
+
+#![cfg(feature = "otel")]
+
+mod common;
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use common::{project_with_pipelines, ECHO_PIPELINE};
+
+/// Accept OTLP requests, replying 200 and sending each request line and body on
+fn stub_collector(listener: TcpListener, requests: mpsc::Sender<(String, Vec<u8>)>) {
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else { return };
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+
+        let mut content_length = 0;
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header.trim().is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+            .unwrap();
+        let _ = requests.send((request_line.trim().to_string(), body));
+    }
+}
+
+#[test]
+fn test_run_spans_export_to_otlp_endpoint() {
+    let project = project_with_pipelines(&[("echo", ECHO_PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "hello").unwrap();
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || stub_collector(listener, tx));
+
+    // --quiet silences the log output but not the export
+    let output = Command::new(env!("CARGO_BIN_EXE_arkai"))
+        .args(["--quiet", "run", "echo", "-i", "input.txt"])
+        .current_dir(project.path())
+        .env("ARKAI_HOME", project.path().join(".arkai-home"))
+        .env("ARKAI_OTLP_ENDPOINT", &endpoint)
+        .env_remove("RUST_LOG")
+        .env_remove("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .env_remove("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT")
+        .output()
+        .expect("failed to run arkai");
+    assert!(output.status.success(), "{:?}", output);
+
+    // Spans are flushed before the process exits
+    let (request_line, body) = rx
+        .recv_timeout(Duration::from_secs(5))
+        .expect("no spans exported");
+    assert_eq!(request_line, "POST /v1/traces HTTP/1.1");
+    let contains = |needle: &[u8]| body.windows(needle.len()).any(|w| w == needle);
+    assert!(contains(b"arkai"), "service name missing");
+    assert!(contains(b"step"), "step span missing");
+}
@@ -0,0 +1,65 @@
+//! Output Format Integration Tests
+//!
+//! Tests for a step's declared `output_format` being checked after each attempt.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState};
+
+#[tokio::test]
+async fn test_invalid_json_output_is_retried_until_valid() {
+    let home = common::init_home();
+
+    // Mock adapter: prose on the first attempt, JSON on the next
+    let marker = home.join("attempted");
+    let yaml = format!(
+        r#"
+name: output_format_test
+description: A step that must produce JSON
+steps:
+  - name: extract
+    adapter: shell
+    action: "if [ -f {marker} ]; then echo '{{\"ok\": true}}'; else touch {marker}; echo 'Sure! Here is the JSON'; fi"
+    input_from: pipeline_input
+    output_format: json
+    retry_policy:
+      max_attempts: 2
+      initial_delay_ms: 10
+"#,
+        marker = marker.display()
+    );
+
+    let pipeline = Pipeline::from_yaml(&yaml).unwrap();
+    let run = Orchestrator::new()
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let artifact: serde_json::Value =
+        serde_json::from_str(&run.artifacts["extract"].content).unwrap();
+    assert_eq!(artifact["ok"], true);
+
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let retry = events
+        .iter()
+        .find(|e| e.event_type == EventType::StepRetrying)
+        .unwrap();
+    assert!(retry.error.as_deref().unwrap().contains("not valid JSON"));
+
+    // The declared format is recorded on completion
+    let completed = events
+        .iter()
+        .find(|e| e.event_type == EventType::StepCompleted)
+        .unwrap();
+    assert_eq!(
+        completed.payload.as_ref().unwrap()["output_format"],
+        serde_json::json!("json")
+    );
+}
@@ -0,0 +1,63 @@
+//! Output Schema Integration Tests
+//!
+//! Tests for a step's `output_schema` being checked after each attempt.
+//! Only built with the `json-schema` feature.
+
+#![cfg(feature = "json-schema")]
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState};
+
+#[tokio::test]
+async fn test_schema_violation_is_retried_until_output_matches() {
+    let home = common::init_home();
+
+    // Mock adapter: JSON missing the required field first, then a match
+    let marker = home.join("attempted");
+    let yaml = format!(
+        r#"
+name: output_schema_test
+description: A step whose JSON output must match a schema
+steps:
+  - name: extract
+    adapter: shell
+    action: "if [ -f {marker} ]; then echo '{{\"claims\": [\"x\"]}}'; else touch {marker}; echo '{{\"claim\": \"x\"}}'; fi"
+    input_from: pipeline_input
+    output_format: json
+    output_schema:
+      type: object
+      required: [claims]
+    retry_policy:
+      max_attempts: 2
+      initial_delay_ms: 10
+"#,
+        marker = marker.display()
+    );
+
+    let pipeline = Pipeline::from_yaml(&yaml).unwrap();
+    let run = Orchestrator::new()
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let artifact: serde_json::Value =
+        serde_json::from_str(&run.artifacts["extract"].content).unwrap();
+    assert_eq!(artifact["claims"][0], "x");
+
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let retry = events
+        .iter()
+        .find(|e| e.event_type == EventType::StepRetrying)
+        .unwrap();
+    let error = retry.error.as_deref().unwrap();
+    assert!(error.contains("does not match schema"));
+    assert!(error.contains("claims"));
+}
@@ -0,0 +1,89 @@
+//! Parallel Step Integration Tests
+//!
+//! Tests for running independent steps concurrently and resuming only the
+//! unfinished branches of a partially completed run.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{verify_events, Run, RunState, StepStatus};
+use tempfile::TempDir;
+
+/// `left` and `right` each wait for the other to start, so the run only
+/// succeeds if they run at the same time. `right` fails until `allow_right`
+/// exists. `join` waits for both.
+fn pipeline(dir: &std::path::Path) -> Pipeline {
+    let dir = dir.display();
+    Pipeline::from_yaml(&format!(
+        r#"
+name: parallel_test
+description: Two independent branches and a join
+
+steps:
+  - name: left
+    adapter: shell
+    action: "touch {dir}/left; while [ ! -f {dir}/right ]; do sleep 0.05; done; echo run >> {dir}/left_runs; cat"
+    input_from: pipeline_input
+    timeout_seconds: 10
+
+  - name: right
+    adapter: shell
+    action: "touch {dir}/right; while [ ! -f {dir}/left ]; do sleep 0.05; done; test -f {dir}/allow_right && tr a-z A-Z"
+    input_from: pipeline_input
+    timeout_seconds: 10
+    retry_policy:
+      max_attempts: 1
+
+  - name: join
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: left
+    depends_on: [right]
+"#
+    ))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_independent_steps_run_concurrently_and_resume_unfinished() {
+    common::init_home();
+    let scratch = TempDir::new().unwrap();
+
+    let pipeline = pipeline(scratch.path());
+    pipeline.validate().unwrap();
+    let orchestrator = Orchestrator::new();
+
+    // left completes alongside right, which fails; join never starts
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(run.state, RunState::Failed { .. }));
+    assert_eq!(run.step_statuses["left"], StepStatus::Completed);
+    assert_eq!(run.step_statuses["right"], StepStatus::Failed);
+    assert!(!run.step_statuses.contains_key("join"));
+
+    // The interleaved log replays to the same state and stays well-formed
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    assert!(verify_events(&events, Some(3)).is_empty());
+    let replayed = Run::from_events(&events).unwrap();
+    assert_eq!(replayed.step_statuses, run.step_statuses);
+
+    // Resume reruns only the failed branch and the join
+    std::fs::write(scratch.path().join("allow_right"), "").unwrap();
+    let resumed = orchestrator
+        .resume_run(run.id, &pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(resumed.state, RunState::Completed);
+    assert_eq!(resumed.artifacts["right"].content.trim(), "HELLO");
+    assert_eq!(resumed.artifacts["join"].content.trim(), "hello");
+    let left_runs = std::fs::read_to_string(scratch.path().join("left_runs")).unwrap();
+    assert_eq!(left_runs.lines().count(), 1);
+}
@@ -0,0 +1,112 @@
+//! Pipeline Evidence Integration Tests
+//!
+//! Tests for the post-run `evidence:` block, using shell steps as a mock
+//! extractor.
+
+mod common;
+
+use arkai::core::{Orchestrator, Pipeline};
+use arkai::domain::RunState;
+
+const PIPELINE_YAML: &str = r#"
+name: evidence_test
+description: Shell pipeline that proposes claims about its input
+
+steps:
+  - name: transcript
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+
+  - name: claims
+    adapter: shell
+    action: >-
+      cat >/dev/null;
+      echo '[{"claim": "Tests should be fast", "quote": "keep your tests fast", "confidence": 0.9},
+      {"claim": "Made up", "quote": "never said this", "confidence": 0.4}]'
+    input_from:
+      previous_step: transcript
+"#;
+
+const EVIDENCE_BLOCK: &str = r#"
+evidence:
+  claims: claims
+  source: transcript
+"#;
+
+const INPUT: &str = "Rule one: always keep your tests fast and focused.";
+
+#[tokio::test]
+async fn test_evidence_block_grounds_claims_after_run() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+
+    let pipeline = Pipeline::from_yaml(&format!("{}{}", PIPELINE_YAML, EVIDENCE_BLOCK)).unwrap();
+    pipeline.validate().unwrap();
+    let run = orchestrator
+        .run_pipeline(&pipeline, INPUT.to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let runs_dir = arkai::config::runs_dir().unwrap();
+    let evidence =
+        std::fs::read_to_string(runs_dir.join(run.id.to_string()).join("evidence.jsonl")).unwrap();
+    let records: Vec<serde_json::Value> = evidence
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(records.len(), 2);
+    let resolved = records
+        .iter()
+        .find(|r| r["status"] == "resolved")
+        .expect("one claim should resolve");
+    assert_eq!(resolved["span"]["artifact"], "transcript");
+    assert!(records.iter().any(|r| r["status"] == "unresolved"));
+
+    // The run still completes cleanly with the extra event
+    let status = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(status.state, RunState::Completed);
+}
+
+#[tokio::test]
+async fn test_pipeline_without_evidence_block_writes_no_evidence() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let runs_dir = arkai::config::runs_dir().unwrap();
+    let plain = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = orchestrator
+        .run_pipeline(&plain, INPUT.to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+    assert!(!runs_dir
+        .join(run.id.to_string())
+        .join("evidence.jsonl")
+        .exists());
+}
+
+#[tokio::test]
+async fn test_evidence_spans_name_custom_artifact() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let runs_dir = arkai::config::runs_dir().unwrap();
+
+    // Spans name the file the source step's artifact is stored under
+    let renamed = PIPELINE_YAML.replace(
+        "    action: cat\n",
+        "    action: cat\n    artifact_name: source_text\n",
+    );
+    let pipeline = Pipeline::from_yaml(&format!("{}{}", renamed, EVIDENCE_BLOCK)).unwrap();
+    let run = orchestrator
+        .run_pipeline(&pipeline, INPUT.to_string())
+        .await
+        .unwrap();
+    let evidence =
+        std::fs::read_to_string(runs_dir.join(run.id.to_string()).join("evidence.jsonl")).unwrap();
+    assert!(evidence.contains(r#""artifact":"source_text""#));
+}
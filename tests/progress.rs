@@ -0,0 +1,65 @@
+//! Step Progress Integration Tests
+//!
+//! Tests for the progress callback behind the `arkai run` spinner.
+
+mod common;
+
+use std::sync::Mutex;
+
+use arkai::core::{Orchestrator, Pipeline, StepProgressKind};
+
+const PIPELINE_YAML: &str = r#"
+name: progress_test
+description: Three shell steps, the last one optional and failing
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: second
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: first
+  - name: third
+    adapter: shell
+    action: "false"
+    input_from:
+      previous_step: second
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 1
+"#;
+
+#[tokio::test]
+async fn test_progress_callback_fires_for_each_step() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let seen = Mutex::new(Vec::new());
+
+    Orchestrator::new()
+        .run_pipeline_with_progress(&pipeline, "hello".to_string(), &|progress| {
+            seen.lock().unwrap().push((
+                progress.step.clone(),
+                progress.index,
+                progress.total,
+                progress.kind,
+            ));
+        })
+        .await
+        .unwrap();
+
+    let seen = seen.into_inner().unwrap();
+    assert_eq!(
+        seen,
+        vec![
+            ("first".to_string(), 0, 3, StepProgressKind::Started),
+            ("first".to_string(), 0, 3, StepProgressKind::Completed),
+            ("second".to_string(), 1, 3, StepProgressKind::Started),
+            ("second".to_string(), 1, 3, StepProgressKind::Completed),
+            ("third".to_string(), 2, 3, StepProgressKind::Started),
+            ("third".to_string(), 2, 3, StepProgressKind::Failed),
+        ]
+    );
+}
@@ -0,0 +1,29 @@
+//! Quiet Mode Integration Tests
+//!
+//! Runs the `arkai` binary with `--quiet` and checks that only the final
+//! output is printed.
+
+mod common;
+
+use common::{project_with_pipelines, run_arkai, ECHO_PIPELINE};
+
+#[test]
+fn test_quiet_run_prints_only_the_output() {
+    let project = project_with_pipelines(&[("echo", ECHO_PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "quiet please").unwrap();
+
+    let loud = run_arkai(project.path(), &["run", "echo", "-i", "input.txt"]);
+    assert!(loud.status.success());
+    assert!(String::from_utf8_lossy(&loud.stderr).contains("completed successfully"));
+
+    let quiet = run_arkai(
+        project.path(),
+        &["run", "echo", "-i", "input.txt", "--quiet"],
+    );
+    assert!(quiet.status.success());
+    assert_eq!(String::from_utf8_lossy(&quiet.stderr), "");
+    assert_eq!(
+        String::from_utf8_lossy(&quiet.stdout).trim(),
+        "quiet please"
+    );
+}
@@ -0,0 +1,122 @@
+//! Rate Limit Integration Tests
+//!
+//! Tests that concurrent `adapter: fabric` steps are spaced out by the Fabric
+//! rate limiter and that the wait is recorded on the step.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{EventStore, Orchestrator, Pipeline, RateLimit, RateLimiter};
+use arkai::domain::{EventType, Run, RunState};
+use async_trait::async_trait;
+
+/// Stands in for Fabric, recording when each call arrives
+struct TimedFabric {
+    calls: Arc<Mutex<Vec<Instant>>>,
+}
+
+#[async_trait]
+impl Adapter for TimedFabric {
+    fn name(&self) -> &str {
+        "timed-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        self.calls.lock().unwrap().push(Instant::now());
+        Ok(AdapterOutput::new(input.to_string()))
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: rate_limit_test
+description: Three independent Fabric steps
+steps:
+  - name: a
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: b
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+  - name: c
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+"#;
+
+/// Run the pipeline under a 300/min limiter (one call every 200ms),
+/// returning the run, the sorted call times and the limiter
+async fn run_limited() -> (Run, Vec<Instant>, Arc<RateLimiter>) {
+    common::init_home();
+
+    let limiter = Arc::new(RateLimiter::new(RateLimit {
+        requests_per_minute: 300,
+        burst: 1,
+    }));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(TimedFabric {
+            calls: calls.clone(),
+        })
+        .with_rate_limiter(Some(limiter.clone()))
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    let mut calls = calls.lock().unwrap().clone();
+    calls.sort();
+    (run, calls, limiter)
+}
+
+#[tokio::test]
+async fn test_concurrent_fabric_steps_are_spaced_out() {
+    let (run, calls, limiter) = run_limited().await;
+    assert_eq!(run.state, RunState::Completed);
+
+    assert_eq!(calls.len(), 3);
+    for pair in calls.windows(2) {
+        let gap = pair[1] - pair[0];
+        assert!(gap >= Duration::from_millis(150), "calls {:?} apart", gap);
+    }
+
+    let state = limiter.state();
+    assert_eq!(state.throttled_calls, 2);
+    assert!(state.total_delay_ms >= 500);
+}
+
+#[tokio::test]
+async fn test_throttle_wait_is_recorded_on_held_back_steps() {
+    let (run, _calls, _limiter) = run_limited().await;
+
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let throttled = events
+        .iter()
+        .filter(|event| event.event_type == EventType::StepCompleted)
+        .filter(|event| {
+            event
+                .payload
+                .as_ref()
+                .is_some_and(|payload| payload.get("throttled_ms").is_some())
+        })
+        .count();
+    assert_eq!(throttled, 2);
+}
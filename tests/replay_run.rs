@@ -0,0 +1,66 @@
+//! Replay Run Integration Tests
+//!
+//! Tests for reconstructing a run and its events in one read.
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::error::ArkaiError;
+use uuid::Uuid;
+
+const PIPELINE_YAML: &str = r#"
+name: replay_run_test
+description: Two shell steps
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: second
+    adapter: shell
+    action: tr a-z A-Z
+    input_from:
+      previous_step: first
+"#;
+
+#[tokio::test]
+async fn test_replay_run_matches_status_and_event_log() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let orchestrator = Orchestrator::new();
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    let (replayed, events) = orchestrator.replay_run(run.id).await.unwrap();
+    let status = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(
+        serde_json::to_value(&replayed).unwrap(),
+        serde_json::to_value(&status).unwrap()
+    );
+    assert_eq!(replayed.pipeline_name, "replay_run_test");
+
+    let logged = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&events).unwrap(),
+        serde_json::to_value(&logged).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_replay_unknown_run_is_not_found() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    assert!(matches!(
+        orchestrator.replay_run(Uuid::new_v4()).await,
+        Err(ArkaiError::RunNotFound(_))
+    ));
+}
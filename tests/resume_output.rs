@@ -0,0 +1,64 @@
+//! Resume Output Integration Tests
+//!
+//! Runs the `arkai` binary, resumes a failed run into a partial completion,
+//! and checks that the final output and banner match those of `arkai run`.
+
+mod common;
+
+use common::{project_with_pipelines, run_arkai, stdout, stdout_json};
+
+const PIPELINE: &str = r#"
+name: gated
+description: An optional step that always fails, then one that waits for a file
+steps:
+  - name: first
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: optional
+    adapter: shell
+    action: "false"
+    input_from:
+      previous_step: first
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 1
+  - name: gate
+    adapter: shell
+    action: test -f ready && tr a-z A-Z
+    input_from:
+      previous_step: first
+    retry_policy:
+      max_attempts: 1
+  - name: skipped
+    adapter: shell
+    action: rev
+    input_from:
+      previous_step: gate
+    enabled: false
+"#;
+
+#[test]
+fn test_resume_into_partial_completion_prints_final_output() {
+    let project = project_with_pipelines(&[("gated", PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "some input").unwrap();
+
+    let failed = run_arkai(project.path(), &["run", "gated", "-i", "input.txt"]);
+    assert!(!failed.status.success());
+    let runs = stdout_json(&run_arkai(project.path(), &["--json", "runs"]));
+    let run_id = runs[0]["id"].as_str().unwrap().to_string();
+
+    std::fs::write(project.path().join("ready"), "").unwrap();
+    let resumed = run_arkai(project.path(), &["resume", &run_id]);
+
+    // The disabled last step is passed over for the gate's output, printed
+    // after the logs
+    assert_eq!(stdout(&resumed).lines().last(), Some("SOME INPUT"));
+    let stderr = String::from_utf8_lossy(&resumed.stderr);
+    assert!(
+        stderr.contains("resumed and partially completed; failed steps: optional"),
+        "{}",
+        stderr
+    );
+    assert!(!stderr.contains("in state"), "{}", stderr);
+}
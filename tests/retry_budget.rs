@@ -0,0 +1,105 @@
+//! Retry Budget Integration Tests
+//!
+//! Tests that `safety_limits.max_total_retries` stops a run once retries
+//! across all its steps exceed the budget, before every step has used up
+//! its own retry policy.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{AdapterType, Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use async_trait::async_trait;
+
+/// Stands in for Fabric, failing every call
+struct DownFabric {
+    calls: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Adapter for DownFabric {
+    fn name(&self) -> &str {
+        "down-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        _input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        anyhow::bail!("provider unavailable")
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: retry_budget_test
+description: Three optional steps, each allowed three attempts
+max_parallel: 1
+safety_limits:
+  max_total_retries: 2
+steps:
+  - name: a
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 3
+      initial_delay_ms: 1
+      max_delay_ms: 1
+  - name: b
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 3
+      initial_delay_ms: 1
+      max_delay_ms: 1
+  - name: c
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+    continue_on_error: true
+    retry_policy:
+      max_attempts: 3
+      initial_delay_ms: 1
+      max_delay_ms: 1
+"#;
+
+#[tokio::test]
+async fn test_run_stops_when_retry_budget_is_spent() {
+    common::init_home();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(DownFabric {
+            calls: calls.clone(),
+        })
+        .with_circuit_breaker(AdapterType::Fabric, None)
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    match run.state {
+        RunState::SafetyLimitReached { ref limit } => {
+            assert!(limit.contains("Retry budget exceeded"), "limit: {}", limit)
+        }
+        ref state => panic!("Expected SafetyLimitReached, got {:?}", state),
+    }
+
+    // Step a spends the budget on its two retries; step b's first retry
+    // would exceed it, and step c never runs
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,75 @@
+//! Run Chaining Integration Tests
+//!
+//! Tests for feeding one run's output into another (`arkai run --from-run`).
+
+mod common;
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::RunState;
+
+const UPPERCASE_YAML: &str = r#"
+name: uppercase
+description: Uppercase the input, then exclaim
+
+steps:
+  - name: upper
+    adapter: shell
+    action: tr a-z A-Z
+    input_from: pipeline_input
+
+  - name: exclaim
+    adapter: shell
+    action: sed 's/$/!/'
+    input_from:
+      previous_step: upper
+"#;
+
+const ECHO_YAML: &str = r#"
+name: echo
+description: Pass input through
+
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+"#;
+
+#[tokio::test]
+async fn test_from_run_chains_output_into_next_pipeline() {
+    common::init_home();
+
+    let orchestrator = Orchestrator::new();
+
+    let first = Pipeline::from_yaml(UPPERCASE_YAML).unwrap();
+    let run_a = orchestrator
+        .run_pipeline(&first, "hello chain".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run_a.state, RunState::Completed);
+    let a_output = run_a.artifacts["exclaim"].content.clone();
+
+    // Short IDs resolve to the full run
+    let short = &run_a.id.to_string()[..8];
+    let run_id = EventStore::resolve_run_id(short).await.unwrap();
+    assert_eq!(run_id, run_a.id);
+
+    // Final output by default, or a named step
+    let input = orchestrator.load_run_output(run_id, None).await.unwrap();
+    assert_eq!(input, a_output);
+    let upper = orchestrator
+        .load_run_output(run_id, Some("upper"))
+        .await
+        .unwrap();
+    assert!(upper.starts_with("HELLO CHAIN"));
+    assert!(orchestrator
+        .load_run_output(run_id, Some("missing"))
+        .await
+        .is_err());
+
+    let second = Pipeline::from_yaml(ECHO_YAML).unwrap();
+    let run_b = orchestrator.run_pipeline(&second, input).await.unwrap();
+    assert_eq!(run_b.state, RunState::Completed);
+    assert_eq!(run_b.input, a_output);
+    assert_eq!(run_b.artifacts["echo"].content, a_output);
+}
@@ -0,0 +1,93 @@
+//! Run Environment Integration Tests
+//!
+//! Tests that the environment snapshot is recorded on `RunStarted` and
+//! reconstructed from the event log.
+
+mod common;
+
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EnvironmentSnapshot, EventType};
+use async_trait::async_trait;
+
+/// Stands in for Fabric, reporting a fixed binary and version
+struct MockFabric;
+
+#[async_trait]
+impl Adapter for MockFabric {
+    fn name(&self) -> &str {
+        "mock-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        Ok(AdapterOutput::new(input.to_string()))
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn binary_path(&self) -> Option<String> {
+        Some("/opt/fabric/bin/fabric-ai".to_string())
+    }
+
+    async fn version(&self) -> Option<String> {
+        Some("v1.4.200".to_string())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: run_environment_test
+description: One Fabric step
+steps:
+  - name: echo
+    adapter: fabric
+    action: summarize
+    input_from: pipeline_input
+"#;
+
+#[tokio::test]
+async fn test_environment_snapshot_recorded_and_reconstructed() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let orchestrator = Orchestrator::new().with_fabric_adapter(MockFabric);
+    let run = orchestrator
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    let expected = EnvironmentSnapshot {
+        fabric_binary: Some("/opt/fabric/bin/fabric-ai".to_string()),
+        fabric_version: Some("v1.4.200".to_string()),
+        ..EnvironmentSnapshot::current()
+    };
+    assert_eq!(expected.arkai_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(expected.os, std::env::consts::OS);
+
+    // Recorded in the RunStarted payload
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    let started = events
+        .iter()
+        .find(|event| event.event_type == EventType::RunStarted)
+        .unwrap();
+    let payload = started.payload.as_ref().unwrap();
+    assert_eq!(payload["environment"]["fabric_version"], "v1.4.200");
+    assert_eq!(payload["environment"]["arch"], std::env::consts::ARCH);
+
+    // Reconstructed from the log
+    let status = orchestrator.get_run_status(run.id).await.unwrap();
+    assert_eq!(status.environment(), Some(expected));
+}
@@ -0,0 +1,103 @@
+//! Run Index Integration Tests
+//!
+//! Tests for the run index: appended when runs end, upserted on resume,
+//! backfilled for unindexed runs and rebuilt from the run directories.
+
+mod common;
+
+use arkai::core::{Orchestrator, Pipeline, RunIndex};
+use arkai::domain::{RunState, RunSummary};
+
+fn pipeline(action: &str) -> Pipeline {
+    Pipeline::from_yaml(&format!(
+        r#"
+name: run_index_test
+description: One shell step
+steps:
+  - name: only
+    adapter: shell
+    action: {}
+    input_from: pipeline_input
+"#,
+        action
+    ))
+    .unwrap()
+}
+
+/// Every line of the index, oldest first
+fn index_lines(index: &RunIndex) -> Vec<RunSummary> {
+    std::fs::read_to_string(index.path())
+        .unwrap_or_default()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_index_append_resume_backfill_and_rebuild() {
+    let home = common::init_home();
+
+    let orchestrator = Orchestrator::new();
+    let index = RunIndex::open().unwrap();
+
+    // A run that ends is appended
+    let completed = orchestrator
+        .run_pipeline(&pipeline("cat"), "hello".to_string())
+        .await
+        .unwrap();
+    let lines = index_lines(&index);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].id, completed.id);
+    assert_eq!(lines[0].pipeline, "run_index_test");
+    assert_eq!(lines[0].state, RunState::Completed);
+
+    // A failed run, resumed: running again on resume, then completed
+    let failed = orchestrator
+        .run_pipeline(&pipeline("false"), "hello".to_string())
+        .await
+        .unwrap();
+    assert!(matches!(failed.state, RunState::Failed { .. }));
+    orchestrator
+        .resume_run(failed.id, &pipeline("cat"), "hello".to_string())
+        .await
+        .unwrap();
+    let states: Vec<_> = index_lines(&index)
+        .into_iter()
+        .filter(|summary| summary.id == failed.id)
+        .map(|summary| summary.state)
+        .collect();
+    assert!(matches!(states[0], RunState::Failed { .. }));
+    assert_eq!(states[1..], [RunState::Running, RunState::Completed]);
+    assert_eq!(
+        index.load().await.unwrap()[&failed.id].state,
+        RunState::Completed
+    );
+
+    // Listing reflects the latest entry, most recent first
+    let listed = orchestrator.list_run_summaries(0, 10).await.unwrap();
+    assert_eq!(
+        listed.iter().map(|s| s.id).collect::<Vec<_>>(),
+        [failed.id, completed.id]
+    );
+    assert_eq!(
+        orchestrator.list_run_summaries(1, 10).await.unwrap()[0].id,
+        completed.id
+    );
+
+    // Unindexed runs are backfilled
+    std::fs::remove_file(index.path()).unwrap();
+    assert_eq!(
+        orchestrator.list_run_summaries(0, 10).await.unwrap(),
+        listed
+    );
+    assert_eq!(index_lines(&index).len(), 2);
+
+    // Rebuild drops drift: a deleted run and garbage lines
+    std::fs::remove_dir_all(home.join("runs").join(completed.id.to_string())).unwrap();
+    std::fs::write(index.path(), "not json\n").unwrap();
+    assert_eq!(index.rebuild().await.unwrap(), 1);
+    let lines = index_lines(&index);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].id, failed.id);
+    assert_eq!(lines[0].state, RunState::Completed);
+}
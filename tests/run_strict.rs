@@ -0,0 +1,100 @@
+//! Strict Run Integration Tests
+//!
+//! Runs the `arkai` binary and checks that pipeline warnings are judged
+//! after `--run-timeout`/`--step-timeout` are applied, and reported once.
+
+mod common;
+
+use std::process::Output;
+
+use common::{project_with_pipelines, run_arkai};
+use tempfile::TempDir;
+
+const PIPELINE: &str = r#"
+name: slow
+description: One step that may take up to a minute per attempt
+safety_limits:
+  step_timeout_seconds: 60
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+    retry_policy:
+      max_attempts: 1
+"#;
+
+const WARNING: &str = "exceeds the run timeout";
+
+/// Everything the run printed; logs go to stdout
+fn printed(output: &Output) -> String {
+    format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
+
+fn project() -> TempDir {
+    let project = project_with_pipelines(&[("slow", PIPELINE)]);
+    std::fs::write(project.path().join("input.txt"), "some input").unwrap();
+    project
+}
+
+#[test]
+fn test_strict_checks_the_overridden_timeouts() {
+    let project = project();
+
+    let fits = run_arkai(
+        project.path(),
+        &["run", "slow", "-i", "input.txt", "--strict"],
+    );
+    assert!(fits.status.success());
+
+    // A run timeout too short for the step is refused before running
+    let refused = run_arkai(
+        project.path(),
+        &[
+            "run",
+            "slow",
+            "-i",
+            "input.txt",
+            "--strict",
+            "--run-timeout",
+            "30",
+        ],
+    );
+    assert!(!refused.status.success());
+    let printed = printed(&refused);
+    assert_eq!(printed.matches(WARNING).count(), 1, "{}", printed);
+
+    // Loosening the step timeout to match lets it through
+    let loosened = run_arkai(
+        project.path(),
+        &[
+            "run",
+            "slow",
+            "-i",
+            "input.txt",
+            "--strict",
+            "--run-timeout",
+            "30",
+            "--step-timeout",
+            "20",
+        ],
+    );
+    assert!(loosened.status.success());
+}
+
+#[test]
+fn test_warning_for_overridden_timeouts_is_logged_once() {
+    let project = project();
+
+    let output = run_arkai(
+        project.path(),
+        &["run", "slow", "-i", "input.txt", "--run-timeout", "30"],
+    );
+    assert!(output.status.success());
+    let printed = printed(&output);
+    assert_eq!(printed.matches(WARNING).count(), 1, "{}", printed);
+}
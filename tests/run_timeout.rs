@@ -0,0 +1,61 @@
+//! Run Timeout Integration Tests
+//!
+//! Tests that the run timeout stops a step that is still running, not just
+//! the steps after it.
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use arkai::core::{EventStore, Orchestrator, Pipeline};
+use arkai::domain::{EventType, RunState};
+
+const PIPELINE_YAML: &str = r#"
+name: run_timeout_test
+description: A step that outlives the run timeout
+safety_limits:
+  step_timeout_seconds: 30
+  run_timeout_seconds: 1
+steps:
+  - name: slow
+    adapter: shell
+    action: sleep 10; cat
+    input_from: pipeline_input
+  - name: never
+    adapter: shell
+    action: cat
+    input_from:
+      previous_step: slow
+"#;
+
+#[tokio::test]
+async fn test_run_timeout_stops_running_step() {
+    common::init_home();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let started = Instant::now();
+    let run = Orchestrator::new()
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    match run.state {
+        RunState::SafetyLimitReached { ref limit } => {
+            assert!(limit.contains("Run timeout"), "limit: {}", limit)
+        }
+        ref state => panic!("Expected SafetyLimitReached, got {:?}", state),
+    }
+    assert!(!run.artifacts.contains_key("never"));
+
+    let events = EventStore::open(run.id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap();
+    assert_eq!(
+        events.last().unwrap().event_type,
+        EventType::SafetyLimitReached
+    );
+}
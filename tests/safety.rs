@@ -244,6 +244,9 @@ safety_limits:
   denylist_patterns:
     - "**/*.password"
     - "**/api_keys/*"
+  max_total_cost_usd: 2.5
+  max_total_tokens: 100000
+  max_total_retries: 20
 
 steps:
   - name: test
@@ -260,6 +263,9 @@ steps:
     assert_eq!(pipeline.safety_limits.step_timeout_seconds, 60);
     assert_eq!(pipeline.safety_limits.run_timeout_seconds, 600);
     assert_eq!(pipeline.safety_limits.denylist_patterns.len(), 2);
+    assert_eq!(pipeline.safety_limits.max_total_cost_usd, Some(2.5));
+    assert_eq!(pipeline.safety_limits.max_total_tokens, Some(100000));
+    assert_eq!(pipeline.safety_limits.max_total_retries, Some(20));
 }
 
 #[test]
@@ -272,4 +278,6 @@ fn test_safety_limits_default_values() {
     assert_eq!(limits.max_output_bytes, 10 * 1024 * 1024); // 10MB
     assert_eq!(limits.step_timeout_seconds, 300); // 5 min
     assert_eq!(limits.run_timeout_seconds, 3600); // 1 hour
+    assert_eq!(limits.max_total_cost_usd, None);
+    assert_eq!(limits.max_total_tokens, None);
 }
@@ -0,0 +1,275 @@
+//! HTTP API Integration Tests
+//!
+//! Starts `arkai serve` on a free local port and drives a run through the
+//! API: start it, poll it until it completes, list it and resume it. Also
+//! checks the requests a web page could forge are refused, and that runs in
+//! flight at Ctrl+C are recorded as cancelled.
+
+use std::net::TcpListener;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+const PIPELINE: &str = r#"
+name: echo
+description: Echo the input back
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+"#;
+
+const SLOW_PIPELINE: &str = r#"
+name: slow
+description: Takes longer than the test waits
+steps:
+  - name: wait
+    adapter: shell
+    action: sleep 30
+    input_from: pipeline_input
+"#;
+
+/// Kills the server when the test ends, pass or fail
+struct Server(Child);
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn start_server(project: &Path, port: u16) -> Server {
+    let child = Command::new(env!("CARGO_BIN_EXE_arkai"))
+        .args(["serve", "--address", &format!("127.0.0.1:{}", port)])
+        .current_dir(project)
+        .env("ARKAI_HOME", project.join(".arkai-home"))
+        .env_remove("RUST_LOG")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start arkai serve");
+    Server(child)
+}
+
+async fn get_json(client: &reqwest::Client, url: &str) -> (u16, Value) {
+    let response = client.get(url).send().await.unwrap();
+    let status = response.status().as_u16();
+    (status, response.json().await.unwrap())
+}
+
+/// A project directory with the test pipelines in `pipelines/`
+fn project_with_pipelines() -> TempDir {
+    let project = TempDir::new().unwrap();
+    let pipelines = project.path().join("pipelines");
+    std::fs::create_dir_all(&pipelines).unwrap();
+    std::fs::write(pipelines.join("echo.yaml"), PIPELINE).unwrap();
+    std::fs::write(pipelines.join("slow.yaml"), SLOW_PIPELINE).unwrap();
+    project
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Start the server on `port` and wait until it accepts connections
+async fn start_ready_server(project: &Path, port: u16, client: &reqwest::Client) -> Server {
+    let server = start_server(project, port);
+    for _ in 0..100 {
+        let url = format!("http://127.0.0.1:{}/runs", port);
+        if client.get(url).send().await.is_ok() {
+            return server;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("server did not start");
+}
+
+#[tokio::test]
+async fn test_start_poll_list_and_resume_a_run() {
+    let project = project_with_pipelines();
+    let port = free_port();
+    let client = reqwest::Client::new();
+    let _server = start_ready_server(project.path(), port, &client).await;
+    let base = format!("http://127.0.0.1:{}", port);
+
+    let started = client
+        .post(format!("{}/runs", base))
+        .json(&serde_json::json!({ "pipeline": "echo", "input": "hello" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(started.status().as_u16(), 202);
+    let run_id = started.json::<Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let mut run = Value::Null;
+    for _ in 0..100 {
+        let (status, body) = get_json(&client, &format!("{}/runs/{}", base, run_id)).await;
+        assert_eq!(status, 200);
+        run = body;
+        if run["state"]["status"] == "completed" {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(run["state"]["status"], "completed");
+    assert_eq!(run["artifact_metadata"]["echo"]["byte_len"], 5);
+
+    let (status, runs) = get_json(&client, &format!("{}/runs?limit=5", base)).await;
+    assert_eq!(status, 200);
+    assert_eq!(runs[0]["id"], run_id.as_str());
+
+    let resumed = client
+        .post(format!("{}/runs/{}/resume", base, run_id))
+        .header("content-type", "application/json")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resumed.status().as_u16(), 202);
+
+    let unknown = client
+        .post(format!("{}/runs", base))
+        .json(&serde_json::json!({ "pipeline": "missing", "input": "hello" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(unknown.status().as_u16(), 404);
+
+    // Names that would leave pipelines/ are refused, even if the file exists
+    std::fs::write(project.path().join("outside.yaml"), PIPELINE).unwrap();
+    for name in ["../outside", "..\\outside", ".hidden"] {
+        let traversal = client
+            .post(format!("{}/runs", base))
+            .json(&serde_json::json!({ "pipeline": name, "input": "hello" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(traversal.status().as_u16(), 400, "{}", name);
+    }
+
+    // Fabric may or may not be installed here; either way the check answers
+    let (status, health) = get_json(&client, &format!("{}/healthz", base)).await;
+    assert!(status == 200 || status == 503);
+    assert!(health["status"].is_string());
+    assert!(health["fabric_rate_limit"].is_null());
+}
+
+#[tokio::test]
+async fn test_healthz_reports_throttle_state() {
+    let project = project_with_pipelines();
+    std::fs::create_dir_all(project.path().join(".arkai")).unwrap();
+    std::fs::write(
+        project.path().join(".arkai").join("config.yaml"),
+        "fabric:\n  rate_limit:\n    requests_per_minute: 20\n    burst: 2\n",
+    )
+    .unwrap();
+    let port = free_port();
+    let client = reqwest::Client::new();
+    let _server = start_ready_server(project.path(), port, &client).await;
+
+    let (_, health) = get_json(&client, &format!("http://127.0.0.1:{}/healthz", port)).await;
+    let throttle = &health["fabric_rate_limit"];
+    assert_eq!(throttle["requests_per_minute"], 20);
+    assert_eq!(throttle["burst"], 2);
+    assert_eq!(throttle["throttled_calls"], 0);
+}
+
+#[tokio::test]
+async fn test_requests_a_web_page_could_forge_are_refused() {
+    let project = project_with_pipelines();
+    let port = free_port();
+    let client = reqwest::Client::new();
+    let _server = start_ready_server(project.path(), port, &client).await;
+    let runs = format!("http://127.0.0.1:{}/runs", port);
+
+    // A cross-site form or fetch without a preflight
+    let plain = client
+        .post(&runs)
+        .header("content-type", "text/plain")
+        .body(r#"{"pipeline": "echo", "input": "hello"}"#)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(plain.status().as_u16(), 415);
+
+    // DNS rebinding: the browser sends the attacker's host name
+    let rebound = client
+        .get(&runs)
+        .header("host", format!("evil.example:{}", port))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(rebound.status().as_u16(), 421);
+
+    let oversized = client
+        .post(&runs)
+        .header("content-type", "application/json")
+        .body(vec![b' '; 17 * 1024 * 1024])
+        .send()
+        .await;
+    // The server may answer before reading it all, or drop the connection
+    if let Ok(response) = oversized {
+        assert_eq!(response.status().as_u16(), 413);
+    }
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_shutdown_records_in_flight_runs_as_cancelled() {
+    let project = project_with_pipelines();
+    let port = free_port();
+    let client = reqwest::Client::new();
+    let mut server = start_ready_server(project.path(), port, &client).await;
+    let base = format!("http://127.0.0.1:{}", port);
+
+    let started = client
+        .post(format!("{}/runs", base))
+        .json(&serde_json::json!({ "pipeline": "slow", "input": "" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(started.status().as_u16(), 202);
+    let run_id = started.json::<Value>().await.unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    tokio::time::sleep(Duration::from_millis(300)).await;
+
+    // Ctrl+C
+    let status = Command::new("kill")
+        .args(["-INT", &server.0.id().to_string()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+    for _ in 0..100 {
+        if server.0.try_wait().unwrap().is_some() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert!(
+        server.0.try_wait().unwrap().is_some(),
+        "server did not shut down"
+    );
+
+    let port = free_port();
+    let _restarted = start_ready_server(project.path(), port, &client).await;
+    let (status, run) = get_json(
+        &client,
+        &format!("http://127.0.0.1:{}/runs/{}", port, run_id),
+    )
+    .await;
+    assert_eq!(status, 200);
+    assert_eq!(run["state"]["status"], "cancelled");
+}
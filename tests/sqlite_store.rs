@@ -0,0 +1,58 @@
+//! SQLite Event Store Integration Tests
+//!
+//! Runs the `arkai` binary before and after switching to `storage: sqlite`
+//! and checks that new runs are logged in the database while the earlier
+//! JSONL run can still be listed and read.
+
+#![cfg(feature = "sqlite")]
+
+mod common;
+
+use common::{project_with_pipelines, run_arkai, stdout_json, ECHO_PIPELINE};
+
+#[test]
+fn test_sqlite_runs_alongside_jsonl_runs() {
+    let project = project_with_pipelines(&[("echo", ECHO_PIPELINE)]);
+    std::fs::write(project.path().join("first.txt"), "first").unwrap();
+    std::fs::write(project.path().join("second.txt"), "second").unwrap();
+    let runs_dir = project.path().join(".arkai-home").join("runs");
+
+    let jsonl_run = run_arkai(project.path(), &["run", "echo", "-i", "first.txt"]);
+    assert!(jsonl_run.status.success(), "{:?}", jsonl_run);
+
+    std::fs::create_dir_all(project.path().join(".arkai")).unwrap();
+    std::fs::write(
+        project.path().join(".arkai").join("config.yaml"),
+        "storage: sqlite\n",
+    )
+    .unwrap();
+    let sqlite_run = run_arkai(project.path(), &["run", "echo", "-i", "second.txt"]);
+    assert!(sqlite_run.status.success(), "{:?}", sqlite_run);
+    assert!(runs_dir.join("events.db").exists());
+
+    let runs = stdout_json(&run_arkai(project.path(), &["--json", "runs"]));
+    let runs = runs.as_array().unwrap();
+    assert_eq!(runs.len(), 2);
+    assert!(runs.iter().all(|run| run["state"] == "completed"));
+
+    for run in runs {
+        let run_id = run["id"].as_str().unwrap();
+        let has_jsonl = runs_dir.join(run_id).join("events.jsonl").exists();
+        let status = stdout_json(&run_arkai(project.path(), &["--json", "status", run_id]));
+        assert_eq!(status["step_statuses"]["echo"], "completed");
+        let byte_len = status["artifact_metadata"]["echo"]["byte_len"].as_u64();
+        // The older run stayed in events.jsonl; the newer one is in SQLite
+        assert_eq!(byte_len, Some(if has_jsonl { 5 } else { 6 }));
+
+        let verify = run_arkai(project.path(), &["verify", run_id]);
+        assert!(verify.status.success(), "{:?}", verify);
+    }
+    let jsonl_runs = runs
+        .iter()
+        .filter(|run| {
+            let run_id = run["id"].as_str().unwrap();
+            runs_dir.join(run_id).join("events.jsonl").exists()
+        })
+        .count();
+    assert_eq!(jsonl_runs, 1);
+}
@@ -0,0 +1,184 @@
+//! Step Span Integration Tests
+//!
+//! Captures tracing output with a test layer and checks that each step runs
+//! in a `step` span carrying the step, adapter and attempt, with a
+//! `Step completed` event recording duration and usage.
+
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use async_trait::async_trait;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+type Fields = HashMap<String, String>;
+
+#[derive(Default)]
+struct FieldVisitor(Fields);
+
+impl Visit for FieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+/// An event's fields, and the fields of its `step` span if it has one
+#[derive(Debug, Clone)]
+struct Captured {
+    fields: Fields,
+    step_span: Option<Fields>,
+}
+
+/// Keeps span fields in span extensions and records every event
+struct CaptureLayer {
+    events: Arc<Mutex<Vec<Captured>>>,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        ctx.span(id).unwrap().extensions_mut().insert(visitor.0);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).unwrap();
+        let mut extensions = span.extensions_mut();
+        let mut visitor = FieldVisitor(extensions.remove::<Fields>().unwrap_or_default());
+        values.record(&mut visitor);
+        extensions.insert(visitor.0);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let step_span = ctx.event_scope(event).and_then(|scope| {
+            scope
+                .into_iter()
+                .find(|span| span.name() == "step")
+                .and_then(|span| span.extensions().get::<Fields>().cloned())
+        });
+        self.events.lock().unwrap().push(Captured {
+            fields: visitor.0,
+            step_span,
+        });
+    }
+}
+
+/// Stands in for Fabric: fails once, then reports usage
+struct FlakyFabric {
+    calls: Mutex<u32>,
+}
+
+#[async_trait]
+impl Adapter for FlakyFabric {
+    fn name(&self) -> &str {
+        "flaky-fabric"
+    }
+
+    async fn execute(
+        &self,
+        _action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        let mut calls = self.calls.lock().unwrap();
+        *calls += 1;
+        if *calls == 1 {
+            anyhow::bail!("transient failure");
+        }
+        Ok(AdapterOutput {
+            content: input.to_uppercase(),
+            tokens_used: Some(42),
+            cost_usd: Some(0.5),
+        })
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: spans_test
+description: A shell step, then a Fabric step that needs a retry
+steps:
+  - name: echo
+    adapter: shell
+    action: cat
+    input_from: pipeline_input
+  - name: shout
+    adapter: fabric
+    action: summarize
+    input_from:
+      previous_step: echo
+    retry_policy:
+      max_attempts: 2
+      initial_delay_ms: 10
+"#;
+
+#[tokio::test]
+async fn test_steps_run_in_spans_with_completion_metrics() {
+    common::init_home();
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(CaptureLayer {
+        events: events.clone(),
+    });
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(FlakyFabric {
+            calls: Mutex::new(0),
+        })
+        .run_pipeline(&pipeline, "hello".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+
+    let events = events.lock().unwrap().clone();
+    let completed: Vec<&Captured> = events
+        .iter()
+        .filter(|event| event.fields.get("message").map(String::as_str) == Some("Step completed"))
+        .collect();
+    assert_eq!(completed.len(), 2);
+
+    let span = |event: &Captured| event.step_span.clone().expect("event is in a step span");
+    let echo = span(completed[0]);
+    assert_eq!(echo["step"], "echo");
+    assert_eq!(echo["adapter"], "shell");
+    assert_eq!(echo["attempt"], "1");
+    assert!(completed[0].fields["duration_ms"].parse::<u64>().is_ok());
+    assert!(!completed[0].fields.contains_key("tokens"));
+
+    // The retry is recorded on the span, the usage on the event
+    let shout = span(completed[1]);
+    assert_eq!(shout["step"], "shout");
+    assert_eq!(shout["adapter"], "fabric");
+    assert_eq!(shout["attempt"], "2");
+    assert_eq!(completed[1].fields["tokens"], "42");
+    assert_eq!(completed[1].fields["cost_usd"], "0.5");
+
+    let retrying = events
+        .iter()
+        .find(|event| {
+            event.fields.get("message").map(String::as_str) == Some("Step failed, retrying")
+        })
+        .unwrap();
+    assert_eq!(span(retrying)["attempt"], "1");
+}
@@ -0,0 +1,118 @@
+//! Streaming Output Integration Tests
+//!
+//! Tests that the final step's adapter output reaches the output stream
+//! chunk by chunk, and that the full output is still stored as its artifact.
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{Orchestrator, Pipeline};
+use arkai::domain::RunState;
+use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream};
+
+/// Stands in for Fabric, producing `<action>:` followed by the input one
+/// word at a time
+struct ChunkingFabric;
+
+impl ChunkingFabric {
+    fn chunks(action: &str, input: &str) -> Vec<String> {
+        std::iter::once(format!("{}:", action))
+            .chain(input.split_whitespace().map(|word| format!(" {}", word)))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Adapter for ChunkingFabric {
+    fn name(&self) -> &str {
+        "chunking-fabric"
+    }
+
+    async fn execute(
+        &self,
+        action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        Ok(AdapterOutput::new(Self::chunks(action, input).concat()))
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn execute_streaming<'a>(
+        &'a self,
+        action: &'a str,
+        input: &'a str,
+        _timeout: Duration,
+    ) -> BoxStream<'a, anyhow::Result<String>> {
+        Box::pin(stream::iter(
+            Self::chunks(action, input).into_iter().map(Ok),
+        ))
+    }
+}
+
+const PIPELINE_YAML: &str = r#"
+name: streaming_test
+description: Two Fabric steps; only the last is streamed
+steps:
+  - name: first
+    adapter: fabric
+    action: extract
+    input_from: pipeline_input
+  - name: last
+    adapter: fabric
+    action: summarize
+    input_from:
+      previous_step: first
+"#;
+
+#[tokio::test]
+async fn test_final_step_output_is_streamed_and_stored() {
+    common::init_home();
+
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let sink = chunks.clone();
+    let pipeline = Pipeline::from_yaml(PIPELINE_YAML).unwrap();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(ChunkingFabric)
+        .with_output_stream(move |chunk| sink.lock().unwrap().push(chunk.to_string()))
+        .run_pipeline(&pipeline, "hello world".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(run.state, RunState::Completed);
+    assert_eq!(run.artifacts["first"].content, "extract: hello world");
+    assert_eq!(
+        run.artifacts["last"].content,
+        "summarize: extract: hello world"
+    );
+    assert_eq!(
+        *chunks.lock().unwrap(),
+        vec!["summarize:", " extract:", " hello", " world"]
+    );
+}
+
+#[tokio::test]
+async fn test_cleaned_final_step_is_not_streamed_raw() {
+    common::init_home();
+
+    // A final step that cleans its output isn't streamed raw
+    let chunks = Arc::new(Mutex::new(Vec::new()));
+    let cleaned =
+        Pipeline::from_yaml(&format!("{}    clean_output: true\n", PIPELINE_YAML)).unwrap();
+    let sink = chunks.clone();
+    let run = Orchestrator::new()
+        .with_fabric_adapter(ChunkingFabric)
+        .with_output_stream(move |chunk| sink.lock().unwrap().push(chunk.to_string()))
+        .run_pipeline(&cleaned, "hello world".to_string())
+        .await
+        .unwrap();
+    assert_eq!(run.state, RunState::Completed);
+    assert!(chunks.lock().unwrap().is_empty());
+}
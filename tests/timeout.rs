@@ -83,6 +83,7 @@ fn test_retry_delay_calculation() {
         initial_delay_ms: 1000,
         max_delay_ms: 10000,
         backoff_multiplier: 2.0,
+        jitter_fraction: 0.0,
     };
 
     // Attempt 1: initial delay
@@ -0,0 +1,119 @@
+//! Voice Summary Integration Tests
+//!
+//! Tests for summarizing voice transcripts through the orchestrator and
+//! including the summary in exported notes.
+
+mod common;
+
+use std::time::Duration;
+
+use arkai::adapters::{Adapter, AdapterOutput};
+use arkai::core::{EventStore, Orchestrator};
+use arkai::domain::VoiceQueueStatus;
+use arkai::ingest::{
+    export_to_vault, exportable, summarize_item, NoteFormat, TranscriptResult, VoiceQueue,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+
+/// Stands in for Fabric: "summarizes" by echoing the pattern and input
+struct MockFabric;
+
+#[async_trait]
+impl Adapter for MockFabric {
+    fn name(&self) -> &str {
+        "mock-fabric"
+    }
+
+    async fn execute(
+        &self,
+        action: &str,
+        input: &str,
+        _timeout: Duration,
+    ) -> anyhow::Result<AdapterOutput> {
+        Ok(AdapterOutput::new(format!(
+            "[{}] {} words about groceries\n",
+            action,
+            input.split_whitespace().count()
+        )))
+    }
+
+    async fn health_check(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_summary_is_recorded_and_exported() {
+    let home = common::init_home();
+
+    let queue = VoiceQueue::new(home.join("voice_queue.jsonl"));
+    let audio = home.join("Shopping.m4a");
+    std::fs::write(&audio, b"fake audio").unwrap();
+    let id = queue
+        .enqueue(&audio, 10, Utc::now())
+        .await
+        .unwrap()
+        .id()
+        .to_string();
+    let transcript = TranscriptResult {
+        text: "Buy milk, eggs and bread on the way home.".to_string(),
+        language: "en".to_string(),
+        duration_seconds: 5.0,
+        segments: Vec::new(),
+    };
+    queue.record_transcript(&id, &transcript).await.unwrap();
+    queue.mark_done(&id).await.unwrap();
+
+    let item = queue.get(&id).await.unwrap().unwrap();
+    assert_eq!(item.status, VoiceQueueStatus::Done);
+    let orchestrator = Orchestrator::new().with_fabric_adapter(MockFabric);
+    let (summary, run_id) = summarize_item(&orchestrator, &queue, &item, "summarize")
+        .await
+        .unwrap();
+    assert_eq!(summary, "[summarize] 9 words about groceries");
+
+    // The summary is a run artifact with its own event log
+    assert_eq!(
+        orchestrator
+            .load_run_output(run_id, Some("summary"))
+            .await
+            .unwrap()
+            .trim(),
+        summary
+    );
+    assert!(!EventStore::open(run_id)
+        .await
+        .unwrap()
+        .replay()
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Stored on the queue item and included in the export
+    let items = queue.replay().await.unwrap();
+    assert_eq!(items[&id].summary.as_deref(), Some(summary.as_str()));
+
+    let vault = home.join("vault");
+    let selected = exportable(items.values(), None);
+    let report = export_to_vault(&selected, &vault, &NoteFormat::default(), false)
+        .await
+        .unwrap();
+    let note = std::fs::read_to_string(&report.written[0]).unwrap();
+    assert!(note.ends_with(
+        "## Summary\n\n[summarize] 9 words about groceries\n\n## Transcript\n\nBuy milk, eggs and bread on the way home.\n"
+    ));
+
+    let templated = NoteFormat {
+        template: Some("{{summary}}\n---\n{{transcript}}".to_string()),
+        filename: "{{id}}".to_string(),
+        ..NoteFormat::default()
+    };
+    let report = export_to_vault(&selected, &vault, &templated, false)
+        .await
+        .unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&report.written[0]).unwrap(),
+        format!("{}\n---\n{}", summary, transcript.text)
+    );
+}